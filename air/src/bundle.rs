@@ -0,0 +1,225 @@
+//! The serialization format of `ProofBundle` is as follows:
+//!
+//! (Metadata)
+//! - `MAGIC_BUNDLE`
+//! - `VERSION`
+//!
+//! (Public inputs)
+//! - `program_info` (`ProgramInfo`)
+//! - `stack_inputs` (`StackInputs`)
+//! - `stack_outputs` (`StackOutputs`)
+//!
+//! (Proof)
+//! - `proof` (`ExecutionProof`)
+//!
+//! (Proof Metadata)
+//! - `prover_version` (`Option<String>`)
+//! - `execution_options` (`Option<ExecutionOptions>`)
+//! - `proving_time_ms` (`Option<u64>`)
+
+use alloc::{format, string::String};
+
+use vm_core::{
+    ProgramInfo, StackInputs, StackOutputs,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+use crate::{ExecutionOptions, ExecutionProof};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic string for detecting that a file is a serialized [ProofBundle].
+const MAGIC_BUNDLE: &[u8; 5] = b"MPRF\0";
+
+/// The version of the [ProofBundle] serialization format.
+const VERSION: [u8; 3] = [0, 0, 0];
+
+// PROOF BUNDLE
+// ================================================================================================
+
+/// A self-describing proof of correct execution of Miden VM.
+///
+/// Unlike [ExecutionProof], a bundle contains all public inputs needed to verify the proof (i.e.,
+/// the program info, and the stack inputs and outputs), as well as optional metadata describing
+/// how the proof was generated.
+#[derive(Debug, Clone)]
+pub struct ProofBundle {
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+    metadata: ProofMetadata,
+}
+
+impl ProofBundle {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ProofBundle] consisting of the specified proof and the public inputs against
+    /// which it should be verified, with empty metadata.
+    pub fn new(
+        program_info: ProgramInfo,
+        stack_inputs: StackInputs,
+        stack_outputs: StackOutputs,
+        proof: ExecutionProof,
+    ) -> Self {
+        Self {
+            program_info,
+            stack_inputs,
+            stack_outputs,
+            proof,
+            metadata: ProofMetadata::default(),
+        }
+    }
+
+    /// Sets the metadata of this bundle.
+    pub fn with_metadata(mut self, metadata: ProofMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the info of the program whose execution the proof attests to.
+    pub fn program_info(&self) -> &ProgramInfo {
+        &self.program_info
+    }
+
+    /// Returns the stack inputs against which the program was executed.
+    pub fn stack_inputs(&self) -> &StackInputs {
+        &self.stack_inputs
+    }
+
+    /// Returns the stack outputs produced by the program.
+    pub fn stack_outputs(&self) -> &StackOutputs {
+        &self.stack_outputs
+    }
+
+    /// Returns the proof of the program's execution.
+    pub fn proof(&self) -> &ExecutionProof {
+        &self.proof
+    }
+
+    /// Returns the metadata describing how the proof was generated.
+    pub fn metadata(&self) -> &ProofMetadata {
+        &self.metadata
+    }
+
+    // DESTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the program info, stack inputs, stack outputs and proof contained in this bundle.
+    pub fn into_parts(self) -> (ProgramInfo, StackInputs, StackOutputs, ExecutionProof) {
+        (self.program_info, self.stack_inputs, self.stack_outputs, self.proof)
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the provided bytes start with the magic string of a serialized
+    /// [ProofBundle].
+    pub fn is_bundle(bytes: &[u8]) -> bool {
+        bytes.starts_with(MAGIC_BUNDLE)
+    }
+}
+
+// PROOF METADATA
+// ================================================================================================
+
+/// Optional information describing how the proof in a [ProofBundle] was generated.
+///
+/// The STARK protocol parameters and the hash function used to generate the proof are always
+/// available via [ExecutionProof].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProofMetadata {
+    /// Version of the prover which generated the proof.
+    pub prover_version: Option<String>,
+    /// Execution options with which the program was executed.
+    pub execution_options: Option<ExecutionOptions>,
+    /// Time it took to generate the proof, in milliseconds.
+    pub proving_time_ms: Option<u64>,
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ProofBundle {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // Write magic & version
+        target.write_bytes(MAGIC_BUNDLE);
+        target.write_bytes(&VERSION);
+
+        // Write public inputs
+        self.program_info.write_into(target);
+        self.stack_inputs.write_into(target);
+        self.stack_outputs.write_into(target);
+
+        // Write proof
+        self.proof.write_into(target);
+
+        // Write metadata
+        self.metadata.write_into(target);
+    }
+}
+
+impl Deserializable for ProofBundle {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // Read and validate magic & version
+        let magic: [u8; 5] = source.read_array()?;
+        if magic != *MAGIC_BUNDLE {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid magic bytes. Expected '{MAGIC_BUNDLE:?}', got '{magic:?}'"
+            )));
+        }
+
+        let version: [u8; 3] = source.read_array()?;
+        if version != VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported version. Got '{version:?}', but only '{VERSION:?}' is supported"
+            )));
+        }
+
+        // Read public inputs
+        let program_info = ProgramInfo::read_from(source)?;
+        let stack_inputs = StackInputs::read_from(source)?;
+        let stack_outputs = StackOutputs::read_from(source)?;
+
+        // Read proof
+        let proof = ExecutionProof::read_from(source)?;
+
+        // Read metadata
+        let metadata = ProofMetadata::read_from(source)?;
+
+        Ok(Self {
+            program_info,
+            stack_inputs,
+            stack_outputs,
+            proof,
+            metadata,
+        })
+    }
+}
+
+impl Serializable for ProofMetadata {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.prover_version.write_into(target);
+        self.execution_options.write_into(target);
+        self.proving_time_ms.write_into(target);
+    }
+}
+
+impl Deserializable for ProofMetadata {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let prover_version = Option::<String>::read_from(source)?;
+        let execution_options = Option::<ExecutionOptions>::read_from(source)?;
+        let proving_time_ms = Option::<u64>::read_from(source)?;
+
+        Ok(Self {
+            prover_version,
+            execution_options,
+            proving_time_ms,
+        })
+    }
+}
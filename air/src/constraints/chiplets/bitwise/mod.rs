@@ -22,6 +22,27 @@ pub mod tests;
 /// The number of transition constraints on the bitwise chiplet.
 pub const NUM_CONSTRAINTS: usize = 17;
 
+/// The names of the constraints on the bitwise chiplet, in the same order as their degrees.
+pub const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] = [
+    "selector_binary",
+    "selector_copy",
+    "a0_binary",
+    "a1_binary",
+    "a2_binary",
+    "a3_binary",
+    "b0_binary",
+    "b1_binary",
+    "b2_binary",
+    "b3_binary",
+    "a_first_row",
+    "b_first_row",
+    "a_agg",
+    "b_agg",
+    "output_prev_first_row",
+    "output_prev_copy",
+    "output_agg",
+];
+
 // PERIODIC COLUMNS
 // ================================================================================================
 
@@ -21,6 +21,40 @@ mod tests;
 /// The number of constraints on the management of the hash chiplet.
 pub const NUM_CONSTRAINTS: usize = 30;
 
+/// The names of the constraints on the hash chiplet, in the same order as their degrees.
+pub const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] = [
+    "s0_binary",
+    "s1_binary",
+    "s2_binary",
+    "s1_copy",
+    "s2_copy",
+    "s0_next_zero",
+    "flag_combinations",
+    "node_index_out",
+    "node_index_bit_binary",
+    "node_index_copy",
+    "rpo_round::h0",
+    "rpo_round::h1",
+    "rpo_round::h2",
+    "rpo_round::h3",
+    "rpo_round::h4",
+    "rpo_round::h5",
+    "rpo_round::h6",
+    "rpo_round::h7",
+    "rpo_round::h8",
+    "rpo_round::h9",
+    "rpo_round::h10",
+    "rpo_round::h11",
+    "capacity_copy::h0",
+    "capacity_copy::h1",
+    "capacity_copy::h2",
+    "capacity_copy::h3",
+    "merkle_absorb::h4",
+    "merkle_absorb::h5",
+    "merkle_absorb::h6",
+    "merkle_absorb::h7",
+];
+
 /// The number of periodic columns which are used as selectors to specify a particular row or rows
 /// within the hash cycle.
 pub const NUM_PERIODIC_SELECTOR_COLUMNS: usize = 3;
@@ -33,6 +33,29 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     9, 9, 9, 9, // Constrain the values in all rows of the chiplet except the first.
 ];
 
+/// The names of the constraints on the memory chiplet, in the same order as their degrees.
+pub const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] = [
+    "is_read_binary",
+    "is_word_access_binary",
+    "idx0_binary",
+    "idx1_binary",
+    "n0_binary",
+    "n0_on_ctx_change",
+    "n1_binary",
+    "word_addr_no_change",
+    "delta",
+    "f_scw",
+    "same_clk_reads",
+    "v0_first_row",
+    "v1_first_row",
+    "v2_first_row",
+    "v3_first_row",
+    "v0",
+    "v1",
+    "v2",
+    "v3",
+];
+
 // MEMORY TRANSITION CONSTRAINTS
 // ================================================================================================
 
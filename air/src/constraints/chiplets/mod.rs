@@ -1,9 +1,9 @@
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use super::super::{
     CHIPLETS_OFFSET, EvaluationFrame, Felt, FieldElement, TransitionConstraintDegree,
 };
-use crate::utils::{are_equal, binary_not, is_binary};
+use crate::utils::{are_equal, binary_not, constraint_names, is_binary};
 
 mod bitwise;
 mod hasher;
@@ -21,6 +21,17 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     2, 3, 4, // Selector flags can only change from 0 -> 1.
 ];
 
+/// The names of the constraints on the management of the Chiplets module, in the same order as
+/// their degrees.
+pub const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] = [
+    "s0_binary",
+    "s1_binary",
+    "s2_binary",
+    "s0_monotonic",
+    "s1_monotonic",
+    "s2_monotonic",
+];
+
 // PERIODIC COLUMNS
 // ================================================================================================
 
@@ -50,6 +61,17 @@ pub fn get_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
     degrees
 }
 
+/// Returns the names of the transition constraints for the chiplets module and all chiplet
+/// components, in the same order as their degrees.
+pub fn get_transition_constraint_names() -> Vec<String> {
+    let mut names = constraint_names("chiplets::selectors", &CONSTRAINT_NAMES);
+    names.append(&mut constraint_names("chiplets::hasher", &hasher::CONSTRAINT_NAMES));
+    names.append(&mut constraint_names("chiplets::bitwise", &bitwise::CONSTRAINT_NAMES));
+    names.append(&mut constraint_names("chiplets::memory", &memory::CONSTRAINT_NAMES));
+
+    names
+}
+
 /// Returns the number of transition constraints for the chiplets.
 pub fn get_transition_constraint_count() -> usize {
     NUM_CONSTRAINTS
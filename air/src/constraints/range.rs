@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use vm_core::{ExtensionOf, ZERO};
 
@@ -7,7 +7,7 @@ use crate::{
     chiplets::ChipletsFrameExt,
     constraints::MainFrameExt,
     trace::range::{B_RANGE_COL_IDX, M_COL_IDX, V_COL_IDX},
-    utils::are_equal,
+    utils::{are_equal, constraint_names},
 };
 
 // CONSTANTS
@@ -24,6 +24,8 @@ pub const NUM_CONSTRAINTS: usize = 1;
 pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     9, // Enforce values of column v transition.
 ];
+/// The names of the range checker's constraints, in the same order as their degrees.
+pub const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] = ["v_delta"];
 
 // --- Auxiliary column constraints for multiset checks -------------------------------------------
 
@@ -33,6 +35,8 @@ pub const NUM_AUX_ASSERTIONS: usize = 2;
 pub const NUM_AUX_CONSTRAINTS: usize = 1;
 /// The degrees of the Range Checker's auxiliary column constraints, used for multiset checks.
 pub const AUX_CONSTRAINT_DEGREES: [usize; NUM_AUX_CONSTRAINTS] = [9];
+/// The names of the Range Checker's auxiliary column constraints, used for multiset checks.
+pub const AUX_CONSTRAINT_NAMES: [&str; NUM_AUX_CONSTRAINTS] = ["b_range"];
 
 // BOUNDARY CONSTRAINTS
 // ================================================================================================
@@ -81,6 +85,11 @@ pub fn get_transition_constraint_count() -> usize {
     NUM_CONSTRAINTS
 }
 
+/// Returns the names of the range checker's transition constraints.
+pub fn get_transition_constraint_names() -> Vec<String> {
+    constraint_names("range", &CONSTRAINT_NAMES)
+}
+
 /// Enforces constraints for the range checker.
 pub fn enforce_constraints<E: FieldElement>(frame: &EvaluationFrame<E>, result: &mut [E]) {
     // Constrain the transition of the value column between rows in the range checker table.
@@ -106,6 +115,11 @@ pub fn get_aux_transition_constraint_degrees() -> Vec<TransitionConstraintDegree
         .collect()
 }
 
+/// Returns the names of the transition constraints for the range checker's auxiliary columns.
+pub fn get_aux_transition_constraint_names() -> Vec<String> {
+    constraint_names("range", &AUX_CONSTRAINT_NAMES)
+}
+
 /// Enforces constraints on the range checker's auxiliary columns.
 pub fn enforce_aux_constraints<F, E>(
     main_frame: &EvaluationFrame<F>,
@@ -33,6 +33,32 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     8, 8, 9, 9, // four constraints for EXT2MUL field operation.
 ];
 
+/// The names of the constraints in the field operations, in the same order as their degrees.
+pub const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] = [
+    "add",
+    "neg",
+    "mul",
+    "inv",
+    "incr",
+    "not",
+    "and::b_binary",
+    "and::result",
+    "or::b_binary",
+    "or::result",
+    "eq::diff_or_result_zero",
+    "eq::result",
+    "eqz::top_or_result_zero",
+    "eqz::result",
+    "expacc::exp_square",
+    "expacc::val",
+    "expacc::acc",
+    "expacc::b_shift",
+    "ext2mul::c1",
+    "ext2mul::c0",
+    "ext2mul::d1",
+    "ext2mul::d0",
+];
+
 // FIELD OPERATIONS TRANSITION CONSTRAINTS
 // ================================================================================================
 
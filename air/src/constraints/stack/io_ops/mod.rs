@@ -19,6 +19,10 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     8, // constraint for SDEPTH operation.
 ];
 
+/// The names of the constraints in the input/output operations, in the same order as their
+/// degrees.
+pub const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] = ["sdepth"];
+
 // INPUT/OUTPUT OPERATIONS TRANSITION CONSTRAINTS
 // ================================================================================================
 
@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use vm_core::{StackOutputs, stack::MIN_STACK_DEPTH};
 
@@ -8,7 +8,7 @@ use super::super::{
 };
 use crate::{
     decoder::{IS_CALL_FLAG_COL_IDX, IS_SYSCALL_FLAG_COL_IDX, USER_OP_HELPERS_OFFSET},
-    utils::{are_equal, is_binary},
+    utils::{are_equal, constraint_names, is_binary},
 };
 
 pub mod field_ops;
@@ -51,6 +51,28 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_GENERAL_CONSTRAINTS] = [
     8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 9,
 ];
 
+/// The names of the general stack constraints: one for each of the 16 stack positions, followed by
+/// the constraint on the top element being binary.
+pub const CONSTRAINT_NAMES: [&str; NUM_GENERAL_CONSTRAINTS] = [
+    "s0",
+    "s1",
+    "s2",
+    "s3",
+    "s4",
+    "s5",
+    "s6",
+    "s7",
+    "s8",
+    "s9",
+    "s10",
+    "s11",
+    "s12",
+    "s13",
+    "s14",
+    "s15",
+    "s0_binary",
+];
+
 // --- Auxiliary column constraints ---------------------------------------------------------------
 
 /// The number of auxiliary assertions.
@@ -83,6 +105,23 @@ pub fn get_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
     degrees
 }
 
+/// Returns the names of the transition constraints for the stack module and all the stack
+/// operations, in the same order as their degrees.
+pub fn get_transition_constraint_names() -> Vec<String> {
+    let mut names = constraint_names("stack::overflow", &overflow::CONSTRAINT_NAMES);
+    names.append(&mut constraint_names("stack::system_ops", &system_ops::CONSTRAINT_NAMES));
+    names.append(&mut constraint_names("stack::field_ops", &field_ops::CONSTRAINT_NAMES));
+    names.append(&mut constraint_names(
+        "stack::stack_manipulation",
+        &stack_manipulation::CONSTRAINT_NAMES,
+    ));
+    names.append(&mut constraint_names("stack::u32_ops", &u32_ops::CONSTRAINT_NAMES));
+    names.append(&mut constraint_names("stack::io_ops", &io_ops::CONSTRAINT_NAMES));
+    names.append(&mut constraint_names("stack::general", &CONSTRAINT_NAMES));
+
+    names
+}
+
 /// Returns the number of transition constraints for the stack operations.
 pub fn get_transition_constraint_count() -> usize {
     overflow::get_transition_constraint_count()
@@ -19,6 +19,10 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     7, 8, // constraint for stack overflow bookkeeping index, b1.
 ];
 
+/// The names of the stack overflow constraints, in the same order as their degrees.
+pub const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] =
+    ["depth", "overflow_flag", "overflow_addr", "last_item_on_left_shift"];
+
 // STACK OVERFLOW TRANSITION CONSTRAINTS
 // ================================================================================================
 
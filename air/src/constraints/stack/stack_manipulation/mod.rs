@@ -29,6 +29,60 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     9, 9, 9, 9, 9, 9, 9, 9, 9, 9, // 10 constraints for CSWAP and CSWAPW operations
 ];
 
+/// The names of the constraints in the stack manipulation operations, in the same order as their
+/// degrees.
+pub const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] = [
+    "pad",
+    "dup",
+    "dup1",
+    "dup_movup2",
+    "dup_movup3",
+    "dup_movup4",
+    "dup_movup5",
+    "dup_movup6",
+    "dup_movup7",
+    "movup8",
+    "dup9",
+    "dup11",
+    "dup13",
+    "dup15",
+    "swap::s0",
+    "swap::s1",
+    "swapwx::s0",
+    "swapwx::s1",
+    "swapwx::s2",
+    "swapwx::s3",
+    "swapwx::s0_next",
+    "swapwx::s1_next",
+    "swapwx::s2_next",
+    "swapwx::s3_next",
+    "swapdw::s4",
+    "swapdw::s5",
+    "swapdw::s6",
+    "swapdw::s7",
+    "swapdw::s12",
+    "swapdw::s13",
+    "swapdw::s14",
+    "swapdw::s15",
+    "movdn2",
+    "movdn3",
+    "movdn4",
+    "movdn5",
+    "movdn6",
+    "movdn7",
+    "movdn8",
+    "cswap::s0_next",
+    "cswap::s1_next",
+    "cswapw::s0_next",
+    "cswapw::s1_next",
+    "cswapw::s2_next",
+    "cswapw::s3_next",
+    "cswapw::s4_next",
+    "cswapw::s5_next",
+    "cswapw::s6_next",
+    "cswapw::s7_next",
+];
+
 // STACK MANIPULATION OPERATIONS TRANSITION CONSTRAINTS
 // ================================================================================================
 
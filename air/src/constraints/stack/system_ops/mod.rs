@@ -21,6 +21,9 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     8, // constraint for FMPUPDATE operation.
 ];
 
+/// The names of the constraints in the system operations, in the same order as their degrees.
+pub const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] = ["assert", "fmpadd", "fmpupdate"];
+
 // SYSTEM OPERATIONS TRANSITION CONSTRAINTS
 // ================================================================================================
 
@@ -43,6 +43,23 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     8, 7, 7, // constraint for U32DIV operation
 ];
 
+/// The names of the constraints in the u32 operations, in the same order as their degrees.
+pub const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] = [
+    "element_validity",
+    "limbs_agg::lo",
+    "limbs_agg::hi",
+    "u32split",
+    "u32add",
+    "u32add3",
+    "u32sub::diff",
+    "u32sub::borrow_binary",
+    "u32mul",
+    "u32madd",
+    "u32div::product",
+    "u32div::lo",
+    "u32div::hi",
+];
+
 // U32 OPERATIONS TRANSITION CONSTRAINTS
 // ================================================================================================
 
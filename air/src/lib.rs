@@ -6,7 +6,7 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use vm_core::{
     ExtensionOf, ONE, ProgramInfo, StackInputs, StackOutputs, ZERO,
@@ -26,6 +26,7 @@ pub mod trace;
 pub use trace::rows::RowIndex;
 use trace::*;
 
+mod bundle;
 mod errors;
 mod options;
 mod proof;
@@ -33,6 +34,7 @@ mod proof;
 mod utils;
 // RE-EXPORTS
 // ================================================================================================
+pub use bundle::{ProofBundle, ProofMetadata};
 pub use errors::ExecutionOptionsError;
 pub use options::{ExecutionOptions, ProvingOptions};
 pub use proof::{ExecutionProof, HashFunction};
@@ -59,6 +61,22 @@ impl ProcessorAir {
     pub fn last_step(&self) -> usize {
         self.trace_length() - self.context().num_transition_exemptions()
     }
+
+    /// Returns the names of the main trace transition constraints, in the order in which their
+    /// evaluations are written by [Air::evaluate_transition()].
+    pub fn transition_constraint_names() -> Vec<String> {
+        let mut names = vec![String::from("system::clk")];
+        names.append(&mut stack::get_transition_constraint_names());
+        names.append(&mut range::get_transition_constraint_names());
+        names.append(&mut chiplets::get_transition_constraint_names());
+        names
+    }
+
+    /// Returns the names of the auxiliary trace transition constraints, in the order in which
+    /// their evaluations are written by [Air::evaluate_aux_transition()].
+    pub fn aux_transition_constraint_names() -> Vec<String> {
+        range::get_aux_transition_constraint_names()
+    }
 }
 
 impl Air for ProcessorAir {
@@ -250,7 +268,7 @@ impl Air for ProcessorAir {
 // PUBLIC INPUTS
 // ================================================================================================
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PublicInputs {
     program_info: ProgramInfo,
     stack_inputs: StackInputs,
@@ -269,6 +287,20 @@ impl PublicInputs {
             stack_outputs,
         }
     }
+    /// Returns the info of the program whose execution these public inputs describe.
+    pub fn program_info(&self) -> &ProgramInfo {
+        &self.program_info
+    }
+
+    /// Returns the inputs with which the program was executed.
+    pub fn stack_inputs(&self) -> &StackInputs {
+        &self.stack_inputs
+    }
+
+    /// Returns the outputs left on the stack by the execution of the program.
+    pub fn stack_outputs(&self) -> &StackOutputs {
+        &self.stack_outputs
+    }
 }
 
 impl vm_core::ToElements<Felt> for PublicInputs {
@@ -1,3 +1,6 @@
+use alloc::string::ToString;
+
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use winter_air::BatchingMethod;
 
 use super::{
@@ -8,6 +11,11 @@ use super::{
 // ================================================================================================
 
 /// A set of parameters specifying how Miden VM execution proofs are to be generated.
+///
+/// Proofs generated with any of these parameters are not zero-knowledge: the STARK backend does not
+/// randomize the execution trace nor hide the composition polynomial, and thus the values of the
+/// trace at the queried rows of the low-degree extension may leak information about the secret
+/// inputs of the program (e.g., the data provided via the advice provider).
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ProvingOptions {
     exec_options: ExecutionOptions,
@@ -156,6 +164,26 @@ impl ProvingOptions {
         }
     }
 
+    /// Creates a new preset instance of [ProvingOptions] targeting 96-bit security level,
+    /// using the Poseidon2 hashing function.
+    pub fn with_96_bit_security_poseidon2() -> Self {
+        Self {
+            exec_options: ExecutionOptions::default(),
+            proof_options: Self::RECURSIVE_96_BITS,
+            hash_fn: HashFunction::Poseidon2,
+        }
+    }
+
+    /// Creates a new preset instance of [ProvingOptions] targeting 128-bit security level,
+    /// using the Poseidon2 hashing function.
+    pub fn with_128_bit_security_poseidon2() -> Self {
+        Self {
+            exec_options: ExecutionOptions::default(),
+            proof_options: Self::RECURSIVE_128_BITS,
+            hash_fn: HashFunction::Poseidon2,
+        }
+    }
+
     /// Sets [ExecutionOptions] for this [ProvingOptions].
     ///
     /// This sets the maximum number of cycles a program is allowed to execute as well as
@@ -297,3 +325,27 @@ impl ExecutionOptions {
         self.enable_debugging
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ExecutionOptions {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.max_cycles);
+        target.write_u32(self.expected_cycles);
+        target.write_bool(self.enable_tracing);
+        target.write_bool(self.enable_debugging);
+    }
+}
+
+impl Deserializable for ExecutionOptions {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let max_cycles = source.read_u32()?;
+        let expected_cycles = source.read_u32()?;
+        let enable_tracing = source.read_bool()?;
+        let enable_debugging = source.read_bool()?;
+
+        Self::new(Some(max_cycles), expected_cycles, enable_tracing, enable_debugging)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
@@ -1,5 +1,7 @@
 #[cfg(any(test, feature = "testing"))]
 use alloc::vec::Vec;
+#[cfg(any(test, feature = "testing"))]
+use core::ops::DerefMut;
 use core::ops::{Deref, Range};
 
 use vm_core::{Felt, ONE, Word, ZERO, utils::range};
@@ -46,6 +48,13 @@ impl Deref for MainTrace {
     }
 }
 
+#[cfg(any(test, feature = "testing"))]
+impl DerefMut for MainTrace {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.columns
+    }
+}
+
 impl MainTrace {
     pub fn new(main_trace: ColMatrix<Felt>, last_program_row: RowIndex) -> Self {
         Self { columns: main_trace, last_program_row }
@@ -1,3 +1,4 @@
+use alloc::{format, string::String, vec::Vec};
 use core::ops::Range;
 
 use vm_core::utils::range;
@@ -103,3 +104,63 @@ pub const AUX_TRACE_WIDTH: usize = CHIPLETS_AUX_TRACE_RANGE.end;
 /// Number of random elements available to the prover after the commitment to the main trace
 /// segment.
 pub const AUX_TRACE_RAND_ELEMENTS: usize = 16;
+
+// COLUMN NAMES
+// ================================================================================================
+
+/// Returns the names of the columns of the main trace, in the order of the main trace layout.
+///
+/// Columns are prefixed with the component they belong to (e.g. `decoder::addr`), except for the
+/// system columns and the top of the stack (e.g. `stack[0]`). Chiplet columns are named by their
+/// index, since their meaning depends on the chiplet of the row.
+pub fn main_trace_column_names() -> Vec<String> {
+    let mut names = Vec::with_capacity(TRACE_WIDTH);
+
+    // system
+    names.extend(["clk", "fmp", "ctx", "in_syscall"].map(String::from));
+    names.extend((0..FN_HASH_RANGE.len()).map(|i| format!("fn_hash[{i}]")));
+
+    // decoder
+    names.push("decoder::addr".into());
+    names.extend((0..decoder::NUM_OP_BITS).map(|i| format!("decoder::op_bits[{i}]")));
+    names.extend((0..decoder::NUM_HASHER_COLUMNS).map(|i| format!("decoder::hasher[{i}]")));
+    names.extend(
+        ["decoder::in_span", "decoder::group_count", "decoder::op_index"].map(String::from),
+    );
+    names.extend((0..decoder::NUM_OP_BATCH_FLAGS).map(|i| format!("decoder::op_batch_flags[{i}]")));
+    names.extend(
+        (0..decoder::NUM_OP_BITS_EXTRA_COLS).map(|i| format!("decoder::op_bits_extra[{i}]")),
+    );
+
+    // stack
+    names.extend(stack::STACK_TOP_RANGE.map(|i| format!("stack[{i}]")));
+    names.extend(["stack::b0", "stack::b1", "stack::h0"].map(String::from));
+
+    // range checks
+    names.extend(["range::m", "range::v"].map(String::from));
+
+    // chiplets
+    names.extend((0..CHIPLETS_WIDTH).map(|i| format!("chiplets[{i}]")));
+
+    debug_assert_eq!(names.len(), TRACE_WIDTH);
+    names
+}
+
+/// Returns the names of the columns of the auxiliary trace, in the order of the auxiliary trace
+/// layout.
+pub fn aux_trace_column_names() -> Vec<String> {
+    let names = [
+        "decoder::p1",
+        "decoder::p2",
+        "decoder::p3",
+        "stack::p1",
+        "range::b_range",
+        "chiplets::vtable",
+        "chiplets::bus",
+    ]
+    .map(String::from)
+    .to_vec();
+
+    debug_assert_eq!(names.len(), AUX_TRACE_WIDTH);
+    names
+}
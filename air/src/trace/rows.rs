@@ -4,7 +4,10 @@ use core::{
     ops::{Add, AddAssign, Bound, Index, IndexMut, Mul, RangeBounds, Sub, SubAssign},
 };
 
-use vm_core::Felt;
+use vm_core::{
+    Felt,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 
 /// Represents the types of errors that can occur when converting from and into [`RowIndex`] and
 /// using its operations.
@@ -38,6 +41,18 @@ impl Display for RowIndex {
     }
 }
 
+impl Serializable for RowIndex {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.0);
+    }
+}
+
+impl Deserializable for RowIndex {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        source.read_u32().map(Self)
+    }
+}
+
 // FROM ROW INDEX
 // ================================================================================================
 
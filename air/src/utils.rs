@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use core::ops::Range;
 
 use vm_core::utils::range as create_range;
@@ -28,6 +28,15 @@ pub fn is_zero<E: FieldElement>(v: E) -> E {
     v
 }
 
+// CONSTRAINT NAMES
+// ================================================================================================
+
+/// Returns the names of the specified constraints qualified by the module which defines them
+/// (e.g. `stack::field_ops::mul`).
+pub fn constraint_names(module: &str, names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| format!("{module}::{name}")).collect()
+}
+
 // TRAIT TO SIMPLIFY CONSTRAINT AGGREGATION
 // ================================================================================================
 
@@ -148,4 +157,28 @@ mod tests {
         let selected_range = select_result_range!(&mut result, range);
         assert_eq!(selected_range, [6]);
     }
+
+    #[test]
+    fn constraint_names() {
+        use crate::{
+            ProcessorAir,
+            constraints::{chiplets, range, stack},
+        };
+
+        let num_constraints = 1
+            + stack::get_transition_constraint_degrees().len()
+            + range::get_transition_constraint_degrees().len()
+            + chiplets::get_transition_constraint_degrees().len();
+        let names = ProcessorAir::transition_constraint_names();
+        assert_eq!(names.len(), num_constraints);
+        assert_eq!(names[0], "system::clk");
+        assert_eq!(names[1 + stack::get_transition_constraint_count()], "range::v_delta");
+
+        // every constraint is identified by its own name
+        let unique_names: alloc::collections::BTreeSet<_> = names.iter().collect();
+        assert_eq!(unique_names.len(), names.len());
+
+        let names = ProcessorAir::aux_transition_constraint_names();
+        assert_eq!(names.len(), range::get_aux_transition_constraint_degrees().len());
+    }
 }
@@ -1,8 +1,4 @@
-use alloc::{
-    collections::{BTreeMap, BTreeSet},
-    sync::Arc,
-    vec::Vec,
-};
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
 use core::ops::{Index, IndexMut};
 
 use miette::{IntoDiagnostic, Report};
@@ -22,7 +18,7 @@ use crate::{AssemblyError, Library};
 // ================================================================================================
 
 /// Constant that decides how many operation batches disqualify a procedure from inlining.
-const PROCEDURE_INLINING_THRESHOLD: usize = 32;
+pub(crate) const PROCEDURE_INLINING_THRESHOLD: usize = 32;
 
 // MAST FOREST BUILDER
 // ================================================================================================
@@ -58,10 +54,6 @@ pub struct MastForestBuilder {
     hash_by_node_id: BTreeMap<MastNodeId, MastNodeFingerprint>,
     /// A map of decorator fingerprints to their corresponding positions in the MAST forest.
     decorator_id_by_fingerprint: BTreeMap<DecoratorFingerprint, DecoratorId>,
-    /// A set of IDs for basic blocks which have been merged into a bigger basic blocks. This is
-    /// used as a candidate set of nodes that may be eliminated if the are not referenced by any
-    /// other node in the forest and are not a root of any procedure.
-    merged_basic_block_ids: BTreeSet<MastNodeId>,
     /// A MastForest that contains vendored libraries, it's used to find precompiled procedures and
     /// copy their subtrees instead of inserting external nodes.
     vendored_mast: Arc<MastForest>,
@@ -92,71 +84,42 @@ impl MastForestBuilder {
         })
     }
 
+    /// Returns an empty builder which has access to the same vendored libraries as this builder.
+    pub fn fork(&self) -> Self {
+        let mut mast_forest = MastForest::default();
+        *mast_forest.advice_map_mut() = self.mast_forest.advice_map().clone();
+        MastForestBuilder {
+            mast_forest,
+            vendored_mast: self.vendored_mast.clone(),
+            ..Self::default()
+        }
+    }
+
     /// Removes the unused nodes that were created as part of the assembly process, and returns the
     /// resulting MAST forest.
     ///
+    /// The nodes of the returned forest are laid out in a canonical order: the subtrees of the
+    /// procedure roots are copied one after the other, in the order of the roots. Thus, the
+    /// returned forest does not depend on the order in which nodes were added to this builder,
+    /// e.g., on whether procedures were compiled in parallel or copied from a module cache. Nodes
+    /// which are not reachable from any procedure root are removed.
+    ///
     /// It also returns the map from old node IDs to new node IDs. Any [`MastNodeId`] used in
     /// reference to the old [`MastForest`] should be remapped using this map.
-    pub fn build(mut self) -> (MastForest, BTreeMap<MastNodeId, MastNodeId>) {
-        let nodes_to_remove = get_nodes_to_remove(self.merged_basic_block_ids, &self.mast_forest);
-        let id_remappings = self.mast_forest.remove_nodes(&nodes_to_remove);
-
-        (self.mast_forest, id_remappings)
-    }
-}
-
-/// Takes the set of MAST node ids (all basic blocks) that were merged as part of the assembly
-/// process (i.e. they were contiguous and were merged into a single basic block), and returns the
-/// subset of nodes that can be removed from the MAST forest.
-///
-/// Specifically, MAST node ids can be reused, so merging a basic block doesn't mean it should be
-/// removed (specifically in the case where another node refers to it). Hence, we cycle through all
-/// nodes of the forest and only mark for removal those nodes that are not referenced by any node.
-/// We also ensure that procedure roots are not removed.
-fn get_nodes_to_remove(
-    merged_node_ids: BTreeSet<MastNodeId>,
-    mast_forest: &MastForest,
-) -> BTreeSet<MastNodeId> {
-    // make sure not to remove procedure roots
-    let mut nodes_to_remove: BTreeSet<MastNodeId> = merged_node_ids
-        .iter()
-        .filter(|&&mast_node_id| !mast_forest.is_procedure_root(mast_node_id))
-        .copied()
-        .collect();
-
-    for node in mast_forest.nodes() {
-        match node {
-            MastNode::Join(node) => {
-                if nodes_to_remove.contains(&node.first()) {
-                    nodes_to_remove.remove(&node.first());
-                }
-                if nodes_to_remove.contains(&node.second()) {
-                    nodes_to_remove.remove(&node.second());
-                }
-            },
-            MastNode::Split(node) => {
-                if nodes_to_remove.contains(&node.on_true()) {
-                    nodes_to_remove.remove(&node.on_true());
-                }
-                if nodes_to_remove.contains(&node.on_false()) {
-                    nodes_to_remove.remove(&node.on_false());
-                }
-            },
-            MastNode::Loop(node) => {
-                if nodes_to_remove.contains(&node.body()) {
-                    nodes_to_remove.remove(&node.body());
-                }
-            },
-            MastNode::Call(node) => {
-                if nodes_to_remove.contains(&node.callee()) {
-                    nodes_to_remove.remove(&node.callee());
-                }
-            },
-            MastNode::Block(_) | MastNode::Dyn(_) | MastNode::External(_) => (),
+    pub fn build(self) -> (MastForest, BTreeMap<MastNodeId, MastNodeId>) {
+        let mut builder = self.fork();
+        let mut importer = SubtreeImporter::default();
+        for &root in self.mast_forest.procedure_roots() {
+            // the copy has at most as many nodes and decorators as the original forest, so the
+            // import cannot fail
+            let new_root = importer
+                .import(&self.mast_forest, &mut builder, root)
+                .expect("failed to copy a procedure of a valid MAST forest");
+            builder.make_root(new_root);
         }
-    }
 
-    nodes_to_remove
+        (builder.mast_forest, importer.nodes)
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -183,6 +146,17 @@ impl MastForestBuilder {
     pub fn get_mast_node(&self, id: MastNodeId) -> Option<&MastNode> {
         self.mast_forest.get_node_by_id(id)
     }
+
+    /// Returns a reference to the MAST forest being built.
+    pub fn mast_forest(&self) -> &MastForest {
+        &self.mast_forest
+    }
+
+    /// Returns an iterator over the procedures added to this MAST forest builder.
+    #[cfg(feature = "std")]
+    pub fn procedures(&self) -> impl Iterator<Item = (GlobalProcedureIndex, &Procedure)> {
+        self.procedures.iter().map(|(&gid, procedure)| (gid, procedure))
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -353,9 +327,6 @@ impl MastForestBuilder {
             }
         }
 
-        // Mark the removed basic blocks as merged
-        self.merged_basic_block_ids.extend(contiguous_basic_block_ids.iter());
-
         if !operations.is_empty() || !decorators.is_empty() {
             let merged_basic_block = self.ensure_block(operations, Some(decorators))?;
             merged_basic_blocks.push(merged_basic_block);
@@ -510,6 +481,12 @@ impl MastForestBuilder {
         let new_node_fingerprint = self.fingerprint_for_node(&self[node_id]);
         self.hash_by_node_id.insert(node_id, new_node_fingerprint);
     }
+
+    /// Marks the specified node as the root of a procedure, so that it is retained by
+    /// [`Self::build`].
+    pub fn make_root(&mut self, node_id: MastNodeId) {
+        self.mast_forest.make_root(node_id);
+    }
 }
 
 impl MastForestBuilder {
@@ -544,6 +521,95 @@ impl IndexMut<DecoratorId> for MastForestBuilder {
     }
 }
 
+// SUBTREE IMPORTER
+// ================================================================================================
+
+/// Copies subtrees of a [`MastForest`] into a [`MastForestBuilder`], together with their
+/// decorators.
+///
+/// The importer keeps track of the nodes and decorators it has already copied, so that nodes shared
+/// by multiple imported subtrees are visited only once. Thus, a given importer must always be used
+/// with the same source forest and builder.
+#[derive(Debug, Default)]
+pub struct SubtreeImporter {
+    /// The ids of the nodes copied so far, in the forest of the builder.
+    nodes: Remapping,
+    /// The ids of the decorators copied so far, in the forest of the builder.
+    decorators: BTreeMap<DecoratorId, DecoratorId>,
+}
+
+impl SubtreeImporter {
+    /// Copies the subtree rooted at `root` in `forest` into `builder`, and returns the id of the
+    /// root in the forest of the builder.
+    pub fn import(
+        &mut self,
+        forest: &MastForest,
+        builder: &mut MastForestBuilder,
+        root: MastNodeId,
+    ) -> Result<MastNodeId, AssemblyError> {
+        // visit the subtree in post-order, so that children are copied before their parents
+        let mut stack = vec![(root, false)];
+        while let Some((node_id, children_visited)) = stack.pop() {
+            if self.nodes.contains_key(&node_id) {
+                continue;
+            }
+            let node = &forest[node_id];
+            if !children_visited && node.has_children() {
+                let mut children = Vec::new();
+                node.append_children_to(&mut children);
+                stack.push((node_id, true));
+                stack.extend(children.into_iter().map(|child| (child, false)));
+                continue;
+            }
+
+            let mut node = node.remap_children(&self.nodes);
+            if let MastNode::Block(block) = &mut node {
+                let decorators = block
+                    .decorators()
+                    .iter()
+                    .map(|&(op_idx, id)| Ok((op_idx, self.import_decorator(forest, builder, id)?)))
+                    .collect::<Result<DecoratorList, AssemblyError>>()?;
+                block.set_decorators(decorators);
+            } else {
+                let before_enter = self.import_decorators(forest, builder, node.before_enter())?;
+                let after_exit = self.import_decorators(forest, builder, node.after_exit())?;
+                node.set_before_enter(before_enter);
+                node.set_after_exit(after_exit);
+            }
+            let new_node_id = builder.ensure_node(node)?;
+            self.nodes.insert(node_id, new_node_id);
+        }
+
+        Ok(self.nodes[&root])
+    }
+
+    fn import_decorators(
+        &mut self,
+        forest: &MastForest,
+        builder: &mut MastForestBuilder,
+        decorator_ids: &[DecoratorId],
+    ) -> Result<Vec<DecoratorId>, AssemblyError> {
+        decorator_ids
+            .iter()
+            .map(|&decorator_id| self.import_decorator(forest, builder, decorator_id))
+            .collect()
+    }
+
+    fn import_decorator(
+        &mut self,
+        forest: &MastForest,
+        builder: &mut MastForestBuilder,
+        decorator_id: DecoratorId,
+    ) -> Result<DecoratorId, AssemblyError> {
+        if let Some(&new_decorator_id) = self.decorators.get(&decorator_id) {
+            return Ok(new_decorator_id);
+        }
+        let new_decorator_id = builder.ensure_decorator(forest[decorator_id].clone())?;
+        self.decorators.insert(decorator_id, new_decorator_id);
+        Ok(new_decorator_id)
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -1,13 +1,19 @@
-use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
 
 use basic_block_builder::BasicBlockOrDecorators;
-use mast_forest_builder::MastForestBuilder;
-use module_graph::{ProcedureWrapper, WrappedModule};
+use mast_forest_builder::{MastForestBuilder, SubtreeImporter};
+use module_graph::{CacheKey, CachedModule, CachedProcedure, ProcedureWrapper};
 use vm_core::{
     DecoratorList, Felt, Kernel, Operation, Program, WORD_SIZE,
     crypto::hash::RpoDigest,
     debuginfo::SourceSpan,
-    mast::{DecoratorId, MastNodeId},
+    mast::{
+        DecoratorId, MastForest, MastForestOptimizer, MastNodeId, OptimizationReport, Remapping,
+    },
 };
 
 use crate::{
@@ -15,7 +21,7 @@ use crate::{
     ast::{self, Export, InvocationTarget, InvokeKind, ModuleKind, QualifiedProcedureName},
     diagnostics::Report,
     library::{KernelLibrary, Library},
-    sema::SemanticAnalysisError,
+    sema::{Lint, LintConfig, LintLevel, SemanticAnalysisError},
 };
 
 mod basic_block_builder;
@@ -31,15 +37,36 @@ mod tests;
 #[cfg(test)]
 mod mast_forest_merger_tests;
 
+pub(crate) use self::mast_forest_builder::PROCEDURE_INLINING_THRESHOLD;
 use self::{
     basic_block_builder::BasicBlockBuilder,
     module_graph::{CallerInfo, ModuleGraph, ResolvedTarget},
 };
 pub use self::{
     id::{GlobalProcedureIndex, ModuleIndex},
+    module_graph::ModuleCache,
     procedure::{Procedure, ProcedureContext},
 };
 
+// OPTIMIZATION LEVEL
+// ================================================================================================
+
+/// The optimizations applied by the [Assembler] to the MAST it produces.
+///
+/// Optimizations change the MAST roots of the affected procedures. Thus, the MAST roots of
+/// procedures assembled at different optimization levels differ, and e.g. a program which invokes
+/// procedures of a library by MAST root must be assembled against the library assembled at the
+/// same optimization level as the one provided to the VM.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum OptimizationLevel {
+    /// The MAST is emitted exactly as written.
+    #[default]
+    O0,
+    /// The MAST is optimized with a [MastForestOptimizer], which removes unreachable procedures,
+    /// redundant operations, and merges adjacent basic blocks.
+    O1,
+}
+
 // ASSEMBLER
 // ================================================================================================
 
@@ -69,10 +96,17 @@ pub struct Assembler {
     module_graph: ModuleGraph,
     /// Whether to treat warning diagnostics as errors
     warnings_as_errors: bool,
+    /// The levels of the lints checked when compiling modules from source.
+    lints: LintConfig,
     /// Whether the assembler enables extra debugging information.
     in_debug_mode: bool,
     /// Collects libraries that can be used during assembly to vendor procedures.
     vendored_libraries: BTreeMap<RpoDigest, Library>,
+    /// The optimizations applied to the assembled MAST.
+    optimization_level: OptimizationLevel,
+    /// The maximum number of threads used to compile independent modules, or `None` to use the
+    /// available parallelism of the host.
+    num_threads: Option<usize>,
 }
 
 impl Default for Assembler {
@@ -83,8 +117,11 @@ impl Default for Assembler {
             source_manager,
             module_graph,
             warnings_as_errors: false,
+            lints: LintConfig::default(),
             in_debug_mode: false,
             vendored_libraries: BTreeMap::new(),
+            optimization_level: OptimizationLevel::default(),
+            num_threads: None,
         }
     }
 }
@@ -99,8 +136,11 @@ impl Assembler {
             source_manager,
             module_graph,
             warnings_as_errors: false,
+            lints: LintConfig::default(),
             in_debug_mode: false,
             vendored_libraries: BTreeMap::new(),
+            optimization_level: OptimizationLevel::default(),
+            num_threads: None,
         }
     }
 
@@ -123,6 +163,22 @@ impl Assembler {
         self
     }
 
+    /// Sets the level of `lint` for all modules compiled from source by this assembler, except
+    /// those added with explicit [CompileOptions].
+    ///
+    /// The level can still be overridden for individual items using lint attributes, e.g.
+    /// `@allow(unused_procedure)`. See [Lint] for details.
+    pub fn with_lint_level(mut self, lint: Lint, level: LintLevel) -> Self {
+        self.set_lint_level(lint, level);
+        self
+    }
+
+    /// Sets the level of `lint` for all modules compiled from source by this assembler, except
+    /// those added with explicit [CompileOptions].
+    pub fn set_lint_level(&mut self, lint: Lint, level: LintLevel) {
+        self.lints.set_level(lint, level);
+    }
+
     /// Puts the assembler into the debug mode.
     pub fn with_debug_mode(mut self, yes: bool) -> Self {
         self.in_debug_mode = yes;
@@ -134,6 +190,48 @@ impl Assembler {
         self.in_debug_mode = yes;
     }
 
+    /// Sets the optimization level of the assembler.
+    ///
+    /// See [OptimizationLevel] for details on the optimizations applied at each level.
+    pub fn with_optimization_level(mut self, level: OptimizationLevel) -> Self {
+        self.optimization_level = level;
+        self
+    }
+
+    /// Sets the optimization level of the assembler.
+    pub fn set_optimization_level(&mut self, level: OptimizationLevel) {
+        self.optimization_level = level;
+    }
+
+    /// Sets the maximum number of threads used to compile the procedures of independent modules,
+    /// which requires the `std` feature. Setting it to 1 disables parallel compilation.
+    ///
+    /// By default, the available parallelism of the host is used. The assembled MAST does not
+    /// depend on the number of threads.
+    pub fn with_num_threads(mut self, num_threads: usize) -> Self {
+        self.set_num_threads(num_threads);
+        self
+    }
+
+    /// Sets the maximum number of threads used to compile the procedures of independent modules.
+    pub fn set_num_threads(&mut self, num_threads: usize) {
+        self.num_threads = Some(num_threads.max(1));
+    }
+
+    /// Sets the [ModuleCache] used by the assembler to reuse the compiled procedures of modules
+    /// which did not change since they were last assembled.
+    ///
+    /// The cache can be shared by multiple assemblers.
+    pub fn with_module_cache(mut self, cache: Arc<ModuleCache>) -> Self {
+        self.set_module_cache(cache);
+        self
+    }
+
+    /// Sets the [ModuleCache] used by the assembler.
+    pub fn set_module_cache(&mut self, cache: Arc<ModuleCache>) {
+        self.module_graph.set_module_cache(cache);
+    }
+
     /// Adds `module` to the module graph of the assembler.
     ///
     /// The given module must be a library module, or an error will be returned.
@@ -163,7 +261,11 @@ impl Assembler {
     /// The given module must be a library module, or an error will be returned.
     #[inline]
     pub fn add_module(&mut self, module: impl Compile) -> Result<ModuleIndex, Report> {
-        self.add_module_with_options(module, CompileOptions::for_library())
+        let options = CompileOptions {
+            lints: self.lints.clone(),
+            ..CompileOptions::for_library()
+        };
+        self.add_module_with_options(module, options)
     }
 
     /// Adds `module` to the module graph of the assembler, using the provided options.
@@ -287,11 +389,21 @@ impl Assembler {
         self.warnings_as_errors
     }
 
+    /// Returns the levels of the lints checked by this assembler.
+    pub fn lints(&self) -> &LintConfig {
+        &self.lints
+    }
+
     /// Returns true if this assembler was instantiated in debug mode.
     pub fn in_debug_mode(&self) -> bool {
         self.in_debug_mode
     }
 
+    /// Returns the optimization level of this assembler.
+    pub fn optimization_level(&self) -> OptimizationLevel {
+        self.optimization_level
+    }
+
     /// Returns a reference to the kernel for this assembler.
     ///
     /// If the assembler was instantiated without a kernel, the internal kernel will be empty.
@@ -319,31 +431,36 @@ impl Assembler {
         mut self,
         modules: impl IntoIterator<Item = impl Compile>,
         options: CompileOptions,
-    ) -> Result<Library, Report> {
+    ) -> Result<(Library, Option<OptimizationReport>), Report> {
         let mut mast_forest_builder = MastForestBuilder::new(self.vendored_libraries.values())?;
 
         let ast_module_indices = self.add_modules_with_options(modules, options)?;
 
         let mut exports = {
-            let mut exports = BTreeMap::new();
-
+            let mut exported_procedures = Vec::new();
             for module_idx in ast_module_indices {
                 // Note: it is safe to use `unwrap_ast()` here, since all of the modules contained
                 // in `ast_module_indices` are in AST form by definition.
                 let ast_module = self.module_graph[module_idx].unwrap_ast().clone();
-
                 for (proc_idx, fqn) in ast_module.exported_procedures() {
-                    let gid = module_idx + proc_idx;
-                    self.compile_subgraph(gid, &mut mast_forest_builder)?;
-
-                    let proc_root_node_id = mast_forest_builder
-                        .get_procedure(gid)
-                        .expect("compilation succeeded but root not found in cache")
-                        .body_node_id();
-                    exports.insert(fqn, proc_root_node_id);
+                    exported_procedures.push((module_idx + proc_idx, fqn));
                 }
             }
 
+            self.compile_subgraphs(
+                exported_procedures.iter().map(|(gid, _)| *gid),
+                &mut mast_forest_builder,
+            )?;
+
+            let mut exports = BTreeMap::new();
+            for (gid, fqn) in exported_procedures {
+                let proc_root_node_id = mast_forest_builder
+                    .get_procedure(gid)
+                    .expect("compilation succeeded but root not found in cache")
+                    .body_node_id();
+                exports.insert(fqn, proc_root_node_id);
+            }
+
             exports
         };
 
@@ -354,7 +471,15 @@ impl Assembler {
             }
         }
 
-        Ok(Library::new(mast_forest.into(), exports)?)
+        let (mast_forest, id_remappings, report) =
+            self.optimize(mast_forest, exports.values().copied())?;
+        for (_proc_name, node_id) in exports.iter_mut() {
+            if let Some(&new_node_id) = id_remappings.get(node_id) {
+                *node_id = new_node_id;
+            }
+        }
+
+        Ok((Library::new(mast_forest.into(), exports)?, report))
     }
 
     /// Assembles a set of modules into a [Library].
@@ -366,9 +491,26 @@ impl Assembler {
         self,
         modules: impl IntoIterator<Item = impl Compile>,
     ) -> Result<Library, Report> {
+        self.assemble_library_with_report(modules).map(|(library, _)| library)
+    }
+
+    /// Assembles a set of modules into a [Library], and returns it along with the report of the
+    /// optimizations applied to its MAST.
+    ///
+    /// The report is `None` if the optimization level of this assembler is
+    /// [OptimizationLevel::O0].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing or compilation of the specified modules fails.
+    pub fn assemble_library_with_report(
+        self,
+        modules: impl IntoIterator<Item = impl Compile>,
+    ) -> Result<(Library, Option<OptimizationReport>), Report> {
         let options = CompileOptions {
             kind: ModuleKind::Library,
             warnings_as_errors: self.warnings_as_errors,
+            lints: self.lints.clone(),
             path: None,
         };
         self.assemble_common(modules, options)
@@ -380,13 +522,30 @@ impl Assembler {
     ///
     /// Returns an error if parsing or compilation of the specified modules fails.
     pub fn assemble_kernel(self, module: impl Compile) -> Result<KernelLibrary, Report> {
+        self.assemble_kernel_with_report(module).map(|(kernel, _)| kernel)
+    }
+
+    /// Assembles the provided module into a [KernelLibrary] intended to be used as a Kernel, and
+    /// returns it along with the report of the optimizations applied to its MAST.
+    ///
+    /// The report is `None` if the optimization level of this assembler is
+    /// [OptimizationLevel::O0].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing or compilation of the specified modules fails.
+    pub fn assemble_kernel_with_report(
+        self,
+        module: impl Compile,
+    ) -> Result<(KernelLibrary, Option<OptimizationReport>), Report> {
         let options = CompileOptions {
             kind: ModuleKind::Kernel,
             warnings_as_errors: self.warnings_as_errors,
+            lints: self.lints.clone(),
             path: Some(LibraryPath::from(LibraryNamespace::Kernel)),
         };
-        let library = self.assemble_common([module], options)?;
-        Ok(library.try_into()?)
+        let (library, report) = self.assemble_common([module], options)?;
+        Ok((library.try_into()?, report))
     }
 
     /// Compiles the provided module into a [`Program`]. The resulting program can be executed on
@@ -396,10 +555,28 @@ impl Assembler {
     ///
     /// Returns an error if parsing or compilation of the specified program fails, or if the source
     /// doesn't have an entrypoint.
-    pub fn assemble_program(mut self, source: impl Compile) -> Result<Program, Report> {
+    pub fn assemble_program(self, source: impl Compile) -> Result<Program, Report> {
+        self.assemble_program_with_report(source).map(|(program, _)| program)
+    }
+
+    /// Compiles the provided module into a [`Program`], and returns it along with the report of
+    /// the optimizations applied to its MAST.
+    ///
+    /// The report is `None` if the optimization level of this assembler is
+    /// [OptimizationLevel::O0].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing or compilation of the specified program fails, or if the source
+    /// doesn't have an entrypoint.
+    pub fn assemble_program_with_report(
+        mut self,
+        source: impl Compile,
+    ) -> Result<(Program, Option<OptimizationReport>), Report> {
         let options = CompileOptions {
             kind: ModuleKind::Executable,
             warnings_as_errors: self.warnings_as_errors,
+            lints: self.lints.clone(),
             path: Some(LibraryPath::from(LibraryNamespace::Exec)),
         };
 
@@ -420,7 +597,7 @@ impl Assembler {
         // Compile the module graph rooted at the entrypoint
         let mut mast_forest_builder = MastForestBuilder::new(self.vendored_libraries.values())?;
 
-        self.compile_subgraph(entrypoint, &mut mast_forest_builder)?;
+        self.compile_subgraphs([entrypoint], &mut mast_forest_builder)?;
         let entry_node_id = mast_forest_builder
             .get_procedure(entrypoint)
             .expect("compilation succeeded but root not found in cache")
@@ -430,26 +607,58 @@ impl Assembler {
         let (mast_forest, id_remappings) = mast_forest_builder.build();
         let entry_node_id = *id_remappings.get(&entry_node_id).unwrap_or(&entry_node_id);
 
-        Ok(Program::with_kernel(
+        let (mast_forest, id_remappings, report) = self.optimize(mast_forest, [entry_node_id])?;
+        let entry_node_id = *id_remappings.get(&entry_node_id).unwrap_or(&entry_node_id);
+
+        let program = Program::with_kernel(
             mast_forest.into(),
             entry_node_id,
             self.module_graph.kernel().clone(),
-        ))
+        );
+        Ok((program, report))
     }
 
-    /// Compile the uncompiled procedure in the module graph which are members of the subgraph
-    /// rooted at `root`, placing them in the MAST forest builder once compiled.
+    /// Optimizes the assembled MAST forest according to the optimization level of this assembler,
+    /// retaining the specified procedure roots.
+    ///
+    /// Returns the optimized forest along with the mapping from the node ids of the original forest
+    /// to their ids in the optimized forest, and the report of the applied optimizations (if any).
+    fn optimize(
+        &self,
+        mast_forest: MastForest,
+        roots: impl IntoIterator<Item = MastNodeId>,
+    ) -> Result<(MastForest, Remapping, Option<OptimizationReport>), AssemblyError> {
+        match self.optimization_level {
+            OptimizationLevel::O0 => Ok((mast_forest, Remapping::new(), None)),
+            OptimizationLevel::O1 => {
+                let (mast_forest, id_remappings, report) =
+                    MastForestOptimizer::optimize(&mast_forest, roots).map_err(|err| {
+                        AssemblyError::forest_error("failed to optimize the MAST forest", err)
+                    })?;
+                Ok((mast_forest, id_remappings, Some(report)))
+            },
+        }
+    }
+
+    /// Compiles the uncompiled procedures in the module graph which are members of the subgraphs
+    /// rooted at `roots`, placing them in the MAST forest builder once compiled.
+    ///
+    /// If the module graph has a [ModuleCache], procedures found in the cache are reused rather
+    /// than recompiled. With the `std` feature enabled, the procedures of modules which do not
+    /// depend on each other are compiled in parallel.
     ///
     /// Returns an error if any of the provided Miden Assembly is invalid.
-    fn compile_subgraph(
+    fn compile_subgraphs(
         &mut self,
-        root: GlobalProcedureIndex,
+        roots: impl IntoIterator<Item = GlobalProcedureIndex>,
         mast_forest_builder: &mut MastForestBuilder,
     ) -> Result<(), Report> {
-        let mut worklist: Vec<GlobalProcedureIndex> = self
-            .module_graph
-            .topological_sort_from_root(root)
-            .map_err(|cycle| {
+        // Collect the AST procedures of all subgraphs, ordered such that each procedure is
+        // preceded by all of its dependencies
+        let mut procedures = Vec::new();
+        let mut visited = BTreeSet::new();
+        for root in roots {
+            let sorted = self.module_graph.topological_sort_from_root(root).map_err(|cycle| {
                 let iter = cycle.into_node_ids();
                 let mut nodes = Vec::with_capacity(iter.len());
                 for node in iter {
@@ -458,106 +667,332 @@ impl Assembler {
                     nodes.push(format!("{}::{}", module, proc.name()));
                 }
                 AssemblyError::Cycle { nodes }
-            })?
-            .into_iter()
-            .filter(|&gid| self.module_graph.get_procedure_unsafe(gid).is_ast())
-            .collect();
+            })?;
+            for gid in sorted.into_iter().rev() {
+                if self.module_graph.get_procedure_unsafe(gid).is_ast() && visited.insert(gid) {
+                    procedures.push(gid);
+                }
+            }
+        }
+
+        let cache_keys = match self.module_graph.module_cache() {
+            Some(_) => self.module_graph.compute_cache_keys(&procedures, &self.cache_config()),
+            None => BTreeMap::new(),
+        };
+
+        // If we have already compiled a procedure, do not recompile it
+        let mut pending = Vec::with_capacity(procedures.len());
+        for gid in procedures {
+            match mast_forest_builder.get_procedure(gid) {
+                Some(proc) => self.module_graph.register_procedure_root(gid, proc.mast_root())?,
+                None => pending.push(gid),
+            }
+        }
 
-        assert!(!worklist.is_empty());
+        #[cfg(feature = "std")]
+        let mut workers = Vec::new();
+        for wave in self.module_graph.compilation_waves(&pending) {
+            #[cfg(feature = "std")]
+            let compiled =
+                self.compile_wave(&wave, &cache_keys, mast_forest_builder, &mut workers)?;
+            #[cfg(not(feature = "std"))]
+            let compiled =
+                self.compile_procedures(&wave.concat(), &cache_keys, mast_forest_builder)?;
+
+            // Make the MAST roots available to all dependents
+            for (gid, procedure) in compiled {
+                self.module_graph.register_procedure_root(gid, procedure.mast_root())?;
+            }
+        }
 
-        self.process_graph_worklist(&mut worklist, mast_forest_builder)
+        Ok(())
     }
 
-    /// Compiles all procedures in the `worklist`.
-    fn process_graph_worklist(
-        &mut self,
-        worklist: &mut Vec<GlobalProcedureIndex>,
+    /// Compiles the groups of procedures of a single wave computed by
+    /// [ModuleGraph::compilation_waves], placing them in the MAST forest builder.
+    ///
+    /// The groups are compiled in parallel if the assembler may use more than one thread, in which
+    /// case `workers` holds the builders used by the threads across waves.
+    ///
+    /// Returns the compiled procedures.
+    #[cfg(feature = "std")]
+    fn compile_wave(
+        &self,
+        wave: &[Vec<GlobalProcedureIndex>],
+        cache_keys: &BTreeMap<GlobalProcedureIndex, CacheKey>,
         mast_forest_builder: &mut MastForestBuilder,
-    ) -> Result<(), Report> {
-        // Process the topological ordering in reverse order (bottom-up), so that
-        // each procedure is compiled with all of its dependencies fully compiled
-        while let Some(procedure_gid) = worklist.pop() {
-            // If we have already compiled this procedure, do not recompile
-            if let Some(proc) = mast_forest_builder.get_procedure(procedure_gid) {
-                self.module_graph.register_procedure_root(procedure_gid, proc.mast_root())?;
-                continue;
+        workers: &mut Vec<CompileWorker>,
+    ) -> Result<Vec<(GlobalProcedureIndex, Procedure)>, Report> {
+        let num_threads = self
+            .num_threads
+            .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, usize::from))
+            .min(wave.len());
+        if num_threads > 1 {
+            return self.compile_wave_in_parallel(
+                wave,
+                num_threads,
+                cache_keys,
+                mast_forest_builder,
+                workers,
+            );
+        }
+
+        self.compile_procedures(&wave.concat(), cache_keys, mast_forest_builder)
+    }
+
+    /// Compiles the groups of procedures of a single wave on `num_threads` threads.
+    ///
+    /// Each thread compiles its share of the groups into the builder of its worker, which is first
+    /// brought up to date with the procedures compiled so far. The compiled procedures are then
+    /// copied into `mast_forest_builder`, in the order of the groups. The node order of the
+    /// resulting MAST forest is canonicalized by [MastForestBuilder::build], and thus does not
+    /// depend on the number of threads.
+    #[cfg(feature = "std")]
+    fn compile_wave_in_parallel(
+        &self,
+        wave: &[Vec<GlobalProcedureIndex>],
+        num_threads: usize,
+        cache_keys: &BTreeMap<GlobalProcedureIndex, CacheKey>,
+        mast_forest_builder: &mut MastForestBuilder,
+        workers: &mut Vec<CompileWorker>,
+    ) -> Result<Vec<(GlobalProcedureIndex, Procedure)>, Report> {
+        while workers.len() < num_threads {
+            workers.push(CompileWorker::new(mast_forest_builder.fork()));
+        }
+        for worker in workers.iter_mut().take(num_threads) {
+            worker.import_procedures(mast_forest_builder)?;
+        }
+
+        let chunk_size = wave.len().div_ceil(num_threads);
+        let results = std::thread::scope(|scope| {
+            let handles: Vec<_> = wave
+                .chunks(chunk_size)
+                .zip(workers.iter_mut())
+                .map(|(groups, worker)| {
+                    scope.spawn(move || {
+                        let procedures = groups.concat();
+                        self.compile_procedures(&procedures, cache_keys, &mut worker.builder)
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap_or_else(|err| std::panic::resume_unwind(err)))
+                .collect::<Vec<_>>()
+        });
+
+        let mut compiled = Vec::new();
+        for (result, worker) in results.into_iter().zip(workers.iter_mut()) {
+            for (gid, procedure) in result? {
+                let procedure = worker.export_procedure(procedure, mast_forest_builder)?;
+                mast_forest_builder.insert_procedure(gid, procedure.clone())?;
+                compiled.push((gid, procedure));
             }
-            // Fetch procedure metadata from the graph
-            let module = match &self.module_graph[procedure_gid.module] {
-                WrappedModule::Ast(ast_module) => ast_module,
-                // Note: if the containing module is in `Info` representation, there is nothing to
-                // compile.
-                WrappedModule::Info(_) => continue,
+        }
+
+        Ok(compiled)
+    }
+
+    /// Compiles the specified procedures, which must be sorted such that each procedure is
+    /// preceded by its dependencies, placing them in the MAST forest builder.
+    ///
+    /// If the module graph has a [ModuleCache], procedures cached under their key in `cache_keys`
+    /// are copied from the cache instead of being compiled, and the cache is updated with the
+    /// compiled procedures.
+    ///
+    /// Returns the compiled procedures.
+    fn compile_procedures(
+        &self,
+        procedures: &[GlobalProcedureIndex],
+        cache_keys: &BTreeMap<GlobalProcedureIndex, CacheKey>,
+        mast_forest_builder: &mut MastForestBuilder,
+    ) -> Result<Vec<(GlobalProcedureIndex, Procedure)>, Report> {
+        let cache = self.module_graph.module_cache();
+        let mut cached_modules = BTreeMap::new();
+        let mut compiled = Vec::with_capacity(procedures.len());
+
+        for &gid in procedures {
+            let cached_body = match (cache, cache_keys.get(&gid)) {
+                (Some(cache), Some(key)) => {
+                    let cached_module = cached_modules.entry(gid.module).or_insert_with(|| {
+                        let path = self.module_graph[gid.module].path();
+                        cache.get(path).map(|module| (module, SubtreeImporter::default()))
+                    });
+                    let name = self.module_graph.get_procedure_unsafe(gid).name().clone();
+                    match cached_module {
+                        Some((module, importer)) => module
+                            .get_procedure(&name, key)
+                            .map(|body_node_id| {
+                                importer.import(
+                                    module.mast_forest(),
+                                    mast_forest_builder,
+                                    body_node_id,
+                                )
+                            })
+                            .transpose()?,
+                        None => None,
+                    }
+                },
+                _ => None,
             };
 
-            let export = &module[procedure_gid.index];
-            match export {
-                Export::Procedure(proc) => {
-                    let num_locals = proc.num_locals();
-                    let name = QualifiedProcedureName {
-                        span: proc.span(),
-                        module: module.path().clone(),
-                        name: proc.name().clone(),
-                    };
-                    let pctx = ProcedureContext::new(
-                        procedure_gid,
-                        name,
-                        proc.visibility(),
-                        module.is_kernel(),
-                        self.source_manager.clone(),
-                    )
-                    .with_num_locals(num_locals)
-                    .with_span(proc.span());
-
-                    // Compile this procedure
-                    let procedure = self.compile_procedure(pctx, mast_forest_builder)?;
-                    // TODO: if a re-exported procedure with the same MAST root had been previously
-                    // added to the builder, this will result in unreachable nodes added to the
-                    // MAST forest. This is because while we won't insert a duplicate node for the
-                    // procedure body node itself, all nodes that make up the procedure body would
-                    // be added to the forest.
-
-                    // Cache the compiled procedure
-                    self.module_graph
-                        .register_procedure_root(procedure_gid, procedure.mast_root())?;
-                    mast_forest_builder.insert_procedure(procedure_gid, procedure)?;
+            let procedure = match cached_body {
+                Some(body_node_id) => {
+                    cache.unwrap().record_hit();
+                    let mast_root = mast_forest_builder[body_node_id].digest();
+                    self.procedure_context(gid).into_procedure(mast_root, body_node_id)
                 },
-                Export::Alias(proc_alias) => {
-                    let name = QualifiedProcedureName {
-                        span: proc_alias.span(),
-                        module: module.path().clone(),
-                        name: proc_alias.name().clone(),
-                    };
-                    let pctx = ProcedureContext::new(
-                        procedure_gid,
-                        name,
-                        ast::Visibility::Public,
-                        module.is_kernel(),
-                        self.source_manager.clone(),
-                    )
-                    .with_span(proc_alias.span());
-
-                    let proc_node_id = self.resolve_target(
-                        InvokeKind::ProcRef,
-                        &proc_alias.target().into(),
-                        &pctx,
-                        mast_forest_builder,
+                None => {
+                    let procedure = self.compile_export(gid, mast_forest_builder)?;
+                    let export = self.module_graph.get_procedure_unsafe(gid);
+                    if let (Some(cache), Export::Procedure(_)) = (cache, export.unwrap_ast()) {
+                        cache.record_miss();
+                    }
+                    procedure
+                },
+            };
+
+            // Cache the compiled procedure
+            mast_forest_builder.insert_procedure(gid, procedure.clone())?;
+            compiled.push((gid, procedure));
+        }
+
+        if let Some(cache) = cache {
+            self.update_module_cache(cache, &compiled, cache_keys, mast_forest_builder)?;
+        }
+
+        Ok(compiled)
+    }
+
+    /// Stores the compiled procedures in the [ModuleCache], grouped by module.
+    ///
+    /// Procedures of a module cached previously, but not compiled this time, are retained.
+    fn update_module_cache(
+        &self,
+        cache: &ModuleCache,
+        compiled: &[(GlobalProcedureIndex, Procedure)],
+        cache_keys: &BTreeMap<GlobalProcedureIndex, CacheKey>,
+        mast_forest_builder: &MastForestBuilder,
+    ) -> Result<(), AssemblyError> {
+        let mut compiled_by_module: BTreeMap<ModuleIndex, Vec<_>> = BTreeMap::new();
+        for (gid, procedure) in compiled {
+            let export = self.module_graph.get_procedure_unsafe(*gid);
+            if let (Export::Procedure(_), Some(key)) = (export.unwrap_ast(), cache_keys.get(gid)) {
+                compiled_by_module.entry(gid.module).or_default().push((key, procedure));
+            }
+        }
+
+        for (module_index, procedures) in compiled_by_module {
+            let path = self.module_graph[module_index].path();
+            let mut builder = MastForestBuilder::default();
+            let mut cached_procedures = BTreeMap::new();
+
+            if let Some(previous) = cache.get(path) {
+                let mut importer = SubtreeImporter::default();
+                for (name, procedure) in previous.procedures() {
+                    let body_node_id = importer.import(
+                        previous.mast_forest(),
+                        &mut builder,
+                        procedure.body_node_id,
                     )?;
-                    let proc_mast_root =
-                        mast_forest_builder.get_mast_node(proc_node_id).unwrap().digest();
+                    builder.make_root(body_node_id);
+                    cached_procedures
+                        .insert(name.clone(), CachedProcedure { body_node_id, ..*procedure });
+                }
+            }
 
-                    let procedure = pctx.into_procedure(proc_mast_root, proc_node_id);
+            let mut importer = SubtreeImporter::default();
+            for (&key, procedure) in procedures {
+                let body_node_id = importer.import(
+                    mast_forest_builder.mast_forest(),
+                    &mut builder,
+                    procedure.body_node_id(),
+                )?;
+                builder.make_root(body_node_id);
+                cached_procedures
+                    .insert(procedure.name().clone(), CachedProcedure { key, body_node_id });
+            }
 
-                    // Make the MAST root available to all dependents
-                    self.module_graph.register_procedure_root(procedure_gid, proc_mast_root)?;
-                    mast_forest_builder.insert_procedure(procedure_gid, procedure)?;
-                },
+            let (mast_forest, id_remappings) = builder.build();
+            for procedure in cached_procedures.values_mut() {
+                procedure.body_node_id = procedure.body_node_id.remap(&id_remappings);
             }
+            cache.insert(path, CachedModule::new(mast_forest, cached_procedures));
         }
 
         Ok(())
     }
 
+    /// Returns the bytes identifying the options of this assembler which affect the MAST of the
+    /// procedures it compiles, for use in [ModuleCache] keys.
+    fn cache_config(&self) -> Vec<u8> {
+        let mut config = Vec::from(env!("CARGO_PKG_VERSION").as_bytes());
+        config.push(self.in_debug_mode as u8);
+        for library_digest in self.vendored_libraries.keys() {
+            config.extend_from_slice(&library_digest.as_bytes());
+        }
+        config
+    }
+
+    /// Returns the [ProcedureContext] for the procedure or procedure alias `gid`, which must be
+    /// defined in an AST module.
+    fn procedure_context(&self, gid: GlobalProcedureIndex) -> ProcedureContext {
+        let module = self.module_graph[gid.module].unwrap_ast();
+        let name = QualifiedProcedureName {
+            span: module[gid.index].span(),
+            module: module.path().clone(),
+            name: module[gid.index].name().clone(),
+        };
+        match &module[gid.index] {
+            Export::Procedure(proc) => ProcedureContext::new(
+                gid,
+                name,
+                proc.visibility(),
+                module.is_kernel(),
+                self.source_manager.clone(),
+            )
+            .with_num_locals(proc.num_locals())
+            .with_span(proc.span()),
+            Export::Alias(proc_alias) => ProcedureContext::new(
+                gid,
+                name,
+                ast::Visibility::Public,
+                module.is_kernel(),
+                self.source_manager.clone(),
+            )
+            .with_span(proc_alias.span()),
+        }
+    }
+
+    /// Compiles the procedure or procedure alias `gid`, which must be defined in an AST module.
+    fn compile_export(
+        &self,
+        gid: GlobalProcedureIndex,
+        mast_forest_builder: &mut MastForestBuilder,
+    ) -> Result<Procedure, Report> {
+        let pctx = self.procedure_context(gid);
+        match self.module_graph.get_procedure_unsafe(gid).unwrap_ast() {
+            // TODO: if a re-exported procedure with the same MAST root had been previously added
+            // to the builder, this will result in unreachable nodes added to the MAST forest. This
+            // is because while we won't insert a duplicate node for the procedure body node
+            // itself, all nodes that make up the procedure body would be added to the forest.
+            Export::Procedure(_) => self.compile_procedure(pctx, mast_forest_builder),
+            Export::Alias(proc_alias) => {
+                let proc_node_id = self.resolve_target(
+                    InvokeKind::ProcRef,
+                    &proc_alias.target().into(),
+                    &pctx,
+                    mast_forest_builder,
+                )?;
+                let proc_mast_root =
+                    mast_forest_builder.get_mast_node(proc_node_id).unwrap().digest();
+
+                Ok(pctx.into_procedure(proc_mast_root, proc_node_id))
+            },
+        }
+    }
+
     /// Compiles a single Miden Assembly procedure to its MAST representation.
     fn compile_procedure(
         &self,
@@ -862,3 +1297,56 @@ struct BodyWrapper {
     prologue: Vec<Operation>,
     epilogue: Vec<Operation>,
 }
+
+/// The state of a thread compiling procedures in parallel, which is retained across the waves of
+/// a single assembly.
+#[cfg(feature = "std")]
+struct CompileWorker {
+    /// The builder into which the thread compiles its procedures.
+    builder: MastForestBuilder,
+    /// Copies procedures from the MAST forest builder of the assembler into `builder`.
+    importer: SubtreeImporter,
+    /// Copies procedures compiled into `builder` into the MAST forest builder of the assembler.
+    exporter: SubtreeImporter,
+}
+
+#[cfg(feature = "std")]
+impl CompileWorker {
+    fn new(builder: MastForestBuilder) -> Self {
+        Self {
+            builder,
+            importer: SubtreeImporter::default(),
+            exporter: SubtreeImporter::default(),
+        }
+    }
+
+    /// Copies the procedures of `source` which are not yet in the builder of this worker, so that
+    /// they can be invoked by the procedures compiled by this worker.
+    fn import_procedures(&mut self, source: &MastForestBuilder) -> Result<(), AssemblyError> {
+        for (gid, procedure) in source.procedures() {
+            if self.builder.get_procedure(gid).is_none() {
+                let body_node_id = self.importer.import(
+                    source.mast_forest(),
+                    &mut self.builder,
+                    procedure.body_node_id(),
+                )?;
+                self.builder
+                    .insert_procedure(gid, procedure.clone().with_body_node_id(body_node_id))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the body of `procedure`, compiled by this worker, into `target`, and returns the
+    /// procedure with its body node id in `target`.
+    fn export_procedure(
+        &mut self,
+        procedure: Procedure,
+        target: &mut MastForestBuilder,
+    ) -> Result<Procedure, AssemblyError> {
+        let body_node_id =
+            self.exporter
+                .import(self.builder.mast_forest(), target, procedure.body_node_id())?;
+        Ok(procedure.with_body_node_id(body_node_id))
+    }
+}
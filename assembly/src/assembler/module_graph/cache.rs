@@ -0,0 +1,251 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use vm_core::{
+    mast::{MastForest, MastNodeId},
+    utils::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, sync::RwLock,
+    },
+};
+
+use crate::{LibraryPath, ast::ProcedureName};
+
+/// The key under which the compiled form of a procedure is stored in a [ModuleCache].
+pub(crate) type CacheKey = [u8; 32];
+
+// MODULE CACHE
+// ================================================================================================
+
+/// A cache of compiled modules, used by the [crate::Assembler] to avoid recompiling the procedures
+/// of modules which did not change since they were last assembled.
+///
+/// Compiled procedures are cached per module, and are keyed by the hash of the source of their
+/// module, together with the digests of all procedures they depend on. The keys of dependencies
+/// which are compiled from source are derived in the same way, so a cached procedure is reused only
+/// if neither its module, nor any module it transitively depends on, has changed. The assembler
+/// options which affect the generated MAST (e.g. the debug mode) are part of the key as well.
+///
+/// By default, the cache is kept in memory, and can be shared by multiple assemblers. With the
+/// `std` feature enabled, the cache can also be persisted to a directory using
+/// [ModuleCache::with_dir], which allows reusing it across processes.
+#[derive(Debug, Default)]
+pub struct ModuleCache {
+    /// The cached modules, keyed by module path.
+    modules: RwLock<BTreeMap<String, Arc<CachedModule>>>,
+    /// The directory cached modules are persisted to.
+    #[cfg(feature = "std")]
+    dir: Option<std::path::PathBuf>,
+    num_hits: AtomicUsize,
+    num_misses: AtomicUsize,
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Constructors
+impl ModuleCache {
+    /// Returns a new, empty, in-memory [ModuleCache].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new [ModuleCache] which persists cached modules to `dir`.
+    ///
+    /// Modules cached in `dir` by previous runs are loaded lazily, when first needed. The directory
+    /// is created if it does not exist yet.
+    #[cfg(feature = "std")]
+    pub fn with_dir(dir: impl Into<std::path::PathBuf>) -> Self {
+        Self { dir: Some(dir.into()), ..Self::default() }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Public accessors
+impl ModuleCache {
+    /// Returns the number of procedures which were reused from this cache, rather than compiled.
+    pub fn num_hits(&self) -> usize {
+        self.num_hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of procedures which had to be compiled, as they were not found in this
+    /// cache.
+    pub fn num_misses(&self) -> usize {
+        self.num_misses.load(Ordering::Relaxed)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Cache lookups and updates
+impl ModuleCache {
+    /// Returns the cached module with the specified path, if any.
+    pub(crate) fn get(&self, path: &LibraryPath) -> Option<Arc<CachedModule>> {
+        let path = path.to_string();
+        if let Some(module) = self.modules.read().get(&path) {
+            return Some(module.clone());
+        }
+
+        #[cfg(feature = "std")]
+        if let Some(module) = self.load(&path) {
+            let module = Arc::new(module);
+            self.modules.write().insert(path, module.clone());
+            return Some(module);
+        }
+
+        None
+    }
+
+    /// Stores `module` in the cache, replacing any previously cached module with the same path.
+    pub(crate) fn insert(&self, path: &LibraryPath, module: CachedModule) {
+        let path = path.to_string();
+
+        #[cfg(feature = "std")]
+        self.store(&path, &module);
+
+        self.modules.write().insert(path, Arc::new(module));
+    }
+
+    pub(crate) fn record_hit(&self) {
+        self.num_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_miss(&self) {
+        self.num_misses.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Persistence
+#[cfg(feature = "std")]
+impl ModuleCache {
+    /// The extension of the files cached modules are persisted to.
+    const FILE_EXTENSION: &'static str = "masc";
+
+    /// Returns the path of the file the module with the specified path is persisted to.
+    fn file_path(&self, module_path: &str) -> Option<std::path::PathBuf> {
+        use vm_core::{
+            crypto::hash::{Blake3_256, Digest},
+            utils::DisplayHex,
+        };
+
+        let dir = self.dir.as_ref()?;
+        let hash = Digest::as_bytes(&Blake3_256::hash(module_path.as_bytes()));
+        let name = format!("{:x}", DisplayHex(hash.as_slice()));
+        Some(dir.join(name).with_extension(Self::FILE_EXTENSION))
+    }
+
+    /// Loads the module with the specified path from the cache directory.
+    ///
+    /// Missing or malformed files are treated as if the module was not cached.
+    fn load(&self, module_path: &str) -> Option<CachedModule> {
+        let bytes = std::fs::read(self.file_path(module_path)?).ok()?;
+        CachedModule::read_from_bytes(&bytes).ok()
+    }
+
+    /// Persists `module` to the cache directory.
+    ///
+    /// Failing to persist a module is not an error, as it only means that the module will have to
+    /// be recompiled next time.
+    fn store(&self, module_path: &str, module: &CachedModule) {
+        let Some(file_path) = self.file_path(module_path) else {
+            return;
+        };
+        // write to a temporary file first, so that concurrent readers never see a partially
+        // written module
+        let tmp_path = file_path.with_extension(format!("{}.tmp", std::process::id()));
+        let result = std::fs::create_dir_all(file_path.parent().unwrap())
+            .and_then(|_| std::fs::write(&tmp_path, module.to_bytes()))
+            .and_then(|_| std::fs::rename(&tmp_path, &file_path));
+        if result.is_err() {
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+    }
+}
+
+// CACHED MODULE
+// ================================================================================================
+
+/// The compiled procedures of a single module stored in a [ModuleCache].
+#[derive(Debug)]
+pub(crate) struct CachedModule {
+    /// The MAST forest containing the bodies of all cached procedures of the module.
+    mast_forest: MastForest,
+    /// The cached procedures, keyed by name.
+    procedures: BTreeMap<ProcedureName, CachedProcedure>,
+}
+
+/// A procedure stored in a [CachedModule].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CachedProcedure {
+    /// The key under which the procedure was cached.
+    pub key: CacheKey,
+    /// The id of the procedure body in the MAST forest of the cached module.
+    pub body_node_id: MastNodeId,
+}
+
+impl CachedModule {
+    /// The magic bytes identifying a serialized [CachedModule].
+    const MAGIC: &'static [u8; 4] = b"MASC";
+
+    pub fn new(
+        mast_forest: MastForest,
+        procedures: BTreeMap<ProcedureName, CachedProcedure>,
+    ) -> Self {
+        Self { mast_forest, procedures }
+    }
+
+    /// Returns the MAST forest containing the bodies of the cached procedures.
+    pub fn mast_forest(&self) -> &MastForest {
+        &self.mast_forest
+    }
+
+    /// Returns an iterator over the cached procedures of this module.
+    pub fn procedures(&self) -> impl Iterator<Item = (&ProcedureName, &CachedProcedure)> {
+        self.procedures.iter()
+    }
+
+    /// Returns the body of the procedure named `name`, if it was cached under `key`.
+    pub fn get_procedure(&self, name: &ProcedureName, key: &CacheKey) -> Option<MastNodeId> {
+        self.procedures
+            .get(name)
+            .filter(|procedure| &procedure.key == key)
+            .map(|procedure| procedure.body_node_id)
+    }
+}
+
+impl Serializable for CachedModule {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(Self::MAGIC);
+        self.mast_forest.write_into(target);
+        target.write_usize(self.procedures.len());
+        for (name, procedure) in self.procedures.iter() {
+            name.write_into(target);
+            target.write_bytes(&procedure.key);
+            target.write_u32(procedure.body_node_id.as_u32());
+        }
+    }
+}
+
+impl Deserializable for CachedModule {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic: [u8; 4] = source.read_array()?;
+        if &magic != Self::MAGIC {
+            return Err(DeserializationError::InvalidValue(
+                "invalid cached module header".to_string(),
+            ));
+        }
+
+        let mast_forest = MastForest::read_from(source)?;
+        let num_procedures = source.read_usize()?;
+        let mut procedures = BTreeMap::new();
+        for _ in 0..num_procedures {
+            let name = ProcedureName::read_from(source)?;
+            let key = source.read_array()?;
+            let body_node_id = MastNodeId::from_u32_safe(source.read_u32()?, &mast_forest)?;
+            procedures.insert(name, CachedProcedure { key, body_node_id });
+        }
+
+        Ok(Self { mast_forest, procedures })
+    }
+}
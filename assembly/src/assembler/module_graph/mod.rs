@@ -1,17 +1,30 @@
 mod analysis;
+mod cache;
 mod callgraph;
 mod debug;
 mod name_resolver;
 mod rewrites;
 
-use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    sync::Arc,
+    vec::Vec,
+};
 use core::ops::Index;
 
 use smallvec::{SmallVec, smallvec};
-use vm_core::{Kernel, crypto::hash::RpoDigest};
+use vm_core::{
+    Kernel,
+    crypto::hash::{Blake3_256, Digest, RpoDigest},
+    utils::{ByteWriter, Serializable},
+};
 
+pub(crate) use self::cache::{CacheKey, CachedModule, CachedProcedure};
 use self::{analysis::MaybeRewriteCheck, name_resolver::NameResolver, rewrites::ModuleRewriter};
 pub use self::{
+    cache::ModuleCache,
     callgraph::{CallGraph, CycleError},
     name_resolver::{CallerInfo, ResolvedTarget},
 };
@@ -164,6 +177,8 @@ pub struct ModuleGraph {
     kernel_index: Option<ModuleIndex>,
     kernel: Kernel,
     source_manager: Arc<dyn SourceManager>,
+    /// The cache of compiled modules used to avoid recompiling unchanged procedures.
+    cache: Option<Arc<ModuleCache>>,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -179,6 +194,7 @@ impl ModuleGraph {
             kernel_index: None,
             kernel: Default::default(),
             source_manager,
+            cache: None,
         }
     }
 
@@ -504,6 +520,136 @@ impl ModuleGraph {
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+/// Incremental compilation
+impl ModuleGraph {
+    /// Sets the [ModuleCache] used to reuse the compiled procedures of unchanged modules.
+    pub fn set_module_cache(&mut self, cache: Arc<ModuleCache>) {
+        self.cache = Some(cache);
+    }
+
+    /// Returns the [ModuleCache] of this graph, if any.
+    pub fn module_cache(&self) -> Option<&ModuleCache> {
+        self.cache.as_deref()
+    }
+
+    /// Computes the [ModuleCache] keys of the specified procedures.
+    ///
+    /// The procedures must be sorted such that every procedure is preceded by the AST procedures it
+    /// depends on. The key of a procedure is derived from the key of its module (see
+    /// [Self::module_cache_key]), its name, and the keys of its callees, or the MAST roots of the
+    /// callees which are already compiled.
+    ///
+    /// `config` identifies the assembler options affecting the generated MAST, and is made part of
+    /// every key.
+    pub(crate) fn compute_cache_keys(
+        &self,
+        procedures: &[GlobalProcedureIndex],
+        config: &[u8],
+    ) -> BTreeMap<GlobalProcedureIndex, CacheKey> {
+        let mut module_keys = BTreeMap::new();
+        let mut keys: BTreeMap<GlobalProcedureIndex, CacheKey> = BTreeMap::new();
+        for &gid in procedures {
+            let WrappedModule::Ast(module) = &self[gid.module] else {
+                continue;
+            };
+            let module_key = *module_keys
+                .entry(gid.module)
+                .or_insert_with(|| self.module_cache_key(module, config));
+
+            let mut bytes = Vec::from(module_key);
+            module[gid.index].name().write_into(&mut bytes);
+            for &callee in self.callgraph.out_edges(gid) {
+                match (keys.get(&callee), self.get_procedure_unsafe(callee)) {
+                    (Some(callee_key), _) => bytes.write_bytes(callee_key),
+                    (None, ProcedureWrapper::Info(callee)) => callee.digest.write_into(&mut bytes),
+                    (None, ProcedureWrapper::Ast(_)) => {
+                        panic!("dependency {callee:?} of {gid:?} has no cache key")
+                    },
+                }
+            }
+            keys.insert(gid, Digest::as_bytes(&Blake3_256::hash(&bytes)));
+        }
+
+        keys
+    }
+
+    /// Computes the key of `module`, from which the [ModuleCache] keys of its procedures are
+    /// derived.
+    ///
+    /// The key is the hash of the module's path, kind, and source. The source is hashed both as
+    /// the module was written, when available, so that changes to the source locations recorded
+    /// in debug info invalidate the key, and in its pretty-printed form, which reflects the
+    /// resolution of its imports.
+    fn module_cache_key(&self, module: &Module, config: &[u8]) -> CacheKey {
+        let mut bytes = Vec::from(config);
+        module.path().to_string().write_into(&mut bytes);
+        module.kind().to_string().write_into(&mut bytes);
+        module.to_string().write_into(&mut bytes);
+        if let Ok(source_file) = self.source_manager.get(module.span().source_id()) {
+            source_file.name().write_into(&mut bytes);
+            source_file.as_str().write_into(&mut bytes);
+        }
+        Digest::as_bytes(&Blake3_256::hash(&bytes))
+    }
+
+    /// Partitions the specified procedures into groups which can be compiled independently of each
+    /// other.
+    ///
+    /// The procedures must be sorted such that every procedure is preceded by the AST procedures it
+    /// depends on. The result is a sequence of waves, each consisting of groups of procedures from
+    /// a single module, in their original relative order. The procedures of a group depend only on
+    /// procedures of the same group, procedures of earlier waves, or procedures not in
+    /// `procedures`. If modules depend on each other cyclically, all of their procedures are placed
+    /// in a single group of the last wave.
+    pub(crate) fn compilation_waves(
+        &self,
+        procedures: &[GlobalProcedureIndex],
+    ) -> Vec<Vec<Vec<GlobalProcedureIndex>>> {
+        let mut pending_modules: BTreeMap<ModuleIndex, Vec<GlobalProcedureIndex>> = BTreeMap::new();
+        for &gid in procedures {
+            pending_modules.entry(gid.module).or_default().push(gid);
+        }
+        let mut pending: BTreeSet<GlobalProcedureIndex> = procedures.iter().copied().collect();
+
+        let mut waves = Vec::new();
+        while !pending_modules.is_empty() {
+            let ready: Vec<ModuleIndex> = pending_modules
+                .iter()
+                .filter(|(module, procedures)| {
+                    procedures.iter().all(|&gid| {
+                        self.callgraph
+                            .out_edges(gid)
+                            .iter()
+                            .all(|callee| callee.module == **module || !pending.contains(callee))
+                    })
+                })
+                .map(|(module, _)| *module)
+                .collect();
+
+            if ready.is_empty() {
+                let remaining = procedures.iter().filter(|gid| pending.contains(gid)).copied();
+                waves.push(vec![remaining.collect()]);
+                break;
+            }
+
+            let wave = ready
+                .into_iter()
+                .map(|module| {
+                    let procedures = pending_modules.remove(&module).unwrap();
+                    for gid in procedures.iter() {
+                        pending.remove(gid);
+                    }
+                    procedures
+                })
+                .collect();
+            waves.push(wave);
+        }
+
+        waves
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 /// Accessors/Queries
 impl ModuleGraph {
@@ -519,7 +665,7 @@ impl ModuleGraph {
     ///
     /// # Panics
     /// - Panics if index is invalid.
-    pub fn get_procedure_unsafe(&self, id: GlobalProcedureIndex) -> ProcedureWrapper {
+    pub fn get_procedure_unsafe(&self, id: GlobalProcedureIndex) -> ProcedureWrapper<'_> {
         match &self.modules[id.module.as_usize()] {
             WrappedModule::Ast(m) => ProcedureWrapper::Ast(&m[id.index]),
             WrappedModule::Info(m) => {
@@ -162,6 +162,14 @@ impl Procedure {
         self.span = span;
         self
     }
+
+    /// Sets the MAST node ID of the procedure body, e.g. after the body was copied into another
+    /// MAST forest.
+    #[cfg(feature = "std")]
+    pub(crate) fn with_body_node_id(mut self, body_node_id: MastNodeId) -> Self {
+        self.body_node_id = body_node_id;
+        self
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
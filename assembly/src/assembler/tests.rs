@@ -148,8 +148,9 @@ fn nested_blocks() -> Result<(), Report> {
         .join_nodes(vec![before, r#if1, nested, exec_foo_bar_baz_node_id, syscall_foo_node_id])
         .unwrap();
 
-    let mut expected_mast_forest = expected_mast_forest_builder.build().0;
-    expected_mast_forest.make_root(combined_node_id);
+    expected_mast_forest_builder.make_root(combined_node_id);
+    let (expected_mast_forest, id_remappings) = expected_mast_forest_builder.build();
+    let combined_node_id = id_remappings[&combined_node_id];
     let expected_program = Program::new(expected_mast_forest.into(), combined_node_id);
     assert_eq!(expected_program.hash(), program.hash());
 
@@ -3,7 +3,11 @@ use core::fmt;
 
 use vm_core::FieldElement;
 
-use crate::{Felt, SourceSpan, Span, Spanned, ast::Ident, parser::ParsingError};
+use crate::{
+    Felt, SourceSpan, Span, Spanned,
+    ast::{AttributeSet, Ident},
+    parser::ParsingError,
+};
 
 // CONSTANT
 // ================================================================================================
@@ -18,12 +22,20 @@ pub struct Constant {
     pub name: Ident,
     /// The expression associated with the constant.
     pub value: ConstantExpr,
+    /// The attributes attached to this definition, e.g. `@allow(unused_constant)`.
+    pub attrs: AttributeSet,
 }
 
 impl Constant {
     /// Creates a new [Constant] from the given source span, name, and value.
     pub fn new(span: SourceSpan, name: Ident, value: ConstantExpr) -> Self {
-        Self { span, docs: None, name, value }
+        Self {
+            span,
+            docs: None,
+            name,
+            value,
+            attrs: Default::default(),
+        }
     }
 
     /// Adds documentation to this constant declaration.
@@ -31,6 +43,20 @@ impl Constant {
         self.docs = docs;
         self
     }
+
+    /// Adds attributes to this constant declaration.
+    pub fn with_attributes<I>(mut self, attrs: I) -> Self
+    where
+        I: IntoIterator<Item = crate::ast::Attribute>,
+    {
+        self.attrs.extend(attrs);
+        self
+    }
+
+    /// Returns the attributes attached to this constant declaration.
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.attrs
+    }
 }
 
 impl fmt::Debug for Constant {
@@ -39,6 +65,7 @@ impl fmt::Debug for Constant {
             .field("docs", &self.docs)
             .field("name", &self.name)
             .field("value", &self.value)
+            .field("attrs", &self.attrs)
             .finish()
     }
 }
@@ -58,6 +85,9 @@ impl crate::prettier::PrettyPrint for Constant {
         }
 
         doc += nl();
+        for attr in self.attrs.iter() {
+            doc += attr.render() + nl();
+        }
         doc += flatten(const_text("const") + const_text(".") + display(&self.name));
         doc += const_text("=");
 
@@ -1,6 +1,9 @@
 use core::fmt;
 
-use crate::{LibraryNamespace, LibraryPath, SourceSpan, Spanned, ast::Ident};
+use crate::{
+    LibraryNamespace, LibraryPath, SourceSpan, Spanned,
+    ast::{AttributeSet, Ident},
+};
 
 // IMPORT
 // ================================================================================================
@@ -19,6 +22,8 @@ pub struct Import {
     pub path: LibraryPath,
     /// The number of times this import has been used locally.
     pub uses: usize,
+    /// The attributes attached to this import, e.g. `@allow(unused_import)`.
+    pub attrs: AttributeSet,
 }
 
 impl Import {
@@ -41,6 +46,11 @@ impl Import {
     pub fn is_used(&self) -> bool {
         self.uses > 0
     }
+
+    /// Returns the attributes attached to this import.
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.attrs
+    }
 }
 
 impl fmt::Debug for Import {
@@ -49,6 +59,7 @@ impl fmt::Debug for Import {
             .field("name", &self.name)
             .field("path", &self.path)
             .field("uses", &self.uses)
+            .field("attrs", &self.attrs)
             .finish()
     }
 }
@@ -57,7 +68,14 @@ impl crate::prettier::PrettyPrint for Import {
     fn render(&self) -> crate::prettier::Document {
         use crate::prettier::*;
 
-        let mut doc = const_text("use") + const_text(".") + display(&self.path);
+        let mut doc = self
+            .attrs
+            .iter()
+            .map(|attr| attr.render() + nl())
+            .reduce(|acc, attr| acc + attr)
+            .unwrap_or(Document::Empty);
+
+        doc += const_text("use") + const_text(".") + display(&self.path);
         if self.is_aliased() {
             doc += const_text("->") + display(&self.name);
         }
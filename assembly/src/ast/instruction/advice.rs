@@ -63,7 +63,7 @@ impl fmt::Display for SystemEventNode {
             Self::InsertMem => write!(f, "insert_mem"),
             Self::InsertHdword => write!(f, "insert_hdword"),
             Self::InsertHdwordWithDomain => write!(f, "insert_hdword_d"),
-            Self::InsertHperm => writeln!(f, "insert_hperm"),
+            Self::InsertHperm => write!(f, "insert_hperm"),
         }
     }
 }
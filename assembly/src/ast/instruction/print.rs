@@ -282,11 +282,11 @@ impl PrettyPrint for Instruction {
             Self::Exec(InvocationTarget::AbsoluteProcedurePath { name, path }) => {
                 const_text("exec") + const_text(".") + text(format!("::{}::{}", path, name))
             },
-            Self::Call(InvocationTarget::MastRoot(root)) => {
+            Self::Call(InvocationTarget::MastRoot(root)) => flatten(
                 const_text("call")
                     + const_text(".")
-                    + text(format!("{:#x}", DisplayHex(root.as_bytes().as_slice())))
-            },
+                    + text(format!("{:#x}", DisplayHex(root.as_bytes().as_slice()))),
+            ),
             Self::Call(InvocationTarget::ProcedureName(name)) => {
                 flatten(const_text("call") + const_text(".") + text(name))
             },
@@ -296,11 +296,11 @@ impl PrettyPrint for Instruction {
             Self::Call(InvocationTarget::AbsoluteProcedurePath { name, path }) => {
                 const_text("call") + const_text(".") + text(format!("::{}::{}", path, name))
             },
-            Self::SysCall(InvocationTarget::MastRoot(root)) => {
+            Self::SysCall(InvocationTarget::MastRoot(root)) => flatten(
                 const_text("syscall")
                     + const_text(".")
-                    + text(format!("{:#x}", DisplayHex(root.as_bytes().as_slice())))
-            },
+                    + text(format!("{:#x}", DisplayHex(root.as_bytes().as_slice()))),
+            ),
             Self::SysCall(InvocationTarget::ProcedureName(name)) => {
                 flatten(const_text("syscall") + const_text(".") + text(format!("{}", name)))
             },
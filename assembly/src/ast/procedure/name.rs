@@ -1,14 +1,13 @@
-use alloc::{
-    string::{String, ToString},
-    sync::Arc,
-};
+use alloc::{string::ToString, sync::Arc};
 use core::{
     fmt,
     hash::{Hash, Hasher},
     str::FromStr,
 };
 
-use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use vm_core::utils::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, read_string,
+};
 
 use crate::{
     LibraryNamespace, LibraryPath, SourceSpan, Span, Spanned,
@@ -361,7 +360,7 @@ impl Serializable for ProcedureName {
 
 impl Deserializable for ProcedureName {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let str: String = source.read()?;
+        let str = read_string(source)?;
         let proc_name = ProcedureName::new(str)
             .map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
         Ok(proc_name)
@@ -3,7 +3,7 @@ use alloc::{string::ToString, sync::Arc, vec::Vec};
 use pretty_assertions::assert_eq;
 
 use crate::{
-    Felt, Span, assert_diagnostic, assert_diagnostic_lines,
+    Felt, Lint, LintLevel, Span, assert_diagnostic, assert_diagnostic_lines,
     ast::*,
     diagnostics::{Report, reporting::PrintDiagnostic},
     regex, source_file,
@@ -124,6 +124,7 @@ macro_rules! import {
             name,
             path,
             uses: 0,
+            attrs: Default::default(),
         })
     }};
 
@@ -135,6 +136,7 @@ macro_rules! import {
             name,
             path,
             uses: 0,
+            attrs: Default::default(),
         })
     };
 }
@@ -804,7 +806,7 @@ fn test_ast_parsing_simple_docs() -> Result<(), Report> {
 
 #[test]
 fn test_ast_parsing_module_docs_valid() {
-    let context = TestContext::new();
+    let context = TestContext::new().with_lint_level(Lint::UnusedProcedure, LintLevel::Allow);
 
     let source = source_file!(
         &context,
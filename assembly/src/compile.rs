@@ -15,6 +15,7 @@ use crate::{
         WrapErr,
     },
     library::{LibraryNamespace, LibraryPath},
+    sema::LintConfig,
 };
 
 // COMPILE OPTIONS
@@ -29,6 +30,8 @@ pub struct Options {
     pub kind: ModuleKind,
     /// When true, promote warning diagnostics to errors
     pub warnings_as_errors: bool,
+    /// The levels of the lints checked during compilation, unless overridden by lint attributes
+    pub lints: LintConfig,
     /// The name to give the compiled [Module]
     ///
     /// This option overrides `namespace`.
@@ -44,6 +47,7 @@ impl Default for Options {
         Self {
             kind: ModuleKind::Executable,
             warnings_as_errors: false,
+            lints: Default::default(),
             path: None,
         }
     }
@@ -193,6 +197,7 @@ impl Compile for Arc<SourceFile> {
         };
         let mut parser = Module::parser(options.kind);
         parser.set_warnings_as_errors(options.warnings_as_errors);
+        parser.set_lints(options.lints);
         parser.parse(path, source_file)
     }
 }
@@ -245,6 +250,7 @@ impl Compile for Box<str> {
         let name = Arc::<str>::from(path.path().into_owned().into_boxed_str());
         let mut parser = Module::parser(options.kind);
         parser.set_warnings_as_errors(options.warnings_as_errors);
+        parser.set_lints(options.lints);
         let content = SourceContent::new(name.clone(), self);
         let source_file = source_manager.load_from_raw_parts(name, content);
         parser.parse(path, source_file)
@@ -342,6 +348,7 @@ where
         let source_file = source_manager.load_from_raw_parts(name, content);
         let mut parser = Module::parser(options.kind);
         parser.set_warnings_as_errors(options.warnings_as_errors);
+        parser.set_lints(options.lints);
         parser.parse(path, source_file)
     }
 }
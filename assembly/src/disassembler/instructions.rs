@@ -0,0 +1,366 @@
+use vm_core::{
+    Felt, ONE, Operation,
+    Operation::*,
+    ZERO,
+    sys_events::{
+        EVENT_EXT2_INV, EVENT_MERKLE_NODE_MERGE, EVENT_MERKLE_NODE_TO_STACK, EVENT_U32_CLO,
+        EVENT_U32_CLZ, EVENT_U32_CTO, EVENT_U32_CTZ, SystemEvent,
+    },
+};
+
+use crate::ast::{Instruction, SystemEventNode};
+
+// FIXED OPERATION SEQUENCES
+// ================================================================================================
+
+/// Operation sequences emitted by the assembler for instructions which do not take any
+/// immediate values.
+///
+/// The sequences are matched in order, so a sequence must appear before any other sequence which
+/// is a prefix of it.
+#[rustfmt::skip]
+const FIXED_SEQUENCES: &[(&[Operation], Instruction)] = &[
+    // ----- cryptographic instructions -----------------------------------------------------------
+    (&[
+        Emit(EVENT_MERKLE_NODE_TO_STACK), AdvPop, AdvPop, AdvPop, AdvPop,
+        MovUp5, MovUp5, Dup5, Dup5, SwapDW, SwapW, SwapW2, Dup13, Dup13, MrUpdate,
+        MovUp4, Drop, MovUp4, Drop, SwapW, Drop, Drop, Drop, Drop, SwapW, Drop, Drop, Drop, Drop,
+        SwapW,
+    ], Instruction::MTreeSet),
+    (&[
+        Emit(EVENT_MERKLE_NODE_TO_STACK), AdvPop, AdvPop, AdvPop, AdvPop,
+        MpVerify(0), MovUp4, Drop, MovUp4, Drop,
+    ], Instruction::MTreeGet),
+    (&[
+        Emit(EVENT_MERKLE_NODE_MERGE),
+        Pad, Pad, Pad, Pad, SwapW2, SwapW, HPerm, Drop, Drop, Drop, Drop, SwapW, Drop, Drop, Drop,
+        Drop,
+    ], Instruction::MTreeMerge),
+    (&[
+        Push(Felt::new(4)), Pad, Pad, Pad, SwapW, Pad, Dup7, Dup7, Dup7, HPerm,
+        Drop, Drop, Drop, Drop, SwapW, Drop, Drop, Drop, Drop,
+    ], Instruction::Hash),
+    (&[
+        Pad, Pad, Pad, Pad, SwapW2, SwapW, HPerm, Drop, Drop, Drop, Drop, SwapW, Drop, Drop, Drop,
+        Drop,
+    ], Instruction::HMerge),
+    // ----- field instructions -------------------------------------------------------------------
+    (&[
+        Push(Felt::new(2)), Pad, Incr, Swap, Pad, Expacc, Expacc, Expacc, Expacc, Expacc, Expacc,
+        Drop, Drop, Swap, Eqz, Assert(0),
+    ], Instruction::Pow2),
+    (&[Dup0, Dup2, Or, MovDn2, And, Not, And], Instruction::Xor),
+    (&[Neg, Add], Instruction::Sub),
+    (&[Inv, Mul], Instruction::Div),
+    (&[Eq, Not], Instruction::Neq),
+    (&[Ext2Mul, Drop, Drop], Instruction::Ext2Mul),
+    (&[
+        Emit(EVENT_EXT2_INV), AdvPop, AdvPop, Ext2Mul, MovUp2, Eqz, Assert(0), MovUp2, Assert(0),
+        Ext2Mul, Drop, Drop,
+    ], Instruction::Ext2Div),
+    (&[
+        Emit(EVENT_EXT2_INV), AdvPop, AdvPop, Ext2Mul, MovUp2, Eqz, Assert(0), MovUp2, Assert(0),
+    ], Instruction::Ext2Inv),
+    // ----- u32 instructions ---------------------------------------------------------------------
+    (&[Dup0, U32split, Swap, Drop, Eqz], Instruction::U32Test),
+    (&[Dup1, Dup1, U32and, Neg, Add, Add], Instruction::U32Or),
+    (&[U32split, Drop], Instruction::U32Cast),
+    (&[
+        Emit(EVENT_U32_CLZ), AdvPop, Push(Felt::new(32)), Dup1, Neg, Add,
+        Push(Felt::new(2)), Pad, Incr, Swap, Pad, Expacc, Expacc, Expacc, Expacc, Expacc, Expacc,
+        Drop, Drop, Swap, Eqz, Assert(0),
+        Push(ONE), Neg, Add, Push(Felt::new(2)), U32div, Drop, Dup0, Incr,
+        Push(Felt::new(u32::MAX as u64)), MovUp2, Neg, Add, Dup3, Eqz, MovDn3, MovUp4, U32and, Eq,
+        MovUp2, Or, Assert(0),
+    ], Instruction::U32Clz),
+    (&[
+        Emit(EVENT_U32_CLO), AdvPop, Push(Felt::new(32)), Dup1, Neg, Add,
+        Push(Felt::new(2)), Pad, Incr, Swap, Pad, Expacc, Expacc, Expacc, Expacc, Expacc, Expacc,
+        Drop, Drop, Swap, Eqz, Assert(0),
+        Push(ONE), Neg, Add, Dup0, Push(Felt::new(2)), U32div, Drop,
+        Push(Felt::new(u32::MAX as u64)), Swap, Neg, Add, MovUp3, U32and,
+        Push(Felt::new(u32::MAX as u64)), MovUp2, Neg, Add, Eq, Assert(0),
+    ], Instruction::U32Clo),
+    (&[
+        Emit(EVENT_U32_CTZ), AdvPop, Swap, Dup1,
+        Push(Felt::new(2)), Pad, Incr, Swap, Pad, Expacc, Expacc, Expacc, Expacc, Expacc, Expacc,
+        Drop, Drop, Swap, Eqz, Assert(0),
+        Dup0, Pad, Incr, Neg, Add, Swap, U32split, Drop, Dup0, MovUp2, Add, MovUp2, U32and, Eq,
+        Assert(0),
+    ], Instruction::U32Ctz),
+    (&[
+        Emit(EVENT_U32_CTO), AdvPop, Swap, Dup1,
+        Push(Felt::new(2)), Pad, Incr, Swap, Pad, Expacc, Expacc, Expacc, Expacc, Expacc, Expacc,
+        Drop, Drop, Swap, Eqz, Assert(0),
+        Dup0, Pad, Incr, Neg, Add, Swap, U32split, Drop, Dup1, Add, MovUp2, U32and, Eq, Assert(0),
+    ], Instruction::U32Cto),
+    (&[U32add, Drop], Instruction::U32WrappingAdd),
+    (&[U32add3, Drop], Instruction::U32WrappingAdd3),
+    (&[U32sub, Drop], Instruction::U32WrappingSub),
+    (&[U32mul, Drop], Instruction::U32WrappingMul),
+    (&[U32madd, Drop], Instruction::U32WrappingMadd),
+    (&[U32div, Swap, Drop], Instruction::U32Mod),
+    (&[U32div, Drop], Instruction::U32Div),
+    // ----- stack manipulation -------------------------------------------------------------------
+    (&[Drop, Drop, Drop, Drop], Instruction::DropW),
+    (&[Pad, Pad, Pad, Pad], Instruction::PadW),
+    (&[Dup3, Dup3, Dup3, Dup3], Instruction::DupW0),
+    (&[Dup7, Dup7, Dup7, Dup7], Instruction::DupW1),
+    (&[Dup11, Dup11, Dup11, Dup11], Instruction::DupW2),
+    (&[Dup15, Dup15, Dup15, Dup15], Instruction::DupW3),
+    (&[Pad, Dup9, Add], Instruction::Dup8),
+    (&[Pad, Dup11, Add], Instruction::Dup10),
+    (&[Pad, Dup13, Add], Instruction::Dup12),
+    (&[Pad, Dup15, Add], Instruction::Dup14),
+    (&[MovDn8, SwapDW, Swap, SwapDW, MovUp8], Instruction::Swap9),
+    (&[MovDn8, SwapDW, Swap, MovUp2, SwapDW, MovUp8], Instruction::Swap10),
+    (&[MovDn8, SwapDW, MovDn2, MovUp3, SwapDW, MovUp8], Instruction::Swap11),
+    (&[MovDn8, SwapDW, MovDn3, MovUp4, SwapDW, MovUp8], Instruction::Swap12),
+    (&[MovDn8, SwapDW, MovDn4, MovUp5, SwapDW, MovUp8], Instruction::Swap13),
+    (&[MovDn8, SwapDW, MovDn5, MovUp6, SwapDW, MovUp8], Instruction::Swap14),
+    (&[MovDn8, SwapDW, MovDn6, MovUp7, SwapDW, MovUp8], Instruction::Swap15),
+    (&[MovDn8, SwapDW, Swap, SwapDW], Instruction::MovDn9),
+    (&[MovDn8, SwapDW, MovDn2, SwapDW], Instruction::MovDn10),
+    (&[MovDn8, SwapDW, MovDn3, SwapDW], Instruction::MovDn11),
+    (&[MovDn8, SwapDW, MovDn4, SwapDW], Instruction::MovDn12),
+    (&[MovDn8, SwapDW, MovDn5, SwapDW], Instruction::MovDn13),
+    (&[MovDn8, SwapDW, MovDn6, SwapDW], Instruction::MovDn14),
+    (&[MovDn8, SwapDW, MovDn7, SwapDW], Instruction::MovDn15),
+    (&[SwapDW, Swap, SwapDW, MovUp8], Instruction::MovUp9),
+    (&[SwapDW, MovUp2, SwapDW, MovUp8], Instruction::MovUp10),
+    (&[SwapDW, MovUp3, SwapDW, MovUp8], Instruction::MovUp11),
+    (&[SwapDW, MovUp4, SwapDW, MovUp8], Instruction::MovUp12),
+    (&[SwapDW, MovUp5, SwapDW, MovUp8], Instruction::MovUp13),
+    (&[SwapDW, MovUp6, SwapDW, MovUp8], Instruction::MovUp14),
+    (&[SwapDW, MovUp7, SwapDW, MovUp8], Instruction::MovUp15),
+    (&[SwapW, SwapW2, SwapW3], Instruction::MovUpW3),
+    (&[SwapW, SwapW2], Instruction::MovUpW2),
+    (&[SwapW3, SwapW2, SwapW], Instruction::MovDnW3),
+    (&[SwapW2, SwapW], Instruction::MovDnW2),
+    (&[Swap, MovUp2], Instruction::Swap2),
+    (&[MovDn2, MovUp3], Instruction::Swap3),
+    (&[MovDn3, MovUp4], Instruction::Swap4),
+    (&[MovDn4, MovUp5], Instruction::Swap5),
+    (&[MovDn5, MovUp6], Instruction::Swap6),
+    (&[MovDn6, MovUp7], Instruction::Swap7),
+    (&[MovDn7, MovUp8], Instruction::Swap8),
+    (&[CSwapW, Drop, Drop, Drop, Drop], Instruction::CDropW),
+    (&[CSwap, Drop], Instruction::CDrop),
+    // ----- memory instructions ------------------------------------------------------------------
+    (&[MStore, Drop], Instruction::MemStore),
+];
+
+// INSTRUCTION DECODING
+// ================================================================================================
+
+/// Decodes the instruction at the start of `ops`, returning the instruction along with the
+/// number of operations it spans.
+///
+/// `num_locals` is the number of locals declared by the procedure containing the operations, and
+/// is used to recover `locaddr` and `loc_*` instructions.
+pub(super) fn decode_instruction(
+    ops: &[Operation],
+    num_locals: u16,
+) -> Option<(Instruction, usize)> {
+    decode_local_access(ops, num_locals)
+        .or_else(|| decode_with_immediate(ops))
+        .or_else(|| {
+            FIXED_SEQUENCES
+                .iter()
+                .find(|(sequence, _)| ops.starts_with(sequence))
+                .map(|(sequence, instruction)| (instruction.clone(), sequence.len()))
+        })
+        .or_else(|| decode_single(ops[0]).map(|instruction| (instruction, 1)))
+}
+
+/// Decodes instructions accessing procedure locals, i.e. `locaddr` and `loc_*` instructions.
+///
+/// The assembler computes the absolute address of a local as `fmp - (num_locals - index)`.
+fn decode_local_access(ops: &[Operation], num_locals: u16) -> Option<(Instruction, usize)> {
+    let [Push(offset), FmpAdd, rest @ ..] = ops else {
+        return None;
+    };
+    let offset = (ZERO - *offset).as_int();
+    if num_locals == 0 || offset == 0 || offset > num_locals as u64 {
+        return None;
+    }
+    let index = num_locals - offset as u16;
+    let is_word_access_valid = offset >= 4 && index % 4 == 0;
+
+    let decoded = match rest {
+        [MLoad, ..] => (Instruction::LocLoad(index.into()), 3),
+        [MLoadW, ..] if is_word_access_valid => (Instruction::LocLoadW(index.into()), 3),
+        [MStore, Drop, ..] => (Instruction::LocStore(index.into()), 4),
+        [MStoreW, ..] if is_word_access_valid => (Instruction::LocStoreW(index.into()), 3),
+        _ => (Instruction::Locaddr(index.into()), 2),
+    };
+    Some(decoded)
+}
+
+/// Decodes instructions whose operation sequence depends on an immediate value.
+fn decode_with_immediate(ops: &[Operation]) -> Option<(Instruction, usize)> {
+    if let Some(decoded) = decode_exp_bit_length(ops) {
+        return Some(decoded);
+    }
+
+    let decoded = match ops {
+        [Eq, Assert(0), ..] => (Instruction::AssertEq, 2),
+        [Eq, Assert(err_code), ..] => (Instruction::AssertEqWithError((*err_code).into()), 2),
+        [Eqz, Assert(0), ..] => (Instruction::Assertz, 2),
+        [Eqz, Assert(err_code), ..] => (Instruction::AssertzWithError((*err_code).into()), 2),
+        [Pad, U32assert2(0), Drop, ..] => (Instruction::U32Assert, 3),
+        [Pad, U32assert2(err_code), Drop, ..] => {
+            (Instruction::U32AssertWithError((*err_code).into()), 3)
+        },
+        [Push(imm), Add, ..] if imm.as_int() > 2 => (Instruction::AddImm((*imm).into()), 2),
+        [Push(imm), Mul, ..] if imm.as_int() > 1 => (Instruction::MulImm((*imm).into()), 2),
+        [Push(imm), Eq, ..] if *imm != ZERO => (Instruction::EqImm((*imm).into()), 2),
+        [Push(addr), MLoad, ..] => (Instruction::MemLoadImm(as_u32(*addr)?.into()), 2),
+        [Push(addr), MLoadW, ..] => (Instruction::MemLoadWImm(as_u32(*addr)?.into()), 2),
+        [Push(addr), MStore, Drop, ..] => (Instruction::MemStoreImm(as_u32(*addr)?.into()), 3),
+        [Push(addr), MStoreW, ..] => (Instruction::MemStoreWImm(as_u32(*addr)?.into()), 2),
+        [Pad, Incr, ..] => (Instruction::Push(ONE.into()), 2),
+        [AdvPop, ..] => {
+            let count = ops.iter().take(16).take_while(|op| matches!(op, AdvPop)).count();
+            (Instruction::AdvPush((count as u8).into()), count)
+        },
+        _ => return None,
+    };
+    Some(decoded)
+}
+
+/// Decodes the `exp.uN` instruction, which raises a value to a power of at most `N` bits.
+fn decode_exp_bit_length(ops: &[Operation]) -> Option<(Instruction, usize)> {
+    let [Pad, Incr, MovUp2, Pad, rest @ ..] = ops else {
+        return None;
+    };
+    let num_bits = rest.iter().take_while(|op| matches!(op, Expacc)).count();
+    match &rest[num_bits..] {
+        [Drop, Drop, Swap, Eqz, Assert(0), ..] if num_bits < 64 => {
+            Some((Instruction::ExpBitLength(num_bits as u8), num_bits + 9))
+        },
+        _ => None,
+    }
+}
+
+/// Decodes instructions which compile to exactly one operation.
+fn decode_single(op: Operation) -> Option<Instruction> {
+    let instruction = match op {
+        Noop => Instruction::Nop,
+        Assert(0) => Instruction::Assert,
+        Assert(err_code) => Instruction::AssertWithError(err_code.into()),
+        SDepth => Instruction::Sdepth,
+        Caller => Instruction::Caller,
+        Clk => Instruction::Clk,
+        Emit(event_id) => match SystemEvent::from_event_id(event_id).and_then(to_event_node) {
+            Some(event) => Instruction::SysEvent(event),
+            None => Instruction::Emit(event_id.into()),
+        },
+
+        Add => Instruction::Add,
+        Neg => Instruction::Neg,
+        Mul => Instruction::Mul,
+        Inv => Instruction::Inv,
+        Incr => Instruction::Incr,
+        And => Instruction::And,
+        Or => Instruction::Or,
+        Not => Instruction::Not,
+        Eq => Instruction::Eq,
+        Eqz => Instruction::EqImm(ZERO.into()),
+
+        U32split => Instruction::U32Split,
+        U32add => Instruction::U32OverflowingAdd,
+        U32assert2(0) => Instruction::U32Assert2,
+        U32assert2(err_code) => Instruction::U32Assert2WithError(err_code.into()),
+        U32add3 => Instruction::U32OverflowingAdd3,
+        U32sub => Instruction::U32OverflowingSub,
+        U32mul => Instruction::U32OverflowingMul,
+        U32madd => Instruction::U32OverflowingMadd,
+        U32div => Instruction::U32DivMod,
+        U32and => Instruction::U32And,
+        U32xor => Instruction::U32Xor,
+
+        Pad => Instruction::Push(ZERO.into()),
+        Drop => Instruction::Drop,
+        Dup0 => Instruction::Dup0,
+        Dup1 => Instruction::Dup1,
+        Dup2 => Instruction::Dup2,
+        Dup3 => Instruction::Dup3,
+        Dup4 => Instruction::Dup4,
+        Dup5 => Instruction::Dup5,
+        Dup6 => Instruction::Dup6,
+        Dup7 => Instruction::Dup7,
+        Dup9 => Instruction::Dup9,
+        Dup11 => Instruction::Dup11,
+        Dup13 => Instruction::Dup13,
+        Dup15 => Instruction::Dup15,
+        Swap => Instruction::Swap1,
+        SwapW => Instruction::SwapW1,
+        SwapW2 => Instruction::SwapW2,
+        SwapW3 => Instruction::SwapW3,
+        SwapDW => Instruction::SwapDw,
+        MovUp2 => Instruction::MovUp2,
+        MovUp3 => Instruction::MovUp3,
+        MovUp4 => Instruction::MovUp4,
+        MovUp5 => Instruction::MovUp5,
+        MovUp6 => Instruction::MovUp6,
+        MovUp7 => Instruction::MovUp7,
+        MovUp8 => Instruction::MovUp8,
+        MovDn2 => Instruction::MovDn2,
+        MovDn3 => Instruction::MovDn3,
+        MovDn4 => Instruction::MovDn4,
+        MovDn5 => Instruction::MovDn5,
+        MovDn6 => Instruction::MovDn6,
+        MovDn7 => Instruction::MovDn7,
+        MovDn8 => Instruction::MovDn8,
+        CSwap => Instruction::CSwap,
+        CSwapW => Instruction::CSwapW,
+
+        // the assembler always emits `PAD` and `PAD INCR` when pushing 0 and 1 respectively
+        Push(imm) if imm != ZERO && imm != ONE => Instruction::Push(imm.into()),
+        AdvPopW => Instruction::AdvLoadW,
+        MLoadW => Instruction::MemLoadW,
+        MStoreW => Instruction::MemStoreW,
+        MLoad => Instruction::MemLoad,
+        MStream => Instruction::MemStream,
+        Pipe => Instruction::AdvPipe,
+
+        HPerm => Instruction::HPerm,
+        MpVerify(0) => Instruction::MTreeVerify,
+        MpVerify(err_code) => Instruction::MTreeVerifyWithError(err_code.into()),
+        FriE2F4 => Instruction::FriExt2Fold4,
+        HornerBase => Instruction::HornerBase,
+        HornerExt => Instruction::HornerExt,
+
+        _ => return None,
+    };
+    Some(instruction)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the [SystemEventNode] which is assembled into the specified system event, if the event
+/// can be emitted directly via an `adv.*` instruction.
+fn to_event_node(event: SystemEvent) -> Option<SystemEventNode> {
+    let node = match event {
+        SystemEvent::U64Div => SystemEventNode::PushU64Div,
+        SystemEvent::FalconDiv => SystemEventNode::PushFalconDiv,
+        SystemEvent::Ext2Intt => SystemEventNode::PushExt2intt,
+        SystemEvent::SmtPeek => SystemEventNode::PushSmtPeek,
+        SystemEvent::MapValueToStack => SystemEventNode::PushMapVal,
+        SystemEvent::MapValueToStackN => SystemEventNode::PushMapValN,
+        SystemEvent::MerkleNodeToStack => SystemEventNode::PushMtNode,
+        SystemEvent::MemToMap => SystemEventNode::InsertMem,
+        SystemEvent::HdwordToMap => SystemEventNode::InsertHdword,
+        SystemEvent::HdwordToMapWithDomain => SystemEventNode::InsertHdwordWithDomain,
+        SystemEvent::HpermToMap => SystemEventNode::InsertHperm,
+        _ => return None,
+    };
+    Some(node)
+}
+
+/// Returns the specified field element as a `u32` memory address, if it fits.
+fn as_u32(value: Felt) -> Option<u32> {
+    u32::try_from(value.as_int()).ok().filter(|&addr| addr > 1)
+}
@@ -0,0 +1,817 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::fmt::{self, Write};
+
+use vm_core::{
+    Operation, Program,
+    mast::{MastForest, MastNode, MastNodeId},
+};
+
+use crate::{
+    LibraryNamespace, LibraryPath, RpoDigest, Span,
+    assembler::PROCEDURE_INLINING_THRESHOLD,
+    ast::{Instruction, InvocationTarget, ProcedureName, QualifiedProcedureName},
+    library::Library,
+};
+
+mod instructions;
+#[cfg(test)]
+mod tests;
+
+// DISASSEMBLER
+// ================================================================================================
+
+/// Reconstructs Miden Assembly source code from compiled MAST.
+///
+/// The disassembler recovers the control flow structure of each procedure (i.e., `if`, `while`,
+/// and `repeat` blocks, as well as `exec`, `call`, and `syscall` invocations), and decodes the
+/// operations of each basic block back into assembly instructions. The produced source code
+/// assembles back into MAST with the same digest as the original, with the following exceptions:
+/// - Operation sequences for which no assembly instruction exists (e.g., sequences produced by
+///   hand-built MAST forests) are emitted as comments. The number of such operations is reported
+///   via [DisassembledModule::num_unsupported_ops].
+/// - Names of procedures are not retained in MAST. Procedures exported from a library keep their
+///   exported names, and procedures from libraries registered with the disassembler (see
+///   [Self::with_library]) are referenced by their fully-qualified names. All other procedures
+///   are given synthetic names, or are referenced by MAST root.
+#[derive(Debug, Default, Clone)]
+pub struct Disassembler {
+    /// Fully-qualified names of procedures from known libraries, keyed by MAST root.
+    known_procedures: BTreeMap<RpoDigest, QualifiedProcedureName>,
+}
+
+/// Constructors
+impl Disassembler {
+    /// Returns a new [Disassembler] without any known libraries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the procedures exported from the specified library with this disassembler.
+    ///
+    /// References to these procedures in disassembled code are emitted using their
+    /// fully-qualified names rather than their MAST roots.
+    pub fn with_library(mut self, library: impl AsRef<Library>) -> Self {
+        self.add_library(library);
+        self
+    }
+
+    /// Registers the procedures exported from the specified library with this disassembler.
+    ///
+    /// See [Self::with_library] for details.
+    pub fn add_library(&mut self, library: impl AsRef<Library>) {
+        let library = library.as_ref();
+        for proc_name in library.exports() {
+            let node_id = library.get_export_node_id(proc_name);
+            let digest = library.mast_forest()[node_id].digest();
+            self.known_procedures.entry(digest).or_insert_with(|| proc_name.clone());
+        }
+    }
+}
+
+/// Disassembly
+impl Disassembler {
+    /// Disassembles the specified program into a single executable module.
+    pub fn disassemble_program(&self, program: &Program) -> DisassembledModule {
+        let forest = program.mast_forest();
+        let entrypoint_digest = forest[program.entrypoint()].digest();
+
+        // all procedures other than the entrypoint are given synthetic names
+        let mut local_procedures = LocalProcedures::default();
+        for &root in forest.procedure_roots() {
+            if forest[root].digest() != entrypoint_digest {
+                local_procedures.add_private(forest, root);
+            }
+        }
+
+        let path = LibraryPath::from(LibraryNamespace::Exec);
+        let mut module = ModuleDisassembler::new(forest, &path, &local_procedures, self);
+        let entrypoint = module.procedure(program.entrypoint());
+        module.finish(&[], Some(&entrypoint))
+    }
+
+    /// Disassembles the specified library, returning one module per module of the library.
+    ///
+    /// Procedures which are not exported from the library are emitted in every module which
+    /// references them.
+    pub fn disassemble_library(&self, library: &Library) -> Vec<DisassembledModule> {
+        let forest = library.mast_forest();
+
+        let mut local_procedures = LocalProcedures::default();
+        let mut exports_by_module = BTreeMap::<&LibraryPath, Vec<&QualifiedProcedureName>>::new();
+        for proc_name in library.exports() {
+            exports_by_module.entry(&proc_name.module).or_default().push(proc_name);
+            if !library.is_reexport(proc_name) {
+                let root = library.get_export_node_id(proc_name);
+                local_procedures.add_export(forest, root, proc_name);
+            }
+        }
+        for &root in forest.procedure_roots() {
+            local_procedures.add_private(forest, root);
+        }
+
+        exports_by_module
+            .into_iter()
+            .map(|(path, exports)| {
+                let mut module = ModuleDisassembler::new(forest, path, &local_procedures, self);
+                let mut aliases = Vec::new();
+                for proc_name in exports {
+                    let root = library.get_export_node_id(proc_name);
+                    if library.is_reexport(proc_name) {
+                        let target = module.invocation_target(forest[root].digest(), false);
+                        aliases.push((target, proc_name.name.clone()));
+                    } else {
+                        module.visit_export(proc_name, forest[root].digest());
+                    }
+                }
+                module.finish(&aliases, None)
+            })
+            .collect()
+    }
+}
+
+// DISASSEMBLED MODULE
+// ================================================================================================
+
+/// Miden Assembly source code of a single module reconstructed by the [Disassembler].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisassembledModule {
+    path: LibraryPath,
+    source: String,
+    num_unsupported_ops: usize,
+}
+
+impl DisassembledModule {
+    /// Returns the path of this module.
+    ///
+    /// For disassembled programs, this is always the executable namespace (i.e., `$exec`).
+    pub fn path(&self) -> &LibraryPath {
+        &self.path
+    }
+
+    /// Returns the Miden Assembly source code of this module.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the number of operations and nodes which could not be expressed in assembly.
+    pub fn num_unsupported_ops(&self) -> usize {
+        self.num_unsupported_ops
+    }
+
+    /// Returns true if the source code of this module assembles back into MAST identical to the
+    /// disassembled MAST.
+    pub fn is_lossless(&self) -> bool {
+        self.num_unsupported_ops == 0
+    }
+}
+
+impl fmt::Display for DisassembledModule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+// STATEMENTS
+// ================================================================================================
+
+/// A statement of the reconstructed procedure body.
+#[derive(Debug, Clone)]
+enum Stmt {
+    Inst(Instruction),
+    /// An operation or node for which no assembly equivalent exists.
+    Unsupported(String),
+    If {
+        then_blk: Vec<Stmt>,
+        else_blk: Vec<Stmt>,
+    },
+    While {
+        body: Vec<Stmt>,
+    },
+    Repeat {
+        count: usize,
+        body: Vec<Stmt>,
+    },
+}
+
+/// A procedure reconstructed by the [Disassembler].
+struct DisassembledProcedure {
+    num_locals: u16,
+    body: Vec<Stmt>,
+}
+
+// LOCAL PROCEDURES
+// ================================================================================================
+
+/// Procedures which are defined in the disassembled MAST forest, keyed by MAST root.
+#[derive(Default)]
+struct LocalProcedures {
+    procedures: BTreeMap<RpoDigest, LocalProcedure>,
+    /// Operations of procedures with locals whose body consists of a single basic block.
+    ///
+    /// When such a procedure is executed via `exec`, its body is merged into the basic block of
+    /// the caller, so invocations can only be recovered by matching the operations of the block.
+    inlined_frames: Vec<(RpoDigest, Vec<Operation>)>,
+    num_private: usize,
+}
+
+struct LocalProcedure {
+    root: MastNodeId,
+    /// The exported name of the procedure, or `None` for procedures which were not exported.
+    export: Option<QualifiedProcedureName>,
+    name: ProcedureName,
+}
+
+impl LocalProcedures {
+    fn add_export(&mut self, forest: &MastForest, root: MastNodeId, name: &QualifiedProcedureName) {
+        if !self.procedures.contains_key(&forest[root].digest()) {
+            self.insert(forest, root, Some(name.clone()), name.name.clone());
+        }
+    }
+
+    fn add_private(&mut self, forest: &MastForest, root: MastNodeId) {
+        if forest[root].is_external() || self.procedures.contains_key(&forest[root].digest()) {
+            return;
+        }
+        let name = ProcedureName::new(format!("proc_{}", self.num_private))
+            .expect("synthetic procedure name is a valid identifier");
+        self.num_private += 1;
+        self.insert(forest, root, None, name);
+    }
+
+    fn insert(
+        &mut self,
+        forest: &MastForest,
+        root: MastNodeId,
+        export: Option<QualifiedProcedureName>,
+        name: ProcedureName,
+    ) {
+        let digest = forest[root].digest();
+        if let Some(block) = forest[root].get_basic_block() {
+            let ops: Vec<Operation> = block.operations().copied().collect();
+            if matches!(ops.as_slice(), [Operation::Push(_), Operation::FmpUpdate, ..]) {
+                self.inlined_frames.push((digest, ops));
+            }
+        }
+        self.procedures.insert(digest, LocalProcedure { root, export, name });
+    }
+
+    /// Returns the MAST root and the number of operations of the procedure with locals whose
+    /// body was inlined at the start of `ops`, if any.
+    fn find_inlined_frame(&self, ops: &[Operation]) -> Option<(RpoDigest, usize)> {
+        if !matches!(ops, [Operation::Push(_), Operation::FmpUpdate, ..]) {
+            return None;
+        }
+        self.inlined_frames
+            .iter()
+            .filter(|(_, frame)| ops.starts_with(frame))
+            .max_by_key(|(_, frame)| frame.len())
+            .map(|(digest, frame)| (*digest, frame.len()))
+    }
+}
+
+// MODULE DISASSEMBLER
+// ================================================================================================
+
+/// Reconstructs the source code of a single module.
+struct ModuleDisassembler<'a> {
+    forest: &'a MastForest,
+    path: &'a LibraryPath,
+    local_procedures: &'a LocalProcedures,
+    known_procedures: &'a BTreeMap<RpoDigest, QualifiedProcedureName>,
+    /// Local procedures referenced by the procedure being disassembled.
+    referenced: BTreeSet<RpoDigest>,
+    /// Local procedures which have already been visited.
+    visited: BTreeSet<RpoDigest>,
+    /// Procedures defined in this module, in dependency order.
+    procedures: Vec<(bool, ProcedureName, DisassembledProcedure)>,
+    num_locals: u16,
+    num_unsupported_ops: usize,
+}
+
+impl<'a> ModuleDisassembler<'a> {
+    fn new(
+        forest: &'a MastForest,
+        path: &'a LibraryPath,
+        local_procedures: &'a LocalProcedures,
+        disassembler: &'a Disassembler,
+    ) -> Self {
+        Self {
+            forest,
+            path,
+            local_procedures,
+            known_procedures: &disassembler.known_procedures,
+            referenced: BTreeSet::new(),
+            visited: BTreeSet::new(),
+            procedures: Vec::new(),
+            num_locals: 0,
+            num_unsupported_ops: 0,
+        }
+    }
+
+    /// Adds the local procedure with the specified MAST root to this module, preceded by all
+    /// procedures it depends on which are not defined in other modules.
+    fn visit(&mut self, digest: RpoDigest) {
+        let Some(local) = self.local_procedures.procedures.get(&digest) else {
+            return;
+        };
+        let is_export = match &local.export {
+            Some(export) if &export.module != self.path => return,
+            export => export.is_some(),
+        };
+        if !self.visited.insert(digest) {
+            return;
+        }
+
+        let procedure = self.procedure(local.root);
+        for callee in core::mem::take(&mut self.referenced) {
+            self.visit(callee);
+        }
+        self.procedures.push((is_export, local.name.clone(), procedure));
+    }
+
+    /// Adds the specified exported procedure to this module.
+    ///
+    /// If the procedure has the same MAST root as a procedure exported earlier, it is emitted as
+    /// an invocation of that procedure.
+    fn visit_export(&mut self, proc_name: &QualifiedProcedureName, digest: RpoDigest) {
+        self.visit(digest);
+
+        let local = &self.local_procedures.procedures[&digest];
+        if local.export.as_ref() != Some(proc_name) {
+            let target = self.invocation_target(digest, false);
+            self.referenced.clear();
+            let body = vec![Stmt::Inst(Instruction::Exec(target))];
+            let procedure = DisassembledProcedure { num_locals: 0, body };
+            self.procedures.push((true, proc_name.name.clone(), procedure));
+        }
+    }
+
+    /// Renders this module, consuming it.
+    fn finish(
+        mut self,
+        aliases: &[(InvocationTarget, ProcedureName)],
+        entrypoint: Option<&DisassembledProcedure>,
+    ) -> DisassembledModule {
+        if entrypoint.is_some() {
+            for callee in core::mem::take(&mut self.referenced) {
+                self.visit(callee);
+            }
+        }
+
+        let mut writer = SourceWriter::default();
+        for (target, alias) in aliases {
+            let target = format!("{}", Instruction::Exec(target.clone()));
+            writer.line(format_args!("export.{}->{alias}", &target["exec.".len()..]));
+        }
+        for (is_export, name, procedure) in self.procedures.iter() {
+            writer.separator();
+            let keyword = if *is_export { "export" } else { "proc" };
+            match procedure.num_locals {
+                0 => writer.line(format_args!("{keyword}.{name}")),
+                n => writer.line(format_args!("{keyword}.{name}.{n}")),
+            }
+            writer.block(&procedure.body);
+            writer.line("end");
+        }
+        if let Some(entrypoint) = entrypoint {
+            writer.separator();
+            writer.line("begin");
+            writer.block(&entrypoint.body);
+            writer.line("end");
+        }
+
+        DisassembledModule {
+            path: self.path.clone(),
+            source: writer.out,
+            num_unsupported_ops: self.num_unsupported_ops,
+        }
+    }
+
+    // PROCEDURE STRUCTURE
+    // --------------------------------------------------------------------------------------------
+
+    /// Reconstructs the body of the procedure with the specified root node.
+    ///
+    /// Procedures with locals are recognized by the `fmp` updates the assembler wraps their body
+    /// with; these are stripped from the body and turned into a locals declaration.
+    fn procedure(&mut self, root: MastNodeId) -> DisassembledProcedure {
+        let leaves = self.leaves(root).unwrap_or_else(|| vec![root]);
+
+        let first = self.forest[leaves[0]].get_basic_block();
+        let last = self.forest[leaves[leaves.len() - 1]].get_basic_block();
+        if let (Some(first), Some(last)) = (first, last) {
+            let first: Vec<Operation> = first.operations().copied().collect();
+            let last: Vec<Operation> = last.operations().copied().collect();
+            if let Some(frame_size) = locals_frame_size(&first, &last, leaves.len() == 1) {
+                let num_locals = self.infer_num_locals(root, frame_size);
+                self.num_locals = num_locals;
+                let mut body = Vec::new();
+                if leaves.len() == 1 {
+                    self.decode(&first[2..first.len() - 2], &mut body);
+                } else {
+                    self.decode(&first[2..], &mut body);
+                    self.sequence(&leaves[1..leaves.len() - 1], &mut body);
+                    self.decode(&last[..last.len() - 2], &mut body);
+                }
+                self.num_locals = 0;
+                return DisassembledProcedure { num_locals, body };
+            }
+        }
+
+        let mut body = Vec::new();
+        if leaves.len() == 1 {
+            self.node(root, &mut body);
+        } else {
+            self.sequence(&leaves, &mut body);
+        }
+        DisassembledProcedure { num_locals: 0, body }
+    }
+
+    /// Returns the number of locals declared by the procedure with the specified root, given the
+    /// size of its locals frame.
+    ///
+    /// Locals are addressed relative to the end of the declared locals rather than the end of the
+    /// frame, so the declared number is recovered from the alignment of word accesses, which must
+    /// be made to locals with an index divisible by 4.
+    fn infer_num_locals(&self, root: MastNodeId, frame_size: u16) -> u16 {
+        let mut max_offset = 0;
+        let mut word_alignments = BTreeSet::new();
+        let mut worklist = vec![root];
+        while let Some(node_id) = worklist.pop() {
+            match &self.forest[node_id] {
+                _ if node_id != root && self.local_procedure(node_id).is_some() => (),
+                MastNode::Block(block) => {
+                    let ops: Vec<Operation> = block.operations().copied().collect();
+                    for (i, window) in ops.windows(2).enumerate() {
+                        let [Operation::Push(offset), Operation::FmpAdd] = window else {
+                            continue;
+                        };
+                        let offset = (-*offset).as_int();
+                        max_offset = max_offset.max(offset);
+                        if matches!(ops.get(i + 2), Some(Operation::MLoadW | Operation::MStoreW)) {
+                            word_alignments.insert(offset % 4);
+                        }
+                    }
+                },
+                MastNode::Join(join) => worklist.extend([join.first(), join.second()]),
+                MastNode::Split(split) => worklist.extend([split.on_true(), split.on_false()]),
+                MastNode::Loop(loop_node) => worklist.push(loop_node.body()),
+                _ => (),
+            }
+        }
+
+        // word accesses are only aligned if the number of locals is congruent to their offsets
+        let num_locals = match word_alignments.first() {
+            Some(&alignment) if word_alignments.len() == 1 => {
+                frame_size - ((4 - alignment as u16) % 4)
+            },
+            _ => frame_size,
+        };
+        if num_locals as u64 >= max_offset {
+            num_locals
+        } else {
+            frame_size
+        }
+    }
+
+    /// Reconstructs the statements of a body which was compiled into the specified node.
+    fn body(&mut self, node_id: MastNodeId) -> Vec<Stmt> {
+        let mut stmts = Vec::new();
+        if self.local_procedure(node_id).is_some() {
+            self.unit(node_id, &mut stmts);
+            return stmts;
+        }
+        match self.leaves(node_id) {
+            Some(leaves) => self.sequence(&leaves, &mut stmts),
+            None => self.node(node_id, &mut stmts),
+        }
+        stmts
+    }
+
+    /// Returns the nodes the assembler combined into the specified node when compiling a body,
+    /// or `None` if the node could not have been produced from a list of nodes.
+    ///
+    /// The assembler merges contiguous basic blocks of a body, and then joins the remaining nodes
+    /// pairwise from left to right into a balanced tree (see `MastForestBuilder::join_nodes()`).
+    /// The shape of the tree only depends on the number of nodes, so all possible numbers of
+    /// nodes are tried, starting from the largest one.
+    fn leaves(&self, node_id: MastNodeId) -> Option<Vec<MastNodeId>> {
+        // roots of other procedures are invoked rather than inlined, so they are never split
+        let is_join = |id: MastNodeId| {
+            matches!(self.forest[id], MastNode::Join(_))
+                && (id == node_id || self.local_procedure(id).is_none())
+        };
+        let count_leaves = |id: MastNodeId| {
+            let mut count = 0;
+            let mut worklist = vec![id];
+            while let Some(id) = worklist.pop() {
+                match &self.forest[id] {
+                    MastNode::Join(join) if is_join(id) => {
+                        worklist.extend([join.first(), join.second()])
+                    },
+                    _ => count += 1,
+                }
+            }
+            count
+        };
+        fn collect(
+            forest: &MastForest,
+            is_join: &dyn Fn(MastNodeId) -> bool,
+            node_id: MastNodeId,
+            num_leaves: usize,
+            leaves: &mut Vec<MastNodeId>,
+        ) -> bool {
+            if num_leaves == 1 {
+                leaves.push(node_id);
+                return true;
+            }
+            let MastNode::Join(join) = &forest[node_id] else {
+                return false;
+            };
+            if !is_join(node_id) {
+                return false;
+            }
+            // the left subtree is always a perfect binary tree
+            let num_left = 1 << (usize::BITS - 1 - (num_leaves - 1).leading_zeros());
+            collect(forest, is_join, join.first(), num_left, leaves)
+                && collect(forest, is_join, join.second(), num_leaves - num_left, leaves)
+        }
+
+        if !is_join(node_id) {
+            return Some(vec![node_id]);
+        }
+
+        let mut leaves = Vec::new();
+        for num_leaves in (2..=count_leaves(node_id)).rev() {
+            leaves.clear();
+            if collect(self.forest, &is_join, node_id, num_leaves, &mut leaves)
+                && !leaves.windows(2).any(|pair| {
+                    self.is_mergeable_block(pair[0]) && self.is_mergeable_block(pair[1])
+                })
+            {
+                return Some(leaves);
+            }
+        }
+
+        None
+    }
+
+    /// Returns true if the specified node is a basic block which the assembler would have merged
+    /// with an adjacent basic block.
+    ///
+    /// Basic blocks are always merged, except for roots of procedures which are too large to be
+    /// inlined (see `PROCEDURE_INLINING_THRESHOLD`).
+    fn is_mergeable_block(&self, node_id: MastNodeId) -> bool {
+        match &self.forest[node_id] {
+            MastNode::Block(block) => {
+                self.local_procedure(node_id).is_none()
+                    || block.num_op_batches() < PROCEDURE_INLINING_THRESHOLD
+            },
+            _ => false,
+        }
+    }
+
+    /// Emits the statements for a sequence of nodes compiled from a single body.
+    ///
+    /// Runs of identical nodes are emitted as `repeat` blocks. JOIN nodes which are not procedure
+    /// roots can only be produced by compiling the body of a `repeat` block, and are emitted as
+    /// such.
+    fn sequence(&mut self, nodes: &[MastNodeId], stmts: &mut Vec<Stmt>) {
+        let mut nodes = nodes;
+        while let Some(&node_id) = nodes.first() {
+            let digest = self.forest[node_id].digest();
+            let count = nodes.iter().take_while(|&&id| self.forest[id].digest() == digest).count();
+            let is_nested_body = matches!(self.forest[node_id], MastNode::Join(_))
+                && self.local_procedure(node_id).is_none();
+
+            if is_nested_body {
+                let body = self.body(node_id);
+                stmts.push(Stmt::Repeat { count, body });
+            } else if count > 1 {
+                let mut body = Vec::new();
+                self.unit(node_id, &mut body);
+                stmts.push(Stmt::Repeat { count, body });
+            } else {
+                self.unit(node_id, stmts);
+            }
+            nodes = &nodes[count..];
+        }
+    }
+
+    /// Emits the statements for a single node of a body, invoking the node as a procedure if it
+    /// is the root of a local procedure.
+    fn unit(&mut self, node_id: MastNodeId, stmts: &mut Vec<Stmt>) {
+        if self.local_procedure(node_id).is_some() {
+            let target = self.invocation_target(self.forest[node_id].digest(), false);
+            stmts.push(Stmt::Inst(Instruction::Exec(target)));
+        } else {
+            self.node(node_id, stmts);
+        }
+    }
+
+    /// Emits the statements for a single node of a body.
+    fn node(&mut self, node_id: MastNodeId, stmts: &mut Vec<Stmt>) {
+        match &self.forest[node_id] {
+            MastNode::Block(block) => {
+                let ops: Vec<Operation> = block.operations().copied().collect();
+                self.decode(&ops, stmts);
+            },
+            MastNode::Join(join) => {
+                // a JOIN of two basic blocks cannot be produced by the assembler, since contiguous
+                // basic blocks are always merged
+                self.num_unsupported_ops += 1;
+                stmts.push(Stmt::Unsupported(String::from("join of basic blocks")));
+                let (first, second) = (join.first(), join.second());
+                self.unit(first, stmts);
+                self.unit(second, stmts);
+            },
+            MastNode::Split(split) => {
+                let then_blk = self.body(split.on_true());
+                let else_blk = self.body(split.on_false());
+                stmts.push(Stmt::If { then_blk, else_blk });
+            },
+            MastNode::Loop(loop_node) => {
+                let body = self.body(loop_node.body());
+                stmts.push(Stmt::While { body });
+            },
+            MastNode::Call(call) => {
+                let callee = self.forest[call.callee()].digest();
+                let instruction = if call.is_syscall() {
+                    Instruction::SysCall(self.invocation_target(callee, true))
+                } else {
+                    Instruction::Call(self.invocation_target(callee, false))
+                };
+                stmts.push(Stmt::Inst(instruction));
+            },
+            MastNode::Dyn(dyn_node) => {
+                let instruction = if dyn_node.is_dyncall() {
+                    Instruction::DynCall
+                } else {
+                    Instruction::DynExec
+                };
+                stmts.push(Stmt::Inst(instruction));
+            },
+            MastNode::External(external) => {
+                let target = self.invocation_target(external.digest(), false);
+                stmts.push(Stmt::Inst(Instruction::Exec(target)));
+            },
+        }
+    }
+
+    /// Decodes the operations of a basic block into instructions.
+    fn decode(&mut self, ops: &[Operation], stmts: &mut Vec<Stmt>) {
+        let mut ops = ops;
+        while !ops.is_empty() {
+            if let Some((digest, num_ops)) = self.local_procedures.find_inlined_frame(ops) {
+                let target = self.invocation_target(digest, false);
+                stmts.push(Stmt::Inst(Instruction::Exec(target)));
+                ops = &ops[num_ops..];
+                continue;
+            }
+
+            match instructions::decode_instruction(ops, self.num_locals) {
+                Some((instruction, num_ops)) => {
+                    stmts.push(Stmt::Inst(instruction));
+                    ops = &ops[num_ops..];
+                },
+                None => {
+                    self.num_unsupported_ops += 1;
+                    stmts.push(Stmt::Unsupported(format!("{}", ops[0])));
+                    ops = &ops[1..];
+                },
+            }
+        }
+    }
+
+    // NAME RESOLUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the local procedure with the same MAST root as the specified node, if any.
+    fn local_procedure(&self, node_id: MastNodeId) -> Option<&'a LocalProcedure> {
+        self.local_procedures.procedures.get(&self.forest[node_id].digest())
+    }
+
+    /// Returns the target through which the procedure with the specified MAST root is invoked.
+    fn invocation_target(&mut self, digest: RpoDigest, is_syscall: bool) -> InvocationTarget {
+        let local = self.local_procedures.procedures.get(&digest).filter(|_| !is_syscall);
+        if let Some(local) = local {
+            return match &local.export {
+                Some(export) if &export.module != self.path => {
+                    InvocationTarget::AbsoluteProcedurePath {
+                        name: export.name.clone(),
+                        path: export.module.clone(),
+                    }
+                },
+                _ => {
+                    self.referenced.insert(digest);
+                    InvocationTarget::ProcedureName(local.name.clone())
+                },
+            };
+        }
+
+        match self.known_procedures.get(&digest) {
+            Some(proc_name) if is_syscall => {
+                if proc_name.module.is_kernel_path() {
+                    InvocationTarget::ProcedureName(proc_name.name.clone())
+                } else {
+                    InvocationTarget::MastRoot(Span::unknown(digest))
+                }
+            },
+            Some(proc_name) => InvocationTarget::AbsoluteProcedurePath {
+                name: proc_name.name.clone(),
+                path: proc_name.module.clone(),
+            },
+            None => InvocationTarget::MastRoot(Span::unknown(digest)),
+        }
+    }
+}
+
+// SOURCE WRITER
+// ================================================================================================
+
+/// Renders statements into Miden Assembly source code.
+#[derive(Default)]
+struct SourceWriter {
+    out: String,
+    indent: usize,
+}
+
+impl SourceWriter {
+    const INDENT: usize = 4;
+
+    fn line(&mut self, line: impl fmt::Display) {
+        writeln!(self.out, "{:indent$}{line}", "", indent = self.indent)
+            .expect("writing to a string cannot fail");
+    }
+
+    /// Inserts an empty line if anything has been written so far.
+    fn separator(&mut self) {
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+    }
+
+    /// Renders an indented block of statements.
+    fn block(&mut self, stmts: &[Stmt]) {
+        self.indent += Self::INDENT;
+        if stmts.is_empty() {
+            self.line("nop");
+        }
+        for stmt in stmts {
+            match stmt {
+                Stmt::Inst(instruction) => self.line(instruction),
+                Stmt::Unsupported(what) => {
+                    self.line(format_args!("# {what}: no Miden Assembly equivalent"))
+                },
+                Stmt::If { then_blk, else_blk } => {
+                    self.line("if.true");
+                    self.block(then_blk);
+                    self.line("else");
+                    self.block(else_blk);
+                    self.line("end");
+                },
+                Stmt::While { body } => {
+                    self.line("while.true");
+                    self.block(body);
+                    self.line("end");
+                },
+                Stmt::Repeat { count, body } => {
+                    self.line(format_args!("repeat.{count}"));
+                    self.block(body);
+                    self.line("end");
+                },
+            }
+        }
+        self.indent -= Self::INDENT;
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the number of locals allocated by a procedure whose body starts with the operations of
+/// `first` and ends with the operations of `last`, or `None` if the body does not allocate any.
+///
+/// If `is_single_block` is true, `first` and `last` are the same basic block.
+fn locals_frame_size(
+    first: &[Operation],
+    last: &[Operation],
+    is_single_block: bool,
+) -> Option<u16> {
+    let [Operation::Push(frame), Operation::FmpUpdate, ..] = first else {
+        return None;
+    };
+    let [.., Operation::Push(neg_frame), Operation::FmpUpdate] = last else {
+        return None;
+    };
+    let num_locals = u16::try_from(frame.as_int()).ok()?;
+    let is_valid_frame = num_locals > 0 && num_locals % 4 == 0 && *neg_frame == -*frame;
+    // a body consisting of only the frame updates cannot be expressed in assembly
+    let has_body = if is_single_block { first.len() > 4 } else { true };
+
+    (is_valid_frame && has_body).then_some(num_locals)
+}
@@ -0,0 +1,226 @@
+use alloc::{string::String, vec::Vec};
+use core::str::FromStr;
+
+use vm_core::{ONE, Operation, mast::MastForest};
+
+use super::*;
+use crate::{Assembler, Lint, LintLevel, diagnostics::Report, parse_module, testing::TestContext};
+
+// HELPERS
+// ================================================================================================
+
+/// Assembles `source`, disassembles the resulting program, and checks that the disassembled
+/// source assembles into a program with the same digest.
+fn assert_program_roundtrip(context: &TestContext, source: &str) -> Result<String, Report> {
+    let program = context.assemble(String::from(source))?;
+    let disassembled = Disassembler::new().disassemble_program(&program);
+    assert!(disassembled.is_lossless(), "lossy disassembly:\n{disassembled}");
+
+    let reassembled = context.assemble(String::from(disassembled.source()))?;
+    assert_eq!(program.hash(), reassembled.hash(), "digest mismatch:\n{disassembled}");
+
+    Ok(String::from(disassembled.source()))
+}
+
+// PROGRAMS
+// ================================================================================================
+
+#[test]
+fn disassemble_instructions() -> Result<(), Report> {
+    let context = TestContext::new();
+    let source = "
+    begin
+        push.0 push.1 push.2 push.3.4 add sub mul div neg inv
+        add.1 add.2 add.7 mul.0 mul.5 eq eq.0 eq.9 neq xor and or not
+        assert assert.err=5 assertz assertz.err=7 assert_eq assert_eq.err=9
+        exp.u5 pow2 ilog2 ext2add ext2sub ext2mul ext2neg ext2inv ext2div
+        u32assert u32assert.err=3 u32assert2 u32cast u32split u32test u32or u32xor u32and
+        u32wrapping_add u32overflowing_add u32wrapping_add3 u32overflowing_add3
+        u32wrapping_sub u32overflowing_sub u32wrapping_mul u32overflowing_mul
+        u32wrapping_madd u32overflowing_madd u32div u32mod u32divmod
+        u32clz u32ctz u32clo u32cto
+        drop dropw padw dup dup.7 dup.8 dup.10 dup.12 dup.14 dup.15 dupw dupw.1 dupw.2 dupw.3
+        swap swap.2 swap.5 swap.8 swap.9 swap.12 swap.15 swapw swapw.2 swapw.3 swapdw
+        movup.2 movup.8 movup.9 movup.15 movdn.2 movdn.8 movdn.9 movdn.15
+        movupw.2 movupw.3 movdnw.2 movdnw.3 cswap cswapw cdrop cdropw
+        mem_load mem_load.0 mem_load.1 mem_load.8 mem_loadw mem_loadw.12
+        mem_store mem_store.1 mem_store.3 mem_storew mem_storew.16 mem_stream adv_pipe
+        adv_push.1 adv_push.16 adv_loadw adv.push_u64div adv.insert_hperm adv.push_mtnode
+        emit.42 sdepth clk
+        hash hmerge hperm mtree_get mtree_set mtree_merge mtree_verify mtree_verify.err=2
+        fri_ext2fold4 horner_eval_base horner_eval_ext
+    end";
+    assert_program_roundtrip(&context, source)?;
+
+    Ok(())
+}
+
+#[test]
+fn disassemble_control_flow() -> Result<(), Report> {
+    let context = TestContext::new().with_lint_level(Lint::UnreachableCode, LintLevel::Allow);
+    let source = "
+    begin
+        push.1
+        if.true
+            push.2 push.3
+            while.true
+                push.4 drop push.0
+            end
+        else
+            repeat.3
+                push.5
+                if.true add else mul end
+            end
+        end
+        repeat.2 push.7 end
+        if.true dynexec else dyncall end
+        repeat.2
+            if.true nop end
+            repeat.3 push.1 end
+        end
+        push.8
+    end";
+    let disassembled = assert_program_roundtrip(&context, source)?;
+    assert!(disassembled.contains("while.true"));
+    assert!(disassembled.contains("repeat.2"));
+
+    Ok(())
+}
+
+#[test]
+fn disassemble_procedures() -> Result<(), Report> {
+    let context = TestContext::new();
+    let source = "
+    proc.with_locals.6
+        loc_store.0 loc_storew.0 locaddr.5 loc_load.5 loc_loadw.0
+        if.true
+            push.1 loc_store.3
+        end
+    end
+
+    proc.callee
+        push.1 add
+        while.true
+            exec.with_locals
+        end
+    end
+
+    proc.inlined
+        mul
+    end
+
+    begin
+        exec.callee
+        call.callee
+        exec.inlined
+        push.2
+        exec.inlined
+        call.0x0000000000000000000000000000000000000000000000000000000000000001
+        exec.0x0000000000000000000000000000000000000000000000000000000000000002
+    end";
+    let disassembled = assert_program_roundtrip(&context, source)?;
+    assert!(
+        disassembled.contains("proc.proc_0.6\n"),
+        "locals not recovered:\n{disassembled}"
+    );
+    assert!(disassembled.contains("loc_storew"), "locals not recovered:\n{disassembled}");
+
+    Ok(())
+}
+
+#[test]
+fn disassemble_unsupported_operations() {
+    let mut forest = MastForest::new();
+    let block = forest
+        .add_block(vec![Operation::Push(ONE), Operation::FmpAdd, Operation::Add], None)
+        .unwrap();
+    forest.make_root(block);
+    let program = Program::new(forest.into(), block);
+
+    let disassembled = Disassembler::new().disassemble_program(&program);
+    assert_eq!(disassembled.num_unsupported_ops(), 2);
+    assert!(!disassembled.is_lossless());
+    assert!(disassembled.source().contains("# fmpadd: no Miden Assembly equivalent"));
+}
+
+// LIBRARIES
+// ================================================================================================
+
+#[test]
+fn disassemble_library() -> Result<(), Report> {
+    let context = TestContext::new();
+
+    let baz = r#"
+        export.baz1
+            push.7 push.8 sub
+        end
+    "#;
+    let baz = parse_module!(&context, "lib1::baz", baz);
+    let lib1 = Assembler::new(context.source_manager()).assemble_library([baz])?;
+
+    let foo = r#"
+        proc.foo1.4
+            loc_store.1 push.1 add
+        end
+
+        export.foo2
+            push.2 add
+            exec.foo1
+        end
+
+        export.foo3
+            push.3 mul
+            exec.foo1
+            call.foo2
+        end
+    "#;
+    let foo = parse_module!(&context, "lib2::foo", foo);
+    let bar = r#"
+        use.lib1::baz
+        use.lib2::foo
+
+        export.baz::baz1->bar1
+
+        export.bar2
+            exec.foo::foo2
+            exec.baz::baz1
+        end
+
+        export.bar3
+            push.1 push.2 mul
+        end
+
+        export.bar4
+            push.1 push.2 mul
+        end
+    "#;
+    let bar = parse_module!(&context, "lib2::bar", bar);
+    let lib2 = Assembler::new(context.source_manager())
+        .with_library(&lib1)?
+        .assemble_library([foo, bar])?;
+
+    let modules = Disassembler::new().with_library(&lib1).disassemble_library(&lib2);
+    assert_eq!(modules.len(), 2);
+    assert!(modules.iter().all(DisassembledModule::is_lossless));
+    assert!(modules[0].source().contains("export.::lib1::baz::baz1->bar1"));
+    assert!(modules[0].source().contains("exec.::lib2::foo::foo2"));
+    assert!(modules[0].source().contains("export.bar4\n    exec.bar3\n"));
+
+    let modules = modules
+        .iter()
+        .map(|module| context.parse_module_with_path(module.path().clone(), module.source()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let reassembled = Assembler::new(context.source_manager())
+        .with_library(&lib1)?
+        .assemble_library(modules)?;
+
+    for name in ["lib2::foo::foo2", "lib2::foo::foo3", "lib2::bar::bar2", "lib2::bar::bar4"] {
+        let name = QualifiedProcedureName::from_str(name).unwrap();
+        let expected = lib2.mast_forest()[lib2.get_export_node_id(&name)].digest();
+        let actual = reassembled.mast_forest()[reassembled.get_export_node_id(&name)].digest();
+        assert_eq!(expected, actual, "digest mismatch for {name}");
+    }
+    assert!(reassembled.is_reexport(&QualifiedProcedureName::from_str("lib2::bar::bar1").unwrap()));
+
+    Ok(())
+}
@@ -20,6 +20,7 @@ mod assembler;
 pub mod ast;
 mod compile;
 pub mod diagnostics;
+mod disassembler;
 mod errors;
 mod library;
 mod parser;
@@ -35,19 +36,23 @@ mod tests;
 pub use vm_core::mast;
 pub use vm_core::utils;
 
+#[cfg(feature = "std")]
+pub use self::parser::read_modules_from_dir;
 pub use self::{
-    assembler::Assembler,
+    assembler::{Assembler, ModuleCache, OptimizationLevel},
     compile::{Compile, Options as CompileOptions},
     diagnostics::{
         DefaultSourceManager, Report, SourceFile, SourceId, SourceManager, SourceSpan, Span,
         Spanned,
     },
+    disassembler::{DisassembledModule, Disassembler},
     errors::AssemblyError,
     library::{
         KernelLibrary, Library, LibraryError, LibraryNamespace, LibraryPath, LibraryPathComponent,
         PathError, Version, VersionError,
     },
     parser::ModuleParser,
+    sema::{Lint, LintConfig, LintLevel},
 };
 
 // CONSTANTS
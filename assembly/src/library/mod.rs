@@ -308,6 +308,27 @@ mod use_std_library {
             assembler.assemble_library(modules)
         }
 
+        /// Constructs a [Library] from the modules in several directories, all of which are
+        /// parsed under the same namespace, as in [Library::from_dir].
+        ///
+        /// Returns an error if the same module is defined in more than one directory.
+        pub fn from_dirs(
+            paths: impl IntoIterator<Item = impl AsRef<Path>>,
+            namespace: LibraryNamespace,
+            assembler: Assembler,
+        ) -> Result<Self, Report> {
+            let src_manager = assembler.source_manager();
+            let mut modules = Vec::new();
+            for path in paths {
+                modules.extend(crate::parser::read_modules_from_dir(
+                    namespace.clone(),
+                    path.as_ref(),
+                    &src_manager,
+                )?);
+            }
+            assembler.assemble_library(modules)
+        }
+
         pub fn deserialize_from_file(path: impl AsRef<Path>) -> Result<Self, DeserializationError> {
             let path = path.as_ref();
             let mut file = fs::File::open(path).map_err(|err| {
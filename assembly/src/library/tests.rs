@@ -150,11 +150,9 @@ fn library_procedure_collision() -> Result<(), Report> {
     let lib2_bar_bar2 = QualifiedProcedureName::from_str("lib2::bar::bar2").unwrap();
     assert_eq!(lib2.get_export_node_id(&lib2_bar_bar1), lib2.get_export_node_id(&lib2_bar_bar2));
 
-    // make sure only one node was added to the forest
-    // NOTE: the MAST forest should actually have only 1 node (external node for the re-exported
-    // procedure), because nodes for the local procedure nodes should be pruned from the forest,
-    // but this is not implemented yet
-    assert_eq!(lib2.mast_forest().num_nodes(), 5);
+    // make sure only one node was added to the forest (the external node for the re-exported
+    // procedure), since the nodes of the local procedure are pruned from the forest
+    assert_eq!(lib2.mast_forest().num_nodes(), 1);
 
     Ok(())
 }
@@ -278,7 +278,10 @@ impl<'input> Lexer<'input> {
             '!' => pop!(self, Token::Bang),
             ':' => match self.peek() {
                 ':' => pop2!(self, Token::ColonColon),
-                _ => Err(ParsingError::InvalidToken { span: self.span() }),
+                _ => {
+                    self.skip();
+                    Err(ParsingError::InvalidToken { span: self.span() })
+                },
             },
             '.' => pop!(self, Token::Dot),
             ',' => pop!(self, Token::Comma),
@@ -317,9 +320,15 @@ impl<'input> Lexer<'input> {
             'A'..='Z' => self.lex_const_identifier(),
             '_' => match self.peek() {
                 c if c.is_ascii_alphanumeric() => self.lex_identifier(),
-                _ => Err(ParsingError::InvalidToken { span: self.span() }),
+                _ => {
+                    self.skip();
+                    Err(ParsingError::InvalidToken { span: self.span() })
+                },
+            },
+            _ => {
+                self.skip();
+                Err(ParsingError::InvalidToken { span: self.span() })
             },
-            _ => Err(ParsingError::InvalidToken { span: self.span() }),
         }
     }
 
@@ -30,7 +30,7 @@ pub use self::{
 use crate::{
     LibraryPath, SourceManager, ast,
     diagnostics::{Report, SourceFile, SourceSpan, Span, Spanned},
-    sema,
+    sema::{self, LintConfig},
 };
 
 // TYPE ALIASES
@@ -69,6 +69,8 @@ pub struct ModuleParser {
     interned: BTreeSet<Arc<str>>,
     /// When true, all warning diagnostics are promoted to error severity
     warnings_as_errors: bool,
+    /// The lint levels which apply to parsed modules, unless overridden by lint attributes
+    lints: LintConfig,
 }
 
 impl ModuleParser {
@@ -78,6 +80,7 @@ impl ModuleParser {
             kind,
             interned: Default::default(),
             warnings_as_errors: false,
+            lints: Default::default(),
         }
     }
 
@@ -86,6 +89,11 @@ impl ModuleParser {
         self.warnings_as_errors = yes;
     }
 
+    /// Configure the levels of the lints checked in the modules parsed by this parser.
+    pub fn set_lints(&mut self, lints: LintConfig) {
+        self.lints = lints;
+    }
+
     /// Parse a [ast::Module] from `source`, and give it the provided `path`.
     pub fn parse(
         &mut self,
@@ -94,7 +102,8 @@ impl ModuleParser {
     ) -> Result<Box<ast::Module>, Report> {
         let forms = parse_forms_internal(source.clone(), &mut self.interned)
             .map_err(|err| Report::new(err).with_source_code(source.clone()))?;
-        sema::analyze(source, self.kind, path, forms, self.warnings_as_errors).map_err(Report::new)
+        sema::analyze(source, self.kind, path, forms, self.warnings_as_errors, self.lints.clone())
+            .map_err(Report::new)
     }
 
     /// Parse a [ast::Module], `name`, from `path`.
@@ -4,7 +4,7 @@ use alloc::{
     vec::Vec,
 };
 
-use super::{SemanticAnalysisError, SyntaxError};
+use super::{Lint, LintConfig, LintLevel, SemanticAnalysisError, SyntaxError};
 use crate::{
     Felt, SourceFile, Span, Spanned,
     ast::*,
@@ -15,20 +15,29 @@ use crate::{
 pub struct AnalysisContext {
     /// A map of constants to the value of that constant
     constants: BTreeMap<Ident, Constant>,
+    /// The set of constants which have been referenced
+    used_constants: BTreeSet<Ident>,
     procedures: BTreeSet<ProcedureName>,
     errors: Vec<SemanticAnalysisError>,
     source_file: Arc<SourceFile>,
     warnings_as_errors: bool,
+    /// The lint levels which apply to the module, unless overridden by a lint attribute
+    lints: LintConfig,
+    /// The number of lints reported at the `deny` level
+    num_denied: usize,
 }
 
 impl AnalysisContext {
     pub fn new(source_file: Arc<SourceFile>) -> Self {
         Self {
             constants: Default::default(),
+            used_constants: Default::default(),
             procedures: Default::default(),
             errors: Default::default(),
             source_file,
             warnings_as_errors: false,
+            lints: Default::default(),
+            num_denied: 0,
         }
     }
 
@@ -41,6 +50,53 @@ impl AnalysisContext {
         self.warnings_as_errors
     }
 
+    pub fn set_lints(&mut self, lints: LintConfig) {
+        self.lints = lints;
+    }
+
+    /// Returns the lint levels which apply to an item with the given attributes.
+    ///
+    /// The `@allow`, `@warn`, and `@deny` attributes override the level of the lints they list,
+    /// any unknown lints are reported.
+    pub fn scoped_lints(&mut self, attrs: &AttributeSet) -> LintConfig {
+        let mut lints = self.lints.clone();
+        for attr in attrs.iter() {
+            let Some(level) = LintLevel::from_name(attr.name()) else {
+                continue;
+            };
+            let Attribute::List(list) = attr else {
+                self.errors.push(SemanticAnalysisError::UnknownLint { span: attr.span() });
+                continue;
+            };
+            for item in list.items.iter() {
+                match item {
+                    MetaExpr::Ident(name) => match Lint::from_name(name.as_str()) {
+                        Some(lint) => lints.set_level(lint, level),
+                        None => self
+                            .errors
+                            .push(SemanticAnalysisError::UnknownLint { span: name.span() }),
+                    },
+                    other => {
+                        self.errors.push(SemanticAnalysisError::UnknownLint { span: other.span() })
+                    },
+                }
+            }
+        }
+        lints
+    }
+
+    /// Reports a violation of `lint`, at the level given by `lints`.
+    pub fn lint(&mut self, lints: &LintConfig, lint: Lint, diagnostic: SemanticAnalysisError) {
+        match lints.level(lint) {
+            LintLevel::Allow => (),
+            LintLevel::Warn => self.errors.push(diagnostic),
+            LintLevel::Deny => {
+                self.num_denied += 1;
+                self.errors.push(diagnostic);
+            },
+        }
+    }
+
     pub fn register_procedure_name(&mut self, name: ProcedureName) {
         self.procedures.insert(name);
     }
@@ -76,7 +132,7 @@ impl AnalysisContext {
         }
     }
 
-    fn const_eval(&self, value: &ConstantExpr) -> Result<Felt, SemanticAnalysisError> {
+    fn const_eval(&mut self, value: &ConstantExpr) -> Result<Felt, SemanticAnalysisError> {
         match value {
             ConstantExpr::Literal(value) => Ok(value.into_inner()),
             ConstantExpr::Var(name) => self.get_constant(name),
@@ -94,18 +150,29 @@ impl AnalysisContext {
         }
     }
 
-    /// Get the constant value bound to `name`
+    /// Get the constant value bound to `name`, and mark the constant as used
     ///
     /// Returns `Err` if the symbol is undefined
-    pub fn get_constant(&self, name: &Ident) -> Result<Felt, SemanticAnalysisError> {
+    pub fn get_constant(&mut self, name: &Ident) -> Result<Felt, SemanticAnalysisError> {
         let span = name.span();
         if let Some(expr) = self.constants.get(name) {
+            self.used_constants.insert(name.clone());
             Ok(expr.value.expect_literal())
         } else {
             Err(SemanticAnalysisError::SymbolUndefined { span })
         }
     }
 
+    /// Returns an iterator over the constants defined in the module
+    pub fn constants(&self) -> impl Iterator<Item = &Constant> + '_ {
+        self.constants.values()
+    }
+
+    /// Returns true if the constant `name` has been referenced
+    pub fn is_constant_used(&self, name: &Ident) -> bool {
+        self.used_constants.contains(name)
+    }
+
     pub fn error(&mut self, diagnostic: SemanticAnalysisError) {
         self.errors.push(diagnostic);
     }
@@ -114,6 +181,9 @@ impl AnalysisContext {
         if self.warnings_as_errors() {
             return !self.errors.is_empty();
         }
+        if self.num_denied > 0 {
+            return true;
+        }
         self.errors
             .iter()
             .any(|err| matches!(err.severity().unwrap_or(Severity::Error), Severity::Error))
@@ -121,6 +191,7 @@ impl AnalysisContext {
 
     pub fn has_failed(&mut self) -> Result<(), SyntaxError> {
         if self.has_errors() {
+            self.num_denied = 0;
             Err(SyntaxError {
                 source_file: self.source_file.clone(),
                 errors: core::mem::take(&mut self.errors),
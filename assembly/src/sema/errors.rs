@@ -86,6 +86,64 @@ pub enum SemanticAnalysisError {
         #[label]
         span: SourceSpan,
     },
+    #[error("unused procedure")]
+    #[diagnostic(
+        severity(Warning),
+        help("this procedure is private, and never invoked, so it can be safely removed")
+    )]
+    UnusedProcedure {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("unused constant")]
+    #[diagnostic(severity(Warning), help("this constant is never used and can be safely removed"))]
+    UnusedConstant {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("unreachable code")]
+    #[diagnostic(severity(Warning))]
+    UnreachableCode {
+        #[label("this code will never be executed")]
+        span: SourceSpan,
+        #[label("{reason}")]
+        cause: SourceSpan,
+        reason: &'static str,
+    },
+    #[error("redundant stack shuffle")]
+    #[diagnostic(
+        severity(Warning),
+        help("these instructions leave the operand stack unchanged, and can be safely removed")
+    )]
+    RedundantStackShuffle {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("unnecessary call: the callee never accesses memory")]
+    #[diagnostic(
+        severity(Warning),
+        help(
+            "the callee does not need its own memory context, consider using `exec` instead, \
+            unless the operand stack must be isolated from the callee"
+        )
+    )]
+    UnnecessaryCall {
+        #[label]
+        span: SourceSpan,
+    },
+    #[error("invalid lint attribute: unknown lint")]
+    #[diagnostic(
+        severity(Warning),
+        help(
+            "lint attributes take a list of lint names, e.g. `@allow(unused_import)`, the known \
+            lints are: unused_import, unused_procedure, unused_constant, unreachable_code, \
+            redundant_stack_shuffle, and unnecessary_call"
+        )
+    )]
+    UnknownLint {
+        #[label]
+        span: SourceSpan,
+    },
     #[error("missing import: the referenced module has not been imported")]
     #[diagnostic()]
     MissingImport {
@@ -0,0 +1,155 @@
+use alloc::collections::BTreeMap;
+use core::fmt;
+
+// LINT
+// ================================================================================================
+
+/// A named check performed during semantic analysis, which reports code that is valid, but likely
+/// to be a mistake, or to be needlessly inefficient.
+///
+/// Each lint has a default [LintLevel], which can be overridden for an entire assembly using
+/// [crate::Assembler::with_lint_level], or for a single item using one of the `@allow`, `@warn`,
+/// or `@deny` attributes, e.g.:
+///
+/// ```masm
+/// @allow(unused_import)
+/// use.std::math::u64
+///
+/// @allow(unused_procedure, redundant_stack_shuffle)
+/// proc.foo
+///     swap swap
+/// end
+/// ```
+///
+/// Lint attributes attached to a procedure apply to the procedure itself, as well as to all lints
+/// reported for its body.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Lint {
+    /// An imported module is never used.
+    UnusedImport,
+    /// A private procedure is never invoked from within its module.
+    UnusedProcedure,
+    /// A constant is never referenced.
+    UnusedConstant,
+    /// Code that can never be executed, e.g. code following an assertion which always fails, or
+    /// the body of a loop whose condition is always false.
+    UnreachableCode,
+    /// A sequence of stack manipulation instructions which leaves the stack unchanged, e.g.
+    /// `swap swap`, or `movup.2 movdn.2`.
+    RedundantStackShuffle,
+    /// A `call` to a procedure which never accesses memory, and thus does not need to be executed
+    /// in a new memory context, i.e. `exec` could be used instead.
+    UnnecessaryCall,
+}
+
+impl Lint {
+    /// All of the lints known to the assembler.
+    pub const ALL: [Self; 6] = [
+        Self::UnusedImport,
+        Self::UnusedProcedure,
+        Self::UnusedConstant,
+        Self::UnreachableCode,
+        Self::RedundantStackShuffle,
+        Self::UnnecessaryCall,
+    ];
+
+    /// Returns the name of this lint, as used in lint attributes, e.g. `unused_import`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::UnusedImport => "unused_import",
+            Self::UnusedProcedure => "unused_procedure",
+            Self::UnusedConstant => "unused_constant",
+            Self::UnreachableCode => "unreachable_code",
+            Self::RedundantStackShuffle => "redundant_stack_shuffle",
+            Self::UnnecessaryCall => "unnecessary_call",
+        }
+    }
+
+    /// Returns the lint with the specified name, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|lint| lint.name() == name)
+    }
+
+    /// Returns the level of this lint, unless configured otherwise.
+    ///
+    /// Lints which are prone to false positives are allowed by default.
+    pub const fn default_level(&self) -> LintLevel {
+        match self {
+            Self::UnnecessaryCall => LintLevel::Allow,
+            _ => LintLevel::Warn,
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// LINT LEVEL
+// ================================================================================================
+
+/// Determines how violations of a [Lint] are reported.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintLevel {
+    /// Violations are not reported.
+    Allow,
+    /// Violations are reported as warnings.
+    Warn,
+    /// Violations are reported as errors.
+    Deny,
+}
+
+impl LintLevel {
+    /// Returns the name of this level, which is also the name of the attribute used to set it.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Warn => "warn",
+            Self::Deny => "deny",
+        }
+    }
+
+    /// Returns the lint level with the specified name, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => Some(Self::Allow),
+            "warn" => Some(Self::Warn),
+            "deny" => Some(Self::Deny),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// LINT CONFIG
+// ================================================================================================
+
+/// The level of each [Lint], for lints whose level differs from their default.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LintConfig {
+    levels: BTreeMap<Lint, LintLevel>,
+}
+
+impl LintConfig {
+    /// Returns the level of `lint` in this configuration.
+    pub fn level(&self, lint: Lint) -> LintLevel {
+        self.levels.get(&lint).copied().unwrap_or_else(|| lint.default_level())
+    }
+
+    /// Sets the level of `lint` in this configuration.
+    pub fn set_level(&mut self, lint: Lint, level: LintLevel) {
+        self.levels.insert(lint, level);
+    }
+
+    /// Returns true if `lint` is not allowed in this configuration.
+    pub fn is_enabled(&self, lint: Lint) -> bool {
+        self.level(lint) != LintLevel::Allow
+    }
+}
@@ -1,18 +1,20 @@
 mod context;
 mod errors;
+mod lints;
 mod passes;
 
 use alloc::{
     boxed::Box,
-    collections::{BTreeSet, VecDeque},
+    collections::{BTreeMap, BTreeSet, VecDeque},
     sync::Arc,
     vec::Vec,
 };
 
-use self::passes::{ConstEvalVisitor, VerifyInvokeTargets};
+use self::passes::{ConstEvalVisitor, LintProcedure, ProcedureSummary, VerifyInvokeTargets};
 pub use self::{
     context::AnalysisContext,
     errors::{SemanticAnalysisError, SyntaxError},
+    lints::{Lint, LintConfig, LintLevel},
 };
 use crate::{LibraryPath, Spanned, ast::*, diagnostics::SourceFile};
 
@@ -26,15 +28,18 @@ use crate::{LibraryPath, Spanned, ast::*, diagnostics::SourceFile};
 ///   * Constants referenced by name are replaced with the value of that constant.
 ///   * Calls to imported procedures are resolved concretely
 /// * Semantic analysis is performed on the module to validate it
+/// * Lints are checked, at the levels given by `lints`, unless overridden by lint attributes
 pub fn analyze(
     source: Arc<SourceFile>,
     kind: ModuleKind,
     path: LibraryPath,
     forms: Vec<Form>,
     warnings_as_errors: bool,
+    lints: LintConfig,
 ) -> Result<Box<Module>, SyntaxError> {
     let mut analyzer = AnalysisContext::new(source.clone());
     analyzer.set_warnings_as_errors(warnings_as_errors);
+    analyzer.set_lints(lints);
 
     let mut module = Box::new(Module::new(kind, path).with_span(source.source_span()));
 
@@ -110,14 +115,10 @@ pub fn analyze(
     analyzer.has_failed()?;
 
     // Run procedure checks
-    visit_procedures(&mut module, &mut analyzer)?;
+    let procedures = visit_procedures(&mut module, &mut analyzer)?;
 
-    // Check unused imports
-    for import in module.imports() {
-        if !import.is_used() {
-            analyzer.error(SemanticAnalysisError::UnusedImport { span: import.span() });
-        }
-    }
+    // Check the lints which require a view of the whole module
+    lint_module(&module, &mut analyzer, procedures);
 
     analyzer.into_result().map(move |_| module)
 }
@@ -128,12 +129,16 @@ pub fn analyze(
 /// When this function returns, all local analysis is complete,
 /// and all that remains is construction of a module graph and
 /// global program analysis to perform any remaining transformations.
+///
+/// Returns the lint levels which apply to each procedure, along with the facts gathered about
+/// each procedure for use by [lint_module].
 fn visit_procedures(
     module: &mut Module,
     analyzer: &mut AnalysisContext,
-) -> Result<(), SyntaxError> {
+) -> Result<BTreeMap<ProcedureName, (LintConfig, ProcedureSummary)>, SyntaxError> {
     let is_kernel = module.is_kernel();
     let locals = BTreeSet::from_iter(module.procedures().map(|p| p.name().clone()));
+    let mut summaries = BTreeMap::default();
     let mut procedures = VecDeque::from(core::mem::take(&mut module.procedures));
     while let Some(procedure) = procedures.pop_front() {
         match procedure {
@@ -164,6 +169,12 @@ fn visit_procedures(
                     );
                     let _ = visitor.visit_mut_procedure(&mut procedure);
                 }
+
+                // Finally, check the procedure body for lint violations
+                let lints = analyzer.scoped_lints(procedure.attributes());
+                let summary = LintProcedure::new(analyzer, &lints).run(&procedure);
+                summaries.insert(procedure.name().clone(), (lints, summary));
+
                 module.procedures.push(Export::Procedure(procedure));
             },
             Export::Alias(mut alias) => {
@@ -192,7 +203,116 @@ fn visit_procedures(
         }
     }
 
-    Ok(())
+    Ok(summaries)
+}
+
+/// Check the lints of `module` which can only be checked once all of its procedures have been
+/// visited, i.e. unused items, and unnecessary calls.
+fn lint_module(
+    module: &Module,
+    analyzer: &mut AnalysisContext,
+    procedures: BTreeMap<ProcedureName, (LintConfig, ProcedureSummary)>,
+) {
+    // Check unused imports
+    for import in module.imports() {
+        let lints = analyzer.scoped_lints(import.attributes());
+        if !import.is_used() {
+            analyzer.lint(
+                &lints,
+                Lint::UnusedImport,
+                SemanticAnalysisError::UnusedImport { span: import.span() },
+            );
+        }
+    }
+
+    // Check unused constants
+    let constants = analyzer
+        .constants()
+        .map(|constant| {
+            (
+                constant.span(),
+                constant.attributes().clone(),
+                analyzer.is_constant_used(&constant.name),
+            )
+        })
+        .collect::<Vec<_>>();
+    for (span, attrs, is_used) in constants {
+        let lints = analyzer.scoped_lints(&attrs);
+        if !is_used {
+            analyzer.lint(
+                &lints,
+                Lint::UnusedConstant,
+                SemanticAnalysisError::UnusedConstant { span },
+            );
+        }
+    }
+
+    // Check unused private procedures
+    let invoked = module
+        .procedures()
+        .flat_map(|export| match export {
+            Export::Procedure(procedure) => Some(procedure.invoked()),
+            Export::Alias(_) => None,
+        })
+        .flatten()
+        .filter_map(|invoke| match &invoke.target {
+            InvocationTarget::ProcedureName(name) => Some(name),
+            InvocationTarget::AbsoluteProcedurePath { name, path } if path == module.path() => {
+                Some(name)
+            },
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+    for export in module.procedures() {
+        let Export::Procedure(procedure) = export else {
+            continue;
+        };
+        if procedure.visibility() == Visibility::Private && !invoked.contains(procedure.name()) {
+            let (lints, _) = &procedures[procedure.name()];
+            analyzer.lint(
+                lints,
+                Lint::UnusedProcedure,
+                SemanticAnalysisError::UnusedProcedure { span: procedure.name().span() },
+            );
+        }
+    }
+
+    // Check calls to local procedures which never access memory, directly, or via the local
+    // procedures they execute. Procedures cannot be recursive, so this is guaranteed to converge.
+    let mut accesses_memory = procedures
+        .iter()
+        .map(|(name, (_, summary))| (name, summary.accesses_memory))
+        .collect::<BTreeMap<_, _>>();
+    loop {
+        let mut changed = false;
+        for (name, (_, summary)) in procedures.iter() {
+            if accesses_memory[name] {
+                continue;
+            }
+            let executes_memory_access = summary
+                .local_execs
+                .iter()
+                .any(|callee| accesses_memory.get(callee).copied().unwrap_or(true));
+            if executes_memory_access {
+                accesses_memory.insert(name, true);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    for (lints, summary) in procedures.values() {
+        for call in summary.local_calls.iter() {
+            if !accesses_memory.get(call.inner()).copied().unwrap_or(true) {
+                analyzer.lint(
+                    lints,
+                    Lint::UnnecessaryCall,
+                    SemanticAnalysisError::UnnecessaryCall { span: call.span() },
+                );
+            }
+        }
+    }
 }
 
 fn define_import(
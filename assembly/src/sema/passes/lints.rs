@@ -0,0 +1,296 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::ops::ControlFlow;
+
+use crate::{
+    SourceSpan, Span, Spanned,
+    ast::*,
+    sema::{AnalysisContext, Lint, LintConfig, SemanticAnalysisError},
+};
+
+/// This visitor checks the body of a procedure for violations of the lints which can be detected
+/// locally, i.e. [Lint::UnreachableCode] and [Lint::RedundantStackShuffle].
+///
+/// It also gathers the facts about the procedure needed by the lints which are checked once all
+/// procedures of the module have been visited, see [ProcedureSummary].
+pub struct LintProcedure<'a> {
+    analyzer: &'a mut AnalysisContext,
+    lints: &'a LintConfig,
+    summary: ProcedureSummary,
+}
+
+/// The facts about a single procedure gathered by [LintProcedure].
+#[derive(Default)]
+pub struct ProcedureSummary {
+    /// Whether the procedure itself may access memory, not counting the local procedures it
+    /// executes.
+    pub accesses_memory: bool,
+    /// The local procedures executed by the procedure.
+    pub local_execs: BTreeSet<ProcedureName>,
+    /// The `call` instructions targeting local procedures.
+    pub local_calls: Vec<Span<ProcedureName>>,
+}
+
+impl<'a> LintProcedure<'a> {
+    pub fn new(analyzer: &'a mut AnalysisContext, lints: &'a LintConfig) -> Self {
+        Self {
+            analyzer,
+            lints,
+            summary: Default::default(),
+        }
+    }
+
+    /// Visits `procedure`, and returns the facts gathered about it.
+    pub fn run(mut self, procedure: &Procedure) -> ProcedureSummary {
+        self.summary.accesses_memory = procedure.num_locals() > 0;
+        let _ = self.visit_procedure(procedure);
+        self.summary
+    }
+}
+
+impl LintProcedure<'_> {
+    fn check_unreachable_code(&mut self, ops: &[Op]) {
+        for (i, pair) in ops.windows(2).enumerate() {
+            let Some(value) = literal_value(&pair[0]) else {
+                continue;
+            };
+            match &pair[1] {
+                Op::Inst(inst) if always_fails(inst, value) => {
+                    let rest = &ops[(i + 2)..];
+                    if let (Some(first), Some(last)) = (rest.first(), rest.last()) {
+                        self.analyzer.lint(
+                            self.lints,
+                            Lint::UnreachableCode,
+                            SemanticAnalysisError::UnreachableCode {
+                                span: join_spans(first.span(), last.span()),
+                                cause: inst.span(),
+                                reason: "this assertion always fails",
+                            },
+                        );
+                    }
+                    // Anything following the assertion is unreachable, and already reported
+                    break;
+                },
+                Op::While { body, .. } if value == 0 => {
+                    self.report_unreachable_block(body, pair[0].span());
+                },
+                Op::If { then_blk, else_blk, .. } => {
+                    let unreachable = match value {
+                        0 => then_blk,
+                        1 => else_blk,
+                        _ => continue,
+                    };
+                    self.report_unreachable_block(unreachable, pair[0].span());
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn report_unreachable_block(&mut self, block: &Block, cause: SourceSpan) {
+        if let (Some(first), Some(last)) = (block.iter().next(), block.iter().last()) {
+            self.analyzer.lint(
+                self.lints,
+                Lint::UnreachableCode,
+                SemanticAnalysisError::UnreachableCode {
+                    span: join_spans(first.span(), last.span()),
+                    cause,
+                    reason: "the condition is always the same",
+                },
+            );
+        }
+    }
+
+    fn check_redundant_stack_shuffles(&mut self, ops: &[Op]) {
+        let mut ops = ops.iter().peekable();
+        while let Some(op) = ops.next() {
+            let (Op::Inst(first), Some(Op::Inst(second))) = (op, ops.peek()) else {
+                continue;
+            };
+            if is_inverse_shuffle(first, second) {
+                self.analyzer.lint(
+                    self.lints,
+                    Lint::RedundantStackShuffle,
+                    SemanticAnalysisError::RedundantStackShuffle {
+                        span: join_spans(first.span(), second.span()),
+                    },
+                );
+                // Don't report overlapping pairs, e.g. `swap swap swap`
+                ops.next();
+            }
+        }
+    }
+}
+
+impl Visit for LintProcedure<'_> {
+    fn visit_block(&mut self, block: &Block) -> ControlFlow<()> {
+        let ops = block.iter().as_slice();
+        self.check_unreachable_code(ops);
+        self.check_redundant_stack_shuffles(ops);
+        visit::visit_block(self, block)
+    }
+
+    fn visit_inst(&mut self, inst: &Span<Instruction>) -> ControlFlow<()> {
+        use Instruction::*;
+
+        match &**inst {
+            Exec(InvocationTarget::ProcedureName(name)) => {
+                self.summary.local_execs.insert(name.clone());
+            },
+            Call(InvocationTarget::ProcedureName(name)) => {
+                self.summary.local_calls.push(Span::new(inst.span(), name.clone()));
+                self.summary.accesses_memory = true;
+            },
+            // Conservatively, we assume that anything which might read or write memory, either
+            // directly, or by invoking code we can't see from here, does so.
+            MemLoad | MemLoadImm(_) | MemLoadW | MemLoadWImm(_) | MemStore | MemStoreImm(_)
+            | MemStoreW | MemStoreWImm(_) | MemStream | AdvPipe | Locaddr(_) | LocLoad(_)
+            | LocLoadW(_) | LocStore(_) | LocStoreW(_) | HornerBase | HornerExt | Exec(_)
+            | Call(_) | SysCall(_) | DynExec | DynCall | SysEvent(_) | Emit(_) => {
+                self.summary.accesses_memory = true;
+            },
+            _ => (),
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the value pushed on the stack by `op`, if it is a push of a single literal value.
+fn literal_value(op: &Op) -> Option<u64> {
+    let Op::Inst(inst) = op else {
+        return None;
+    };
+    match &**inst {
+        Instruction::Push(Immediate::Value(value)) => Some(value.as_int()),
+        Instruction::PushU8(value) => Some(*value as u64),
+        Instruction::PushU16(value) => Some(*value as u64),
+        Instruction::PushU32(value) => Some(*value as u64),
+        Instruction::PushFelt(value) => Some(value.as_int()),
+        _ => None,
+    }
+}
+
+/// Returns true if `inst` always fails when `value` is on top of the stack.
+fn always_fails(inst: &Instruction, value: u64) -> bool {
+    match inst {
+        Instruction::Assert | Instruction::AssertWithError(_) => value != 1,
+        Instruction::Assertz | Instruction::AssertzWithError(_) => value != 0,
+        _ => false,
+    }
+}
+
+/// Returns true if executing `first` followed by `second` leaves the operand stack unchanged.
+fn is_inverse_shuffle(first: &Instruction, second: &Instruction) -> bool {
+    match (StackShuffle::of(first), StackShuffle::of(second)) {
+        (Some(first), Some(second)) => first.inverse() == Some(second),
+        _ => false,
+    }
+}
+
+/// Returns a span covering both `start` and `end`.
+fn join_spans(start: SourceSpan, end: SourceSpan) -> SourceSpan {
+    SourceSpan::new(start.source_id(), start.start()..end.end())
+}
+
+/// A stack manipulation instruction, parameterized by the stack position it operates on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum StackShuffle {
+    Swap(u8),
+    SwapW(u8),
+    SwapDw,
+    MovUp(u8),
+    MovDn(u8),
+    MovUpW(u8),
+    MovDnW(u8),
+    Dup,
+    DupW,
+    PadW,
+    Drop,
+    DropW,
+}
+
+impl StackShuffle {
+    fn of(inst: &Instruction) -> Option<Self> {
+        use Instruction::*;
+
+        let shuffle = match inst {
+            Swap1 => Self::Swap(1),
+            Swap2 => Self::Swap(2),
+            Swap3 => Self::Swap(3),
+            Swap4 => Self::Swap(4),
+            Swap5 => Self::Swap(5),
+            Swap6 => Self::Swap(6),
+            Swap7 => Self::Swap(7),
+            Swap8 => Self::Swap(8),
+            Swap9 => Self::Swap(9),
+            Swap10 => Self::Swap(10),
+            Swap11 => Self::Swap(11),
+            Swap12 => Self::Swap(12),
+            Swap13 => Self::Swap(13),
+            Swap14 => Self::Swap(14),
+            Swap15 => Self::Swap(15),
+            SwapW1 => Self::SwapW(1),
+            SwapW2 => Self::SwapW(2),
+            SwapW3 => Self::SwapW(3),
+            SwapDw => Self::SwapDw,
+            MovUp2 => Self::MovUp(2),
+            MovUp3 => Self::MovUp(3),
+            MovUp4 => Self::MovUp(4),
+            MovUp5 => Self::MovUp(5),
+            MovUp6 => Self::MovUp(6),
+            MovUp7 => Self::MovUp(7),
+            MovUp8 => Self::MovUp(8),
+            MovUp9 => Self::MovUp(9),
+            MovUp10 => Self::MovUp(10),
+            MovUp11 => Self::MovUp(11),
+            MovUp12 => Self::MovUp(12),
+            MovUp13 => Self::MovUp(13),
+            MovUp14 => Self::MovUp(14),
+            MovUp15 => Self::MovUp(15),
+            MovDn2 => Self::MovDn(2),
+            MovDn3 => Self::MovDn(3),
+            MovDn4 => Self::MovDn(4),
+            MovDn5 => Self::MovDn(5),
+            MovDn6 => Self::MovDn(6),
+            MovDn7 => Self::MovDn(7),
+            MovDn8 => Self::MovDn(8),
+            MovDn9 => Self::MovDn(9),
+            MovDn10 => Self::MovDn(10),
+            MovDn11 => Self::MovDn(11),
+            MovDn12 => Self::MovDn(12),
+            MovDn13 => Self::MovDn(13),
+            MovDn14 => Self::MovDn(14),
+            MovDn15 => Self::MovDn(15),
+            MovUpW2 => Self::MovUpW(2),
+            MovUpW3 => Self::MovUpW(3),
+            MovDnW2 => Self::MovDnW(2),
+            MovDnW3 => Self::MovDnW(3),
+            Dup0 | Dup1 | Dup2 | Dup3 | Dup4 | Dup5 | Dup6 | Dup7 | Dup8 | Dup9 | Dup10 | Dup11
+            | Dup12 | Dup13 | Dup14 | Dup15 => Self::Dup,
+            DupW0 | DupW1 | DupW2 | DupW3 => Self::DupW,
+            PadW => Self::PadW,
+            Drop => Self::Drop,
+            DropW => Self::DropW,
+            _ => return None,
+        };
+
+        Some(shuffle)
+    }
+
+    /// Returns the shuffle which undoes this one, if any.
+    fn inverse(self) -> Option<Self> {
+        match self {
+            Self::Swap(_) | Self::SwapW(_) | Self::SwapDw => Some(self),
+            Self::MovUp(n) => Some(Self::MovDn(n)),
+            Self::MovDn(n) => Some(Self::MovUp(n)),
+            Self::MovUpW(n) => Some(Self::MovDnW(n)),
+            Self::MovDnW(n) => Some(Self::MovUpW(n)),
+            Self::Dup => Some(Self::Drop),
+            Self::DupW | Self::PadW => Some(Self::DropW),
+            Self::Drop | Self::DropW => None,
+        }
+    }
+}
@@ -1,4 +1,9 @@
 mod const_eval;
+mod lints;
 mod verify_invoke;
 
-pub use self::{const_eval::ConstEvalVisitor, verify_invoke::VerifyInvokeTargets};
+pub use self::{
+    const_eval::ConstEvalVisitor,
+    lints::{LintProcedure, ProcedureSummary},
+    verify_invoke::VerifyInvokeTargets,
+};
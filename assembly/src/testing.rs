@@ -6,7 +6,7 @@ use vm_core::Program;
 #[cfg(feature = "std")]
 use crate::diagnostics::reporting::set_panic_hook;
 use crate::{
-    Compile, CompileOptions, LibraryPath, RpoDigest,
+    Compile, CompileOptions, LibraryPath, Lint, LintLevel, RpoDigest,
     assembler::Assembler,
     ast::{Form, Module, ModuleKind},
     diagnostics::{
@@ -222,6 +222,11 @@ impl TestContext {
         self
     }
 
+    pub fn with_lint_level(mut self, lint: Lint, level: LintLevel) -> Self {
+        self.assembler.set_lint_level(lint, level);
+        self
+    }
+
     #[inline(always)]
     pub fn source_manager(&self) -> Arc<dyn SourceManager> {
         self.source_manager.clone()
@@ -247,6 +252,7 @@ impl TestContext {
             self.source_manager.as_ref(),
             CompileOptions {
                 warnings_as_errors: self.assembler.warnings_as_errors(),
+                lints: self.assembler.lints().clone(),
                 ..Default::default()
             },
         )
@@ -263,6 +269,7 @@ impl TestContext {
             self.source_manager.as_ref(),
             CompileOptions {
                 warnings_as_errors: self.assembler.warnings_as_errors(),
+                lints: self.assembler.lints().clone(),
                 ..CompileOptions::for_kernel()
             },
         )
@@ -278,6 +285,7 @@ impl TestContext {
             self.source_manager.as_ref(),
             CompileOptions {
                 warnings_as_errors: self.assembler.warnings_as_errors(),
+                lints: self.assembler.lints().clone(),
                 ..CompileOptions::for_library()
             },
         )
@@ -294,6 +302,7 @@ impl TestContext {
             self.source_manager.as_ref(),
             CompileOptions {
                 warnings_as_errors: self.assembler.warnings_as_errors(),
+                lints: self.assembler.lints().clone(),
                 ..CompileOptions::new(ModuleKind::Library, path).unwrap()
             },
         )
@@ -1,4 +1,4 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::{string::ToString, sync::Arc, vec::Vec};
 
 use vm_core::{
     Program,
@@ -6,7 +6,8 @@ use vm_core::{
 };
 
 use crate::{
-    Assembler, Deserializable, LibraryPath, ModuleParser, Serializable, assert_diagnostic_lines,
+    Assembler, Deserializable, Library, LibraryPath, Lint, LintLevel, ModuleCache, ModuleParser,
+    OptimizationLevel, Serializable, assert_diagnostic_lines,
     ast::{Module, ModuleKind},
     diagnostics::{IntoDiagnostic, Report},
     regex, source_file,
@@ -619,6 +620,23 @@ fn constant_err_const_not_initialized() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn invalid_token_after_whitespace() -> TestResult {
+    let context = TestContext::default();
+    let source = source_file!(&context, "begin push.1 $ end");
+    assert_assembler_diagnostic!(
+        context,
+        source,
+        "invalid syntax",
+        regex!(r#",-\[test[\d]+:1:14\]"#),
+        "1 | begin push.1 $ end",
+        "  :              |",
+        "  :              `-- occurs here",
+        "  `----"
+    );
+    Ok(())
+}
+
 #[test]
 fn constant_err_div_by_zero() -> TestResult {
     let context = TestContext::default();
@@ -3056,3 +3074,356 @@ fn vendoring() -> TestResult {
     assert!(lib == expected_lib);
     Ok(())
 }
+
+#[test]
+fn optimization_level() -> TestResult {
+    let context =
+        TestContext::default().with_lint_level(Lint::RedundantStackShuffle, LintLevel::Allow);
+    let source = source_file!(
+        &context,
+        "\
+    begin
+        push.1 push.2 swap swap add
+        if.true push.3 dup drop else push.4 end
+    end"
+    );
+    let (unoptimized, report) =
+        Assembler::new(context.source_manager()).assemble_program_with_report(source.clone())?;
+    assert!(report.is_none());
+
+    let (optimized, report) = Assembler::new(context.source_manager())
+        .with_optimization_level(OptimizationLevel::O1)
+        .assemble_program_with_report(source)?;
+    let report = report.expect("optimization report is produced at O1");
+    assert_eq!(report.num_operations_removed, 4);
+    assert!(report.num_cycles_saved > 0);
+
+    let expected = context.assemble(source_file!(
+        &context,
+        "begin push.1 push.2 add if.true push.3 else push.4 end end"
+    ))?;
+    assert_eq!(optimized.hash(), expected.hash());
+    assert_ne!(optimized.hash(), unoptimized.hash());
+
+    Ok(())
+}
+
+// LINTS
+// ================================================================================================
+
+#[test]
+fn lint_unused_items() -> TestResult {
+    let context = TestContext::default();
+
+    // --- unused private procedure --------------------------------------------------------------
+    let source = source_file!(&context, "proc.foo push.1 end\nbegin push.2 end");
+    assert_assembler_diagnostic!(
+        context,
+        source,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "unused procedure",
+        regex!(r#",-\[test[\d]+:1:6\]"#),
+        "1 | proc.foo push.1 end",
+        "  :      ^^^",
+        "2 | begin push.2 end",
+        "  `----",
+        " help: this procedure is private, and never invoked, so it can be safely removed"
+    );
+
+    // --- unused constant -----------------------------------------------------------------------
+    let source = source_file!(&context, "const.FOO=1\nbegin push.2 end");
+    assert_assembler_diagnostic!(
+        context,
+        source,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "unused constant",
+        regex!(r#",-\[test[\d]+:1:1\]"#),
+        "1 | const.FOO=1",
+        "  : ^^^^^^^^^^^",
+        "2 | begin push.2 end",
+        "  `----",
+        " help: this constant is never used and can be safely removed"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lint_unreachable_code() -> TestResult {
+    let context = TestContext::default();
+
+    // --- code following a failing assertion ----------------------------------------------------
+    let source = source_file!(&context, "begin push.0 assert push.1 push.2 end");
+    assert_assembler_diagnostic!(
+        context,
+        source,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "unreachable code",
+        regex!(r#",-\[test[\d]+:1:14\]"#),
+        "1 | begin push.0 assert push.1 push.2 end",
+        "  :              ^^^|^^^^^^^^^|^^^^^^",
+        "  :                 |         `-- this code will never be executed",
+        "  :                 `-- this assertion always fails",
+        "  `----"
+    );
+
+    // --- loop which is never entered -----------------------------------------------------------
+    let source = source_file!(&context, "begin push.0 while.true push.1 end end");
+    assert_assembler_diagnostic!(
+        context,
+        source,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "unreachable code",
+        regex!(r#",-\[test[\d]+:1:7\]"#),
+        "1 | begin push.0 while.true push.1 end end",
+        "  :       ^^^|^^            ^^^|^^",
+        "  :          |                 `-- this code will never be executed",
+        "  :          `-- the condition is always the same",
+        "  `----"
+    );
+
+    // --- conditions which are not known statically are fine ------------------------------------
+    context.assemble(source_file!(&context, "begin push.0 assert.err=1 end"))?;
+    context.assemble(source_file!(&context, "begin push.1 push.1 while.true push.0 end end"))?;
+
+    Ok(())
+}
+
+#[test]
+fn lint_redundant_stack_shuffle() -> TestResult {
+    let context = TestContext::default();
+
+    let source = source_file!(&context, "begin push.1 push.2 push.3 movup.2 movdn.2 swap end");
+    assert_assembler_diagnostic!(
+        context,
+        source,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "redundant stack shuffle",
+        regex!(r#",-\[test[\d]+:1:28\]"#),
+        "1 | begin push.1 push.2 push.3 movup.2 movdn.2 swap end",
+        "  :                            ^^^^^^^^^^^^^^^",
+        "  `----",
+        " help: these instructions leave the operand stack unchanged, and can be safely removed"
+    );
+
+    // `swap swap swap` is reported once
+    let source = source_file!(&context, "begin push.1 push.2 swap swap swap end");
+    let error = context.assemble(source).expect_err("expected diagnostic to be raised");
+    let output = error.to_string() + &format!("{error:?}");
+    assert_eq!(output.matches("redundant stack shuffle").count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn lint_unnecessary_call() -> TestResult {
+    let context = TestContext::default();
+
+    // allowed by default
+    let source = "proc.foo push.1 drop end\nbegin call.foo end";
+    context.assemble(source_file!(&context, source))?;
+
+    let context = context.with_lint_level(Lint::UnnecessaryCall, LintLevel::Warn);
+    assert_assembler_diagnostic!(
+        context,
+        source_file!(&context, source),
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "unnecessary call: the callee never accesses memory",
+        regex!(r#",-\[test[\d]+:2:7\]"#),
+        "1 | proc.foo push.1 drop end",
+        "2 | begin call.foo end",
+        "  :       ^^^^^^^^"
+    );
+
+    // procedures which access memory, directly or via the procedures they execute, are fine
+    context.assemble(source_file!(
+        &context,
+        "proc.foo mem_load.0 drop end\nproc.bar exec.foo end\nbegin call.bar end"
+    ))?;
+    context.assemble(source_file!(&context, "proc.foo.1 push.1 drop end\nbegin call.foo end"))?;
+
+    Ok(())
+}
+
+#[test]
+fn lint_levels_can_be_overridden() -> TestResult {
+    let context = TestContext::default();
+
+    // attributes allow lints for the item they are attached to
+    context.assemble(source_file!(
+        &context,
+        "\
+        @allow(unused_import)
+        use.dummy::math::u64
+        @allow(unused_constant)
+        const.FOO=1
+        @allow(unused_procedure, redundant_stack_shuffle)
+        proc.foo swap swap end
+        begin push.1 end"
+    ))?;
+
+    // assembler options allow lints for all modules
+    let context = context.with_lint_level(Lint::UnusedProcedure, LintLevel::Allow);
+    context.assemble(source_file!(&context, "proc.foo push.1 end\nbegin push.2 end"))?;
+
+    // denied lints are errors, even if warnings are not
+    let assembler = Assembler::new(context.source_manager())
+        .with_lint_level(Lint::UnusedConstant, LintLevel::Deny);
+    assembler
+        .clone()
+        .assemble_program(source_file!(&context, "const.FOO=1\nbegin push.2 end"))
+        .expect_err("expected denied lint to fail assembly");
+    assembler.assemble_program(source_file!(
+        &context,
+        "@warn(unused_constant)\nconst.FOO=1\nbegin push.2 end"
+    ))?;
+
+    let source = source_file!(
+        &context,
+        "@deny(redundant_stack_shuffle)\nproc.foo swap swap end\nbegin exec.foo end"
+    );
+    Assembler::new(context.source_manager())
+        .assemble_program(source)
+        .expect_err("expected denied lint to fail assembly");
+
+    // unknown lints are reported
+    let source =
+        source_file!(&context, "@allow(unused_procedur)\nproc.foo push.1 end\nbegin exec.foo end");
+    assert_assembler_diagnostic!(
+        context,
+        source,
+        "syntax error",
+        "help: see emitted diagnostics for details",
+        "invalid lint attribute: unknown lint",
+        regex!(r#",-\[test[\d]+:1:8\]"#),
+        "1 | @allow(unused_procedur)",
+        "  :        ^^^^^^^^^^^^^^^"
+    );
+
+    Ok(())
+}
+
+// MODULE CACHE
+// ================================================================================================
+
+#[test]
+fn module_cache_reuses_unchanged_procedures() -> TestResult {
+    let context = TestContext::new();
+    let cache = Arc::new(ModuleCache::new());
+    let assemble = |mod1_source: &str, mod2_source: &str| -> Result<Library, Report> {
+        let mod1 = parse_module!(&context, "lib::mod1", mod1_source);
+        let mod2 = parse_module!(&context, "lib::mod2", mod2_source);
+        Assembler::new(context.source_manager())
+            .with_module_cache(cache.clone())
+            .assemble_library([mod1, mod2])
+    };
+    let mod1 = "export.foo push.1 add end export.bar push.2 mul end";
+    let mod2 = "use.lib::mod1 export.baz exec.mod1::foo push.3 end";
+
+    let library = assemble(mod1, mod2)?;
+    assert_eq!((cache.num_hits(), cache.num_misses()), (0, 3));
+
+    // nothing changed, so all procedures are reused
+    let cached_library = assemble(mod1, mod2)?;
+    assert_eq!((cache.num_hits(), cache.num_misses()), (3, 3));
+    assert_eq!(cached_library, library);
+    assert_eq!(cached_library.to_bytes(), library.to_bytes());
+
+    // only the procedures of the changed module are recompiled
+    let library = assemble(mod1, "use.lib::mod1 export.baz exec.mod1::foo push.4 end")?;
+    assert_eq!((cache.num_hits(), cache.num_misses()), (5, 4));
+
+    // changing a module invalidates the procedures depending on it
+    let expected_library = assemble("export.foo push.1 sub end export.bar push.2 mul end", mod2)?;
+    assert_eq!((cache.num_hits(), cache.num_misses()), (5, 7));
+    assert_ne!(expected_library, library);
+
+    Ok(())
+}
+
+#[test]
+fn module_cache_persists_to_dir() -> TestResult {
+    let context = TestContext::new();
+    let dir = std::env::temp_dir().join(format!("miden-module-cache-{}", std::process::id()));
+    let assemble = |cache: &Arc<ModuleCache>| -> Result<Library, Report> {
+        let mod1 = parse_module!(&context, "lib::mod1", "export.foo push.1 add end");
+        let mod2 = parse_module!(&context, "lib::mod2", "export.bar exec.::lib::mod1::foo end");
+        Assembler::new(context.source_manager())
+            .with_module_cache(cache.clone())
+            .assemble_library([mod1, mod2])
+    };
+
+    let cache = Arc::new(ModuleCache::with_dir(&dir));
+    let library = assemble(&cache)?;
+    assert_eq!((cache.num_hits(), cache.num_misses()), (0, 2));
+
+    // a new cache backed by the same directory picks up the previously compiled procedures
+    let cache = Arc::new(ModuleCache::with_dir(&dir));
+    let cached_library = assemble(&cache)?;
+    assert_eq!((cache.num_hits(), cache.num_misses()), (2, 0));
+    assert_eq!(cached_library, library);
+
+    std::fs::remove_dir_all(&dir).into_diagnostic()?;
+    Ok(())
+}
+
+#[test]
+fn independent_modules_assemble_deterministically() -> TestResult {
+    let context = TestContext::new();
+    let assemble = |num_threads: usize, in_debug_mode: bool| -> Result<Library, Report> {
+        let modules = [
+            parse_module!(&context, "lib::a", "export.foo push.1 add end"),
+            parse_module!(
+                &context,
+                "lib::b",
+                "export.foo push.2 add end export.bar push.1 add end"
+            ),
+            parse_module!(&context, "lib::c", "export.foo push.3 mul end"),
+            parse_module!(
+                &context,
+                "lib::d",
+                "export.foo if.true push.3 mul else push.1 add end end
+                export.bar repeat.2 push.5 add end push.7 mul end"
+            ),
+            parse_module!(
+                &context,
+                "lib::e",
+                "use.lib::a use.lib::b use.lib::c
+                export.foo exec.a::foo exec.b::foo exec.b::bar call.c::foo end"
+            ),
+            parse_module!(
+                &context,
+                "lib::f",
+                "use.lib::c use.lib::d
+                export.foo while.true exec.d::foo call.c::foo dup end exec.d::bar end"
+            ),
+        ];
+        Assembler::new(context.source_manager())
+            .with_num_threads(num_threads)
+            .with_debug_mode(in_debug_mode)
+            .assemble_library(modules)
+    };
+
+    // the serialized library does not depend on the number of threads used to assemble it
+    for in_debug_mode in [false, true] {
+        let expected = assemble(1, in_debug_mode)?.to_bytes();
+        for num_threads in [2, 3, 4] {
+            assert_eq!(assemble(num_threads, in_debug_mode)?.to_bytes(), expected);
+        }
+    }
+
+    let library = assemble(4, false)?;
+
+    // procedures shared by independent modules are only added to the forest once
+    let a_foo = library.get_export_node_id(&"lib::a::foo".parse().unwrap());
+    let b_bar = library.get_export_node_id(&"lib::b::bar".parse().unwrap());
+    assert_eq!(a_foo, b_bar);
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ use miden_crypto::{Felt, utils::collections::KvMap};
 
 use crate::{
     crypto::hash::RpoDigest,
-    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, read_vec},
 };
 
 // ADVICE MAP
@@ -121,7 +121,8 @@ impl Deserializable for AdviceMap {
         let mut map = BTreeMap::new();
         let count = source.read_usize()?;
         for _ in 0..count {
-            let (key, values) = source.read()?;
+            let key = source.read()?;
+            let values = read_vec(source)?;
             map.insert(key, values);
         }
         Ok(Self(map))
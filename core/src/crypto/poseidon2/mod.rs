@@ -0,0 +1,331 @@
+use core::ops::Range;
+
+use miden_crypto::hash::{ElementHasher, Hasher, rpo::RpoDigest};
+
+use crate::{Felt, FieldElement, StarkField, ZERO};
+
+mod constants;
+use constants::{EXTERNAL_ROUND_CONSTANTS, INTERNAL_MATRIX_DIAG, INTERNAL_ROUND_CONSTANTS};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Sponge state is set to 12 field elements; 8 elements are reserved for rate and the remaining 4
+/// elements are reserved for capacity.
+const STATE_WIDTH: usize = 12;
+
+/// The rate portion of the state is located in elements 4 through 11.
+const RATE_RANGE: Range<usize> = 4..12;
+const RATE_WIDTH: usize = RATE_RANGE.end - RATE_RANGE.start;
+
+const INPUT1_RANGE: Range<usize> = 4..8;
+const INPUT2_RANGE: Range<usize> = 8..12;
+
+/// The capacity portion of the state is located in elements 0, 1, 2, and 3.
+const CAPACITY_RANGE: Range<usize> = 0..4;
+
+/// The output of the hash function is a digest which consists of 4 field elements read from the
+/// first half of the rate portion of the state.
+const DIGEST_RANGE: Range<usize> = 4..8;
+
+/// The number of bytes needed to encode a digest.
+const DIGEST_BYTES: usize = 32;
+
+/// The number of byte chunks defining a field element when hashing a sequence of bytes.
+const BINARY_CHUNK_SIZE: usize = 7;
+
+/// The number of external (full) rounds, half of which are applied before the internal rounds and
+/// half after them.
+const NUM_EXTERNAL_ROUNDS: usize = 8;
+
+/// The number of internal (partial) rounds.
+const NUM_INTERNAL_ROUNDS: usize = 22;
+
+/// The digest of Poseidon2 consists of four field elements, and is represented in the same way as
+/// an RPO digest.
+pub type Poseidon2Digest = RpoDigest;
+
+// HASHER IMPLEMENTATION
+// ================================================================================================
+
+/// Implementation of the Poseidon2 hash function with 256-bit output.
+///
+/// The permutation is implemented according to the Poseidon2
+/// [specifications](https://eprint.iacr.org/2023/323), while the sponge construction and the
+/// padding rules are the same as for [Rpo256](miden_crypto::hash::rpo::Rpo256).
+///
+/// The parameters used to instantiate the function are:
+/// * Field: 64-bit prime field with modulus p = 2^64 - 2^32 + 1.
+/// * State width: 12 field elements.
+/// * Rate size: r = 8 field elements.
+/// * Capacity size: c = 4 field elements.
+/// * Number of external rounds: 8.
+/// * Number of internal rounds: 22.
+/// * S-Box degree: 7.
+///
+/// The above parameters target a 128-bit security level. The digest consists of four field elements
+/// and it can be serialized into 32 bytes (256 bits).
+///
+/// The permutation is the standard instance of Poseidon2 over this field with a state width of 12,
+/// i.e., it uses the round constants and the internal matrix published with the reference
+/// implementation of Poseidon2, and matches the permutation implemented by e.g. Plonky3. The
+/// sponge construction on top of it is specific to Miden.
+///
+/// ## Hash output consistency
+/// Functions [hash_elements()](Poseidon2::hash_elements), [merge()](Poseidon2::merge), and
+/// [merge_with_int()](Poseidon2::merge_with_int) are internally consistent. That is, computing a
+/// hash for the same set of elements using these functions will always produce the same result.
+/// However, [hash()](Poseidon2::hash) function is not consistent with functions mentioned above,
+/// because it needs to be able to handle arbitrary binary strings.
+///
+/// ## Hashing of empty input
+/// The current implementation hashes empty input to the zero digest [0, 0, 0, 0].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Poseidon2();
+
+impl Hasher for Poseidon2 {
+    /// Poseidon2 collision resistance is 128-bits.
+    const COLLISION_RESISTANCE: u32 = 128;
+
+    type Digest = Poseidon2Digest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // initialize the state with zeroes, and set the first capacity element to a value which
+        // domain separates hashing of bytes from hashing of field elements
+        let mut state = [ZERO; STATE_WIDTH];
+        let num_field_elem = bytes.len().div_ceil(BINARY_CHUNK_SIZE);
+        state[CAPACITY_RANGE.start] =
+            Felt::from((RATE_WIDTH + (num_field_elem % RATE_WIDTH)) as u8);
+
+        // absorb the bytes in chunks of 7 bytes, padding the last chunk with a 1 followed by zeroes
+        let mut buf = [0_u8; 8];
+        let mut rate_pos = 0;
+        for (i, chunk) in bytes.chunks(BINARY_CHUNK_SIZE).enumerate() {
+            buf.fill(0);
+            buf[..chunk.len()].copy_from_slice(chunk);
+            if i == num_field_elem - 1 {
+                buf[chunk.len()] = 1;
+            }
+            state[RATE_RANGE.start + rate_pos] = Felt::new(u64::from_le_bytes(buf));
+
+            rate_pos += 1;
+            if rate_pos == RATE_WIDTH {
+                Self::apply_permutation(&mut state);
+                rate_pos = 0;
+            }
+        }
+
+        // the number of elements in the last block is encoded in the capacity, so the remaining
+        // rate elements are simply set to zero
+        if rate_pos != 0 {
+            state[RATE_RANGE.start + rate_pos..RATE_RANGE.end].fill(ZERO);
+            Self::apply_permutation(&mut state);
+        }
+
+        Poseidon2Digest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        // copy the digests into the rate portion of the state and set the capacity elements to 0
+        let mut state = [ZERO; STATE_WIDTH];
+        let it = Self::Digest::digests_as_elements_iter(values.iter());
+        for (i, v) in it.enumerate() {
+            state[RATE_RANGE.start + i] = *v;
+        }
+
+        Self::apply_permutation(&mut state);
+        Poseidon2Digest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        Self::hash_elements(Self::Digest::digests_as_elements(values))
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        // copy the seed into the first word of the rate and the value into the second word; if the
+        // value does not fit into a single field element, it is split into two elements. the first
+        // capacity element is set to the number of absorbed elements.
+        let mut state = [ZERO; STATE_WIDTH];
+        state[INPUT1_RANGE].copy_from_slice(seed.as_elements());
+        state[INPUT2_RANGE.start] = Felt::new(value);
+        if value < Felt::MODULUS {
+            state[CAPACITY_RANGE.start] = Felt::from(5_u8);
+        } else {
+            state[INPUT2_RANGE.start + 1] = Felt::new(value / Felt::MODULUS);
+            state[CAPACITY_RANGE.start] = Felt::from(6_u8);
+        }
+
+        Self::apply_permutation(&mut state);
+        Poseidon2Digest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+}
+
+impl ElementHasher for Poseidon2 {
+    type BaseField = Felt;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        let elements = E::slice_as_base_elements(elements);
+
+        // initialize the state to all zeros, except for the first capacity element which is set to
+        // the number of elements in the last block
+        let mut state = [ZERO; STATE_WIDTH];
+        state[CAPACITY_RANGE.start] = Felt::from((elements.len() % RATE_WIDTH) as u8);
+
+        // absorb the elements, applying the permutation every time the rate is filled up; the last
+        // block is padded with zeros
+        for block in elements.chunks(RATE_WIDTH) {
+            state[RATE_RANGE.start..RATE_RANGE.start + block.len()].copy_from_slice(block);
+            state[RATE_RANGE.start + block.len()..RATE_RANGE.end].fill(ZERO);
+            Self::apply_permutation(&mut state);
+        }
+
+        Poseidon2Digest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+}
+
+// HASH FUNCTION IMPLEMENTATION
+// ================================================================================================
+
+impl Poseidon2 {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sponge state is set to 12 field elements; 8 elements are reserved for rate and the
+    /// remaining 4 elements are reserved for capacity.
+    pub const STATE_WIDTH: usize = STATE_WIDTH;
+
+    /// The rate portion of the state is located in elements 4 through 11 (inclusive).
+    pub const RATE_RANGE: Range<usize> = RATE_RANGE;
+
+    /// The capacity portion of the state is located in elements 0, 1, 2, and 3.
+    pub const CAPACITY_RANGE: Range<usize> = CAPACITY_RANGE;
+
+    /// The output of the hash function can be read from state elements 4, 5, 6, and 7.
+    pub const DIGEST_RANGE: Range<usize> = DIGEST_RANGE;
+
+    /// The number of bytes needed to encode a digest.
+    pub const DIGEST_BYTES: usize = DIGEST_BYTES;
+
+    /// The number of external (full) rounds of the permutation.
+    pub const NUM_EXTERNAL_ROUNDS: usize = NUM_EXTERNAL_ROUNDS;
+
+    /// The number of internal (partial) rounds of the permutation.
+    pub const NUM_INTERNAL_ROUNDS: usize = NUM_INTERNAL_ROUNDS;
+
+    // TRAIT PASS-THROUGH FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a hash of the provided sequence of bytes.
+    #[inline(always)]
+    pub fn hash(bytes: &[u8]) -> Poseidon2Digest {
+        <Self as Hasher>::hash(bytes)
+    }
+
+    /// Returns a hash of two digests. This method is intended for use in construction of
+    /// Merkle trees and verification of Merkle paths.
+    #[inline(always)]
+    pub fn merge(values: &[Poseidon2Digest; 2]) -> Poseidon2Digest {
+        <Self as Hasher>::merge(values)
+    }
+
+    /// Returns a hash of the provided field elements.
+    #[inline(always)]
+    pub fn hash_elements<E: FieldElement<BaseField = Felt>>(elements: &[E]) -> Poseidon2Digest {
+        <Self as ElementHasher>::hash_elements(elements)
+    }
+
+    // POSEIDON2 PERMUTATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies the Poseidon2 permutation to the provided state.
+    ///
+    /// The permutation applies the external linear layer to the state, followed by half of the
+    /// external rounds, all of the internal rounds, and the other half of the external rounds.
+    pub fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+        Self::apply_external_linear_layer(state);
+
+        let (initial_constants, terminal_constants) =
+            EXTERNAL_ROUND_CONSTANTS.split_at(NUM_EXTERNAL_ROUNDS / 2);
+        for constants in initial_constants {
+            Self::apply_external_round(state, constants);
+        }
+        for &constant in INTERNAL_ROUND_CONSTANTS.iter() {
+            Self::apply_internal_round(state, constant);
+        }
+        for constants in terminal_constants {
+            Self::apply_external_round(state, constants);
+        }
+    }
+
+    /// Adds the round constants and applies the S-box to all elements of the state, followed by
+    /// the external linear layer.
+    #[inline(always)]
+    fn apply_external_round(state: &mut [Felt; STATE_WIDTH], constants: &[Felt; STATE_WIDTH]) {
+        for (element, &constant) in state.iter_mut().zip(constants) {
+            *element = (*element + constant).exp7();
+        }
+        Self::apply_external_linear_layer(state);
+    }
+
+    /// Adds the round constant and applies the S-box to the first element of the state, followed
+    /// by the internal linear layer.
+    #[inline(always)]
+    fn apply_internal_round(state: &mut [Felt; STATE_WIDTH], constant: Felt) {
+        state[0] = (state[0] + constant).exp7();
+        Self::apply_internal_linear_layer(state);
+    }
+
+    /// Multiplies the state by the external matrix, i.e., the block circulant matrix
+    /// `circ(2 * M4, M4, M4)`.
+    ///
+    /// This is computed by multiplying each 4-element chunk of the state by M4, and adding the sum
+    /// of the resulting chunks to each of them.
+    #[inline(always)]
+    fn apply_external_linear_layer(state: &mut [Felt; STATE_WIDTH]) {
+        let mut sums = [ZERO; 4];
+        for chunk in state.chunks_exact_mut(4) {
+            Self::apply_m4(chunk.try_into().unwrap());
+            for (sum, &element) in sums.iter_mut().zip(chunk.iter()) {
+                *sum += element;
+            }
+        }
+        for (i, element) in state.iter_mut().enumerate() {
+            *element += sums[i % 4];
+        }
+    }
+
+    /// Multiplies the state by the internal matrix, i.e., `1 + diag(D)` where `1` is the matrix of
+    /// all ones.
+    #[inline(always)]
+    fn apply_internal_linear_layer(state: &mut [Felt; STATE_WIDTH]) {
+        let sum = state.iter().fold(ZERO, |sum, &element| sum + element);
+        for (element, &diag) in state.iter_mut().zip(INTERNAL_MATRIX_DIAG.iter()) {
+            *element = *element * diag + sum;
+        }
+    }
+
+    /// Multiplies a 4-element chunk of the state by the matrix M4 of the Poseidon2 specification:
+    ///
+    /// ```text
+    /// [5 7 1 3]
+    /// [4 6 1 1]
+    /// [1 3 5 7]
+    /// [1 1 4 6]
+    /// ```
+    #[inline(always)]
+    fn apply_m4(x: &mut [Felt; 4]) {
+        let t0 = x[0] + x[1];
+        let t1 = x[2] + x[3];
+        let t2 = x[1].double() + t1;
+        let t3 = x[3].double() + t0;
+        let t4 = t1.double().double() + t3;
+        let t5 = t0.double().double() + t2;
+        x[0] = t3 + t5;
+        x[1] = t5;
+        x[2] = t2 + t4;
+        x[3] = t4;
+    }
+}
@@ -0,0 +1,146 @@
+use alloc::vec::Vec;
+
+use miden_crypto::hash::{ElementHasher, Hasher};
+use rand_utils::rand_array;
+
+use super::{
+    EXTERNAL_ROUND_CONSTANTS, INTERNAL_MATRIX_DIAG, INTERNAL_ROUND_CONSTANTS, Poseidon2,
+    Poseidon2Digest, STATE_WIDTH,
+};
+use crate::{Felt, FieldElement, ONE, QuadExtension, ZERO};
+
+/// The result of applying the permutation to the state [0, 1, ..., 11], as published with the
+/// reference implementation of Poseidon2 for the Goldilocks field with a state width of 12.
+const PERMUTATION_OUTPUT: [u64; STATE_WIDTH] = [
+    0x01eaef96bdf1c0c1,
+    0x1f0d2cc525b2540c,
+    0x6282c1dfe1e0358d,
+    0xe780d721f698e1e6,
+    0x280c0b6f753d833b,
+    0x1b942dd5023156ab,
+    0x43f0df3fcccb8398,
+    0xe8e8190585489025,
+    0x56bdbf72f77ada22,
+    0x7911c32bf9dcd705,
+    0xec467926508fbe67,
+    0x6a50450ddf85a6ed,
+];
+
+#[test]
+fn permutation_test_vector() {
+    let mut state: [Felt; STATE_WIDTH] = core::array::from_fn(|i| Felt::new(i as u64));
+    Poseidon2::apply_permutation(&mut state);
+    assert_eq!(state.map(|element| element.as_int()), PERMUTATION_OUTPUT);
+}
+
+#[test]
+fn permutation_matches_reference() {
+    let mut state: [Felt; STATE_WIDTH] = rand_array();
+    let mut expected = state;
+    reference_permutation(&mut expected);
+    Poseidon2::apply_permutation(&mut state);
+    assert_eq!(state, expected);
+}
+
+#[test]
+fn merge_is_consistent_with_hash_elements() {
+    let digests = [Poseidon2Digest::new(rand_array()), Poseidon2Digest::new(rand_array())];
+    let elements = digests
+        .iter()
+        .flat_map(|digest| digest.as_elements().iter().copied())
+        .collect::<Vec<_>>();
+    assert_eq!(Poseidon2::merge(&digests), Poseidon2::hash_elements(&elements));
+    assert_eq!(<Poseidon2 as Hasher>::merge_many(&digests), Poseidon2::hash_elements(&elements));
+}
+
+#[test]
+fn merge_with_int_is_consistent_with_hash_elements() {
+    let seed = Poseidon2Digest::new(rand_array());
+    let mut elements = seed.as_elements().to_vec();
+
+    elements.push(Felt::new(42));
+    assert_eq!(
+        <Poseidon2 as Hasher>::merge_with_int(seed, 42),
+        Poseidon2::hash_elements(&elements)
+    );
+
+    let value = u64::MAX;
+    elements[4] = Felt::new(value);
+    elements.push(ONE);
+    assert_eq!(
+        <Poseidon2 as Hasher>::merge_with_int(seed, value),
+        Poseidon2::hash_elements(&elements)
+    );
+}
+
+#[test]
+fn hash_elements_padding() {
+    assert_eq!(Poseidon2::hash_elements::<Felt>(&[]), Poseidon2Digest::default());
+
+    // appending zeros to the input changes the digest
+    let elements: [Felt; 5] = rand_array();
+    let mut padded = elements.to_vec();
+    padded.push(ZERO);
+    assert_ne!(Poseidon2::hash_elements(&elements), Poseidon2::hash_elements(&padded));
+
+    // hashing extension field elements is the same as hashing their base field coefficients
+    let elements: [Felt; 16] = rand_array();
+    let extension = elements.chunks(2).map(|c| QuadExtension::new(c[0], c[1])).collect::<Vec<_>>();
+    assert_eq!(
+        <Poseidon2 as ElementHasher>::hash_elements(&extension),
+        Poseidon2::hash_elements(&elements)
+    );
+}
+
+#[test]
+fn hash_bytes_padding() {
+    assert_eq!(Poseidon2::hash(&[]), Poseidon2Digest::default());
+
+    let bytes = (0..64_u8).collect::<Vec<_>>();
+    let digests = (1..bytes.len()).map(|len| Poseidon2::hash(&bytes[..len])).collect::<Vec<_>>();
+    for (i, digest) in digests.iter().enumerate() {
+        assert!(digests[i + 1..].iter().all(|other| other != digest));
+    }
+
+    // trailing zero bytes are not ignored
+    assert_ne!(Poseidon2::hash(&[1, 2, 3]), Poseidon2::hash(&[1, 2, 3, 0]));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the permutation using explicit matrix multiplications for the linear layers.
+fn reference_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    const M4: [[u64; 4]; 4] = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+    let external_matrix: [[Felt; STATE_WIDTH]; STATE_WIDTH] = core::array::from_fn(|i| {
+        core::array::from_fn(|j| {
+            let factor = if i / 4 == j / 4 { 2 } else { 1 };
+            Felt::new(factor * M4[i % 4][j % 4])
+        })
+    });
+    let internal_matrix: [[Felt; STATE_WIDTH]; STATE_WIDTH] = core::array::from_fn(|i| {
+        core::array::from_fn(|j| if i == j { INTERNAL_MATRIX_DIAG[i] + ONE } else { ONE })
+    });
+    let sbox = |x: Felt| x.exp(7);
+
+    *state = mat_vec_mul(&external_matrix, state);
+    for (round, constants) in EXTERNAL_ROUND_CONSTANTS.iter().enumerate() {
+        if round == EXTERNAL_ROUND_CONSTANTS.len() / 2 {
+            for &constant in INTERNAL_ROUND_CONSTANTS.iter() {
+                state[0] = sbox(state[0] + constant);
+                *state = mat_vec_mul(&internal_matrix, state);
+            }
+        }
+        for (element, &constant) in state.iter_mut().zip(constants) {
+            *element = sbox(*element + constant);
+        }
+        *state = mat_vec_mul(&external_matrix, state);
+    }
+}
+
+fn mat_vec_mul(
+    matrix: &[[Felt; STATE_WIDTH]; STATE_WIDTH],
+    vector: &[Felt; STATE_WIDTH],
+) -> [Felt; STATE_WIDTH] {
+    core::array::from_fn(|i| matrix[i].iter().zip(vector).fold(ZERO, |acc, (&a, &b)| acc + a * b))
+}
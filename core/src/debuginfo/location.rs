@@ -2,6 +2,9 @@ use alloc::sync::Arc;
 use core::{fmt, ops::Range};
 
 use super::ByteIndex;
+use crate::utils::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, read_string,
+};
 
 /// A [Location] represents file and span information for portability across source managers
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -31,6 +34,23 @@ impl Location {
     }
 }
 
+impl Serializable for Location {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.path.as_ref().write_into(target);
+        target.write_u32(self.start.to_u32());
+        target.write_u32(self.end.to_u32());
+    }
+}
+
+impl Deserializable for Location {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let path = read_string(source)?;
+        let start = ByteIndex::new(source.read_u32()?);
+        let end = ByteIndex::new(source.read_u32()?);
+        Ok(Self::new(path.into(), start, end))
+    }
+}
+
 /// A [FileLineCol] represents traditional file/line/column information for use in rendering.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct FileLineCol {
@@ -101,7 +101,7 @@ impl SourceManagerError {
     }
 }
 
-pub trait SourceManager {
+pub trait SourceManager: Send + Sync {
     /// Returns true if `file` is managed by this source manager
     fn is_manager_of(&self, file: &SourceFile) -> bool {
         match self.get(file.id()) {
@@ -270,6 +270,7 @@ impl DefaultSourceManagerImpl {
             .expect("system limit: source manager has exhausted its supply of source ids");
         let file = Arc::new(SourceFile::from_raw_parts(id, content));
         self.files.push(Arc::clone(&file));
+        self.names.insert(name, id);
         file
     }
 
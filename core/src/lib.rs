@@ -60,6 +60,8 @@ mod kernel;
 pub use kernel::Kernel;
 pub use miden_crypto::{EMPTY_WORD, ONE, WORD_SIZE, Word, ZERO};
 pub mod crypto {
+    mod poseidon2;
+
     pub mod merkle {
         pub use miden_crypto::merkle::{
             DefaultMerkleStore, EmptySubtreeRoots, InnerNodeInfo, LeafIndex, MerkleError,
@@ -75,6 +77,8 @@ pub mod crypto {
             rpo::{Rpo256, RpoDigest},
             rpx::{Rpx256, RpxDigest},
         };
+
+        pub use super::poseidon2::{Poseidon2, Poseidon2Digest};
     }
 
     pub mod random {
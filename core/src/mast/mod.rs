@@ -24,6 +24,9 @@ mod merger;
 pub(crate) use merger::MastForestMerger;
 pub use merger::MastForestRootMap;
 
+mod optimizer;
+pub use optimizer::{MastForestOptimizer, OptimizationReport};
+
 mod multi_forest_node_iterator;
 pub(crate) use multi_forest_node_iterator::*;
 
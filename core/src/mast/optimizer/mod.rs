@@ -0,0 +1,482 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::{fmt, mem};
+
+use crate::{
+    DecoratorList, ONE, Operation, ZERO,
+    mast::{
+        BasicBlockNode, DecoratorId, MastForest, MastForestError, MastNode, MastNodeId, Remapping,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+// MAST FOREST OPTIMIZER
+// ================================================================================================
+
+/// A type that rewrites a [`MastForest`] into an equivalent forest which executes in fewer VM
+/// cycles.
+///
+/// The optimizer performs the following passes:
+///
+/// 1. Dead procedure elimination: procedure roots which are neither among the retained roots, nor
+///    reachable from them, are removed together with all nodes only they reference.
+/// 2. Peephole simplification: sequences of operations inside basic blocks which have no effect on
+///    the state of the VM (e.g. `SWAP SWAP`, `PAD ADD` or `DUP0 DROP`) are removed.
+/// 3. Basic block merging: JOIN nodes whose children are both basic blocks are replaced with a
+///    single basic block containing the operations of both children, and the decorators of the
+///    JOIN node. This is also done for a basic block followed by a JOIN node whose first child is
+///    a basic block.
+///
+/// All of these passes change the MAST roots of the rewritten procedures, and thus the optimized
+/// forest is not interchangeable with the original one.
+///
+/// Forests containing DYN nodes are never optimized, since the targets of dynamic calls are
+/// identified by MAST roots computed at runtime (e.g. using `procref`), which the optimizer could
+/// not update.
+pub struct MastForestOptimizer<'forest> {
+    forest: &'forest MastForest,
+    optimized_forest: MastForest,
+    /// Mappings from node ids of the original forest to their ids in the optimized forest.
+    node_id_map: Remapping,
+    report: OptimizationReport,
+}
+
+impl<'forest> MastForestOptimizer<'forest> {
+    /// Optimizes the provided [`MastForest`], retaining the specified procedure roots (e.g. the
+    /// entrypoint of a program, or the exports of a library).
+    ///
+    /// Returns the optimized forest, the mapping from node ids of the original forest to their ids
+    /// in the optimized forest, and a report of the savings achieved by the optimization.
+    pub fn optimize(
+        forest: &'forest MastForest,
+        roots: impl IntoIterator<Item = MastNodeId>,
+    ) -> Result<(MastForest, Remapping, OptimizationReport), MastForestError> {
+        let num_nodes = forest.nodes().len();
+        let mut report = OptimizationReport {
+            num_nodes_before: num_nodes,
+            num_nodes_after: num_nodes,
+            ..Default::default()
+        };
+        if forest.nodes().iter().any(MastNode::is_dyn) {
+            return Ok((forest.clone(), Remapping::new(), report));
+        }
+
+        let mut optimized_forest = MastForest::new();
+        for decorator in forest.decorators() {
+            optimized_forest.add_decorator(decorator.clone())?;
+        }
+        *optimized_forest.advice_map_mut() = forest.advice_map().clone();
+
+        let mut optimizer = Self {
+            forest,
+            optimized_forest,
+            node_id_map: Remapping::new(),
+            report: OptimizationReport::default(),
+        };
+        optimizer.optimize_roots(roots)?;
+
+        let Self {
+            mut optimized_forest,
+            mut node_id_map,
+            report: pass_report,
+            ..
+        } = optimizer;
+
+        // nodes which were merged into their parents are no longer referenced from any root
+        let unreachable = unreachable_nodes(&optimized_forest);
+        let remapping = optimized_forest.remove_nodes(&unreachable);
+        node_id_map.retain(|_, new_id| !unreachable.contains(new_id));
+        for new_id in node_id_map.values_mut() {
+            *new_id = remapping.get(new_id).copied().unwrap_or(*new_id);
+        }
+
+        report.num_nodes_after = optimized_forest.nodes().len();
+        report.num_procedures_removed = pass_report.num_procedures_removed;
+        report.num_operations_removed = pass_report.num_operations_removed;
+        report.num_blocks_merged = pass_report.num_blocks_merged;
+        report.num_cycles_saved = pass_report.num_cycles_saved;
+
+        Ok((optimized_forest, node_id_map, report))
+    }
+
+    /// Adds the retained roots, and all procedure roots reachable from them, to the optimized
+    /// forest.
+    fn optimize_roots(
+        &mut self,
+        roots: impl IntoIterator<Item = MastNodeId>,
+    ) -> Result<(), MastForestError> {
+        for root in roots {
+            self.optimize_node(root)?;
+        }
+
+        // procedure roots are retained in their original order
+        for &root in self.forest.procedure_roots() {
+            match self.node_id_map.get(&root) {
+                Some(&new_root) => self.optimized_forest.make_root(new_root),
+                None => self.report.num_procedures_removed += 1,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the optimized version of the specified node of the original forest to the optimized
+    /// forest, and returns its id in the optimized forest.
+    fn optimize_node(&mut self, node_id: MastNodeId) -> Result<MastNodeId, MastForestError> {
+        if let Some(&new_node_id) = self.node_id_map.get(&node_id) {
+            return Ok(new_node_id);
+        }
+
+        let forest = self.forest;
+        let node = &forest[node_id];
+        let mut new_node = match node {
+            MastNode::Block(block) => self.optimize_block(block)?,
+            MastNode::Join(join) => {
+                let first = self.optimize_node(join.first())?;
+                let second = self.optimize_node(join.second())?;
+                match self.merge_join(first, second)? {
+                    Some(merged) => merged,
+                    None => MastNode::new_join(first, second, &self.optimized_forest)?,
+                }
+            },
+            MastNode::Split(split) => {
+                let on_true = self.optimize_node(split.on_true())?;
+                let on_false = self.optimize_node(split.on_false())?;
+                MastNode::new_split(on_true, on_false, &self.optimized_forest)?
+            },
+            MastNode::Loop(loop_node) => {
+                let body = self.optimize_node(loop_node.body())?;
+                MastNode::new_loop(body, &self.optimized_forest)?
+            },
+            MastNode::Call(call) => {
+                let callee = self.optimize_node(call.callee())?;
+                if call.is_syscall() {
+                    MastNode::new_syscall(callee, &self.optimized_forest)?
+                } else {
+                    MastNode::new_call(callee, &self.optimized_forest)?
+                }
+            },
+            MastNode::Dyn(_) | MastNode::External(_) => node.clone(),
+        };
+
+        // the decorators of basic blocks are already part of their decorator list
+        if !new_node.is_basic_block() {
+            new_node.set_before_enter(node.before_enter().to_vec());
+            new_node.set_after_exit(node.after_exit().to_vec());
+        } else if !node.is_basic_block() {
+            // a JOIN node merged into a basic block
+            let mut decorators: DecoratorList =
+                node.before_enter().iter().map(|&decorator_id| (0, decorator_id)).collect();
+            let MastNode::Block(block) = &mut new_node else {
+                unreachable!()
+            };
+            decorators.extend(block.decorators().iter().copied());
+            let num_ops = block.num_operations() as usize;
+            decorators
+                .extend(node.after_exit().iter().map(|&decorator_id| (num_ops, decorator_id)));
+            block.set_decorators(decorators);
+        }
+
+        let new_node_id = self.optimized_forest.add_node(new_node)?;
+        self.node_id_map.insert(node_id, new_node_id);
+        Ok(new_node_id)
+    }
+
+    /// Returns the basic block with all redundant operation sequences removed from the specified
+    /// block.
+    fn optimize_block(&mut self, block: &BasicBlockNode) -> Result<MastNode, MastForestError> {
+        let ops: Vec<Operation> = block.operations().copied().collect();
+        let (new_ops, decorators) = simplify(&ops, block.decorators());
+        let new_block = BasicBlockNode::new(new_ops, Some(decorators))?;
+
+        self.report.num_operations_removed +=
+            (block.num_operations() - new_block.num_operations()) as usize;
+        self.report.num_cycles_saved += num_cycles(block).saturating_sub(num_cycles(&new_block));
+
+        Ok(MastNode::Block(new_block))
+    }
+
+    /// Returns a basic block equivalent to a JOIN of the specified nodes of the optimized forest,
+    /// or `None` if the nodes cannot be merged into a basic block.
+    ///
+    /// This is the case when both nodes are basic blocks, or when the first node is a basic block
+    /// and the second node is a JOIN node without decorators whose first child is a basic block.
+    /// In the latter case, a JOIN of the merged basic block and the second child is returned.
+    fn merge_join(
+        &mut self,
+        first: MastNodeId,
+        second: MastNodeId,
+    ) -> Result<Option<MastNode>, MastForestError> {
+        let MastNode::Block(first_block) = &self.optimized_forest[first] else {
+            return Ok(None);
+        };
+        let first_block = first_block.clone();
+
+        match &self.optimized_forest[second] {
+            MastNode::Block(second_block) => {
+                let second_block = second_block.clone();
+                let merged = self.merge_blocks(&first_block, &second_block)?;
+                Ok(Some(MastNode::Block(merged)))
+            },
+            MastNode::Join(join)
+                if join.before_enter().is_empty() && join.after_exit().is_empty() =>
+            {
+                let (inner_first, inner_second) = (join.first(), join.second());
+                let MastNode::Block(second_block) = &self.optimized_forest[inner_first] else {
+                    return Ok(None);
+                };
+                let second_block = second_block.clone();
+                let merged = self.merge_blocks(&first_block, &second_block)?;
+                let merged = self.optimized_forest.add_block(
+                    merged.operations().copied().collect(),
+                    Some(merged.decorators().clone()),
+                )?;
+                // the JOIN of the merged block and the inner second child may be merged further
+                match self.merge_join(merged, inner_second)? {
+                    Some(node) => Ok(Some(node)),
+                    None => {
+                        Ok(Some(MastNode::new_join(merged, inner_second, &self.optimized_forest)?))
+                    },
+                }
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns a basic block executing the operations of `first` followed by the operations of
+    /// `second`.
+    fn merge_blocks(
+        &mut self,
+        first: &BasicBlockNode,
+        second: &BasicBlockNode,
+    ) -> Result<BasicBlockNode, MastForestError> {
+        let num_first_ops = first.num_operations() as usize;
+        let ops: Vec<Operation> = first.operations().chain(second.operations()).copied().collect();
+        let decorators: DecoratorList = first
+            .decorators()
+            .iter()
+            .copied()
+            .chain(second.decorators().iter().map(|&(idx, id)| (idx + num_first_ops, id)))
+            .collect();
+
+        // operations at the boundary of the blocks may cancel each other out
+        let (new_ops, decorators) = simplify(&ops, &decorators);
+        let merged = BasicBlockNode::new(new_ops, Some(decorators))?;
+
+        // executing a JOIN node takes 2 cycles (JOIN and END)
+        let num_cycles_before = num_cycles(first) + num_cycles(second) + 2;
+        self.report.num_blocks_merged += 1;
+        self.report.num_operations_removed += ops.len() - merged.num_operations() as usize;
+        self.report.num_cycles_saved += num_cycles_before.saturating_sub(num_cycles(&merged));
+
+        Ok(merged)
+    }
+}
+
+// OPTIMIZATION REPORT
+// ================================================================================================
+
+/// Summary of the savings achieved by a [`MastForestOptimizer`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OptimizationReport {
+    /// Number of nodes in the original forest.
+    pub num_nodes_before: usize,
+    /// Number of nodes in the optimized forest.
+    pub num_nodes_after: usize,
+    /// Number of procedure roots removed by dead procedure elimination.
+    pub num_procedures_removed: usize,
+    /// Number of operations removed from basic blocks.
+    pub num_operations_removed: usize,
+    /// Number of JOIN nodes replaced by merging basic blocks.
+    pub num_blocks_merged: usize,
+    /// Number of VM cycles saved, summed over a single execution of every rewritten node.
+    ///
+    /// This is a static estimate: the actual savings depend on how many times each rewritten node
+    /// is executed.
+    pub num_cycles_saved: usize,
+}
+
+impl OptimizationReport {
+    /// Returns the number of nodes removed from the forest by the optimization.
+    pub fn num_nodes_saved(&self) -> usize {
+        self.num_nodes_before.saturating_sub(self.num_nodes_after)
+    }
+}
+
+impl fmt::Display for OptimizationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "nodes: {} -> {} ({} saved)",
+            self.num_nodes_before,
+            self.num_nodes_after,
+            self.num_nodes_saved()
+        )?;
+        writeln!(f, "procedures removed: {}", self.num_procedures_removed)?;
+        writeln!(f, "operations removed: {}", self.num_operations_removed)?;
+        writeln!(f, "basic blocks merged: {}", self.num_blocks_merged)?;
+        write!(f, "cycles saved (static): {}", self.num_cycles_saved)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Removes redundant operation sequences from `ops`, and returns the remaining operations along
+/// with the updated decorator list.
+///
+/// Decorators attached to removed operations are attached to the operation following the removed
+/// sequence, so that the order of decorators is preserved.
+fn simplify(ops: &[Operation], decorators: &DecoratorList) -> (Vec<Operation>, DecoratorList) {
+    let mut decorators = decorators.iter().copied().peekable();
+    let mut new_ops: Vec<Operation> = Vec::with_capacity(ops.len());
+    let mut op_decorators: Vec<Vec<DecoratorId>> = Vec::with_capacity(ops.len());
+    let mut pending = Vec::new();
+
+    for (op_idx, &op) in ops.iter().enumerate() {
+        while let Some((_, decorator_id)) = decorators.next_if(|&(idx, _)| idx == op_idx) {
+            pending.push(decorator_id);
+        }
+        new_ops.push(op);
+        op_decorators.push(mem::take(&mut pending));
+
+        let num_redundant = num_redundant_ops(&new_ops);
+        if num_redundant > 0 {
+            let start = new_ops.len() - num_redundant;
+            new_ops.truncate(start);
+            pending = op_decorators.drain(start..).flatten().collect();
+        }
+    }
+    pending.extend(decorators.map(|(_, decorator_id)| decorator_id));
+
+    // a basic block cannot be empty
+    if new_ops.is_empty() {
+        new_ops.push(Operation::Noop);
+        op_decorators.push(mem::take(&mut pending));
+    }
+
+    let num_ops = new_ops.len();
+    let new_decorators = op_decorators
+        .into_iter()
+        .enumerate()
+        .flat_map(|(op_idx, ids)| ids.into_iter().map(move |id| (op_idx, id)))
+        .chain(pending.into_iter().map(|id| (num_ops, id)))
+        .collect();
+
+    (new_ops, new_decorators)
+}
+
+/// Returns the number of operations at the end of `ops` which together have no effect on the
+/// state of the VM.
+fn num_redundant_ops(ops: &[Operation]) -> usize {
+    use Operation::*;
+
+    match ops {
+        [.., Noop] => 1,
+        [.., Swap, Swap]
+        | [.., SwapW, SwapW]
+        | [.., SwapW2, SwapW2]
+        | [.., SwapW3, SwapW3]
+        | [.., SwapDW, SwapDW]
+        | [.., Neg, Neg]
+        | [.., Pad, Add]
+        | [.., Pad, Drop]
+        | [.., Push(_), Drop] => 2,
+        [.., Push(imm), Add] if *imm == ZERO => 2,
+        [.., Push(imm), Mul] if *imm == ONE => 2,
+        [.., Pad, Incr, Mul] => 3,
+        [.., dup, Drop] if is_dup(*dup) => 2,
+        [.., first, second] if are_inverse_movements(*first, *second) => 2,
+        _ => 0,
+    }
+}
+
+/// Returns true if the operation pushes a copy of a stack element onto the stack.
+fn is_dup(op: Operation) -> bool {
+    use Operation::*;
+
+    matches!(
+        op,
+        Dup0 | Dup1 | Dup2 | Dup3 | Dup4 | Dup5 | Dup6 | Dup7 | Dup9 | Dup11 | Dup13 | Dup15
+    )
+}
+
+/// Returns true if executing `second` right after `first` leaves the stack unchanged.
+fn are_inverse_movements(first: Operation, second: Operation) -> bool {
+    use Operation::*;
+
+    matches!(
+        (first, second),
+        (MovUp2, MovDn2)
+            | (MovDn2, MovUp2)
+            | (MovUp3, MovDn3)
+            | (MovDn3, MovUp3)
+            | (MovUp4, MovDn4)
+            | (MovDn4, MovUp4)
+            | (MovUp5, MovDn5)
+            | (MovDn5, MovUp5)
+            | (MovUp6, MovDn6)
+            | (MovDn6, MovUp6)
+            | (MovUp7, MovDn7)
+            | (MovDn7, MovUp7)
+            | (MovUp8, MovDn8)
+            | (MovDn8, MovUp8)
+    )
+}
+
+/// Returns the number of VM cycles needed to execute the specified basic block.
+///
+/// This mirrors how the processor executes basic blocks: on top of the operations of the block,
+/// it executes SPAN, END, a RESPAN for each batch after the first one, a NOOP after each
+/// operation carrying an immediate value at the end of a group, and NOOPs padding the number of
+/// groups in each batch to a power of two.
+fn num_cycles(block: &BasicBlockNode) -> usize {
+    let mut num_cycles = block.num_op_batches() + 1;
+
+    for batch in block.op_batches() {
+        let op_counts = batch.op_counts();
+        let mut op_idx = 0;
+        let mut group_idx = 0;
+        let mut next_group_idx = 1;
+
+        for op in batch.ops() {
+            num_cycles += 1;
+            let has_imm = op.imm_value().is_some();
+            if has_imm {
+                next_group_idx += 1;
+            }
+
+            if op_idx == op_counts[group_idx] - 1 {
+                if has_imm {
+                    num_cycles += 1;
+                }
+                group_idx = next_group_idx;
+                next_group_idx += 1;
+                op_idx = 0;
+            } else {
+                op_idx += 1;
+            }
+        }
+
+        num_cycles += batch.num_groups().next_power_of_two().saturating_sub(group_idx);
+    }
+
+    num_cycles
+}
+
+/// Returns the nodes of the forest which are not reachable from any of its procedure roots.
+fn unreachable_nodes(forest: &MastForest) -> BTreeSet<MastNodeId> {
+    let mut reachable = BTreeSet::new();
+    let mut worklist: Vec<MastNodeId> = forest.procedure_roots().to_vec();
+    while let Some(node_id) = worklist.pop() {
+        if reachable.insert(node_id) {
+            forest[node_id].append_children_to(&mut worklist);
+        }
+    }
+
+    (0..forest.nodes().len())
+        .map(|idx| MastNodeId::new_unchecked(idx as u32))
+        .filter(|node_id| !reachable.contains(node_id))
+        .collect()
+}
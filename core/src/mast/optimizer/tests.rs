@@ -0,0 +1,123 @@
+use alloc::vec::Vec;
+
+use super::*;
+use crate::{Decorator, mast::MastNode};
+
+fn block_ops(forest: &MastForest, node_id: MastNodeId) -> Vec<Operation> {
+    forest[node_id].get_basic_block().unwrap().operations().copied().collect()
+}
+
+#[test]
+fn optimize_removes_redundant_operations() {
+    use Operation::*;
+
+    let mut forest = MastForest::new();
+    let ops = vec![Swap, Dup0, Drop, Swap, Add, Pad, Add, MovUp3, MovDn3, Mul, Push(ZERO), Drop];
+    let block = forest.add_block(ops, None).unwrap();
+    forest.make_root(block);
+
+    let (optimized, remapping, report) = MastForestOptimizer::optimize(&forest, [block]).unwrap();
+    let block = remapping[&block];
+    assert_eq!(block_ops(&optimized, block), vec![Add, Mul]);
+    assert_eq!(report.num_operations_removed, 10);
+    assert!(report.num_cycles_saved > 0);
+}
+
+#[test]
+fn optimize_preserves_decorators() {
+    use Operation::*;
+
+    let mut forest = MastForest::new();
+    let trace_0 = forest.add_decorator(Decorator::Trace(0)).unwrap();
+    let trace_1 = forest.add_decorator(Decorator::Trace(1)).unwrap();
+    let trace_2 = forest.add_decorator(Decorator::Trace(2)).unwrap();
+    let decorators = vec![(0, trace_0), (1, trace_1), (3, trace_2)];
+    let block = forest.add_block(vec![Swap, Swap, Add], Some(decorators)).unwrap();
+    forest.make_root(block);
+
+    let (optimized, remapping, _) = MastForestOptimizer::optimize(&forest, [block]).unwrap();
+    let block = optimized[remapping[&block]].get_basic_block().unwrap();
+    assert_eq!(block.operations().copied().collect::<Vec<_>>(), vec![Add]);
+    assert_eq!(block.decorators(), &vec![(0, trace_0), (0, trace_1), (1, trace_2)]);
+}
+
+#[test]
+fn optimize_keeps_blocks_non_empty() {
+    let mut forest = MastForest::new();
+    let block = forest.add_block(vec![Operation::Swap, Operation::Swap], None).unwrap();
+    forest.make_root(block);
+
+    let (optimized, remapping, _) = MastForestOptimizer::optimize(&forest, [block]).unwrap();
+    assert_eq!(block_ops(&optimized, remapping[&block]), vec![Operation::Noop]);
+}
+
+#[test]
+fn optimize_merges_basic_blocks() {
+    use Operation::*;
+
+    let mut forest = MastForest::new();
+    let callee = forest.add_block(vec![Mul], None).unwrap();
+    forest.make_root(callee);
+    let block_a = forest.add_block(vec![Add, Swap], None).unwrap();
+    let block_b = forest.add_block(vec![Swap, Mul], None).unwrap();
+    let block_c = forest.add_block(vec![Neg], None).unwrap();
+    let call = forest.add_call(callee).unwrap();
+    let join_ab = forest.add_join(block_a, block_b).unwrap();
+    let join_c_call = forest.add_join(block_c, call).unwrap();
+    let root = forest.add_join(join_ab, join_c_call).unwrap();
+    forest.make_root(root);
+
+    let (optimized, remapping, report) = MastForestOptimizer::optimize(&forest, [root]).unwrap();
+
+    // JOIN(JOIN(a, b), JOIN(c, CALL)) becomes JOIN(a + b + c, CALL)
+    let MastNode::Join(join) = &optimized[remapping[&root]] else {
+        panic!("expected the root to be a JOIN node");
+    };
+    assert_eq!(block_ops(&optimized, join.first()), vec![Add, Mul, Neg]);
+    assert!(matches!(optimized[join.second()], MastNode::Call(_)));
+
+    assert_eq!(report.num_blocks_merged, 2);
+    assert_eq!(report.num_operations_removed, 2);
+    assert_eq!(report.num_nodes_before, 8);
+    assert_eq!(report.num_nodes_after, 4);
+    assert_eq!(report.num_nodes_saved(), 4);
+    assert!(report.num_cycles_saved > 0);
+    assert_eq!(optimized.procedure_roots().len(), 2);
+}
+
+#[test]
+fn optimize_removes_dead_procedures() {
+    use Operation::*;
+
+    let mut forest = MastForest::new();
+    let callee = forest.add_block(vec![Mul], None).unwrap();
+    forest.make_root(callee);
+    let dead = forest.add_block(vec![Add], None).unwrap();
+    forest.make_root(dead);
+    let entrypoint = forest.add_call(callee).unwrap();
+    forest.make_root(entrypoint);
+
+    let (optimized, remapping, report) =
+        MastForestOptimizer::optimize(&forest, [entrypoint]).unwrap();
+
+    assert_eq!(report.num_procedures_removed, 1);
+    assert!(!remapping.contains_key(&dead));
+    assert_eq!(optimized.num_nodes(), 2);
+    assert_eq!(optimized.procedure_roots(), &[remapping[&callee], remapping[&entrypoint]]);
+}
+
+#[test]
+fn optimize_skips_forests_with_dyn_nodes() {
+    use Operation::*;
+
+    let mut forest = MastForest::new();
+    let block = forest.add_block(vec![Swap, Swap, Add], None).unwrap();
+    let dyn_node = forest.add_dyn().unwrap();
+    let root = forest.add_join(block, dyn_node).unwrap();
+    forest.make_root(root);
+
+    let (optimized, remapping, report) = MastForestOptimizer::optimize(&forest, [root]).unwrap();
+    assert_eq!(optimized, forest);
+    assert!(remapping.is_empty());
+    assert_eq!(report.num_nodes_saved(), 0);
+}
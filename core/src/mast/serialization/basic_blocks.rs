@@ -1,9 +1,9 @@
 use alloc::vec::Vec;
 
-use winter_utils::{ByteReader, DeserializationError, Serializable, SliceReader};
+use winter_utils::{DeserializationError, Serializable, SliceReader};
 
 use super::NodeDataOffset;
-use crate::{Operation, mast::BasicBlockNode};
+use crate::{Operation, mast::BasicBlockNode, utils::read_vec};
 
 // BASIC BLOCK DATA BUILDER
 // ================================================================================================
@@ -61,8 +61,10 @@ impl BasicBlockDataDecoder<'_> {
         ops_offset: NodeDataOffset,
     ) -> Result<Vec<Operation>, DeserializationError> {
         // Read ops
-        let mut ops_data_reader = SliceReader::new(&self.node_data[ops_offset as usize..]);
-        let operations: Vec<Operation> = ops_data_reader.read()?;
+        let ops_data = self.node_data.get(ops_offset as usize..).ok_or_else(|| {
+            DeserializationError::InvalidValue(format!("invalid operations offset: {ops_offset}"))
+        })?;
+        let operations: Vec<Operation> = read_vec(&mut SliceReader::new(ops_data))?;
 
         Ok(operations)
     }
@@ -37,11 +37,15 @@ impl DecoratorInfo {
         string_table: &StringTable,
         decorator_data: &[u8],
     ) -> Result<Decorator, DeserializationError> {
-        // This is safe because for decorators that don't use the offset, `0` is used (and hence
-        // will never access an element outside). Note that in this implementation, we trust the
-        // encoder.
-        let mut data_reader =
-            SliceReader::new(&decorator_data[self.decorator_data_offset as usize..]);
+        // for decorators that don't use the offset, `0` is used (and hence will never access an
+        // element outside)
+        let data = decorator_data.get(self.decorator_data_offset as usize..).ok_or_else(|| {
+            DeserializationError::InvalidValue(format!(
+                "invalid decorator data offset: {}",
+                self.decorator_data_offset
+            ))
+        })?;
+        let mut data_reader = SliceReader::new(data);
         match self.variant {
             EncodedDecoratorVariant::AssemblyOp => {
                 let num_cycles = data_reader.read_u8()?;
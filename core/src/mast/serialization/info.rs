@@ -46,6 +46,11 @@ impl MastNodeInfo {
         match self.ty {
             MastNodeType::Block { ops_offset } => {
                 let operations = basic_block_data_decoder.decode_operations(ops_offset)?;
+                if operations.is_empty() {
+                    return Err(DeserializationError::InvalidValue(
+                        "basic block has no operations".into(),
+                    ));
+                }
                 let block = BasicBlockNode::new_unsafe(operations, Vec::new(), self.digest);
                 Ok(MastNode::Block(block))
             },
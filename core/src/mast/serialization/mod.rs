@@ -42,7 +42,7 @@ use string_table::StringTable;
 use winter_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 use super::{DecoratorId, MastForest, MastNode, MastNodeId};
-use crate::AdviceMap;
+use crate::{AdviceMap, utils::read_vec};
 
 mod decorator;
 
@@ -186,17 +186,17 @@ impl Deserializable for MastForest {
         let decorator_count = source.read_usize()?;
 
         // Reading procedure roots
-        let roots: Vec<u32> = Deserializable::read_from(source)?;
+        let roots: Vec<u32> = read_vec(source)?;
 
         // Reading nodes
-        let basic_block_data: Vec<u8> = Deserializable::read_from(source)?;
+        let basic_block_data: Vec<u8> = read_vec(source)?;
         let mast_node_infos: Vec<MastNodeInfo> = node_infos_iter(source, node_count)
             .collect::<Result<Vec<MastNodeInfo>, DeserializationError>>()?;
 
         let advice_map = AdviceMap::read_from(source)?;
 
         // Reading Decorators
-        let decorator_data: Vec<u8> = Deserializable::read_from(source)?;
+        let decorator_data: Vec<u8> = read_vec(source)?;
         let string_table: StringTable = Deserializable::read_from(source)?;
         let decorator_infos = decorator_infos_iter(source, decorator_count);
 
@@ -305,13 +305,13 @@ fn read_block_decorators<R: ByteReader>(
     mast_forest: &MastForest,
 ) -> Result<Vec<(usize, DecoratorList)>, DeserializationError> {
     let vec_len: usize = source.read()?;
-    let mut out_vec: Vec<_> = Vec::with_capacity(vec_len);
+    let mut out_vec: Vec<_> = Vec::new();
 
     for _ in 0..vec_len {
         let node_id: usize = source.read()?;
 
         let decorator_vec_len: usize = source.read()?;
-        let mut inner_vec: Vec<(usize, DecoratorId)> = Vec::with_capacity(decorator_vec_len);
+        let mut inner_vec: Vec<(usize, DecoratorId)> = Vec::new();
         for _ in 0..decorator_vec_len {
             let op_id: usize = source.read()?;
             let decorator_id = DecoratorId::from_u32_safe(source.read()?, mast_forest)?;
@@ -368,13 +368,13 @@ fn read_before_after_decorators<R: ByteReader>(
     mast_forest: &MastForest,
 ) -> Result<Vec<(usize, Vec<DecoratorId>)>, DeserializationError> {
     let vec_len: usize = source.read()?;
-    let mut out_vec: Vec<_> = Vec::with_capacity(vec_len);
+    let mut out_vec: Vec<_> = Vec::new();
 
     for _ in 0..vec_len {
         let node_id: usize = source.read()?;
 
         let inner_vec_len: usize = source.read()?;
-        let mut inner_vec: Vec<DecoratorId> = Vec::with_capacity(inner_vec_len);
+        let mut inner_vec: Vec<DecoratorId> = Vec::new();
         for _ in 0..inner_vec_len {
             let decorator_id = DecoratorId::from_u32_safe(source.read()?, mast_forest)?;
             inner_vec.push(decorator_id);
@@ -7,6 +7,7 @@ use winter_utils::{
 };
 
 use super::{StringDataOffset, StringIndex};
+use crate::utils::{read_string, read_vec};
 
 pub struct StringTable {
     data: Vec<u8>,
@@ -51,8 +52,10 @@ impl StringTable {
             DeserializationError::InvalidValue(format!("invalid index in strings table: {str_idx}"))
         })?;
 
-        let mut reader = SliceReader::new(&self.data[str_offset..]);
-        reader.read()
+        let data = self.data.get(str_offset..).ok_or_else(|| {
+            DeserializationError::InvalidValue(format!("invalid string offset: {str_offset}"))
+        })?;
+        read_string(&mut SliceReader::new(data))
     }
 }
 
@@ -67,8 +70,8 @@ impl Serializable for StringTable {
 
 impl Deserializable for StringTable {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let table = source.read()?;
-        let data = source.read()?;
+        let table = read_vec(source)?;
+        let data = read_vec(source)?;
 
         Ok(Self::new(table, data))
     }
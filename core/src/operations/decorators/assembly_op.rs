@@ -1,7 +1,12 @@
 use alloc::string::String;
 use core::fmt;
 
-use crate::debuginfo::Location;
+use crate::{
+    debuginfo::Location,
+    utils::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, read_string,
+    },
+};
 
 // ASSEMBLY OP
 // ================================================================================================
@@ -83,3 +88,24 @@ impl fmt::Display for AssemblyOp {
         )
     }
 }
+
+impl Serializable for AssemblyOp {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.location.write_into(target);
+        self.context_name.write_into(target);
+        self.op.write_into(target);
+        target.write_u8(self.num_cycles);
+        target.write_bool(self.should_break);
+    }
+}
+
+impl Deserializable for AssemblyOp {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let location = Option::<Location>::read_from(source)?;
+        let context_name = read_string(source)?;
+        let op = read_string(source)?;
+        let num_cycles = source.read_u8()?;
+        let should_break = source.read_bool()?;
+        Ok(Self::new(location, context_name, num_cycles, op, should_break))
+    }
+}
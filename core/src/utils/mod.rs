@@ -113,6 +113,36 @@ pub fn new_array_vec<T: Debug, const N: usize>(capacity: usize) -> [Vec<T>; N] {
         .expect("failed to convert vector to array")
 }
 
+// UNTRUSTED DESERIALIZATION
+// ================================================================================================
+
+/// Maximum number of elements for which memory is allocated up front when deserializing a vector.
+const MAX_PREALLOCATED_ELEMENTS: usize = 1024;
+
+/// Reads a vector serialized as its length followed by its elements, as by `Vec::read_from()`.
+///
+/// Unlike `Vec::read_from()`, this does not allocate memory for the number of elements read from
+/// the source up front, so that a corrupted or malicious length cannot exhaust the memory before
+/// the source runs out of bytes.
+pub fn read_vec<R: ByteReader, T: Deserializable>(
+    source: &mut R,
+) -> Result<Vec<T>, DeserializationError> {
+    let len = source.read_usize()?;
+    let mut result = Vec::with_capacity(len.min(MAX_PREALLOCATED_ELEMENTS));
+    for _ in 0..len {
+        result.push(T::read_from(source)?);
+    }
+    Ok(result)
+}
+
+/// Reads a string serialized as by `String::read_from()`, with the same guarantees as [read_vec].
+pub fn read_string<R: ByteReader>(
+    source: &mut R,
+) -> Result<alloc::string::String, DeserializationError> {
+    alloc::string::String::from_utf8(read_vec(source)?)
+        .map_err(|err| DeserializationError::InvalidValue(alloc::format!("{err}")))
+}
+
 #[test]
 #[should_panic]
 fn debug_assert_is_checked() {
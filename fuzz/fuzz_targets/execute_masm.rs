@@ -0,0 +1,11 @@
+//! Executes generated Miden assembly programs, and checks that the AIR accepts the execution of
+//! every program which the processor executes successfully.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miden_fuzz::{MasmProgram, check_execution};
+
+fuzz_target!(|program: MasmProgram| {
+    check_execution(&program.to_masm(), program.stack_inputs());
+});
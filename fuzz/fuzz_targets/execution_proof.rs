@@ -0,0 +1,15 @@
+//! Deserializes arbitrary bytes as an execution proof, and checks that deserialized proofs
+//! round-trip.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miden_vm::ExecutionProof;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(proof) = ExecutionProof::from_bytes(data) {
+        let bytes = proof.to_bytes();
+        let proof = ExecutionProof::from_bytes(&bytes).expect("serialized proof is valid");
+        assert_eq!(proof.to_bytes(), bytes);
+    }
+});
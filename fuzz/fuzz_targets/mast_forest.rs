@@ -0,0 +1,17 @@
+//! Deserializes arbitrary bytes as a MAST forest, and checks that deserialized forests round-trip.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vm_core::{
+    mast::MastForest,
+    utils::{Deserializable, Serializable},
+};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(forest) = MastForest::read_from_bytes(data) {
+        let bytes = forest.to_bytes();
+        let forest = MastForest::read_from_bytes(&bytes).expect("serialized forest is valid");
+        assert_eq!(forest.to_bytes(), bytes);
+    }
+});
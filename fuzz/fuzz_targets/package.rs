@@ -0,0 +1,15 @@
+//! Deserializes arbitrary bytes as a package, and checks that deserialized packages round-trip.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use package::Package;
+use vm_core::utils::{Deserializable, Serializable};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(package) = Package::read_from_bytes(data) {
+        let bytes = package.to_bytes();
+        let package = Package::read_from_bytes(&bytes).expect("serialized package is valid");
+        assert_eq!(package.to_bytes(), bytes);
+    }
+});
@@ -0,0 +1,18 @@
+//! Parses arbitrary text as a Miden assembly module of each kind.
+
+#![no_main]
+
+use assembly::{
+    DefaultSourceManager, LibraryPath, SourceManager,
+    ast::{Module, ModuleKind},
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|source: &str| {
+    let source_manager = DefaultSourceManager::default();
+    let source_file = source_manager.load("fuzz.masm", source.to_string());
+    let path = LibraryPath::new("fuzz::module").unwrap();
+    for kind in [ModuleKind::Executable, ModuleKind::Library, ModuleKind::Kernel] {
+        let _ = Module::parse(path.clone(), kind, source_file.clone());
+    }
+});
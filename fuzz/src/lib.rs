@@ -0,0 +1,323 @@
+//! Shared code of the Miden VM fuzz targets.
+//!
+//! The fuzz targets live in `fuzz_targets/`; see the README of this crate for how to run them and
+//! how to turn the crashes they find into regression tests.
+
+use core::fmt;
+use std::sync::Arc;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use assembly::{Assembler, DefaultSourceManager};
+use miden_vm::{DefaultHost, ProgramInfo, ProvingOptions, StackInputs, prove, verify};
+use processor::ExecutionOptions;
+use stdlib::StdLibrary;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of cycles a generated program is allowed to consume.
+pub const MAX_CYCLES: u32 = 1 << 12;
+
+/// Maximum nesting depth of the blocks of a generated program.
+const MAX_DEPTH: usize = 3;
+
+/// Maximum number of instructions in a block of a generated program.
+const MAX_BLOCK_LEN: usize = 16;
+
+/// Maximum number of procedures of a generated program.
+const MAX_PROCEDURES: usize = 4;
+
+/// Memory addresses which generated programs read and write are below this bound; loop counters
+/// are kept at the addresses above it, at a distinct address for each procedure and depth.
+const MEMORY_SIZE: u32 = 256;
+
+// STRUCTURED PROGRAMS
+// ================================================================================================
+
+/// A well-formed Miden assembly program, generated from the fuzzer input.
+///
+/// Generated programs only contain valid instructions with in-range immediate values, and all of
+/// their loops terminate, so that the fuzzer explores the execution of programs rather than the
+/// error paths of the assembler. The [fmt::Debug] implementation renders the program as MASM, so
+/// that `cargo fuzz fmt execute_masm <input>` prints the source of a crashing program.
+pub struct MasmProgram {
+    stack_inputs: Vec<u32>,
+    procedures: Vec<Block>,
+    body: Block,
+}
+
+impl MasmProgram {
+    /// Returns the initial stack of the program.
+    pub fn stack_inputs(&self) -> StackInputs {
+        StackInputs::try_from_ints(self.stack_inputs.iter().map(|&value| value as u64))
+            .expect("at most 16 inputs are generated")
+    }
+
+    /// Returns the MASM source of the program.
+    pub fn to_masm(&self) -> String {
+        let mut masm = String::from("use.std::sys\n\n");
+        for (index, procedure) in self.procedures.iter().enumerate() {
+            masm.push_str(&format!("proc.proc{index}\n"));
+            procedure.write_masm(&mut masm, 1, index);
+            masm.push_str("end\n\n");
+        }
+        masm.push_str("begin\n");
+        self.body.write_masm(&mut masm, 1, self.procedures.len());
+        masm.push_str("    exec.sys::truncate_stack\nend\n");
+        masm
+    }
+}
+
+impl<'a> Arbitrary<'a> for MasmProgram {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let num_inputs = u.int_in_range(0..=16)?;
+        let stack_inputs = (0..num_inputs).map(|_| u.arbitrary()).collect::<Result<_>>()?;
+
+        // a procedure may only execute the procedures defined before it
+        let num_procedures = u.int_in_range(0..=MAX_PROCEDURES)?;
+        let mut procedures = Vec::with_capacity(num_procedures);
+        for index in 0..num_procedures {
+            procedures.push(Block::arbitrary(u, 0, index)?);
+        }
+        let body = Block::arbitrary(u, 0, num_procedures)?;
+
+        Ok(Self { stack_inputs, procedures, body })
+    }
+}
+
+impl fmt::Debug for MasmProgram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# stack inputs: {:?}", self.stack_inputs)?;
+        f.write_str(&self.to_masm())
+    }
+}
+
+/// A sequence of instructions and control flow blocks.
+struct Block(Vec<Op>);
+
+enum Op {
+    Inst(&'static str),
+    InstImm(&'static str, u32),
+    Push(u32),
+    Dup(u8),
+    Swap(u8),
+    MovUp(u8),
+    MovDn(u8),
+    MemLoad(u32),
+    MemStore(u32),
+    Exec(usize),
+    If(Block, Block),
+    While { iterations: u8, body: Block },
+    Repeat { iterations: u8, body: Block },
+}
+
+/// Instructions without immediate values.
+const INSTRUCTIONS: &[&str] = &[
+    "add",
+    "sub",
+    "mul",
+    "div",
+    "neg",
+    "inv",
+    "incr",
+    "pow2",
+    "not",
+    "and",
+    "or",
+    "xor",
+    "eq",
+    "neq",
+    "lt",
+    "lte",
+    "gt",
+    "gte",
+    "is_odd",
+    "drop",
+    "dropw",
+    "padw",
+    "dupw",
+    "swapw",
+    "movupw.2",
+    "movdnw.2",
+    "cswap",
+    "cswapw",
+    "cdrop",
+    "u32assert",
+    "u32split",
+    "u32cast",
+    "u32wrapping_add",
+    "u32overflowing_add",
+    "u32wrapping_sub",
+    "u32overflowing_sub",
+    "u32wrapping_mul",
+    "u32overflowing_mul",
+    "u32div",
+    "u32mod",
+    "u32divmod",
+    "u32and",
+    "u32or",
+    "u32xor",
+    "u32not",
+    "u32shl",
+    "u32shr",
+    "u32rotl",
+    "u32rotr",
+    "u32popcnt",
+    "u32clz",
+    "u32ctz",
+    "u32lt",
+    "u32gt",
+    "u32min",
+    "u32max",
+    "hperm",
+    "hmerge",
+    "sdepth",
+    "clk",
+    "ext2add",
+    "ext2mul",
+];
+
+/// Instructions with an immediate value, which is generated below the associated bound.
+const INSTRUCTIONS_WITH_IMM: &[(&str, u32)] = &[
+    ("add", u32::MAX),
+    ("mul", u32::MAX),
+    ("eq", u32::MAX),
+    ("exp", 64),
+    ("u32wrapping_add", u32::MAX),
+    ("u32div", u32::MAX),
+    ("u32shl", 31),
+    ("u32shr", 31),
+    ("u32lt", u32::MAX),
+];
+
+impl Block {
+    /// Generates a block nested at the specified depth, which may execute the procedures with
+    /// indices lower than `num_procedures`.
+    fn arbitrary(u: &mut Unstructured<'_>, depth: usize, num_procedures: usize) -> Result<Self> {
+        let len = u.int_in_range(1..=MAX_BLOCK_LEN)?;
+        let mut ops = Vec::with_capacity(len);
+        for _ in 0..len {
+            let kind = if depth < MAX_DEPTH {
+                u.int_in_range(0..=12)?
+            } else {
+                u.int_in_range(0..=9)?
+            };
+            let op = match kind {
+                0 => Op::Inst(*u.choose(INSTRUCTIONS)?),
+                1 => {
+                    let &(name, bound) = u.choose(INSTRUCTIONS_WITH_IMM)?;
+                    // `u32div` by zero is rejected by the assembler
+                    Op::InstImm(name, u.int_in_range(1..=bound.max(1))?)
+                },
+                2 => Op::Push(u.arbitrary()?),
+                3 => Op::Dup(u.int_in_range(0..=15)?),
+                4 => Op::Swap(u.int_in_range(1..=15)?),
+                5 => Op::MovUp(u.int_in_range(2..=15)?),
+                6 => Op::MovDn(u.int_in_range(2..=15)?),
+                7 => Op::MemLoad(u.int_in_range(0..=MEMORY_SIZE - 1)?),
+                8 => Op::MemStore(u.int_in_range(0..=MEMORY_SIZE - 1)?),
+                9 if num_procedures > 0 => Op::Exec(u.int_in_range(0..=num_procedures - 1)?),
+                9 => Op::Inst(*u.choose(INSTRUCTIONS)?),
+                10 => Op::If(
+                    Block::arbitrary(u, depth + 1, num_procedures)?,
+                    Block::arbitrary(u, depth + 1, num_procedures)?,
+                ),
+                11 => Op::While {
+                    iterations: u.int_in_range(0..=4)?,
+                    body: Block::arbitrary(u, depth + 1, num_procedures)?,
+                },
+                _ => Op::Repeat {
+                    iterations: u.int_in_range(1..=4)?,
+                    body: Block::arbitrary(u, depth + 1, num_procedures)?,
+                },
+            };
+            ops.push(op);
+        }
+        Ok(Self(ops))
+    }
+
+    /// Appends the MASM source of this block, nested at the specified depth of the procedure with
+    /// the specified index (or of the program body, if the index is the number of procedures), to
+    /// `masm`.
+    fn write_masm(&self, masm: &mut String, depth: usize, procedure: usize) {
+        let indent = "    ".repeat(depth);
+        for op in self.0.iter() {
+            match op {
+                Op::Inst(name) => masm.push_str(&format!("{indent}{name}\n")),
+                Op::InstImm(name, imm) => masm.push_str(&format!("{indent}{name}.{imm}\n")),
+                Op::Push(value) => masm.push_str(&format!("{indent}push.{value}\n")),
+                Op::Dup(n) => masm.push_str(&format!("{indent}dup.{n}\n")),
+                Op::Swap(n) => masm.push_str(&format!("{indent}swap.{n}\n")),
+                Op::MovUp(n) => masm.push_str(&format!("{indent}movup.{n}\n")),
+                Op::MovDn(n) => masm.push_str(&format!("{indent}movdn.{n}\n")),
+                Op::MemLoad(addr) => masm.push_str(&format!("{indent}mem_load.{addr}\n")),
+                Op::MemStore(addr) => masm.push_str(&format!("{indent}mem_store.{addr}\n")),
+                Op::Exec(index) => masm.push_str(&format!("{indent}exec.proc{index}\n")),
+                Op::If(on_true, on_false) => {
+                    masm.push_str(&format!("{indent}if.true\n"));
+                    on_true.write_masm(masm, depth + 1, procedure);
+                    masm.push_str(&format!("{indent}else\n"));
+                    on_false.write_masm(masm, depth + 1, procedure);
+                    masm.push_str(&format!("{indent}end\n"));
+                },
+                Op::While { iterations, body } => {
+                    // the loop counter is kept in memory, so that the body cannot affect it
+                    let counter = MEMORY_SIZE + (procedure * (MAX_DEPTH + 1) + depth) as u32;
+                    masm.push_str(&format!(
+                        "{indent}push.{iterations} dup mem_store.{counter} neq.0\n"
+                    ));
+                    masm.push_str(&format!("{indent}while.true\n"));
+                    body.write_masm(masm, depth + 1, procedure);
+                    masm.push_str(&format!(
+                        "{indent}    mem_load.{counter} sub.1 dup mem_store.{counter} neq.0\n"
+                    ));
+                    masm.push_str(&format!("{indent}end\n"));
+                },
+                Op::Repeat { iterations, body } => {
+                    masm.push_str(&format!("{indent}repeat.{iterations}\n"));
+                    body.write_masm(masm, depth + 1, procedure);
+                    masm.push_str(&format!("{indent}end\n"));
+                },
+            }
+        }
+    }
+}
+
+// DIFFERENTIAL CHECKS
+// ================================================================================================
+
+/// Assembles and executes the provided MASM program, and checks that the AIR accepts the
+/// execution trace of every program which the processor executes successfully, by proving and
+/// verifying the execution.
+///
+/// Programs which fail to assemble or to execute are ignored.
+///
+/// # Panics
+/// Panics if proving the execution fails, or if the proof is rejected by the verifier.
+pub fn check_execution(source: &str, stack_inputs: StackInputs) {
+    let Ok(program) = Assembler::new(Arc::new(DefaultSourceManager::default()))
+        .with_library(StdLibrary::default())
+        .and_then(|assembler| assembler.assemble_program(source))
+    else {
+        return;
+    };
+
+    let execution_options = ExecutionOptions::new(Some(MAX_CYCLES), 64, false, false)
+        .expect("execution options are valid");
+    let mut host = DefaultHost::default();
+    host.load_mast_forest(StdLibrary::default().mast_forest().clone())
+        .expect("stdlib is loaded once");
+    if miden_vm::execute(&program, stack_inputs.clone(), &mut host, execution_options).is_err() {
+        return;
+    }
+
+    let proving_options =
+        ProvingOptions::with_96_bit_security(false).with_execution_options(execution_options);
+    let mut host = DefaultHost::default();
+    host.load_mast_forest(StdLibrary::default().mast_forest().clone())
+        .expect("stdlib is loaded once");
+    let (stack_outputs, proof) = prove(&program, stack_inputs.clone(), &mut host, proving_options)
+        .expect("a successfully executed program must be provable");
+    verify(ProgramInfo::from(program), stack_inputs, stack_outputs, proof)
+        .expect("the proof of a successful execution must be accepted");
+}
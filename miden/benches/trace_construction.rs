@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use miden_vm::{Assembler, DefaultHost, Kernel, StackInputs};
+use processor::{ExecutionOptions, ExecutionTrace, Process};
+use test_utils::rand::rand_vector;
+use vm_core::{Felt, QuadExtension};
+
+/// Number of iterations of the loop of the benchmarked program, each of which exercises the
+/// hasher, bitwise and memory chiplets and the range checker.
+const NUM_ITERATIONS: [usize; 3] = [1 << 8, 1 << 10, 1 << 12];
+
+/// Benchmark the construction of the main and auxiliary execution traces from an executed
+/// process. Run with `--features concurrent` to build the independent segments in parallel.
+fn trace_construction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("trace_construction");
+    group.measurement_time(Duration::from_secs(15));
+
+    for num_iterations in NUM_ITERATIONS {
+        let source = format!(
+            "
+            begin
+                repeat.{num_iterations}
+                    hperm
+                    dup u32split u32and mem_store.100
+                    padw mem_loadw.100 dropw
+                end
+            end"
+        );
+        let program = Assembler::default()
+            .assemble_program(source)
+            .expect("Failed to compile test source.");
+        let execute = || {
+            let mut process = Process::new(
+                Kernel::default(),
+                StackInputs::default(),
+                ExecutionOptions::default(),
+            );
+            let stack_outputs = process.execute(&program, &mut DefaultHost::default()).unwrap();
+            (process, stack_outputs)
+        };
+
+        group.bench_function(format!("main_trace/{num_iterations}"), |bench| {
+            bench.iter_batched(
+                execute,
+                |(process, stack_outputs)| ExecutionTrace::new(process, stack_outputs),
+                BatchSize::LargeInput,
+            );
+        });
+
+        let (process, stack_outputs) = execute();
+        let trace = ExecutionTrace::new(process, stack_outputs);
+        let rand_elements = rand_vector::<QuadExtension<Felt>>(16);
+        group.bench_function(format!("aux_trace/{num_iterations}"), |bench| {
+            bench.iter(|| trace.build_aux_trace(&rand_elements));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benchmark, trace_construction);
+criterion_main!(benchmark);
@@ -0,0 +1,204 @@
+//! Aggregation of multiple Miden VM proofs into a single proof.
+//!
+//! Proofs are aggregated by executing a driver program which verifies each of them using the
+//! recursive STARK verifier of the standard library (`std::crypto::stark::verifier`), and outputs a
+//! commitment to the public inputs (program hash, stack inputs and stack outputs) of all verified
+//! proofs. A proof of the driver program thus attests to the validity of all aggregated proofs.
+
+use alloc::{vec, vec::Vec};
+
+pub use air::PublicInputs;
+use assembly::Assembler;
+use processor::{AdviceInputs, DefaultHost, ExecutionError, MemAdviceProvider, Program};
+use prover::{
+    Digest, ExecutionProof, FieldExtension, HashFunction, ProvingOptions, StackInputs,
+    StackOutputs, prove,
+};
+use stdlib::StdLibrary;
+use verifier::{VerificationError, VerifierError, recursive::generate_advice_inputs};
+use vm_core::{Felt, ToElements, crypto::hash::Rpo256};
+
+/// Source code of the program which verifies a batch of proofs.
+const DRIVER_SOURCE: &str = include_str!("driver.masm");
+
+/// The blowup factor which the recursive verifier expects the aggregated proofs to use.
+const RECURSIVE_BLOWUP_FACTOR: usize = 8;
+
+/// The FRI folding factor which the recursive verifier expects the aggregated proofs to use.
+const RECURSIVE_FRI_FOLDING_FACTOR: usize = 4;
+
+/// The maximum degree of the FRI remainder polynomial which the recursive verifier expects the
+/// aggregated proofs to use.
+const RECURSIVE_FRI_REMAINDER_MAX_DEGREE: usize = 127;
+
+// AGGREGATION
+// ================================================================================================
+
+/// Returns the program which verifies a batch of proofs and outputs a commitment to their public
+/// inputs.
+///
+/// The program expects the number of proofs to be at the top of the operand stack, and the data
+/// required to verify the proofs to be supplied via the advice provider, see
+/// [build_aggregation_inputs].
+pub fn aggregation_program() -> Program {
+    Assembler::default()
+        .with_library(StdLibrary::default())
+        .expect("failed to load the standard library")
+        .assemble_program(DRIVER_SOURCE)
+        .expect("failed to assemble the aggregation program")
+}
+
+/// Returns a host for executing the [aggregation_program] with the provided advice inputs.
+///
+/// The host is loaded with the standard library, which contains the recursive verifier.
+pub fn aggregation_host(advice_inputs: AdviceInputs) -> DefaultHost<MemAdviceProvider> {
+    let mut host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+    host.load_mast_forest(StdLibrary::default().mast_forest().clone())
+        .expect("failed to load the standard library");
+    host
+}
+
+/// Returns the inputs with which the [aggregation_program] verifies the provided proofs.
+///
+/// The recursive verifier currently supports only proofs of programs with an empty kernel, which
+/// were generated using the RPO hash function, a blowup factor of 8, a quadratic field extension,
+/// a FRI folding factor of 4 and a maximum FRI remainder degree of 127. The aggregation program
+/// additionally requires each proof to have a conjectured security level of at least 96 bits, i.e.,
+/// `num_queries * log2(blowup) + grinding >= 96`, and fails otherwise.
+///
+/// # Errors
+/// Returns an error if no proofs are provided, if any of the proofs is not supported by the
+/// recursive verifier, or if any of the proofs is malformed.
+pub fn build_aggregation_inputs(
+    proofs: Vec<(ExecutionProof, PublicInputs)>,
+) -> Result<(StackInputs, AdviceInputs), AggregationError> {
+    if proofs.is_empty() {
+        return Err(AggregationError::NoProofs);
+    }
+
+    let stack_inputs = StackInputs::new(vec![Felt::from(proofs.len() as u32)])
+        .expect("a single stack input is always valid");
+
+    let mut advice_inputs = AdviceInputs::default();
+    for (index, (proof, public_inputs)) in proofs.into_iter().enumerate() {
+        check_recursive_proof(index, &proof, &public_inputs)?;
+
+        let (_, proof) = proof.into_parts();
+        let verifier_data = generate_advice_inputs(proof, public_inputs)
+            .map_err(|source| AggregationError::MalformedProof { index, source })?;
+
+        // the proof parameters are read by the driver program from the advice stack, right before
+        // the data read by the verifier; all values are canonical field elements
+        advice_inputs = advice_inputs
+            .with_stack_values(verifier_data.initial_stack)
+            .and_then(|inputs| inputs.with_stack_values(verifier_data.advice_stack))
+            .expect("verifier data consists of valid field elements")
+            .with_map(verifier_data.advice_map);
+        advice_inputs.extend_merkle_store(verifier_data.store.inner_nodes());
+    }
+
+    Ok((stack_inputs, advice_inputs))
+}
+
+/// Returns the commitment to the provided public inputs, as computed by the
+/// [aggregation_program].
+///
+/// The commitment is computed by sequentially merging the zero digest with the hashes of the
+/// public inputs, in the order in which the proofs are aggregated.
+pub fn aggregation_commitment<'a>(
+    public_inputs: impl IntoIterator<Item = &'a PublicInputs>,
+) -> Digest {
+    public_inputs.into_iter().fold(Digest::default(), |commitment, public_inputs| {
+        let public_inputs_hash = Rpo256::hash_elements(&public_inputs.to_elements());
+        Rpo256::merge(&[commitment, public_inputs_hash])
+    })
+}
+
+/// Generates a single proof attesting to the validity of all provided proofs, and returns it
+/// together with the commitment to the public inputs of the provided proofs.
+///
+/// # Errors
+/// Returns an error if the aggregation inputs cannot be built (see [build_aggregation_inputs]),
+/// or if any of the provided proofs fails verification.
+#[tracing::instrument("aggregate_proofs", skip_all)]
+pub fn prove_aggregation(
+    proofs: Vec<(ExecutionProof, PublicInputs)>,
+    options: ProvingOptions,
+) -> Result<(Digest, ExecutionProof), AggregationError> {
+    let (stack_inputs, advice_inputs) = build_aggregation_inputs(proofs)?;
+
+    let program = aggregation_program();
+    let mut host = aggregation_host(advice_inputs);
+    let (stack_outputs, proof) = prove(&program, stack_inputs, &mut host, options)
+        .map_err(AggregationError::AggregationFailed)?;
+
+    let commitment = stack_outputs.get_stack_word(0).expect("stack outputs contain a word");
+    Ok((commitment.into(), proof))
+}
+
+/// Verifies a proof generated by [prove_aggregation] for proofs with the provided public inputs,
+/// and returns the security level of the aggregated proof.
+///
+/// The public inputs must be provided in the order in which the proofs were aggregated.
+///
+/// # Errors
+/// Returns an error if the provided proof does not attest to the validity of proofs with the
+/// provided public inputs.
+#[tracing::instrument("verify_aggregated_proof", skip_all)]
+pub fn verify_aggregation(
+    public_inputs: &[PublicInputs],
+    proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    let program = aggregation_program();
+    let stack_inputs = StackInputs::new(vec![Felt::from(public_inputs.len() as u32)])
+        .expect("a single stack input is always valid");
+
+    let mut commitment: Vec<Felt> = aggregation_commitment(public_inputs).as_elements().to_vec();
+    commitment.reverse();
+    let stack_outputs = StackOutputs::new(commitment).expect("a single word is a valid output");
+
+    verifier::verify(program.into(), stack_inputs, stack_outputs, proof)
+}
+
+/// Returns an error if the provided proof cannot be verified by the recursive STARK verifier.
+fn check_recursive_proof(
+    index: usize,
+    proof: &ExecutionProof,
+    public_inputs: &PublicInputs,
+) -> Result<(), AggregationError> {
+    let options = proof.stark_proof().options();
+    if proof.hash_fn() != HashFunction::Rpo256
+        || options.blowup_factor() != RECURSIVE_BLOWUP_FACTOR
+        || options.field_extension() != FieldExtension::Quadratic
+        || options.to_fri_options().folding_factor() != RECURSIVE_FRI_FOLDING_FACTOR
+        || options.to_fri_options().remainder_max_degree() != RECURSIVE_FRI_REMAINDER_MAX_DEGREE
+    {
+        return Err(AggregationError::UnsupportedProofOptions(index));
+    }
+    if !public_inputs.program_info().kernel().is_empty() {
+        return Err(AggregationError::NonEmptyKernel(index));
+    }
+
+    Ok(())
+}
+
+// ERRORS
+// ================================================================================================
+
+#[derive(Debug, thiserror::Error)]
+pub enum AggregationError {
+    #[error("no proofs to aggregate")]
+    NoProofs,
+    #[error("proof {0} was generated with options not supported by the recursive verifier")]
+    UnsupportedProofOptions(usize),
+    #[error("proof {0} is for a program with a non-empty kernel")]
+    NonEmptyKernel(usize),
+    #[error("proof {index} is malformed")]
+    MalformedProof {
+        index: usize,
+        #[source]
+        source: VerifierError,
+    },
+    #[error("failed to prove the verification of the aggregated proofs")]
+    AggregationFailed(#[source] ExecutionError),
+}
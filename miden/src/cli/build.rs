@@ -0,0 +1,152 @@
+use std::{collections::BTreeSet, fs, path::PathBuf, sync::Arc};
+
+use assembly::{
+    Assembler, Library, LibraryNamespace,
+    diagnostics::{IntoDiagnostic, Report, WrapErr},
+};
+use clap::Parser;
+use package::{Dependency, DependencyName, MastArtifact, Package, PackageExport, PackageManifest};
+use prover::utils::Serializable;
+use stdlib::StdLibrary;
+
+use super::{
+    project::{ProjectKind, ProjectManifest},
+    utils::get_mast_artifact,
+};
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Build a Miden project described by a miden.toml manifest into a .masp package")]
+pub struct BuildCmd {
+    /// Path to the project directory containing the `miden.toml` manifest.
+    #[clap(value_parser, default_value = ".")]
+    project_dir: PathBuf,
+    /// Include debug symbols, regardless of the `build.debug` setting of the manifest.
+    #[clap(short, long, action)]
+    debug: bool,
+    /// Directory of the local registry, overriding the `build.registry` setting of the manifest.
+    #[clap(long)]
+    registry: Option<PathBuf>,
+    /// Path of the output `.masp` file, defaults to `<out_dir>/<name>.masp` in the project
+    /// directory.
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+}
+
+impl BuildCmd {
+    pub fn execute(&self) -> Result<(), Report> {
+        println!("============================================================");
+        println!("Build project");
+        println!("============================================================");
+
+        let manifest = ProjectManifest::read(&self.project_dir)?;
+        let package = self.build(&manifest)?;
+
+        let output_file = match &self.output {
+            Some(output) => output.clone(),
+            None => self
+                .project_dir
+                .join(&manifest.build.out_dir)
+                .join(&manifest.package.name)
+                .with_extension("masp"),
+        };
+        if let Some(dir) = output_file.parent() {
+            fs::create_dir_all(dir).into_diagnostic()?;
+        }
+        fs::write(&output_file, package.to_bytes())
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Failed to write package `{}`", output_file.display()))?;
+
+        println!(
+            "Built {} {} v{} with digest {} into {}",
+            manifest.package.kind.as_str(),
+            manifest.package.name,
+            manifest.package.version,
+            package.digest(),
+            output_file.display()
+        );
+
+        Ok(())
+    }
+
+    /// Assembles the project described by the provided manifest into a package.
+    fn build(&self, manifest: &ProjectManifest) -> Result<Package, Report> {
+        let debug = self.debug || manifest.build.debug;
+        let mut assembler = Assembler::default().with_debug_mode(debug);
+        assembler.add_library(StdLibrary::default()).wrap_err("Failed to load stdlib")?;
+
+        // load the dependencies of the project
+        let mut dependencies = Vec::new();
+        for (name, spec) in manifest.dependencies.iter() {
+            let path = manifest.dependency_path(
+                &self.project_dir,
+                name,
+                spec,
+                self.registry.as_deref(),
+            )?;
+            let library = match get_mast_artifact(&path)
+                .wrap_err_with(|| format!("Failed to load dependency `{name}`"))?
+            {
+                MastArtifact::Library(library) => library,
+                MastArtifact::Executable(_) => {
+                    return Err(Report::msg(format!("Dependency `{name}` is not a library")));
+                },
+            };
+            assembler
+                .add_library(library.as_ref())
+                .wrap_err_with(|| format!("Failed to link dependency `{name}`"))?;
+            dependencies.push(Dependency {
+                name: DependencyName::from(name.clone()),
+                digest: *library.digest(),
+            });
+        }
+
+        let sources: Vec<PathBuf> =
+            manifest.sources().iter().map(|dir| self.project_dir.join(dir)).collect();
+        let main = manifest.main().map(|main| self.project_dir.join(main));
+
+        let mast = match (manifest.package.kind, main) {
+            (ProjectKind::Program, Some(main)) => {
+                let namespace = manifest.namespace()?;
+                for dir in sources.iter() {
+                    assembler.add_modules_from_dir(namespace.clone(), dir)?;
+                }
+                let program = assembler
+                    .assemble_program(main.as_path())
+                    .wrap_err("Failed to compile program")?;
+                MastArtifact::Executable(Arc::new(program))
+            },
+            (ProjectKind::Kernel, Some(main)) => {
+                for dir in sources.iter() {
+                    assembler.add_modules_from_dir(LibraryNamespace::Kernel, dir)?;
+                }
+                let kernel = assembler
+                    .assemble_kernel(main.as_path())
+                    .wrap_err("Failed to compile kernel")?;
+                MastArtifact::Library(Arc::new(kernel.as_ref().clone()))
+            },
+            (ProjectKind::Library, _) => {
+                let library = Library::from_dirs(sources, manifest.namespace()?, assembler)
+                    .wrap_err("Failed to compile library")?;
+                MastArtifact::Library(Arc::new(library))
+            },
+            (_, None) => unreachable!("programs and kernels always have a main module"),
+        };
+
+        let exports = match &mast {
+            MastArtifact::Executable(_) => BTreeSet::new(),
+            MastArtifact::Library(library) => library
+                .exports()
+                .map(|name| PackageExport {
+                    name: name.clone(),
+                    digest: library.mast_forest()[library.get_export_node_id(name)].digest(),
+                })
+                .collect(),
+        };
+
+        Ok(Package {
+            name: manifest.package.name.clone(),
+            mast,
+            manifest: PackageManifest { exports, dependencies },
+        })
+    }
+}
@@ -1,12 +1,15 @@
 use std::path::PathBuf;
 
 use assembly::{
-    Assembler, KernelLibrary, Library, LibraryNamespace,
+    Assembler, Library, LibraryNamespace,
     diagnostics::{IntoDiagnostic, Report},
+    read_modules_from_dir,
 };
 use clap::Parser;
 use stdlib::StdLibrary;
 
+use super::data::OptLevel;
+
 #[derive(Debug, Clone, Parser)]
 #[clap(
     name = "Compile Library",
@@ -33,6 +36,9 @@ pub struct BundleCmd {
     /// Path of the output `.masl` file.
     #[clap(short, long)]
     output: Option<PathBuf>,
+    /// Optimization level of the library.
+    #[clap(short = 'O', long = "opt-level", value_enum, default_value = "0")]
+    opt_level: OptLevel,
 }
 
 impl BundleCmd {
@@ -41,7 +47,9 @@ impl BundleCmd {
         println!("Build library");
         println!("============================================================");
 
-        let mut assembler = Assembler::default().with_debug_mode(self.debug);
+        let mut assembler = Assembler::default()
+            .with_debug_mode(self.debug)
+            .with_optimization_level(self.opt_level.into());
 
         if self.dir.is_file() {
             return Err(Report::msg("`dir` must be a directory."));
@@ -58,19 +66,22 @@ impl BundleCmd {
             },
         };
 
-        match &self.kernel {
+        let report = match &self.kernel {
             Some(kernel) => {
                 if !kernel.is_file() {
                     return Err(Report::msg("`kernel` must be a file"));
                 };
                 assembler.add_library(StdLibrary::default())?;
-                let library = KernelLibrary::from_dir(kernel, Some(&self.dir), assembler)?;
+                let namespace = LibraryNamespace::new("kernel").expect("invalid namespace");
+                assembler.add_modules_from_dir(namespace, &self.dir)?;
+                let (library, report) = assembler.assemble_kernel_with_report(kernel.as_path())?;
                 library.write_to_file(output_file).into_diagnostic()?;
                 println!(
                     "Built kernel module {} with library {}",
                     kernel.display(),
                     &self.dir.display()
                 );
+                report
             },
             None => {
                 let namespace = match &self.namespace {
@@ -79,10 +90,17 @@ impl BundleCmd {
                 };
                 let library_namespace = namespace.parse::<LibraryNamespace>()?;
                 assembler.add_library(StdLibrary::default())?;
-                let library = Library::from_dir(&self.dir, library_namespace, assembler)?;
+                let source_manager = assembler.source_manager();
+                let modules = read_modules_from_dir(library_namespace, &self.dir, &source_manager)?;
+                let (library, report) = assembler.assemble_library_with_report(modules)?;
                 library.write_to_file(output_file).into_diagnostic()?;
                 println!("Built library {}", namespace);
+                report
             },
+        };
+
+        if let Some(report) = report {
+            println!("optimization report:\n{report}");
         }
 
         Ok(())
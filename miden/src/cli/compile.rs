@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
 use clap::Parser;
 
-use super::data::{Debug, Libraries, ProgramFile};
+use super::data::{Debug, Libraries, OptLevel, ProgramFile};
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Compile a miden program")]
@@ -17,6 +17,9 @@ pub struct CompileCmd {
     /// Path to output file
     #[clap(short = 'o', long = "output", value_parser)]
     output_file: Option<PathBuf>,
+    /// Optimization level of the compiled program
+    #[clap(short = 'O', long = "opt-level", value_enum, default_value = "0")]
+    opt_level: OptLevel,
 }
 
 impl CompileCmd {
@@ -32,12 +35,17 @@ impl CompileCmd {
         let libraries = Libraries::new(&self.library_paths)?;
 
         // compile the program
-        let compiled_program = program.compile(Debug::Off, &libraries.libraries)?;
+        let (compiled_program, report) =
+            program.compile_with_report(Debug::Off, self.opt_level, &libraries.libraries)?;
 
         // report program hash to user
         let program_hash: [u8; 32] = compiled_program.hash().into();
         println!("program hash is {}", hex::encode(program_hash));
 
+        if let Some(report) = report {
+            println!("optimization report:\n{report}");
+        }
+
         // write the compiled program into the specified path if one is provided; if the path is
         // not provided, writes the file into the same directory as the source file, but with
         // `.masb` extension.
@@ -6,12 +6,13 @@ use std::{
 };
 
 use assembly::{
-    Assembler, Library, LibraryNamespace,
+    Assembler, Library, LibraryNamespace, OptimizationLevel,
     ast::{Module, ModuleKind},
     diagnostics::{Report, WrapErr},
+    mast::OptimizationReport,
 };
-use miden_vm::{Digest, ExecutionProof, Program, StackOutputs, utils::SliceReader};
-use prover::utils::Deserializable;
+use miden_vm::{Digest, ExecutionProof, Program, ProofBundle, StackOutputs, utils::SliceReader};
+use prover::utils::{Deserializable, Serializable};
 use serde_derive::{Deserialize, Serialize};
 use stdlib::StdLibrary;
 use tracing::instrument;
@@ -41,6 +42,27 @@ impl From<bool> for Debug {
     }
 }
 
+/// Optimization level of the assembler, as selected on the command line.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OptLevel {
+    /// The MAST is emitted exactly as written.
+    #[default]
+    #[value(name = "0")]
+    O0,
+    /// The MAST is optimized.
+    #[value(name = "1")]
+    O1,
+}
+
+impl From<OptLevel> for OptimizationLevel {
+    fn from(level: OptLevel) -> Self {
+        match level {
+            OptLevel::O0 => OptimizationLevel::O0,
+            OptLevel::O1 => OptimizationLevel::O1,
+        }
+    }
+}
+
 // OUTPUT FILE
 // ================================================================================================
 
@@ -137,42 +159,64 @@ impl ProgramFile {
     }
 
     /// Compiles this program file into a [Program].
-    #[instrument(name = "compile_program", skip_all)]
     pub fn compile<'a, I>(&self, debug: Debug, libraries: I) -> Result<Program, Report>
+    where
+        I: IntoIterator<Item = &'a Library>,
+    {
+        self.compile_with_report(debug, OptLevel::O0, libraries)
+            .map(|(program, _)| program)
+    }
+
+    /// Compiles this program file into a [Program] at the specified optimization level, and
+    /// returns it along with the report of the applied optimizations (if any).
+    #[instrument(name = "compile_program", skip_all)]
+    pub fn compile_with_report<'a, I>(
+        &self,
+        debug: Debug,
+        opt_level: OptLevel,
+        libraries: I,
+    ) -> Result<(Program, Option<OptimizationReport>), Report>
     where
         I: IntoIterator<Item = &'a Library>,
     {
         // compile program
-        let mut assembler =
-            Assembler::new(self.source_manager.clone()).with_debug_mode(debug.is_on());
+        let mut assembler = Assembler::new(self.source_manager.clone())
+            .with_debug_mode(debug.is_on())
+            .with_optimization_level(opt_level.into());
         assembler.add_library(StdLibrary::default()).wrap_err("Failed to load stdlib")?;
 
         for library in libraries {
             assembler.add_library(library).wrap_err("Failed to load libraries")?;
         }
 
-        let program: Program = assembler
-            .assemble_program(self.ast.as_ref())
-            .wrap_err("Failed to compile program")?;
-
-        Ok(program)
+        assembler
+            .assemble_program_with_report(self.ast.as_ref())
+            .wrap_err("Failed to compile program")
     }
 }
 
 // PROOF FILE
 // ================================================================================================
 
+/// Contents of a proof file.
+pub enum ProofFileContents {
+    /// A self-describing proof bundle, as written by the `prove` command.
+    Bundle(Box<ProofBundle>),
+    /// A bare execution proof, as written by older versions of the `prove` command.
+    Proof(Box<ExecutionProof>),
+}
+
 pub struct ProofFile;
 
 /// Helper methods to interact with proof file
 impl ProofFile {
-    /// Read stark proof from file
+    /// Read proof bundle or stark proof from file
     #[instrument(name = "read_proof_file",
         fields(path = %proof_path.clone().unwrap_or(program_path.with_extension("proof")).display()), skip_all)]
     pub fn read(
         proof_path: &Option<PathBuf>,
         program_path: &Path,
-    ) -> Result<ExecutionProof, String> {
+    ) -> Result<ProofFileContents, String> {
         // If proof_path has been provided then use this as path.  Alternatively we will
         // replace the program_path extension with `.proof` and use this as a default.
         let path = match proof_path {
@@ -184,18 +228,25 @@ impl ProofFile {
         let file = fs::read(&path)
             .map_err(|err| format!("Failed to open proof file `{}` - {}", path.display(), err))?;
 
-        // deserialize bytes into a stark proof
-        ExecutionProof::from_bytes(&file)
-            .map_err(|err| format!("Failed to decode proof data - {}", err))
+        // deserialize bytes into a proof bundle, or into a stark proof for older proof files
+        if ProofBundle::is_bundle(&file) {
+            ProofBundle::read_from_bytes(&file)
+                .map(|bundle| ProofFileContents::Bundle(Box::new(bundle)))
+                .map_err(|err| format!("Failed to decode proof bundle - {}", err))
+        } else {
+            ExecutionProof::from_bytes(&file)
+                .map(|proof| ProofFileContents::Proof(Box::new(proof)))
+                .map_err(|err| format!("Failed to decode proof data - {}", err))
+        }
     }
 
-    /// Write stark proof to file
+    /// Write proof bundle to file
     #[instrument(name = "write_data_to_proof_file",
                  fields(
                     path = %proof_path.clone().unwrap_or(program_path.with_extension("proof")).display(),
-                    size = format!("{} KB", proof.to_bytes().len() / 1024)), skip_all)]
+                    size = format!("{} KB", bundle.to_bytes().len() / 1024)), skip_all)]
     pub fn write(
-        proof: ExecutionProof,
+        bundle: &ProofBundle,
         proof_path: &Option<PathBuf>,
         program_path: &Path,
     ) -> Result<(), String> {
@@ -210,10 +261,10 @@ impl ProofFile {
         let mut file = fs::File::create(&path)
             .map_err(|err| format!("Failed to create proof file `{}` - {}", path.display(), err))?;
 
-        let proof_bytes = proof.to_bytes();
+        let bundle_bytes = bundle.to_bytes();
 
-        // write proof bytes to file
-        file.write_all(&proof_bytes).unwrap();
+        // write bundle bytes to file
+        file.write_all(&bundle_bytes).unwrap();
 
         Ok(())
     }
@@ -0,0 +1,103 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use assembly::{
+    DisassembledModule, Disassembler,
+    diagnostics::{IntoDiagnostic, Report, WrapErr},
+};
+use clap::Parser;
+use package::MastArtifact;
+use stdlib::StdLibrary;
+
+use super::{data::Libraries, utils::get_mast_artifact};
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Disassemble a compiled program or library into Miden Assembly")]
+pub struct DisasmCmd {
+    /// Path to a .masb program, .masl library, or .masp package file
+    #[clap(value_parser)]
+    input_file: PathBuf,
+    /// Paths to .masl library files used to resolve the names of invoked procedures
+    #[clap(short = 'l', long = "libraries", value_parser)]
+    library_paths: Vec<PathBuf>,
+    /// Path to output file for a program, or to output directory for a library; if not provided,
+    /// the disassembled source is printed to stdout
+    #[clap(short = 'o', long = "output", value_parser)]
+    output_path: Option<PathBuf>,
+}
+
+impl DisasmCmd {
+    pub fn execute(&self) -> Result<(), Report> {
+        // load libraries from files; procedures of the standard library are always named
+        let libraries = Libraries::new(&self.library_paths)?;
+        let mut disassembler = Disassembler::new().with_library(StdLibrary::default());
+        for library in libraries.libraries.iter() {
+            disassembler.add_library(library);
+        }
+
+        let modules = match get_mast_artifact(&self.input_file)? {
+            MastArtifact::Executable(program) => {
+                vec![disassembler.disassemble_program(&program)]
+            },
+            MastArtifact::Library(library) => disassembler.disassemble_library(&library),
+        };
+
+        for module in modules.iter().filter(|module| !module.is_lossless()) {
+            eprintln!(
+                "warning: module {} contains {} operation(s) without a Miden Assembly equivalent; \
+                the disassembled source will not assemble to the same MAST",
+                module.path(),
+                module.num_unsupported_ops()
+            );
+        }
+
+        match &self.output_path {
+            Some(output_path) => self.write_modules(&modules, output_path),
+            None => {
+                for module in modules.iter() {
+                    if !module.path().is_exec_path() {
+                        println!("# {}\n", module.path());
+                    }
+                    println!("{module}");
+                }
+                Ok(())
+            },
+        }
+    }
+
+    /// Writes the disassembled modules to the output path.
+    ///
+    /// A single program module is written to the output file directly, while library modules are
+    /// written into the output directory, one `.masm` file per module, following the module paths.
+    fn write_modules(
+        &self,
+        modules: &[DisassembledModule],
+        output_path: &Path,
+    ) -> Result<(), Report> {
+        if let [module] = modules {
+            if module.path().is_exec_path() {
+                return fs::write(output_path, module.source())
+                    .into_diagnostic()
+                    .wrap_err("Failed to write the disassembled program");
+            }
+        }
+
+        for module in modules {
+            let mut module_path = output_path.to_path_buf();
+            module_path.extend(module.path().to_string().split("::"));
+            module_path.set_extension("masm");
+            if let Some(parent) = module_path.parent() {
+                fs::create_dir_all(parent)
+                    .into_diagnostic()
+                    .wrap_err("Failed to create the output directory")?;
+            }
+            fs::write(&module_path, module.source())
+                .into_diagnostic()
+                .wrap_err_with(|| format!("Failed to write module {}", module.path()))?;
+        }
+
+        Ok(())
+    }
+}
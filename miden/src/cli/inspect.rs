@@ -0,0 +1,315 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+use assembly::{ast::ProcedureName, diagnostics::Report};
+use clap::Parser;
+use package::MastArtifact;
+use serde_derive::Serialize;
+use vm_core::{Decorator, mast::MastNode, utils::collections::KvMap};
+
+use super::utils::get_mast_artifact;
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Inspect the contents of a compiled program, library or package")]
+pub struct InspectCmd {
+    /// Path to a .masb program, .masl library, or .masp package file
+    #[clap(value_parser, required_unless_present = "diff")]
+    input_file: Option<PathBuf>,
+    /// Compare two artifacts by the digests of their procedures
+    #[clap(long = "diff", num_args = 2, value_names = ["A", "B"], conflicts_with = "input_file")]
+    diff: Option<Vec<PathBuf>>,
+    /// Print the report in JSON format
+    #[clap(long = "json")]
+    json: bool,
+}
+
+impl InspectCmd {
+    pub fn execute(&self) -> Result<(), Report> {
+        match (&self.input_file, &self.diff) {
+            (_, Some(paths)) => {
+                let a = ArtifactReport::read(&paths[0])?;
+                let b = ArtifactReport::read(&paths[1])?;
+                let diff = ArtifactDiff::new(&a, &b);
+                if self.json {
+                    println!("{}", to_json(&diff)?);
+                } else {
+                    diff.print(&paths[0], &paths[1]);
+                }
+            },
+            (Some(path), None) => {
+                let report = ArtifactReport::read(path)?;
+                if self.json {
+                    println!("{}", to_json(&report)?);
+                } else {
+                    report.print(path);
+                }
+            },
+            (None, None) => unreachable!("clap requires either an input file or --diff"),
+        }
+
+        Ok(())
+    }
+}
+
+// ARTIFACT REPORT
+// ================================================================================================
+
+/// A summary of the contents of a MAST artifact.
+#[derive(Debug, Serialize)]
+struct ArtifactReport {
+    /// Either `program` or `library`.
+    kind: &'static str,
+    digest: String,
+    /// Exported procedures, keyed by fully-qualified name; for programs this contains only the
+    /// entrypoint.
+    exports: BTreeMap<String, String>,
+    /// Digests of all procedure roots in the MAST forest.
+    procedure_roots: BTreeSet<String>,
+    nodes: NodeStats,
+    /// Digests of procedures referenced via external nodes.
+    external_dependencies: BTreeSet<String>,
+    advice_map_keys: Vec<String>,
+    kernel_procedures: Vec<String>,
+    decorators: DecoratorStats,
+}
+
+/// Number of nodes in a MAST forest, per node kind.
+#[derive(Debug, Default, Serialize)]
+struct NodeStats {
+    total: usize,
+    basic_block: usize,
+    join: usize,
+    split: usize,
+    #[serde(rename = "loop")]
+    loop_: usize,
+    call: usize,
+    syscall: usize,
+    #[serde(rename = "dyn")]
+    dyn_: usize,
+    dyncall: usize,
+    external: usize,
+}
+
+/// Number of decorators in a MAST forest, per decorator kind.
+#[derive(Debug, Default, Serialize)]
+struct DecoratorStats {
+    total: usize,
+    asm_op: usize,
+    debug: usize,
+    trace: usize,
+}
+
+impl ArtifactReport {
+    /// Loads the artifact at the specified path and builds a report of its contents.
+    fn read(path: &Path) -> Result<Self, Report> {
+        Ok(Self::new(&get_mast_artifact(path)?))
+    }
+
+    fn new(artifact: &MastArtifact) -> Self {
+        let forest = artifact.mast_forest();
+
+        let (kind, exports, kernel_procedures) = match artifact {
+            MastArtifact::Executable(program) => {
+                let entrypoint = forest[program.entrypoint()].digest().to_hex();
+                let exports = BTreeMap::from([(ProcedureName::MAIN_PROC_NAME.into(), entrypoint)]);
+                let kernel = program.kernel().proc_hashes().iter().map(|d| d.to_hex()).collect();
+                ("program", exports, kernel)
+            },
+            MastArtifact::Library(library) => {
+                let exports = library
+                    .exports()
+                    .map(|name| {
+                        let digest = forest[library.get_export_node_id(name)].digest();
+                        (name.to_string(), digest.to_hex())
+                    })
+                    .collect();
+                ("library", exports, Vec::new())
+            },
+        };
+
+        let mut nodes = NodeStats::default();
+        let mut external_dependencies = BTreeSet::new();
+        for node in forest.nodes() {
+            nodes.total += 1;
+            match node {
+                MastNode::Block(_) => nodes.basic_block += 1,
+                MastNode::Join(_) => nodes.join += 1,
+                MastNode::Split(_) => nodes.split += 1,
+                MastNode::Loop(_) => nodes.loop_ += 1,
+                MastNode::Call(call) if call.is_syscall() => nodes.syscall += 1,
+                MastNode::Call(_) => nodes.call += 1,
+                MastNode::Dyn(dyn_node) if dyn_node.is_dyncall() => nodes.dyncall += 1,
+                MastNode::Dyn(_) => nodes.dyn_ += 1,
+                MastNode::External(external) => {
+                    nodes.external += 1;
+                    external_dependencies.insert(external.digest().to_hex());
+                },
+            }
+        }
+
+        let mut decorators = DecoratorStats::default();
+        for decorator in forest.decorators() {
+            decorators.total += 1;
+            match decorator {
+                Decorator::AsmOp(_) => decorators.asm_op += 1,
+                Decorator::Debug(_) => decorators.debug += 1,
+                Decorator::Trace(_) => decorators.trace += 1,
+            }
+        }
+
+        Self {
+            kind,
+            digest: artifact.digest().to_hex(),
+            exports,
+            procedure_roots: forest
+                .procedure_roots()
+                .iter()
+                .map(|&id| forest[id].digest().to_hex())
+                .collect(),
+            nodes,
+            external_dependencies,
+            advice_map_keys: forest.advice_map().iter().map(|(key, _)| key.to_hex()).collect(),
+            kernel_procedures,
+            decorators,
+        }
+    }
+
+    fn print(&self, path: &Path) {
+        println!("============================================================");
+        println!("Inspect {} {}", self.kind, path.display());
+        println!("============================================================");
+        println!("digest: {}", self.digest);
+
+        println!("\nexports ({}):", self.exports.len());
+        for (name, digest) in self.exports.iter() {
+            println!("  {name} {digest}");
+        }
+
+        let nodes = &self.nodes;
+        println!("\nnodes ({}):", nodes.total);
+        for (kind, count) in [
+            ("basic block", nodes.basic_block),
+            ("join", nodes.join),
+            ("split", nodes.split),
+            ("loop", nodes.loop_),
+            ("call", nodes.call),
+            ("syscall", nodes.syscall),
+            ("dyn", nodes.dyn_),
+            ("dyncall", nodes.dyncall),
+            ("external", nodes.external),
+        ] {
+            println!("  {kind:<12} {count}");
+        }
+        println!("  procedure roots: {}", self.procedure_roots.len());
+
+        print_list("external dependencies", self.external_dependencies.iter());
+        print_list("advice map keys", self.advice_map_keys.iter());
+        print_list("kernel procedures", self.kernel_procedures.iter());
+
+        let decorators = &self.decorators;
+        println!("\ndecorators ({}):", decorators.total);
+        println!("  {:<12} {}", "asm op", decorators.asm_op);
+        println!("  {:<12} {}", "debug", decorators.debug);
+        println!("  {:<12} {}", "trace", decorators.trace);
+    }
+}
+
+// ARTIFACT DIFF
+// ================================================================================================
+
+/// The differences between two MAST artifacts, compared by procedure digests.
+#[derive(Debug, Serialize)]
+struct ArtifactDiff {
+    /// Exports present only in the second artifact.
+    added: BTreeMap<String, String>,
+    /// Exports present only in the first artifact.
+    removed: BTreeMap<String, String>,
+    /// Exports present in both artifacts, with different digests in each.
+    changed: BTreeMap<String, (String, String)>,
+    /// Number of exports with the same digest in both artifacts.
+    unchanged: usize,
+    /// Digests of procedure roots present only in the second artifact.
+    added_procedures: BTreeSet<String>,
+    /// Digests of procedure roots present only in the first artifact.
+    removed_procedures: BTreeSet<String>,
+}
+
+impl ArtifactDiff {
+    fn new(a: &ArtifactReport, b: &ArtifactReport) -> Self {
+        let mut added = BTreeMap::new();
+        let mut removed = BTreeMap::new();
+        let mut changed = BTreeMap::new();
+        let mut unchanged = 0;
+
+        for (name, digest_a) in a.exports.iter() {
+            match b.exports.get(name) {
+                Some(digest_b) if digest_a == digest_b => unchanged += 1,
+                Some(digest_b) => {
+                    changed.insert(name.clone(), (digest_a.clone(), digest_b.clone()));
+                },
+                None => {
+                    removed.insert(name.clone(), digest_a.clone());
+                },
+            }
+        }
+        for (name, digest_b) in b.exports.iter() {
+            if !a.exports.contains_key(name) {
+                added.insert(name.clone(), digest_b.clone());
+            }
+        }
+
+        Self {
+            added,
+            removed,
+            changed,
+            unchanged,
+            added_procedures: b.procedure_roots.difference(&a.procedure_roots).cloned().collect(),
+            removed_procedures: a.procedure_roots.difference(&b.procedure_roots).cloned().collect(),
+        }
+    }
+
+    fn print(&self, a: &Path, b: &Path) {
+        println!("============================================================");
+        println!("Diff {} {}", a.display(), b.display());
+        println!("============================================================");
+
+        println!("exports: {} unchanged", self.unchanged);
+        for (name, digest) in self.added.iter() {
+            println!("  + {name} {digest}");
+        }
+        for (name, digest) in self.removed.iter() {
+            println!("  - {name} {digest}");
+        }
+        for (name, (digest_a, digest_b)) in self.changed.iter() {
+            println!("  ~ {name} {digest_a} -> {digest_b}");
+        }
+
+        println!(
+            "procedure roots: {} added, {} removed",
+            self.added_procedures.len(),
+            self.removed_procedures.len()
+        );
+        for digest in self.added_procedures.iter() {
+            println!("  + {digest}");
+        }
+        for digest in self.removed_procedures.iter() {
+            println!("  - {digest}");
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+fn print_list<'a>(title: &str, items: impl ExactSizeIterator<Item = &'a String>) {
+    println!("\n{title} ({}):", items.len());
+    for item in items {
+        println!("  {item}");
+    }
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, Report> {
+    serde_json::to_string_pretty(value).map_err(|err| Report::msg(err.to_string()))
+}
@@ -1,16 +1,25 @@
+mod build;
 mod bundle;
 mod compile;
 pub mod data;
 mod debug;
+mod disasm;
+mod inspect;
+mod new;
+pub mod project;
 mod prove;
 mod repl;
 mod run;
 pub mod utils;
 mod verify;
 
+pub use build::BuildCmd;
 pub use bundle::BundleCmd;
 pub use compile::CompileCmd;
 pub use debug::DebugCmd;
+pub use disasm::DisasmCmd;
+pub use inspect::InspectCmd;
+pub use new::NewCmd;
 pub use prove::ProveCmd;
 pub use repl::ReplCmd;
 pub use run::RunCmd;
@@ -0,0 +1,98 @@
+use std::{fs, path::PathBuf};
+
+use assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
+use clap::Parser;
+
+use super::project::{MANIFEST_FILE_NAME, ProjectKind, ProjectManifest};
+
+/// Source of the main module of a new program project.
+const PROGRAM_SOURCE: &str = "\
+begin
+    push.1 push.2 add
+end
+";
+
+/// Source of the module of a new library project, or of the kernel module of a new kernel project.
+const MODULE_SOURCE: &str = "\
+#! Adds the two elements at the top of the stack.
+export.add
+    add
+end
+";
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Create a new Miden project")]
+pub struct NewCmd {
+    /// Path of the directory of the new project.
+    #[clap(value_parser)]
+    path: PathBuf,
+    /// Kind of the new project.
+    #[clap(short, long, value_enum, default_value = "program")]
+    kind: ProjectKind,
+    /// Name of the package, otherwise the directory name is used.
+    #[clap(short, long)]
+    name: Option<String>,
+}
+
+impl NewCmd {
+    pub fn execute(&self) -> Result<(), Report> {
+        if self.path.join(MANIFEST_FILE_NAME).exists() {
+            return Err(Report::msg(format!(
+                "`{}` already contains a Miden project",
+                self.path.display()
+            )));
+        }
+
+        let name = match &self.name {
+            Some(name) => name.clone(),
+            None => self
+                .path
+                .file_name()
+                .ok_or("`path` cannot end with `..`.")
+                .map_err(Report::msg)?
+                .to_string_lossy()
+                .into_owned(),
+        };
+        let manifest = ProjectManifest::new(name, self.kind);
+        manifest.namespace()?;
+
+        let (source_path, source) = match self.kind {
+            ProjectKind::Program => ("src/main.masm", PROGRAM_SOURCE),
+            ProjectKind::Library => ("src/lib.masm", MODULE_SOURCE),
+            ProjectKind::Kernel => ("src/kernel.masm", MODULE_SOURCE),
+        };
+
+        let files = [
+            (MANIFEST_FILE_NAME, manifest.to_toml()),
+            (".gitignore", format!("/{}\n", manifest.build.out_dir.display())),
+            (source_path, source.to_string()),
+        ];
+
+        // never overwrite existing files, e.g. the `.gitignore` of an existing repository
+        if let Some((path, _)) = files.iter().find(|(path, _)| self.path.join(path).exists()) {
+            return Err(Report::msg(format!(
+                "`{}` already exists",
+                self.path.join(path).display()
+            )));
+        }
+
+        for (path, contents) in files {
+            let path = self.path.join(path);
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).into_diagnostic()?;
+            }
+            fs::write(&path, contents)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("Failed to write `{}`", path.display()))?;
+        }
+
+        println!(
+            "Created {} `{}` in {}",
+            self.kind.as_str(),
+            manifest.package.name,
+            self.path.display()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,300 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use assembly::{
+    LibraryNamespace,
+    diagnostics::{IntoDiagnostic, Report, WrapErr},
+};
+use serde_derive::{Deserialize, Serialize};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Name of the manifest file of a Miden project.
+pub const MANIFEST_FILE_NAME: &str = "miden.toml";
+
+// PROJECT MANIFEST
+// ================================================================================================
+
+/// Manifest of a Miden project, read from a `miden.toml` file at the root of the project.
+///
+/// Example:
+///
+/// ```toml
+/// [package]
+/// name = "fib"
+/// version = "0.1.0"
+/// kind = "program"
+///
+/// [dependencies]
+/// math = { path = "../math/target/math.masp" }
+/// utils = { version = "0.2.0" }
+///
+/// [build]
+/// debug = true
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectManifest {
+    pub package: PackageSection,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dependencies: BTreeMap<String, DependencySpec>,
+    #[serde(default)]
+    pub build: BuildSection,
+}
+
+/// The `[package]` section of a project manifest.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct PackageSection {
+    /// Name of the package, which is also the namespace of its library modules.
+    pub name: String,
+    /// Version of the package.
+    pub version: String,
+    /// Kind of the package.
+    pub kind: ProjectKind,
+    /// Path to the executable module of a program, or to the kernel module of a kernel. Defaults
+    /// to `src/main.masm` for programs and to `src/kernel.masm` for kernels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main: Option<PathBuf>,
+    /// Directories containing library modules. For a library, these are the modules of the
+    /// library and default to `src`; for a program or a kernel, the modules are available to the
+    /// main module under the package (or `kernel`) namespace and default to none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sources: Option<Vec<PathBuf>>,
+}
+
+/// Kind of a Miden project.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectKind {
+    Program,
+    Library,
+    Kernel,
+}
+
+impl ProjectKind {
+    /// Returns the name of this kind of project.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Program => "program",
+            Self::Library => "library",
+            Self::Kernel => "kernel",
+        }
+    }
+}
+
+/// A dependency of a project, specified either by the path to a `.masl` or `.masp` file, or by a
+/// version to be looked up in a local registry.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum DependencySpec {
+    Path { path: PathBuf },
+    Registry { version: String },
+}
+
+/// The `[build]` section of a project manifest.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct BuildSection {
+    /// Whether to include debug information (e.g. source locations and `debug` instructions).
+    #[serde(default)]
+    pub debug: bool,
+    /// Directory into which the package is written.
+    #[serde(default = "default_out_dir")]
+    pub out_dir: PathBuf,
+    /// Directory of the local registry, in which dependencies specified by version are looked up
+    /// as `<registry>/<name>-<version>.masp` or `<registry>/<name>-<version>.masl`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<PathBuf>,
+}
+
+impl Default for BuildSection {
+    fn default() -> Self {
+        Self {
+            debug: false,
+            out_dir: default_out_dir(),
+            registry: None,
+        }
+    }
+}
+
+fn default_out_dir() -> PathBuf {
+    PathBuf::from("target")
+}
+
+impl ProjectManifest {
+    /// Returns a manifest of a new project with the specified name and kind, and default settings.
+    pub fn new(name: impl Into<String>, kind: ProjectKind) -> Self {
+        Self {
+            package: PackageSection {
+                name: name.into(),
+                version: "0.1.0".to_string(),
+                kind,
+                main: None,
+                sources: None,
+            },
+            dependencies: BTreeMap::new(),
+            build: BuildSection::default(),
+        }
+    }
+
+    /// Reads the manifest from the `miden.toml` file in the specified project directory.
+    pub fn read(project_dir: &Path) -> Result<Self, Report> {
+        let path = project_dir.join(MANIFEST_FILE_NAME);
+        let contents = fs::read_to_string(&path)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Failed to read project manifest `{}`", path.display()))?;
+        Self::parse(&contents)
+            .wrap_err_with(|| format!("Invalid project manifest `{}`", path.display()))
+    }
+
+    /// Parses and validates a manifest from its TOML representation.
+    pub fn parse(contents: &str) -> Result<Self, Report> {
+        let manifest: Self = toml::from_str(contents).into_diagnostic()?;
+        manifest.namespace()?;
+        Ok(manifest)
+    }
+
+    /// Returns the TOML representation of this manifest.
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("manifest is always serializable")
+    }
+
+    /// Returns the namespace of the library modules of this project.
+    pub fn namespace(&self) -> Result<LibraryNamespace, Report> {
+        self.package.name.parse::<LibraryNamespace>().map_err(|err| {
+            Report::msg(format!("Invalid package name `{}` - {err}", self.package.name))
+        })
+    }
+
+    /// Returns the path of the main module of this project relative to the project directory, if
+    /// the project has one.
+    pub fn main(&self) -> Option<PathBuf> {
+        match self.package.kind {
+            ProjectKind::Program => {
+                Some(self.package.main.clone().unwrap_or_else(|| PathBuf::from("src/main.masm")))
+            },
+            ProjectKind::Kernel => {
+                Some(self.package.main.clone().unwrap_or_else(|| PathBuf::from("src/kernel.masm")))
+            },
+            ProjectKind::Library => None,
+        }
+    }
+
+    /// Returns the directories of the library modules of this project relative to the project
+    /// directory.
+    pub fn sources(&self) -> Vec<PathBuf> {
+        match (&self.package.sources, self.package.kind) {
+            (Some(sources), _) => sources.clone(),
+            (None, ProjectKind::Library) => vec![PathBuf::from("src")],
+            (None, _) => Vec::new(),
+        }
+    }
+
+    /// Returns the path of the file of the specified dependency, resolving paths relative to the
+    /// project directory.
+    pub fn dependency_path(
+        &self,
+        project_dir: &Path,
+        name: &str,
+        dependency: &DependencySpec,
+        registry: Option<&Path>,
+    ) -> Result<PathBuf, Report> {
+        match dependency {
+            DependencySpec::Path { path } => Ok(project_dir.join(path)),
+            DependencySpec::Registry { version } => {
+                let registry = registry
+                    .map(Path::to_path_buf)
+                    .or_else(|| self.build.registry.as_ref().map(|path| project_dir.join(path)))
+                    .ok_or_else(|| {
+                        Report::msg(format!(
+                            "Dependency `{name}` is specified by version, but no registry is set"
+                        ))
+                    })?;
+                ["masp", "masl"]
+                    .into_iter()
+                    .map(|ext| registry.join(format!("{name}-{version}.{ext}")))
+                    .find(|path| path.is_file())
+                    .ok_or_else(|| {
+                        Report::msg(format!(
+                            "Dependency `{name}` version {version} not found in registry `{}`",
+                            registry.display()
+                        ))
+                    })
+            },
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_manifest() {
+        let manifest = ProjectManifest::parse(
+            r#"
+            [package]
+            name = "fib"
+            version = "0.2.0"
+            kind = "program"
+            sources = ["lib"]
+
+            [dependencies]
+            math = { path = "deps/math.masl" }
+            utils = { version = "0.1.0" }
+
+            [build]
+            debug = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.package.kind, ProjectKind::Program);
+        assert_eq!(manifest.main(), Some(PathBuf::from("src/main.masm")));
+        assert_eq!(manifest.sources(), vec![PathBuf::from("lib")]);
+        assert_eq!(
+            manifest.dependencies["math"],
+            DependencySpec::Path { path: PathBuf::from("deps/math.masl") }
+        );
+        assert_eq!(
+            manifest.dependencies["utils"],
+            DependencySpec::Registry { version: "0.1.0".to_string() }
+        );
+        assert!(manifest.build.debug);
+        assert_eq!(manifest.build.out_dir, PathBuf::from("target"));
+
+        // a version dependency cannot be resolved without a registry
+        let utils = &manifest.dependencies["utils"];
+        assert!(manifest.dependency_path(Path::new("."), "utils", utils, None).is_err());
+    }
+
+    #[test]
+    fn manifest_round_trip() {
+        let manifest = ProjectManifest::new("mylib", ProjectKind::Library);
+        assert_eq!(ProjectManifest::parse(&manifest.to_toml()).unwrap(), manifest);
+        assert_eq!(manifest.main(), None);
+        assert_eq!(manifest.sources(), vec![PathBuf::from("src")]);
+    }
+
+    #[test]
+    fn invalid_manifests() {
+        // invalid package name
+        let manifest = ProjectManifest::new("my-lib", ProjectKind::Library);
+        assert!(ProjectManifest::parse(&manifest.to_toml()).is_err());
+
+        // unknown kind and unknown fields
+        let manifest = "[package]\nname = \"a\"\nversion = \"0.1.0\"\nkind = \"script\"\n";
+        assert!(ProjectManifest::parse(manifest).is_err());
+        let manifest =
+            "[package]\nname = \"a\"\nversion = \"0.1.0\"\nkind = \"library\"\nfoo = 1\n";
+        assert!(ProjectManifest::parse(manifest).is_err());
+    }
+}
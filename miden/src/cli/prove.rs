@@ -2,7 +2,7 @@ use std::{path::PathBuf, time::Instant};
 
 use assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
 use clap::Parser;
-use miden_vm::{ProvingOptions, internal::InputFile};
+use miden_vm::{ProofBundle, ProofMetadata, ProvingOptions, internal::InputFile};
 use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError, Program};
 use stdlib::StdLibrary;
 use tracing::instrument;
@@ -55,6 +55,10 @@ pub struct ProveCmd {
     #[clap(long = "rpx", conflicts_with("recursive"))]
     rpx: bool,
 
+    /// Specifies if the Poseidon2 Hash should be used. Conflicts with the recursive and rpx flags
+    #[clap(long = "poseidon2", conflicts_with_all(["recursive", "rpx"]))]
+    poseidon2: bool,
+
     /// Security level for execution proofs generated by the VM
     #[clap(short = 's', long = "security", default_value = "96bits")]
     security: String,
@@ -72,6 +76,8 @@ impl ProveCmd {
             "96bits" => {
                 if self.rpx {
                     ProvingOptions::with_96_bit_security_rpx()
+                } else if self.poseidon2 {
+                    ProvingOptions::with_96_bit_security_poseidon2()
                 } else {
                     ProvingOptions::with_96_bit_security(self.recursive)
                 }
@@ -79,6 +85,8 @@ impl ProveCmd {
             "128bits" => {
                 if self.rpx {
                     ProvingOptions::with_128_bit_security_rpx()
+                } else if self.poseidon2 {
+                    ProvingOptions::with_128_bit_security_poseidon2()
                 } else {
                     ProvingOptions::with_128_bit_security(self.recursive)
                 }
@@ -118,16 +126,26 @@ impl ProveCmd {
         let proving_options =
             self.get_proof_options().map_err(|err| Report::msg(format!("{err}")))?;
 
+        let execution_options = *proving_options.execution_options();
+
         // execute program and generate proof
         let (stack_outputs, proof) =
-            prover::prove(&program, stack_inputs, &mut host, proving_options)
+            prover::prove(&program, stack_inputs.clone(), &mut host, proving_options)
                 .into_diagnostic()
                 .wrap_err("Failed to prove program")?;
 
-        println!("Program proved in {} ms", now.elapsed().as_millis());
+        let proving_time_ms = now.elapsed().as_millis();
+        println!("Program proved in {} ms", proving_time_ms);
 
-        // write proof to file
-        ProofFile::write(proof, &self.proof_file, &self.program_file).map_err(Report::msg)?;
+        // write proof bundle to file
+        let metadata = ProofMetadata {
+            prover_version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            execution_options: Some(execution_options),
+            proving_time_ms: Some(proving_time_ms as u64),
+        };
+        let bundle = ProofBundle::new(program.into(), stack_inputs, stack_outputs.clone(), proof)
+            .with_metadata(metadata);
+        ProofFile::write(&bundle, &self.proof_file, &self.program_file).map_err(Report::msg)?;
 
         // provide outputs
         if let Some(output_path) = &self.output_file {
@@ -1,9 +1,21 @@
-use std::{path::PathBuf, time::Instant};
+use std::{
+    fs,
+    io::{BufWriter, Write},
+    ops::Range,
+    path::PathBuf,
+    time::Instant,
+};
 
-use assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
+use assembly::{
+    DefaultSourceManager,
+    diagnostics::{IntoDiagnostic, Report, WrapErr},
+};
 use clap::Parser;
 use miden_vm::internal::InputFile;
-use processor::{DefaultHost, ExecutionOptions, ExecutionTrace};
+use processor::{
+    CoverageData, CoverageReport, DefaultHost, ExecutionOptions, ExecutionTrace, Host, Program,
+    StackInputs, TraceExportFormat, TraceExportOptions, check_constraints,
+};
 use stdlib::StdLibrary;
 use tracing::instrument;
 
@@ -50,6 +62,52 @@ pub struct RunCmd {
     /// Enable debug instructions
     #[clap(short = 'd', long = "debug")]
     debug: bool,
+
+    /// Path of an lcov tracefile into which the line and branch coverage of the program is
+    /// written (only code compiled with debug information is covered)
+    #[clap(long = "coverage", value_parser)]
+    coverage_file: Option<PathBuf>,
+
+    /// Path of an HTML file into which the line and branch coverage of the program is written
+    #[clap(long = "coverage-html", value_parser)]
+    coverage_html_file: Option<PathBuf>,
+
+    /// Check that the execution trace satisfies all constraints of the VM, and report the first
+    /// constraint it violates together with the operation being executed (implies --debug)
+    #[clap(long = "check-constraints")]
+    check_constraints: bool,
+
+    /// Path of a file into which the execution trace is exported
+    #[clap(long = "export-trace", value_parser)]
+    export_trace_file: Option<PathBuf>,
+
+    /// Format of the exported trace (defaults to csv for .csv files, and to binary otherwise)
+    #[clap(long = "export-format", value_enum)]
+    export_format: Option<ExportFormat>,
+
+    /// Range of rows of the exported trace, e.g. `100..200`
+    #[clap(long = "export-rows", value_parser = parse_row_range)]
+    export_rows: Option<Range<usize>>,
+
+    /// Export the auxiliary trace in addition to the main trace
+    #[clap(long = "export-aux")]
+    export_aux: bool,
+}
+
+/// Format of an exported execution trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    Csv,
+    Binary,
+}
+
+impl From<ExportFormat> for TraceExportFormat {
+    fn from(format: ExportFormat) -> Self {
+        match format {
+            ExportFormat::Csv => TraceExportFormat::Csv,
+            ExportFormat::Binary => TraceExportFormat::Binary,
+        }
+    }
 }
 
 impl RunCmd {
@@ -116,8 +174,66 @@ impl RunCmd {
             trace.trace_len_summary().chiplets_trace_len().kernel_rom_len(),
         );
 
+        if let Some(path) = &self.export_trace_file {
+            self.export_trace(&trace, path)?;
+        }
+
+        if self.check_constraints {
+            let now = Instant::now();
+            if let Err(violation) = check_constraints(&trace) {
+                // source files are loaded from disk using the paths recorded in the debug
+                // information
+                let location = violation
+                    .file_line_col(&DefaultSourceManager::default())
+                    .map(|location| format!(" at {location}"))
+                    .unwrap_or_default();
+                return Err(Report::msg(format!("Constraint check failed{location}: {violation}")));
+            }
+            println!("All constraints satisfied (checked in {} ms)", now.elapsed().as_millis());
+        }
+
         Ok(())
     }
+
+    /// Exports the execution trace to the specified file.
+    fn export_trace(&self, trace: &ExecutionTrace, path: &PathBuf) -> Result<(), Report> {
+        let format = match self.export_format {
+            Some(format) => format.into(),
+            None if path.extension().is_some_and(|ext| ext == "csv") => TraceExportFormat::Csv,
+            None => TraceExportFormat::Binary,
+        };
+        let mut options = TraceExportOptions::default();
+        if let Some(rows) = &self.export_rows {
+            options = options.with_rows(rows.clone());
+        }
+        if self.export_aux {
+            options = options.with_aux_trace();
+        }
+
+        let file = fs::File::create(path)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Failed to create trace file `{}`", path.display()))?;
+        let mut writer = BufWriter::new(file);
+        trace
+            .export(&mut writer, format, &options)
+            .and_then(|_| writer.flush())
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Failed to export the trace to `{}`", path.display()))?;
+        println!("Exported the execution trace to {}", path.display());
+
+        Ok(())
+    }
+
+    /// Returns the options with which the program is executed.
+    fn execution_options(&self) -> Result<ExecutionOptions, Report> {
+        ExecutionOptions::new(
+            Some(self.max_cycles),
+            self.expected_cycles,
+            self.trace,
+            self.debug || self.check_constraints,
+        )
+        .into_diagnostic()
+    }
 }
 
 // HELPER FUNCTIONS
@@ -133,20 +249,12 @@ fn run_masp_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), Repor
     let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
     let mut host = DefaultHost::new(input_data.parse_advice_provider().map_err(Report::msg)?);
 
-    let execution_options = ExecutionOptions::new(
-        Some(params.max_cycles),
-        params.expected_cycles,
-        params.trace,
-        params.debug,
-    )
-    .into_diagnostic()?;
+    let execution_options = params.execution_options()?;
 
     let program_hash: [u8; 32] = program.hash().into();
 
     // execute program and generate outputs
-    let trace = processor::execute(&program, stack_inputs, &mut host, execution_options)
-        .into_diagnostic()
-        .wrap_err("Failed to generate execution trace")?;
+    let trace = execute_program(params, &program, stack_inputs, &mut host, execution_options)?;
 
     Ok((trace, program_hash))
 }
@@ -167,13 +275,7 @@ fn run_masm_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), Repor
     let program = get_masm_program(&params.program_file, &libraries)?;
     let input_data = InputFile::read(&params.input_file, &params.program_file)?;
 
-    let execution_options = ExecutionOptions::new(
-        Some(params.max_cycles),
-        params.expected_cycles,
-        params.trace,
-        params.debug,
-    )
-    .into_diagnostic()?;
+    let execution_options = params.execution_options()?;
 
     // fetch the stack and program inputs from the arguments
     let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
@@ -185,9 +287,64 @@ fn run_masm_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), Repor
 
     let program_hash: [u8; 32] = program.hash().into();
 
-    let trace = processor::execute(&program, stack_inputs, &mut host, execution_options)
-        .into_diagnostic()
-        .wrap_err("Failed to generate execution trace")?;
+    let trace = execute_program(params, &program, stack_inputs, &mut host, execution_options)?;
 
     Ok((trace, program_hash))
 }
+
+/// Parses a range of rows of the form `start..end`.
+fn parse_row_range(rows: &str) -> Result<Range<usize>, String> {
+    let (start, end) = rows.split_once("..").ok_or_else(|| {
+        format!("expected a range of rows of the form `start..end`, got `{rows}`")
+    })?;
+    let start = start.trim().parse::<usize>().map_err(|err| err.to_string())?;
+    let end = end.trim().parse::<usize>().map_err(|err| err.to_string())?;
+    Ok(start..end)
+}
+
+/// Executes the program, recording its coverage and writing the coverage reports if requested.
+///
+/// The coverage reports are written even if the execution fails.
+fn execute_program(
+    params: &RunCmd,
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: &mut impl Host,
+    execution_options: ExecutionOptions,
+) -> Result<ExecutionTrace, Report> {
+    if params.coverage_file.is_none() && params.coverage_html_file.is_none() {
+        return processor::execute(program, stack_inputs, host, execution_options)
+            .into_diagnostic()
+            .wrap_err("Failed to generate execution trace");
+    }
+
+    let mut coverage = CoverageData::new();
+    let result = processor::execute_with_coverage(
+        program,
+        stack_inputs,
+        host,
+        execution_options,
+        &mut coverage,
+    );
+
+    // source files are loaded from disk using the paths recorded in the debug information
+    let report = CoverageReport::new(&coverage, &DefaultSourceManager::default());
+    if let Some(path) = &params.coverage_file {
+        fs::write(path, report.to_lcov())
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Failed to write coverage file `{}`", path.display()))?;
+    }
+    if let Some(path) = &params.coverage_html_file {
+        fs::write(path, report.to_html())
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Failed to write coverage file `{}`", path.display()))?;
+    }
+    let (lines_found, lines_hit) = report.line_totals();
+    let (branches_found, branches_hit) = report.branch_totals();
+    println!(
+        "Coverage: {lines_hit}/{lines_found} lines, {branches_hit}/{branches_found} branches in {} files",
+        report.files().count()
+    );
+
+    result.into_diagnostic().wrap_err("Failed to generate execution trace")
+}
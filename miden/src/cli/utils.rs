@@ -1,8 +1,12 @@
 use std::{fs, path::Path, sync::Arc};
 
-use assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
+use assembly::{
+    Library,
+    diagnostics::{IntoDiagnostic, Report, WrapErr},
+};
 use package::{MastArtifact, Package};
 use prover::utils::Deserializable;
+use vm_core::Program;
 
 use crate::cli::data::{Debug, Libraries, ProgramFile};
 
@@ -27,3 +31,37 @@ pub fn get_masm_program(path: &Path, libraries: &Libraries) -> Result<vm_core::P
     let program = ProgramFile::read(path)?.compile(Debug::On, &libraries.libraries)?;
     Ok(program)
 }
+
+/// Returns a `MastArtifact` from a `.masb` program file, `.masl` library file, or `.masp` package
+/// file, using the file extension to determine the type of the file.
+pub fn get_mast_artifact(path: &Path) -> Result<MastArtifact, Report> {
+    let ext = path
+        .extension()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| Report::msg("The provided file must have an extension"))?
+        .to_lowercase();
+
+    match ext.as_str() {
+        "masb" => {
+            let bytes = fs::read(path).into_diagnostic().wrap_err("Failed to read program file")?;
+            let program = Program::read_from_bytes(&bytes)
+                .into_diagnostic()
+                .wrap_err("Failed to deserialize program")?;
+            Ok(MastArtifact::Executable(Arc::new(program)))
+        },
+        "masl" => {
+            let library = Library::deserialize_from_file(path)
+                .into_diagnostic()
+                .wrap_err("Failed to deserialize library")?;
+            Ok(MastArtifact::Library(Arc::new(library)))
+        },
+        "masp" => {
+            let bytes = fs::read(path).into_diagnostic().wrap_err("Failed to read package file")?;
+            let package = Package::read_from_bytes(&bytes)
+                .into_diagnostic()
+                .wrap_err("Failed to deserialize package")?;
+            Ok(package.into_mast_artifact())
+        },
+        _ => Err(Report::msg("The provided file must have a .masb, .masl or .masp extension")),
+    }
+}
@@ -5,25 +5,30 @@ use std::{
 
 use assembly::diagnostics::{IntoDiagnostic, Report, Result, WrapErr};
 use clap::Parser;
-use miden_vm::{Kernel, ProgramInfo, internal::InputFile};
+use miden_vm::{Kernel, ProgramInfo, VerifierPolicy, internal::InputFile};
 
-use super::data::{OutputFile, ProgramHash, ProofFile};
+use super::data::{OutputFile, ProgramHash, ProofFile, ProofFileContents};
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Verify a miden program")]
 pub struct VerifyCmd {
-    /// Path to input file
+    /// Path to input file; only used for proof files which are not proof bundles
     #[clap(short = 'i', long = "input", value_parser)]
     input_file: Option<PathBuf>,
-    /// Path to output file
+    /// Path to output file; only used for proof files which are not proof bundles
     #[clap(short = 'o', long = "output", value_parser)]
     output_file: Option<PathBuf>,
     /// Path to proof file
     #[clap(short = 'p', long = "proof", value_parser)]
     proof_file: PathBuf,
-    /// Program hash (hex)
+    /// Program hash (hex); required for proof files which are not proof bundles, and otherwise
+    /// checked against the program hash in the bundle
     #[clap(short = 'x', long = "program-hash")]
-    program_hash: String,
+    program_hash: Option<String>,
+    /// Minimum conjectured security level (in bits) of accepted proofs; if not specified, only
+    /// proofs generated with the standard proving options are accepted
+    #[clap(long = "min-security")]
+    min_security: Option<u32>,
 }
 
 impl VerifyCmd {
@@ -35,35 +40,72 @@ impl VerifyCmd {
         println!("-------------------------------------------------------------------------------");
 
         // read program hash from input
-        let program_hash = ProgramHash::read(&self.program_hash).map_err(Report::msg)?;
-
-        // load input data from file
-        let input_data = InputFile::read(&Some(input_file), self.proof_file.as_ref())?;
-
-        // fetch the stack inputs from the arguments
-        let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
-
-        // load outputs data from file
-        let outputs_data =
-            OutputFile::read(&Some(output_file), self.proof_file.as_ref()).map_err(Report::msg)?;
+        let program_hash = self
+            .program_hash
+            .as_ref()
+            .map(ProgramHash::read)
+            .transpose()
+            .map_err(Report::msg)?;
 
         // load proof from file
-        let proof = ProofFile::read(&Some(self.proof_file.clone()), self.proof_file.as_ref())
+        let proof_file = ProofFile::read(&Some(self.proof_file.clone()), self.proof_file.as_ref())
             .map_err(Report::msg)?;
 
+        let (program_info, stack_inputs, stack_outputs, proof) = match proof_file {
+            ProofFileContents::Bundle(bundle) => {
+                if let Some(program_hash) = program_hash {
+                    if bundle.program_info().program_hash() != &program_hash {
+                        return Err(Report::msg(
+                            "Program hash does not match the program hash in the proof bundle",
+                        ));
+                    }
+                }
+                if let Some(version) = &bundle.metadata().prover_version {
+                    println!("Proof generated by prover version {version}");
+                }
+                bundle.into_parts()
+            },
+            ProofFileContents::Proof(proof) => {
+                let program_hash = program_hash.ok_or_else(|| {
+                    Report::msg("Program hash is required to verify a bare execution proof")
+                })?;
+
+                // load input data from file
+                let input_data = InputFile::read(&Some(input_file), self.proof_file.as_ref())?;
+
+                // fetch the stack inputs from the arguments
+                let stack_inputs = input_data.parse_stack_inputs().map_err(Report::msg)?;
+
+                // load outputs data from file
+                let outputs_data = OutputFile::read(&Some(output_file), self.proof_file.as_ref())
+                    .map_err(Report::msg)?;
+                let stack_outputs = outputs_data.stack_outputs().map_err(Report::msg)?;
+
+                // TODO accept kernel as CLI argument
+                let kernel = Kernel::default();
+                let program_info = ProgramInfo::new(program_hash, kernel);
+
+                (program_info, stack_inputs, stack_outputs, *proof)
+            },
+        };
+
         let now = Instant::now();
 
-        // TODO accept kernel as CLI argument
-        let kernel = Kernel::default();
-        let program_info = ProgramInfo::new(program_hash, kernel);
+        let policy = match self.min_security {
+            Some(bits) => VerifierPolicy::min_conjectured_security(bits),
+            None => VerifierPolicy::default(),
+        };
 
         // verify proof
-        let stack_outputs = outputs_data.stack_outputs().map_err(Report::msg)?;
-        verifier::verify(program_info, stack_inputs, stack_outputs, proof)
-            .into_diagnostic()
-            .wrap_err("Program failed verification!")?;
-
-        println!("Verification complete in {} ms", now.elapsed().as_millis());
+        let security_level =
+            verifier::verify_with_policy(program_info, stack_inputs, stack_outputs, proof, &policy)
+                .into_diagnostic()
+                .wrap_err("Program failed verification!")?;
+
+        println!(
+            "Verification complete in {} ms ({security_level}-bit conjectured security)",
+            now.elapsed().as_millis()
+        );
 
         Ok(())
     }
@@ -1,5 +1,5 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fs,
     path::{Path, PathBuf},
 };
@@ -7,13 +7,19 @@ use std::{
 use assembly::diagnostics::{IntoDiagnostic, Report, WrapErr};
 use serde_derive::Deserialize;
 pub use tracing::{Level, event, instrument};
-use vm_core::Felt;
+use vm_core::{
+    Felt,
+    crypto::merkle::{EmptySubtreeRoots, Smt},
+};
 
 use crate::{
     AdviceInputs, MemAdviceProvider, StackInputs, Word,
     crypto::{MerkleStore, MerkleTree, NodeIndex, PartialMerkleTree, RpoDigest, SimpleSmt},
 };
 
+mod values;
+pub use values::{FileFormat, InputValue, Limbs, Scalar, TypedValue};
+
 // CONSTANTS
 // ================================================================================================
 const SIMPLE_SMT_DEPTH: u8 = u64::BITS as u8;
@@ -40,30 +46,47 @@ pub enum MerkleData {
     /// byte hex string representing the value of the leaf.
     #[serde(rename = "partial_merkle_tree")]
     PartialMerkleTree(Vec<((u8, u64), String)>),
+    /// String representation of a Sparse Merkle Tree of the specified depth. The leaves of the
+    /// tree are represented as a vector of tuples where each tuple consists of a u64 leaf index
+    /// and a 32 byte hex string representing the value of the leaf.
+    #[serde(rename = "simple_smt")]
+    SimpleSmt { depth: u8, leaves: Vec<(u64, String)> },
+    /// String representation of a Sparse Merkle Tree keyed by words. The Sparse Merkle Tree is
+    /// represented as a vector of tuples where each tuple consists of a 32 byte hex string
+    /// representing the key and a 32 byte hex string representing the value. The leaves of the
+    /// tree are also added to the advice map, keyed by their hashes.
+    #[serde(rename = "smt")]
+    Smt(Vec<(String, String)>),
 }
 
 // INPUT FILE
 // ================================================================================================
 
-// TODO consider using final types instead of string representations.
 /// Input file struct that is used to deserialize input data from file. It consists of four
 /// components:
 /// - operand_stack
 /// - advice_stack
 /// - advice_map
 /// - merkle_store
+///
+/// The file can be encoded either as JSON or as TOML. The values of the operand stack, the advice
+/// stack and the advice map are described by [InputValue]s, which can reference external files;
+/// paths of such files are relative to the directory of the input file.
 #[derive(Deserialize, Debug)]
 pub struct InputFile {
-    /// String representation of the initial operand stack, composed of chained field elements.
-    pub operand_stack: Vec<String>,
-    /// Optional string representation of the initial advice stack, composed of chained field
-    /// elements.
-    pub advice_stack: Option<Vec<String>>,
-    /// Optional map of 32 byte hex strings to vectors of u64s representing the initial advice map.
-    pub advice_map: Option<HashMap<String, Vec<u64>>>,
+    /// Representation of the initial operand stack, composed of chained field elements.
+    pub operand_stack: Vec<InputValue>,
+    /// Optional representation of the initial advice stack, composed of chained field elements.
+    pub advice_stack: Option<Vec<InputValue>>,
+    /// Optional map of 32 byte hex strings to vectors of values representing the initial advice
+    /// map.
+    pub advice_map: Option<HashMap<String, Vec<InputValue>>>,
     /// Optional vector of merkle data which will be loaded into the initial merkle store. Merkle
     /// data is represented as 32 byte hex strings and node indexes are represented as u64s.
     pub merkle_store: Option<Vec<MerkleData>>,
+    /// Directory relative to which the paths of referenced files are resolved.
+    #[serde(skip)]
+    pub base_dir: PathBuf,
 }
 
 impl Default for InputFile {
@@ -73,6 +96,7 @@ impl Default for InputFile {
             advice_stack: Some(Vec::new()),
             advice_map: Some(HashMap::new()),
             merkle_store: None,
+            base_dir: PathBuf::new(),
         }
     }
 }
@@ -100,13 +124,34 @@ impl InputFile {
             .wrap_err_with(|| format!("Failed to open input file {}", path.display()))?;
 
         // deserialize input data
-        let inputs: InputFile = serde_json::from_str(&inputs_file)
-            .into_diagnostic()
-            .wrap_err("Failed to deserialize input data")?;
+        let mut inputs = Self::parse(&inputs_file, &path)?;
+        inputs.base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
 
         Ok(inputs)
     }
 
+    /// Deserializes input data from the contents of the file at the specified path.
+    ///
+    /// Files with a `.toml` extension are parsed as TOML and files with a `.json` extension are
+    /// parsed as JSON; otherwise, the format is inferred from the contents of the file.
+    fn parse(inputs_file: &str, path: &Path) -> Result<Self, Report> {
+        let is_json = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => false,
+            Some("json") => true,
+            _ => inputs_file.trim_start().starts_with('{'),
+        };
+
+        if is_json {
+            serde_json::from_str(inputs_file)
+                .into_diagnostic()
+                .wrap_err("Failed to deserialize input data")
+        } else {
+            toml::from_str(inputs_file)
+                .into_diagnostic()
+                .wrap_err("Failed to deserialize input data")
+        }
+    }
+
     /// Parse advice provider data from the input file.
     pub fn parse_advice_provider(&self) -> Result<MemAdviceProvider, String> {
         let mut advice_inputs = AdviceInputs::default();
@@ -123,11 +168,11 @@ impl InputFile {
             advice_inputs = advice_inputs.with_map(map);
         }
 
-        if let Some(merkle_store) = self
+        if let Some((merkle_store, leaves)) = self
             .parse_merkle_store()
             .map_err(|e| format!("failed to parse advice provider: {e}"))?
         {
-            advice_inputs = advice_inputs.with_merkle_store(merkle_store);
+            advice_inputs = advice_inputs.with_merkle_store(merkle_store).with_map(leaves);
         }
 
         Ok(MemAdviceProvider::from(advice_inputs))
@@ -135,15 +180,8 @@ impl InputFile {
 
     /// Parse advice stack data from the input file.
     fn parse_advice_stack(&self) -> Result<Vec<u64>, String> {
-        self.advice_stack
-            .as_deref()
-            .unwrap_or(&[])
-            .iter()
-            .map(|v| {
-                v.parse::<u64>()
-                    .map_err(|e| format!("failed to parse advice stack value '{v}': {e}"))
-            })
-            .collect::<Result<Vec<_>, _>>()
+        values::resolve_values(self.advice_stack.as_deref().unwrap_or(&[]), &self.base_dir, 0)
+            .map_err(|e| format!("failed to parse advice stack: {e}"))
     }
 
     /// Parse advice map data from the input file.
@@ -161,10 +199,11 @@ impl InputFile {
                     .map_err(|e| format!("failed to decode advice map key '{k}': {e}"))?;
 
                 // convert values to Felt
-                let values = v
-                    .iter()
+                let values = values::resolve_values(v, &self.base_dir, 0)
+                    .map_err(|e| format!("failed to parse advice map value for key '{k}': {e}"))?
+                    .into_iter()
                     .map(|v| {
-                        Felt::try_from(*v).map_err(|e| {
+                        Felt::try_from(v).map_err(|e| {
                             format!("failed to convert advice map value '{v}' to Felt: {e}")
                         })
                     })
@@ -176,14 +215,19 @@ impl InputFile {
         Ok(Some(map))
     }
 
-    /// Parse merkle store data from the input file.
-    fn parse_merkle_store(&self) -> Result<Option<MerkleStore>, String> {
+    /// Parse merkle store data from the input file, together with the advice map entries of the
+    /// leaves of the Sparse Merkle Trees keyed by words.
+    #[allow(clippy::type_complexity)]
+    fn parse_merkle_store(
+        &self,
+    ) -> Result<Option<(MerkleStore, BTreeMap<RpoDigest, Vec<Felt>>)>, String> {
         let merkle_data = match &self.merkle_store {
             Some(merkle_data) => merkle_data,
             None => return Ok(None),
         };
 
         let mut merkle_store = MerkleStore::default();
+        let mut leaves = BTreeMap::new();
         for data in merkle_data {
             match data {
                 MerkleData::MerkleTree(data) => {
@@ -219,10 +263,59 @@ impl InputFile {
                         tree.root()
                     );
                 },
+                MerkleData::SimpleSmt { depth, leaves } => {
+                    let root = Self::add_simple_smt(&mut merkle_store, *depth, leaves)?;
+                    event!(
+                        Level::TRACE,
+                        "Added Sparse Merkle tree with root {} to the Merkle store",
+                        root
+                    );
+                },
+                MerkleData::Smt(data) => {
+                    let entries = Self::parse_smt(data)?;
+                    let tree = Smt::with_entries(entries)
+                        .map_err(|e| format!("failed to parse a Sparse Merkle Tree: {e}"))?;
+                    merkle_store.extend(tree.inner_nodes());
+                    leaves.extend(tree.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+                    event!(
+                        Level::TRACE,
+                        "Added Sparse Merkle tree with root {} to the Merkle store",
+                        tree.root()
+                    );
+                },
             }
         }
 
-        Ok(Some(merkle_store))
+        Ok(Some((merkle_store, leaves)))
+    }
+
+    /// Adds a Sparse Merkle Tree of the specified depth with the provided leaves to the Merkle
+    /// store, and returns the root of the tree.
+    fn add_simple_smt(
+        merkle_store: &mut MerkleStore,
+        depth: u8,
+        leaves: &[(u64, String)],
+    ) -> Result<RpoDigest, String> {
+        if depth == 0 || depth > SIMPLE_SMT_DEPTH {
+            return Err(format!("invalid Sparse Merkle Tree depth {depth}"));
+        }
+
+        let mut root = *EmptySubtreeRoots::entry(depth, 0);
+        let mut indexes = BTreeSet::new();
+        for (index, value) in Self::parse_sparse_merkle_tree(leaves)? {
+            if !indexes.insert(index) {
+                return Err(format!("duplicate Sparse Merkle Tree leaf index {index}"));
+            }
+            let node_index = NodeIndex::new(depth, index).map_err(|e| {
+                format!("failed to create node index with depth {depth} and index {index} - {e}")
+            })?;
+            root = merkle_store
+                .set_node(root, node_index, value.into())
+                .map_err(|e| format!("failed to parse a Sparse Merkle Tree: {e}"))?
+                .root;
+        }
+
+        Ok(root)
     }
 
     /// Parse and return merkle tree leaves.
@@ -245,6 +338,17 @@ impl InputFile {
             .collect()
     }
 
+    /// Parse and return the entries of a Sparse Merkle Tree keyed by words.
+    fn parse_smt(tree: &[(String, String)]) -> Result<Vec<(RpoDigest, Word)>, String> {
+        tree.iter()
+            .map(|(key, value)| {
+                let key = Self::parse_word(key)?;
+                let value = Self::parse_word(value)?;
+                Ok((key.into(), value))
+            })
+            .collect()
+    }
+
     /// Parse and return Partial Merkle Tree entries.
     fn parse_partial_merkle_tree(
         tree: &[((u8, u64), String)],
@@ -279,11 +383,7 @@ impl InputFile {
 
     /// Parse and return the stack inputs for the program.
     pub fn parse_stack_inputs(&self) -> Result<StackInputs, String> {
-        let stack_inputs = self
-            .operand_stack
-            .iter()
-            .map(|v| v.parse::<u64>().map_err(|e| e.to_string()))
-            .collect::<Result<Vec<_>, _>>()?;
+        let stack_inputs = values::resolve_values(&self.operand_stack, &self.base_dir, 0)?;
 
         StackInputs::try_from_ints(stack_inputs).map_err(|e| e.to_string())
     }
@@ -365,4 +465,130 @@ mod tests {
         let merkle_store = inputs.parse_merkle_store().unwrap();
         assert!(merkle_store.is_some());
     }
+
+    #[test]
+    fn test_sparse_merkle_data_parsing() {
+        let value = "0x1400000000000000000000000000000000000000000000000000000000000000";
+
+        // a Sparse Merkle Tree of a custom depth is equivalent to a SimpleSmt of the same depth
+        let inputs = InputFile {
+            merkle_store: Some(vec![MerkleData::SimpleSmt {
+                depth: 8,
+                leaves: vec![(3, value.to_string()), (200, value.to_string())],
+            }]),
+            ..Default::default()
+        };
+        let (merkle_store, _) = inputs.parse_merkle_store().unwrap().unwrap();
+        let word = InputFile::parse_word(value).unwrap();
+        let tree = SimpleSmt::<8>::with_leaves([(3, word), (200, word)]).unwrap();
+        let path = merkle_store.get_path(tree.root(), NodeIndex::new(8, 200).unwrap()).unwrap();
+        assert_eq!(path.value, word.into());
+
+        // duplicate indexes and invalid depths are rejected
+        let inputs = InputFile {
+            merkle_store: Some(vec![MerkleData::SimpleSmt {
+                depth: 8,
+                leaves: vec![(3, value.to_string()), (3, value.to_string())],
+            }]),
+            ..Default::default()
+        };
+        assert!(inputs.parse_merkle_store().is_err());
+        let inputs = InputFile {
+            merkle_store: Some(vec![MerkleData::SimpleSmt { depth: 65, leaves: vec![] }]),
+            ..Default::default()
+        };
+        assert!(inputs.parse_merkle_store().is_err());
+
+        // the leaves of a Sparse Merkle Tree keyed by words are added to the advice map
+        let key = "0x0100000000000000000000000000000000000000000000000000000000000000";
+        let inputs = InputFile {
+            merkle_store: Some(vec![MerkleData::Smt(vec![(key.to_string(), value.to_string())])]),
+            ..Default::default()
+        };
+        let (_, leaves) = inputs.parse_merkle_store().unwrap().unwrap();
+        let tree = Smt::with_entries([(InputFile::parse_word(key).unwrap().into(), word)]).unwrap();
+        let (_, leaf) = tree.leaves().next().unwrap();
+        assert_eq!(leaves.get(&leaf.hash()), Some(&leaf.to_elements()));
+    }
+
+    #[test]
+    fn test_toml_parsing() {
+        let inputs = r#"
+            operand_stack = [
+                "1",
+                2,
+                "0x10",
+                { type = "u64", value = "0x0000000100000002" },
+                { type = "u128", value = "18446744073709551616" },
+            ]
+            advice_stack = [
+                { type = "word", value = "0x0100000000000000020000000000000003000000000000000400000000000000" },
+                { type = "bytes", value = "abcde" },
+                { type = "bytes", value = "0x0102030405060708", limbs = "felt" },
+            ]
+
+            [advice_map]
+            "0x0100000000000000000000000000000000000000000000000000000000000000" = [
+                { type = "felt", value = "0xff" },
+            ]
+
+            [[merkle_store]]
+            simple_smt = { depth = 2, leaves = [[1, "0x1400000000000000000000000000000000000000000000000000000000000000"]] }
+        "#;
+        let inputs = InputFile::parse(inputs, Path::new("inputs.toml")).unwrap();
+
+        let stack = inputs.parse_stack_inputs().unwrap();
+        let expected = [1, 2, 16, 2, 1, 0, 0, 1, 0];
+        let expected: Vec<Felt> = expected.into_iter().rev().map(Felt::new).collect();
+        assert_eq!(stack.iter().take(expected.len()).copied().collect::<Vec<_>>(), expected);
+
+        let advice_stack = inputs.parse_advice_stack().unwrap();
+        assert_eq!(advice_stack, vec![1, 2, 3, 4, 0x6463_6261, 0x65, 0x0007_0605_0403_0201, 0x08]);
+
+        let advice_map = inputs.parse_advice_map().unwrap().unwrap();
+        assert_eq!(advice_map.values().next().unwrap(), &vec![Felt::new(255)]);
+        assert!(inputs.parse_merkle_store().unwrap().is_some());
+
+        // invalid values are rejected
+        let inputs = r#"operand_stack = [{ type = "u64", value = "18446744073709551616" }]"#;
+        let inputs = InputFile::parse(inputs, Path::new("inputs.toml")).unwrap();
+        assert!(inputs.parse_stack_inputs().is_err());
+    }
+
+    #[test]
+    fn test_file_references() {
+        let dir = std::env::temp_dir().join(format!("miden-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("data")).unwrap();
+        fs::write(dir.join("data/values.bin"), [1, 0, 0, 0, 2]).unwrap();
+        fs::write(
+            dir.join("data/values.json"),
+            r#"["7", { "type": "file", "path": "values.bin", "limbs": "felt" }]"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("inputs.json"),
+            r#"{
+                "operand_stack": [{ "type": "file", "path": "data/values.json", "format": "json" }],
+                "advice_stack": [{ "type": "file", "path": "data/values.bin" }]
+            }"#,
+        )
+        .unwrap();
+
+        let inputs = InputFile::read(&Some(dir.join("inputs.json")), Path::new("")).unwrap();
+        let stack = inputs.parse_stack_inputs().unwrap();
+        let expected = [Felt::new(0x02_0000_0001), Felt::new(7)];
+        assert_eq!(stack.iter().take(2).copied().collect::<Vec<_>>(), expected);
+        assert_eq!(inputs.parse_advice_stack().unwrap(), vec![1, 2]);
+
+        // a file which references itself is rejected
+        fs::write(
+            dir.join("data/values.json"),
+            r#"[{ "type": "file", "path": "values.json", "format": "json" }]"#,
+        )
+        .unwrap();
+        let inputs = InputFile::read(&Some(dir.join("inputs.json")), Path::new("")).unwrap();
+        assert!(inputs.parse_stack_inputs().is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
 }
@@ -0,0 +1,285 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde_derive::Deserialize;
+
+use super::InputFile;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum depth of nested references to JSON files.
+const MAX_FILE_NESTING_DEPTH: usize = 8;
+
+/// Number of bytes packed into a single field element when packing bytes into field element limbs.
+const BYTES_PER_FELT_LIMB: usize = 7;
+
+// INPUT VALUE
+// ================================================================================================
+
+/// Value of an entry of the operand stack, the advice stack or the advice map in an input file.
+///
+/// A value expands to one or more field elements, which are placed one after another.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum InputValue {
+    /// A decimal or hex (`0x`-prefixed) representation of a single field element, or a 32 byte hex
+    /// representation of a word.
+    String(String),
+    /// A single field element.
+    Number(u64),
+    /// A typed value.
+    Typed(TypedValue),
+}
+
+/// A typed value in an input file, tagged by its `type` field.
+///
+/// Integers are split into 32-bit limbs, and byte strings are packed into 32-bit or field element
+/// limbs; in both cases, the limbs are ordered from the least significant one to the most
+/// significant one.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TypedValue {
+    /// A single field element.
+    Felt { value: Scalar },
+    /// A word, represented as a 32 byte hex string.
+    Word { value: String },
+    /// A 64-bit unsigned integer, split into two 32-bit limbs.
+    U64 { value: Scalar },
+    /// A 128-bit unsigned integer, split into four 32-bit limbs.
+    U128 { value: Scalar },
+    /// A 256-bit unsigned integer, split into eight 32-bit limbs.
+    U256 { value: Scalar },
+    /// A byte string, given either as a hex (`0x`-prefixed) string or as a UTF-8 string.
+    Bytes {
+        value: String,
+        #[serde(default)]
+        limbs: Limbs,
+    },
+    /// Data read from an external file.
+    ///
+    /// The path of the file is relative to the file in which it is referenced. A binary file is
+    /// read as a byte string, while a JSON file must contain an array of input values.
+    File {
+        path: PathBuf,
+        #[serde(default)]
+        format: FileFormat,
+        #[serde(default)]
+        limbs: Limbs,
+    },
+}
+
+/// A numeric value, given either as a number or as a decimal or hex (`0x`-prefixed) string.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum Scalar {
+    Number(u64),
+    String(String),
+}
+
+/// Limbs into which byte strings are packed.
+#[derive(Deserialize, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Limbs {
+    /// Every 4 bytes are packed into a 32-bit limb.
+    #[default]
+    U32,
+    /// Every 7 bytes are packed into a field element limb.
+    Felt,
+}
+
+/// Format of an external file referenced in an input file.
+#[derive(Deserialize, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FileFormat {
+    #[default]
+    Binary,
+    Json,
+}
+
+impl InputValue {
+    /// Returns the elements this value expands to.
+    ///
+    /// Paths of referenced files are resolved relative to `base_dir`.
+    pub fn to_elements(&self, base_dir: &Path) -> Result<Vec<u64>, String> {
+        self.resolve(base_dir, 0)
+    }
+
+    fn resolve(&self, base_dir: &Path, depth: usize) -> Result<Vec<u64>, String> {
+        match self {
+            Self::String(value) => parse_element_or_word(value),
+            Self::Number(value) => Ok(vec![*value]),
+            Self::Typed(value) => value.resolve(base_dir, depth),
+        }
+    }
+}
+
+impl TypedValue {
+    fn resolve(&self, base_dir: &Path, depth: usize) -> Result<Vec<u64>, String> {
+        match self {
+            Self::Felt { value } => Ok(vec![value.parse_u64()?]),
+            Self::Word { value } => {
+                Ok(InputFile::parse_word(value)?.iter().map(|e| e.as_int()).collect())
+            },
+            Self::U64 { value } => value.parse_limbs(2),
+            Self::U128 { value } => value.parse_limbs(4),
+            Self::U256 { value } => value.parse_limbs(8),
+            Self::Bytes { value, limbs } => {
+                let bytes = match value.strip_prefix("0x") {
+                    Some(hex) => hex::decode(hex)
+                        .map_err(|e| format!("failed to decode bytes from hex {value} - {e}"))?,
+                    None => value.as_bytes().to_vec(),
+                };
+                Ok(pack_bytes(&bytes, *limbs))
+            },
+            Self::File { path, format, limbs } => {
+                let path = base_dir.join(path);
+                match format {
+                    FileFormat::Binary => {
+                        let bytes = fs::read(&path).map_err(|e| {
+                            format!("failed to read binary file {} - {e}", path.display())
+                        })?;
+                        Ok(pack_bytes(&bytes, *limbs))
+                    },
+                    FileFormat::Json => {
+                        if depth >= MAX_FILE_NESTING_DEPTH {
+                            return Err(format!(
+                                "failed to read JSON file {} - files are nested too deeply",
+                                path.display()
+                            ));
+                        }
+                        let data = fs::read_to_string(&path).map_err(|e| {
+                            format!("failed to read JSON file {} - {e}", path.display())
+                        })?;
+                        let values: Vec<InputValue> = serde_json::from_str(&data).map_err(|e| {
+                            format!("failed to deserialize JSON file {} - {e}", path.display())
+                        })?;
+                        let base_dir = path.parent().unwrap_or(Path::new(""));
+                        resolve_values(&values, base_dir, depth + 1)
+                    },
+                }
+            },
+        }
+    }
+}
+
+impl Scalar {
+    /// Parses this scalar as a single 64-bit value.
+    fn parse_u64(&self) -> Result<u64, String> {
+        match self {
+            Self::Number(value) => Ok(*value),
+            Self::String(value) => parse_u64(value),
+        }
+    }
+
+    /// Parses this scalar as an unsigned integer, and splits it into the specified number of
+    /// 32-bit limbs, from the least significant to the most significant one.
+    fn parse_limbs(&self, num_limbs: usize) -> Result<Vec<u64>, String> {
+        let value = match self {
+            Self::Number(value) => {
+                let limbs = [*value as u32 as u64, *value >> 32];
+                return Ok(limbs
+                    .into_iter()
+                    .chain(core::iter::repeat(0))
+                    .take(num_limbs)
+                    .collect());
+            },
+            Self::String(value) => value,
+        };
+
+        let mut limbs = vec![0_u32; num_limbs];
+        let overflow = || format!("value {value} does not fit into {} bits", num_limbs * 32);
+        match value.strip_prefix("0x") {
+            Some(hex) => {
+                if hex.is_empty() || !hex.is_ascii() {
+                    return Err(format!("failed to parse hex value {value}"));
+                }
+                if hex.len() > num_limbs * 8 {
+                    return Err(overflow());
+                }
+                let hex = format!("{hex:0>width$}", width = num_limbs * 8);
+                for (limb, chunk) in limbs.iter_mut().rev().zip(hex.as_bytes().chunks(8)) {
+                    let chunk = core::str::from_utf8(chunk).expect("hex string is valid UTF-8");
+                    *limb = u32::from_str_radix(chunk, 16)
+                        .map_err(|e| format!("failed to parse hex value {value} - {e}"))?;
+                }
+            },
+            None => {
+                if value.is_empty() {
+                    return Err("failed to parse an empty decimal value".to_string());
+                }
+                for digit in value.chars() {
+                    let mut carry = digit
+                        .to_digit(10)
+                        .ok_or_else(|| format!("failed to parse decimal value {value}"))?
+                        as u64;
+                    for limb in limbs.iter_mut() {
+                        let product = *limb as u64 * 10 + carry;
+                        *limb = product as u32;
+                        carry = product >> 32;
+                    }
+                    if carry != 0 {
+                        return Err(overflow());
+                    }
+                }
+            },
+        }
+
+        Ok(limbs.into_iter().map(u64::from).collect())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the elements the provided values expand to, placed one after another.
+pub fn resolve_values(
+    values: &[InputValue],
+    base_dir: &Path,
+    depth: usize,
+) -> Result<Vec<u64>, String> {
+    let mut elements = Vec::new();
+    for value in values {
+        elements.extend(value.resolve(base_dir, depth)?);
+    }
+    Ok(elements)
+}
+
+/// Parses a single element from a decimal or hex string, or the four elements of a word from a 32
+/// byte hex string.
+fn parse_element_or_word(value: &str) -> Result<Vec<u64>, String> {
+    match value.strip_prefix("0x") {
+        Some(hex) if hex.len() == 64 => {
+            Ok(InputFile::parse_word(value)?.iter().map(|element| element.as_int()).collect())
+        },
+        _ => Ok(vec![parse_u64(value)?]),
+    }
+}
+
+/// Parses a 64-bit value from a decimal or hex string.
+fn parse_u64(value: &str) -> Result<u64, String> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse::<u64>(),
+    }
+    .map_err(|e| format!("failed to parse value '{value}': {e}"))
+}
+
+/// Packs the provided bytes into limbs, padding the last limb with zeros.
+fn pack_bytes(bytes: &[u8], limbs: Limbs) -> Vec<u64> {
+    let limb_size = match limbs {
+        Limbs::U32 => 4,
+        Limbs::Felt => BYTES_PER_FELT_LIMB,
+    };
+
+    bytes
+        .chunks(limb_size)
+        .map(|chunk| {
+            let mut limb = [0_u8; 8];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(limb)
+        })
+        .collect()
+}
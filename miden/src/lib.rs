@@ -1,6 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![doc = include_str!("../README.md")]
 
+extern crate alloc;
+
 // EXPORTS
 // ================================================================================================
 
@@ -10,15 +12,20 @@ pub use assembly::{
     diagnostics,
 };
 pub use processor::{
-    AdviceInputs, AdviceProvider, AsmOpInfo, DefaultHost, ExecutionError, ExecutionTrace, Host,
-    Kernel, MemAdviceProvider, Operation, Program, ProgramInfo, StackInputs, VmState,
-    VmStateIterator, ZERO, crypto, execute, execute_iter, utils,
+    AdviceInputs, AdviceProvider, AsmOpInfo, ConstraintKind, ConstraintViolation, CoverageData,
+    CoverageReport, DefaultHost, ExecutionError, ExecutionTrace, Host, Kernel, MemAdviceProvider,
+    Operation, Program, ProgramInfo, StackInputs, VmState, VmStateIterator, ZERO,
+    check_constraints, crypto, execute, execute_iter, execute_with_coverage, utils,
 };
+#[cfg(feature = "std")]
+pub use processor::{TraceExportFormat, TraceExportOptions};
 pub use prover::{
-    Digest, ExecutionProof, FieldExtension, HashFunction, InputError, Proof, ProvingOptions,
-    StackOutputs, Word, math, prove,
+    Digest, ExecutionProof, FieldExtension, HashFunction, InputError, Proof, ProofBundle,
+    ProofMetadata, ProvingOptions, StackOutputs, Word, math, prove,
 };
-pub use verifier::{VerificationError, verify};
+pub use verifier::{VerificationError, VerifierPolicy, verify, verify_with_policy};
+
+pub mod aggregation;
 
 // (private) exports
 // ================================================================================================
@@ -24,9 +24,13 @@ pub struct Cli {
 #[derive(Debug, Parser)]
 pub enum Actions {
     Analyze(tools::Analyze),
+    Build(cli::BuildCmd),
     Compile(cli::CompileCmd),
     Bundle(cli::BundleCmd),
     Debug(cli::DebugCmd),
+    Disasm(cli::DisasmCmd),
+    Inspect(cli::InspectCmd),
+    New(cli::NewCmd),
     Prove(cli::ProveCmd),
     Run(cli::RunCmd),
     Verify(cli::VerifyCmd),
@@ -39,9 +43,13 @@ impl Cli {
     pub fn execute(&self) -> Result<(), Report> {
         match &self.action {
             Actions::Analyze(analyze) => analyze.execute(),
+            Actions::Build(build) => build.execute(),
             Actions::Compile(compile) => compile.execute(),
             Actions::Bundle(compile) => compile.execute(),
             Actions::Debug(debug) => debug.execute(),
+            Actions::Disasm(disasm) => disasm.execute(),
+            Actions::Inspect(inspect) => inspect.execute(),
+            Actions::New(new) => new.execute(),
             Actions::Prove(prove) => prove.execute(),
             Actions::Run(run) => run.execute(),
             Actions::Verify(verify) => verify.execute(),
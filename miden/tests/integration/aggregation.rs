@@ -0,0 +1,122 @@
+use assembly::Assembler;
+use miden_vm::{
+    DefaultHost, ExecutionError, ExecutionProof, FieldExtension, HashFunction, ProvingOptions,
+    StackInputs, StackOutputs,
+    aggregation::{
+        AggregationError, PublicInputs, aggregation_commitment, aggregation_host,
+        aggregation_program, build_aggregation_inputs, prove_aggregation, verify_aggregation,
+    },
+};
+use processor::{ExecutionOptions, ProgramInfo};
+use vm_core::assert_matches;
+
+#[test]
+fn aggregated_proofs_are_verified_and_committed() {
+    let proofs = vec![
+        prove_program("begin repeat.32 swap dup.1 add end end", &[0, 1], recursive_options()),
+        prove_program("begin push.5 mul add end", &[3, 4], recursive_options()),
+    ];
+    let public_inputs: Vec<PublicInputs> =
+        proofs.iter().map(|(_, inputs)| inputs.clone()).collect();
+
+    let (stack_inputs, advice_inputs) = build_aggregation_inputs(proofs).unwrap();
+    let mut host = aggregation_host(advice_inputs);
+    let trace = processor::execute(
+        &aggregation_program(),
+        stack_inputs,
+        &mut host,
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+
+    let commitment = trace.stack_outputs().get_stack_word(0).unwrap();
+    assert_eq!(commitment, *aggregation_commitment(&public_inputs));
+    assert_ne!(
+        aggregation_commitment(&public_inputs),
+        aggregation_commitment(public_inputs.iter().rev())
+    );
+}
+
+#[test]
+fn unsupported_proofs_are_rejected() {
+    assert_matches!(build_aggregation_inputs(vec![]), Err(AggregationError::NoProofs));
+
+    let proofs = vec![
+        prove_program("begin push.5 mul add end", &[3, 4], recursive_options()),
+        prove_program("begin push.5 mul add end", &[3, 4], ProvingOptions::default()),
+    ];
+    assert_matches!(
+        build_aggregation_inputs(proofs),
+        Err(AggregationError::UnsupportedProofOptions(1))
+    );
+}
+
+#[test]
+fn aggregated_proofs_are_proven_and_verified() {
+    let proofs = vec![
+        prove_program("begin repeat.32 swap dup.1 add end end", &[0, 1], recursive_options()),
+        prove_program("begin push.5 mul add end", &[3, 4], recursive_options()),
+    ];
+    let public_inputs: Vec<PublicInputs> =
+        proofs.iter().map(|(_, inputs)| inputs.clone()).collect();
+
+    let (commitment, proof) = prove_aggregation(proofs, ProvingOptions::default()).unwrap();
+    assert_eq!(commitment, aggregation_commitment(&public_inputs));
+    verify_aggregation(&public_inputs, proof.clone()).unwrap();
+
+    // the public inputs must be provided in the order in which the proofs were aggregated
+    let reordered: Vec<PublicInputs> = public_inputs.iter().rev().cloned().collect();
+    assert!(verify_aggregation(&reordered, proof.clone()).is_err());
+
+    // the aggregated proof does not attest to proofs with other public inputs
+    let mut tampered = public_inputs.clone();
+    tampered[1] = PublicInputs::new(
+        tampered[1].program_info().clone(),
+        tampered[1].stack_inputs().clone(),
+        StackOutputs::try_from_ints([36]).unwrap(),
+    );
+    assert!(verify_aggregation(&tampered, proof.clone()).is_err());
+    assert!(verify_aggregation(&public_inputs[..1], proof).is_err());
+}
+
+#[test]
+fn insecure_proofs_are_rejected_by_the_driver() {
+    // without grinding, 27 queries give only 81 bits of conjectured security
+    let options =
+        ProvingOptions::new(27, 8, 0, FieldExtension::Quadratic, 4, 127, HashFunction::Rpo256);
+    let proofs = vec![prove_program("begin push.5 mul add end", &[3, 4], options)];
+
+    let (stack_inputs, advice_inputs) = build_aggregation_inputs(proofs).unwrap();
+    let mut host = aggregation_host(advice_inputs);
+    let result = processor::execute(
+        &aggregation_program(),
+        stack_inputs,
+        &mut host,
+        ExecutionOptions::default(),
+    );
+    assert_matches!(result.err(), Some(ExecutionError::FailedAssertion { .. }));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn recursive_options() -> ProvingOptions {
+    ProvingOptions::new(27, 8, 16, FieldExtension::Quadratic, 4, 127, HashFunction::Rpo256)
+}
+
+fn prove_program(
+    source: &str,
+    stack_inputs: &[u64],
+    options: ProvingOptions,
+) -> (ExecutionProof, PublicInputs) {
+    let program = Assembler::default().assemble_program(source).unwrap();
+    let stack_inputs = StackInputs::try_from_ints(stack_inputs.iter().copied()).unwrap();
+    let (stack_outputs, proof) =
+        miden_vm::prove(&program, stack_inputs.clone(), &mut DefaultHost::default(), options)
+            .unwrap();
+
+    (
+        proof,
+        PublicInputs::new(ProgramInfo::from(program), stack_inputs, stack_outputs),
+    )
+}
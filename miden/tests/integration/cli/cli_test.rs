@@ -42,6 +42,75 @@ fn cli_run() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn cli_run_coverage() -> Result<(), Box<dyn std::error::Error>> {
+    let lcov_path = "./tests/integration/cli/data/branches.lcov";
+    let html_path = "./tests/integration/cli/data/branches.html";
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("run")
+        .arg("./tests/integration/cli/data/branches.masm")
+        .arg("--coverage")
+        .arg(lcov_path)
+        .arg("--coverage-html")
+        .arg(html_path);
+    cmd.unwrap()
+        .assert()
+        .stdout(predicate::str::contains("Coverage: 8/8 lines, 3/4 branches in 1 files"));
+
+    // the loop is never skipped, and both branches of the `if.true` block are taken
+    let lcov = fs::read_to_string(lcov_path)?;
+    assert!(lcov.contains("SF:./tests/integration/cli/data/branches.masm\n"));
+    assert!(lcov.contains("BRDA:5,0,0,1\nBRDA:5,0,1,0\n"));
+    assert!(lcov.contains("BRDA:8,0,0,1\nBRDA:8,0,1,2\n"));
+    assert!(lcov.ends_with("end_of_record\n"));
+    assert!(fs::read_to_string(html_path)?.contains("branches.masm"));
+
+    fs::remove_file(lcov_path)?;
+    fs::remove_file(html_path)?;
+
+    Ok(())
+}
+
+#[test]
+fn cli_run_check_constraints() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = bin_under_test().command();
+    cmd.arg("run")
+        .arg("./tests/integration/cli/data/branches.masm")
+        .arg("--check-constraints");
+    cmd.unwrap()
+        .assert()
+        .stdout(predicate::str::contains("All constraints satisfied"));
+
+    Ok(())
+}
+
+#[test]
+fn cli_run_export_trace() -> Result<(), Box<dyn std::error::Error>> {
+    let trace_path = "./tests/integration/cli/data/branches_trace.csv";
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("run")
+        .arg("./tests/integration/cli/data/branches.masm")
+        .arg("--export-trace")
+        .arg(trace_path)
+        .arg("--export-rows")
+        .arg("0..10");
+    cmd.unwrap()
+        .assert()
+        .stdout(predicate::str::contains("Exported the execution trace"));
+
+    let trace = fs::read_to_string(trace_path)?;
+    let lines: Vec<_> = trace.lines().collect();
+    assert_eq!(lines.len(), 11);
+    assert!(lines[0].starts_with("row,clk,fmp,ctx,in_syscall,fn_hash[0]"));
+    assert!(lines[10].starts_with("9,9,"));
+
+    fs::remove_file(trace_path)?;
+
+    Ok(())
+}
+
 #[test]
 fn cli_run_masp() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = bin_under_test().command();
@@ -77,6 +146,118 @@ fn cli_prove_masp() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn cli_prove_and_verify_bundle() -> Result<(), Box<dyn std::error::Error>> {
+    let proof_path = "./tests/integration/cli/data/masp/is_prime_bundle.proof";
+    let outputs_path = "./tests/integration/cli/data/masp/is_prime_bundle.outputs";
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("prove")
+        .arg("./tests/integration/cli/data/masp/is_prime.masp")
+        .arg("-i")
+        .arg("./tests/integration/cli/data/masp/is_prime.inputs")
+        .arg("-p")
+        .arg(proof_path)
+        .arg("-o")
+        .arg(outputs_path);
+    cmd.unwrap().assert().success();
+
+    // the proof bundle contains everything needed to verify the proof
+    let mut cmd = bin_under_test().command();
+    cmd.arg("verify").arg("-p").arg(proof_path);
+    cmd.unwrap()
+        .assert()
+        .stdout(predicate::str::contains("Verification complete"))
+        .stdout(predicate::str::contains("Proof generated by prover version"));
+
+    // verification fails if the program hash does not match the bundle
+    let mut cmd = bin_under_test().command();
+    cmd.arg("verify").arg("-p").arg(proof_path).arg("-x").arg("00".repeat(32));
+    cmd.assert().failure();
+
+    fs::remove_file(proof_path).unwrap();
+    fs::remove_file(outputs_path).unwrap();
+
+    Ok(())
+}
+
+#[test]
+fn cli_verify_bare_proof() -> Result<(), Box<dyn std::error::Error>> {
+    let proof_path = "./tests/integration/cli/data/branches_bare.proof";
+    let inputs_path = "./tests/integration/cli/data/branches_bare.inputs";
+    let outputs_path = "./tests/integration/cli/data/branches_bare.outputs";
+
+    // write a proof in the format used before proof bundles: the hash function byte followed by
+    // the STARK proof, with the inputs and outputs in separate files
+    let source = fs::read_to_string("./tests/integration/cli/data/branches.masm")?;
+    let program = assembly::Assembler::default().assemble_program(source).unwrap();
+    let (stack_outputs, proof) = miden_vm::prove(
+        &program,
+        miden_vm::StackInputs::default(),
+        &mut miden_vm::DefaultHost::default(),
+        miden_vm::ProvingOptions::default(),
+    )
+    .unwrap();
+    let mut bytes = vec![proof.hash_fn() as u8];
+    bytes.extend_from_slice(&proof.stark_proof().to_bytes());
+    fs::write(proof_path, bytes)?;
+    fs::write(inputs_path, r#"{ "operand_stack": [] }"#)?;
+    let outputs: Vec<String> = stack_outputs.iter().map(|felt| felt.to_string()).collect();
+    fs::write(outputs_path, format!(r#"{{ "stack": {outputs:?} }}"#))?;
+
+    let program_hash: String =
+        program.hash().as_bytes().iter().map(|byte| format!("{byte:02x}")).collect();
+    let mut cmd = bin_under_test().command();
+    cmd.arg("verify").arg("-p").arg(proof_path).arg("-x").arg(&program_hash);
+    cmd.unwrap().assert().stdout(predicate::str::contains("Verification complete"));
+
+    // a bare proof cannot be verified without the program hash
+    let mut cmd = bin_under_test().command();
+    cmd.arg("verify").arg("-p").arg(proof_path);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Program hash is required to verify a bare execution proof",
+    ));
+
+    fs::remove_file(proof_path).unwrap();
+    fs::remove_file(inputs_path).unwrap();
+    fs::remove_file(outputs_path).unwrap();
+
+    Ok(())
+}
+
+#[test]
+fn cli_prove_and_verify_poseidon2() -> Result<(), Box<dyn std::error::Error>> {
+    let proof_path = "./tests/integration/cli/data/branches_poseidon2.proof";
+    let outputs_path = "./tests/integration/cli/data/branches_poseidon2.outputs";
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("prove")
+        .arg("./tests/integration/cli/data/branches.masm")
+        .arg("-p")
+        .arg(proof_path)
+        .arg("-o")
+        .arg(outputs_path)
+        .arg("--poseidon2");
+    cmd.unwrap().assert().success();
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("verify").arg("-p").arg(proof_path);
+    cmd.unwrap().assert().stdout(predicate::str::contains("Verification complete"));
+
+    // Poseidon2 cannot be combined with another hash function
+    let mut cmd = bin_under_test().command();
+    cmd.arg("prove")
+        .arg("./tests/integration/cli/data/branches.masm")
+        .arg("--poseidon2")
+        .arg("--rpx");
+    cmd.assert().failure();
+
+    fs::remove_file(proof_path).unwrap();
+    fs::remove_file(outputs_path).unwrap();
+
+    Ok(())
+}
+
 use assembly::Library;
 use vm_core::Decorator;
 
@@ -138,6 +319,48 @@ fn cli_bundle_output() {
     fs::remove_file("test.masl").unwrap()
 }
 
+#[test]
+fn cli_bundle_optimized() {
+    let mut cmd = bin_under_test().command();
+    cmd.arg("bundle")
+        .arg("./tests/integration/cli/data/lib")
+        .arg("-O")
+        .arg("1")
+        .arg("--output")
+        .arg("optimized.masl");
+    cmd.assert().success().stdout(predicate::str::contains("optimization report"));
+    fs::remove_file("optimized.masl").unwrap()
+}
+
+#[test]
+fn cli_compile_optimized() {
+    let mut cmd = bin_under_test().command();
+    cmd.arg("compile")
+        .arg("-a")
+        .arg("./tests/integration/cli/data/branches.masm")
+        .arg("-o")
+        .arg("branches_optimized.masb")
+        .arg("--opt-level")
+        .arg("1");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("optimization report"))
+        .stdout(predicate::str::contains("cycles saved"));
+    fs::remove_file("branches_optimized.masb").unwrap();
+
+    // no report is printed when the program is not optimized
+    let mut cmd = bin_under_test().command();
+    cmd.arg("compile")
+        .arg("-a")
+        .arg("./tests/integration/cli/data/branches.masm")
+        .arg("-o")
+        .arg("branches_unoptimized.masb");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("optimization report").not());
+    fs::remove_file("branches_unoptimized.masb").unwrap();
+}
+
 #[test]
 // First compile a library to a .masl file, then run a program that uses it.
 fn cli_run_with_lib() -> Result<(), Box<dyn std::error::Error>> {
@@ -190,3 +413,148 @@ fn cli_debug_masp() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn cli_disasm_masp() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = bin_under_test().command();
+
+    cmd.arg("disasm").arg("./tests/integration/cli/data/masp/is_prime.masp");
+
+    let output = cmd.unwrap();
+
+    output.assert().success().stdout(predicate::str::contains("begin"));
+
+    Ok(())
+}
+
+#[test]
+fn cli_disasm_library() {
+    let mut cmd = bin_under_test().command();
+    cmd.arg("bundle")
+        .arg("./tests/integration/cli/data/lib")
+        .arg("--output")
+        .arg("disasm_lib.masl");
+    cmd.assert().success();
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("disasm").arg("disasm_lib.masl").arg("--output").arg("disasm_lib");
+    cmd.assert().success();
+    assert!(Path::new("disasm_lib/lib/lib.masm").exists());
+
+    fs::remove_file("disasm_lib.masl").unwrap();
+    fs::remove_dir_all("disasm_lib").unwrap();
+}
+
+#[test]
+fn cli_inspect_masp() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = bin_under_test().command();
+
+    cmd.arg("inspect")
+        .arg("./tests/integration/cli/data/masp/is_prime.masp")
+        .arg("--json");
+
+    let output = cmd.unwrap();
+
+    output
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"kind\": \"program\""))
+        .stdout(predicate::str::contains("\"#main\""));
+
+    Ok(())
+}
+
+#[test]
+fn cli_inspect_diff() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = bin_under_test().command();
+
+    cmd.arg("inspect")
+        .arg("--diff")
+        .arg("./tests/integration/cli/data/masp/is_prime.masp")
+        .arg("./tests/integration/cli/data/masp/is_prime.masp");
+
+    let output = cmd.unwrap();
+
+    output
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("exports: 1 unchanged"))
+        .stdout(predicate::str::contains("procedure roots: 0 added, 0 removed"));
+
+    Ok(())
+}
+
+#[test]
+// Scaffold a library and a program depending on it, then build both into packages.
+fn cli_new_and_build() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = std::env::temp_dir().join(format!("miden-cli-new-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("new").arg(dir.join("mathlib")).arg("--kind").arg("library");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Created library `mathlib`"));
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("build").arg(dir.join("mathlib"));
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Built library mathlib v0.1.0"));
+    assert!(dir.join("mathlib/target/mathlib.masp").exists());
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("new").arg(dir.join("app"));
+    cmd.assert().success();
+
+    // link the program against the library
+    let manifest = fs::read_to_string(dir.join("app/miden.toml"))?;
+    fs::write(
+        dir.join("app/miden.toml"),
+        format!(
+            "{manifest}\n[dependencies]\nmathlib = {{ path = \"../mathlib/target/mathlib.masp\" }}\n"
+        ),
+    )?;
+    fs::write(
+        dir.join("app/src/main.masm"),
+        "use.mathlib::lib\nbegin\n    push.1 push.2 exec.lib::add\nend\n",
+    )?;
+
+    let mut cmd = bin_under_test().command();
+    cmd.arg("build").arg(dir.join("app")).arg("--debug");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Built program app v0.1.0"));
+
+    // a project cannot be created twice
+    let mut cmd = bin_under_test().command();
+    cmd.arg("new").arg(dir.join("app"));
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("already contains a Miden project"));
+
+    fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+fn cli_new_keeps_existing_files() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = std::env::temp_dir().join(format!("miden-cli-new-existing-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let repo = dir.join("repo");
+    fs::create_dir_all(&repo)?;
+    fs::write(repo.join(".gitignore"), "/build\n")?;
+
+    // scaffolding a project into an existing repository does not replace its files
+    let mut cmd = bin_under_test().command();
+    cmd.arg("new").arg(&repo);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(".gitignore` already exists"));
+    assert_eq!(fs::read_to_string(repo.join(".gitignore"))?, "/build\n");
+    assert!(!repo.join("miden.toml").exists());
+    assert!(!repo.join("src/main.masm").exists());
+
+    fs::remove_dir_all(dir)?;
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use test_utils::{CoverageData, CoverageReport, StackInputs, build_debug_test};
+
+// COVERAGE
+// ================================================================================================
+
+#[test]
+fn coverage_across_executions() {
+    let source = "
+        proc.abs_diff
+            dup.1 dup.1 lt
+            if.true
+                swap sub
+            else
+                sub
+            end
+        end
+
+        begin
+            exec.abs_diff
+        end";
+
+    let mut test = build_debug_test!(source, &[3, 5]);
+    let mut coverage = CoverageData::new();
+
+    // a single execution takes only one of the branches
+    test.execute_with_coverage(&mut coverage).unwrap();
+    let report = CoverageReport::new(&coverage, test.source_manager.as_ref());
+    let (lines_found, lines_hit) = report.line_totals();
+    assert!(lines_hit < lines_found);
+    assert_eq!(report.branch_totals(), (2, 1));
+
+    // the coverage of an execution taking the other branch is aggregated with the first one
+    test.stack_inputs = StackInputs::try_from_ints([5, 3]).unwrap();
+    test.execute_with_coverage(&mut coverage).unwrap();
+    let report = CoverageReport::new(&coverage, test.source_manager.as_ref());
+    let (lines_found, lines_hit) = report.line_totals();
+    assert_eq!(lines_hit, lines_found);
+    assert_eq!(report.branch_totals(), (2, 2));
+    assert!(report.to_lcov().contains("BRH:2\n"));
+}
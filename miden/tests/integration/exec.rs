@@ -1,7 +1,18 @@
-use assembly::Assembler;
-use miden_vm::DefaultHost;
-use processor::{ExecutionOptions, MastForest};
+use assembly::{Assembler, OptimizationLevel};
+use miden_vm::{
+    DefaultHost, ExecutionProof, HashFunction, ProofBundle, ProofMetadata, ProvingOptions,
+};
+use processor::{
+    AdviceInputs, AdviceProvider, AdviceSource, ExecutionOptions, HostTranscript, MastForest,
+    MemAdviceProvider, ProcessState, RecordingHost, ReplayHost,
+};
 use prover::{Digest, StackInputs};
+use stdlib::StdLibrary;
+use test_utils::{
+    Felt,
+    crypto::{MerkleTree, init_merkle_store},
+    serde::{Deserializable, Serializable},
+};
 use vm_core::{ONE, Program, assert_matches};
 
 #[test]
@@ -51,3 +62,250 @@ fn advice_map_loaded_before_execution() {
     )
     .unwrap();
 }
+
+#[test]
+fn optimized_program_produces_same_outputs() {
+    let source = "\
+    proc.double
+        dup add
+    end
+
+    proc.large
+        repeat.600
+            push.1 add swap swap
+        end
+    end
+
+    begin
+        push.3 exec.double swap swap
+        exec.large
+        push.1
+        while.true
+            exec.double dup.0 drop dup push.1000 lt
+        end
+        push.0 add swap drop
+    end";
+
+    let mut outputs = Vec::new();
+    for level in [OptimizationLevel::O0, OptimizationLevel::O1] {
+        let program = Assembler::default()
+            .with_optimization_level(level)
+            .assemble_program(source)
+            .unwrap();
+        let mut host = DefaultHost::default();
+        let trace = processor::execute(
+            &program,
+            StackInputs::default(),
+            &mut host,
+            ExecutionOptions::default(),
+        )
+        .unwrap();
+        outputs.push(trace.stack_outputs().clone());
+    }
+
+    assert_eq!(outputs[0], outputs[1]);
+}
+
+#[test]
+fn recorded_host_transcript_replays_execution() {
+    const EVENT_PUSH_TEN: u32 = 7;
+
+    let source = "\
+    use.std::math::u64
+
+    begin
+        mtree_get swapw dropw
+        emit.7 adv_push.1
+        adv_push.1 add
+        push.0 push.3 push.0 exec.u64::wrapping_add drop
+        movup.5 drop
+    end";
+
+    let stdlib = StdLibrary::default();
+    let program = Assembler::default()
+        .with_library(&stdlib)
+        .unwrap()
+        .assemble_program(source)
+        .unwrap();
+
+    let (leaves, store) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let root = MerkleTree::new(leaves).unwrap().root();
+    let mut stack_inputs: Vec<u64> = root.iter().map(|felt| felt.as_int()).collect();
+    stack_inputs.extend([3, 3]);
+    let stack_inputs = StackInputs::try_from_ints(stack_inputs).unwrap();
+
+    // execute the program with a recording host
+    let advice_inputs =
+        AdviceInputs::default().with_stack_values([5]).unwrap().with_merkle_store(store);
+    let mut host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+    host.load_mast_forest(stdlib.as_ref().mast_forest().clone()).unwrap();
+    host.register_handler(EVENT_PUSH_TEN, |_: ProcessState, advice: &mut MemAdviceProvider| {
+        advice.push_stack(AdviceSource::Value(Felt::new(10)))
+    })
+    .unwrap();
+    let mut host = RecordingHost::new(host);
+    let trace =
+        processor::execute(&program, stack_inputs.clone(), &mut host, ExecutionOptions::default())
+            .unwrap();
+    let expected = trace.stack_outputs();
+    assert_eq!(expected.get_stack_item(0), Some(Felt::new(18)));
+
+    // serialize the transcript, and replay the execution using only the transcript
+    let (_, transcript) = host.into_parts();
+    let transcript = HostTranscript::read_from_bytes(&transcript.to_bytes()).unwrap();
+    let mut host = ReplayHost::new(transcript.clone());
+    let trace =
+        processor::execute(&program, stack_inputs.clone(), &mut host, ExecutionOptions::default())
+            .unwrap();
+    assert_eq!(trace.stack_outputs(), expected);
+    assert_eq!(host.num_remaining_interactions(), 0);
+    host.finish().unwrap();
+
+    // prove the execution using only the transcript
+    let mut host = ReplayHost::new(transcript.clone());
+    let (stack_outputs, proof) =
+        miden_vm::prove(&program, stack_inputs.clone(), &mut host, ProvingOptions::default())
+            .unwrap();
+    assert_eq!(&stack_outputs, expected);
+    miden_vm::verify(program.clone().into(), stack_inputs, stack_outputs, proof).unwrap();
+
+    // replaying the transcript for a different execution fails
+    let other_inputs = StackInputs::try_from_ints(
+        root.iter().map(|felt| felt.as_int()).chain([2, 3]).collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let mut host = ReplayHost::new(transcript);
+    let result = processor::execute(&program, other_inputs, &mut host, ExecutionOptions::default());
+    assert_matches!(
+        result.err(),
+        Some(prover::ExecutionError::TranscriptMismatch { index: 0, .. })
+    );
+}
+
+#[test]
+fn replayed_advice_map_lookup_mismatch_is_reported() {
+    // the advice map of the program is looked up in the advice provider before execution
+    let with_advice_map = |source: &str, key: u64| {
+        let program = Assembler::default().assemble_program(source).unwrap();
+        let mut mast_forest = program.mast_forest().as_ref().clone();
+        mast_forest
+            .advice_map_mut()
+            .insert(Digest::new([Felt::new(key), ONE, ONE, ONE]), vec![Felt::new(key)]);
+        Program::new(mast_forest.into(), program.entrypoint())
+    };
+
+    let advice_inputs = AdviceInputs::default().with_stack_values([5]).unwrap();
+    let mut host = RecordingHost::new(DefaultHost::new(MemAdviceProvider::from(advice_inputs)));
+    let program = with_advice_map("begin adv_push.1 drop end", 1);
+    processor::execute(&program, StackInputs::default(), &mut host, ExecutionOptions::default())
+        .unwrap();
+    let (_, transcript) = host.into_parts();
+
+    // a lookup of a different key is reported by the next request of the VM
+    let mut host = ReplayHost::new(transcript.clone());
+    let program = with_advice_map("begin adv_push.1 drop end", 2);
+    let result = processor::execute(
+        &program,
+        StackInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+    );
+    assert_matches!(
+        result.err(),
+        Some(prover::ExecutionError::TranscriptMismatch { index: 0, request: "get_mapped_values" })
+    );
+
+    // or when finishing the replay, if the VM makes no further requests
+    let mut host = ReplayHost::new(transcript);
+    let program = with_advice_map("begin push.1 drop end", 2);
+    processor::execute(&program, StackInputs::default(), &mut host, ExecutionOptions::default())
+        .unwrap();
+    assert_matches!(
+        host.finish(),
+        Err(prover::ExecutionError::TranscriptMismatch { index: 0, request: "get_mapped_values" })
+    );
+}
+
+#[test]
+fn execution_proof_encoding() {
+    let program = Assembler::default().assemble_program("begin push.5 mul add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([3, 4]).unwrap();
+    let (stack_outputs, proof) = miden_vm::prove(
+        &program,
+        stack_inputs.clone(),
+        &mut DefaultHost::default(),
+        ProvingOptions::default(),
+    )
+    .unwrap();
+
+    // a proof is encoded as the hash function byte followed by the STARK proof
+    let mut bytes = vec![proof.hash_fn() as u8];
+    bytes.extend_from_slice(&proof.stark_proof().to_bytes());
+    assert_eq!(proof.to_bytes(), bytes);
+
+    let decoded = ExecutionProof::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, proof);
+    miden_vm::verify(program.into(), stack_inputs, stack_outputs, decoded).unwrap();
+}
+
+#[test]
+fn proof_bundle_round_trip() {
+    let program = Assembler::default().assemble_program("begin push.5 mul add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([3, 4]).unwrap();
+    let options = ProvingOptions::default();
+    let (stack_outputs, proof) = miden_vm::prove(
+        &program,
+        stack_inputs.clone(),
+        &mut DefaultHost::default(),
+        options.clone(),
+    )
+    .unwrap();
+
+    let metadata = ProofMetadata {
+        prover_version: Some("0.13.0".into()),
+        execution_options: Some(*options.execution_options()),
+        proving_time_ms: Some(42),
+    };
+    let bundle = ProofBundle::new(program.clone().into(), stack_inputs, stack_outputs, proof)
+        .with_metadata(metadata.clone());
+
+    let bytes = bundle.to_bytes();
+    assert!(ProofBundle::is_bundle(&bytes));
+    let bundle = ProofBundle::read_from_bytes(&bytes).unwrap();
+    assert_eq!(bundle.program_info().program_hash(), &program.hash());
+    assert_eq!(bundle.metadata(), &metadata);
+
+    let (program_info, stack_inputs, stack_outputs, proof) = bundle.into_parts();
+    assert!(!ProofBundle::is_bundle(&proof.to_bytes()));
+    miden_vm::verify(program_info, stack_inputs, stack_outputs, proof).unwrap();
+
+    // bundles with an unsupported version are rejected
+    let mut bytes = bytes;
+    bytes[5] = 1;
+    assert!(ProofBundle::read_from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn poseidon2_proof_round_trip() {
+    let program = Assembler::default().assemble_program("begin push.5 mul add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([3, 4]).unwrap();
+    let (stack_outputs, proof) = miden_vm::prove(
+        &program,
+        stack_inputs.clone(),
+        &mut DefaultHost::default(),
+        ProvingOptions::with_96_bit_security_poseidon2(),
+    )
+    .unwrap();
+    assert_eq!(proof.hash_fn(), HashFunction::Poseidon2);
+
+    // the hash function survives both serialization formats of the proof
+    let bytes = proof.to_bytes();
+    assert_eq!(bytes[0], HashFunction::Poseidon2 as u8);
+    let proof = ExecutionProof::from_bytes(&bytes).unwrap();
+    let proof = ExecutionProof::read_from_bytes(&Serializable::to_bytes(&proof)).unwrap();
+    assert_eq!(proof.hash_fn(), HashFunction::Poseidon2);
+
+    let security_level =
+        miden_vm::verify(program.into(), stack_inputs, stack_outputs, proof).unwrap();
+    assert_eq!(security_level, 96);
+}
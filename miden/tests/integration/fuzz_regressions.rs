@@ -0,0 +1,126 @@
+//! Replays the inputs in `fuzz/regressions/<target>`, which reproduce crashes found by the fuzz
+//! targets in `fuzz/fuzz_targets/<target>.rs`, against the same checks as the fuzz targets.
+
+use std::{fs, path::PathBuf, sync::Arc};
+
+use assembly::{
+    Assembler, DefaultSourceManager, LibraryPath, SourceManager,
+    ast::{Module, ModuleKind},
+};
+use miden_vm::{
+    DefaultHost, ExecutionProof, ProgramInfo, ProvingOptions, StackInputs, prove, verify,
+};
+use package::Package;
+use processor::ExecutionOptions;
+use stdlib::StdLibrary;
+use vm_core::{
+    mast::MastForest,
+    utils::{Deserializable, Serializable},
+};
+
+// FUZZ REGRESSIONS
+// ================================================================================================
+
+#[test]
+fn parse_masm_regressions() {
+    for (_, data) in regressions("parse_masm") {
+        let Ok(source) = String::from_utf8(data) else {
+            continue;
+        };
+        let source_manager = DefaultSourceManager::default();
+        let source_file = source_manager.load("fuzz.masm", source);
+        let path = LibraryPath::new("fuzz::module").unwrap();
+        for kind in [ModuleKind::Executable, ModuleKind::Library, ModuleKind::Kernel] {
+            let _ = Module::parse(path.clone(), kind, source_file.clone());
+        }
+    }
+}
+
+#[test]
+fn mast_forest_regressions() {
+    for (_, data) in regressions("mast_forest") {
+        if let Ok(forest) = MastForest::read_from_bytes(&data) {
+            let bytes = forest.to_bytes();
+            assert_eq!(MastForest::read_from_bytes(&bytes).unwrap().to_bytes(), bytes);
+        }
+    }
+}
+
+#[test]
+fn package_regressions() {
+    for (_, data) in regressions("package") {
+        if let Ok(package) = Package::read_from_bytes(&data) {
+            let bytes = package.to_bytes();
+            assert_eq!(Package::read_from_bytes(&bytes).unwrap().to_bytes(), bytes);
+        }
+    }
+}
+
+#[test]
+fn execution_proof_regressions() {
+    for (_, data) in regressions("execution_proof") {
+        if let Ok(proof) = ExecutionProof::from_bytes(&data) {
+            let bytes = proof.to_bytes();
+            assert_eq!(ExecutionProof::from_bytes(&bytes).unwrap().to_bytes(), bytes);
+        }
+    }
+}
+
+#[test]
+fn execute_masm_regressions() {
+    for (path, data) in regressions("execute_masm") {
+        let source = String::from_utf8(data).unwrap();
+        let stack_inputs = source
+            .lines()
+            .next()
+            .and_then(|line| line.strip_prefix("# stack inputs: ["))
+            .and_then(|line| line.strip_suffix(']'))
+            .unwrap_or_else(|| panic!("{} has no stack inputs comment", path.display()))
+            .split(',')
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| value.trim().parse::<u64>().unwrap());
+        let stack_inputs = StackInputs::try_from_ints(stack_inputs).unwrap();
+
+        let program = Assembler::new(Arc::new(DefaultSourceManager::default()))
+            .with_library(StdLibrary::default())
+            .unwrap()
+            .assemble_program(source.as_str())
+            .unwrap();
+        let execution_options = ExecutionOptions::new(Some(1 << 12), 64, false, false).unwrap();
+        let mut host = DefaultHost::default();
+        host.load_mast_forest(StdLibrary::default().mast_forest().clone()).unwrap();
+        if miden_vm::execute(&program, stack_inputs.clone(), &mut host, execution_options).is_err()
+        {
+            continue;
+        }
+
+        let proving_options =
+            ProvingOptions::with_96_bit_security(false).with_execution_options(execution_options);
+        let mut host = DefaultHost::default();
+        host.load_mast_forest(StdLibrary::default().mast_forest().clone()).unwrap();
+        let (stack_outputs, proof) =
+            prove(&program, stack_inputs.clone(), &mut host, proving_options).unwrap();
+        verify(ProgramInfo::from(program), stack_inputs, stack_outputs, proof)
+            .unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the paths and contents of the regression inputs of the specified fuzz target.
+fn regressions(target: &str) -> Vec<(PathBuf, Vec<u8>)> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../fuzz/regressions")
+        .join(target);
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+    entries
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let data = fs::read(&path).unwrap();
+            (path, data)
+        })
+        .collect()
+}
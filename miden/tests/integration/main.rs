@@ -2,12 +2,16 @@ extern crate alloc;
 
 use test_utils::{build_op_test, build_test};
 
+mod aggregation;
 mod air;
 mod cli;
+mod coverage;
 mod exec;
 mod exec_iters;
 mod flow_control;
+mod fuzz_regressions;
 mod operations;
+mod verifier_policy;
 
 // TESTS
 // ================================================================================================
@@ -1,5 +1,8 @@
 use assembly::Assembler;
-use processor::{ExecutionOptions, Program};
+use processor::{
+    AdviceProvider, AdviceSource, DefaultHost, ExecutionOptions, MemAdviceProvider, ProcessState,
+    Program, event_id_from_name,
+};
 use prover::StackInputs;
 
 use super::TestHost;
@@ -26,6 +29,50 @@ fn test_event_handling() {
     assert_eq!(host.event_handler, expected);
 }
 
+#[test]
+fn test_registered_event_handlers() {
+    const EVENT_DOUBLE: u32 = 7;
+
+    let mut host = DefaultHost::new(MemAdviceProvider::default());
+    host.register_handler(EVENT_DOUBLE, |process: ProcessState, advice: &mut MemAdviceProvider| {
+        let value = process.get_stack_item(0);
+        advice.push_stack(AdviceSource::Value(value + value))
+    })
+    .unwrap();
+    let event_id = host
+        .register_named_handler(
+            "miden::test::triple",
+            |process: ProcessState, advice: &mut MemAdviceProvider| {
+                let value = process.get_stack_item(0);
+                advice.push_stack(AdviceSource::Value(value + value + value))
+            },
+        )
+        .unwrap();
+    assert_eq!(event_id, event_id_from_name("miden::test::triple"));
+
+    let source = format!(
+        "\
+    begin
+        push.5
+        emit.{EVENT_DOUBLE} adv_push.1
+        swap
+        emit.{event_id} adv_push.1
+        swap drop
+        swapw dropw
+    end"
+    );
+
+    let program: Program = Assembler::default().assemble_program(source).unwrap();
+    let trace = processor::execute(
+        &program,
+        StackInputs::default(),
+        &mut host,
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(&trace.stack_outputs().as_int_vec()[..2], &[15, 10]);
+}
+
 #[test]
 fn test_trace_handling() {
     let source = "\
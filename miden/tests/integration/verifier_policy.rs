@@ -0,0 +1,72 @@
+use assembly::Assembler;
+use miden_vm::{
+    DefaultHost, FieldExtension, HashFunction, ProvingOptions, StackInputs, VerificationError,
+    VerifierPolicy,
+};
+use processor::ProgramInfo;
+use verifier::VerifierError;
+use vm_core::assert_matches;
+
+#[test]
+fn verifier_policy_accepts_custom_options() {
+    let options =
+        ProvingOptions::new(40, 8, 16, FieldExtension::Quadratic, 8, 255, HashFunction::Blake3_256);
+    let program = Assembler::default().assemble_program("begin push.5 mul add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([3, 4]).unwrap();
+    let (stack_outputs, proof) = miden_vm::prove(
+        &program,
+        stack_inputs.clone(),
+        &mut DefaultHost::default(),
+        options.clone(),
+    )
+    .unwrap();
+    let verify = |policy: VerifierPolicy| {
+        miden_vm::verify_with_policy(
+            ProgramInfo::from(program.clone()),
+            stack_inputs.clone(),
+            stack_outputs.clone(),
+            proof.clone(),
+            &policy,
+        )
+    };
+
+    // the default policy accepts only the standard options
+    assert_matches!(
+        verify(VerifierPolicy::default()),
+        Err(VerificationError::ProgramVerificationError(
+            _,
+            VerifierError::UnacceptableProofOptions
+        ))
+    );
+
+    assert_eq!(verify(VerifierPolicy::min_conjectured_security(100)).unwrap(), 127);
+    assert_matches!(
+        verify(VerifierPolicy::min_conjectured_security(128)),
+        Err(VerificationError::ProgramVerificationError(
+            _,
+            VerifierError::InsufficientConjecturedSecurity(128, 127)
+        ))
+    );
+    assert!(verify(VerifierPolicy::min_proven_security(70)).is_ok());
+    assert_matches!(
+        verify(VerifierPolicy::min_proven_security(80)),
+        Err(VerificationError::ProgramVerificationError(
+            _,
+            VerifierError::InsufficientProvenSecurity(80, _)
+        ))
+    );
+    assert!(verify(VerifierPolicy::option_set(vec![options.clone()])).is_ok());
+
+    // options with a different hash function do not match the proof
+    let rpo_options =
+        ProvingOptions::new(40, 8, 16, FieldExtension::Quadratic, 8, 255, HashFunction::Rpo256);
+    assert!(verify(VerifierPolicy::option_set(vec![rpo_options])).is_err());
+
+    // the hash function of the proof must be allowed by the policy
+    let policy = VerifierPolicy::min_conjectured_security(100)
+        .with_hash_functions([HashFunction::Rpo256, HashFunction::Rpx256]);
+    assert_matches!(
+        verify(policy),
+        Err(VerificationError::HashFunctionNotAllowed(HashFunction::Blake3_256))
+    );
+}
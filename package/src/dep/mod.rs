@@ -1,6 +1,8 @@
 use alloc::string::String;
 
-use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use vm_core::utils::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, read_string,
+};
 
 use crate::Digest;
 
@@ -19,7 +21,7 @@ impl Serializable for DependencyName {
 
 impl Deserializable for DependencyName {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let name = String::read_from(source)?;
+        let name = read_string(source)?;
         Ok(Self(name))
     }
 }
@@ -46,7 +48,7 @@ impl Serializable for Dependency {
 
 impl Deserializable for Dependency {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let name = DependencyName(String::read_from(source)?);
+        let name = DependencyName(read_string(source)?);
         let digest = Digest::read_from(source)?;
         Ok(Self { name, digest })
     }
@@ -34,12 +34,14 @@
 //!       - `name` (`String`)
 //!       - `digest` (`Digest`)
 
-use alloc::{collections::BTreeSet, format, string::String, sync::Arc, vec::Vec};
+use alloc::{collections::BTreeSet, format, sync::Arc, vec::Vec};
 
 use assembly::{Library, ast::QualifiedProcedureName};
 use vm_core::{
     Program,
-    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    utils::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, read_string,
+    },
 };
 
 use super::{Dependency, MastArtifact, Package, PackageExport, PackageManifest};
@@ -103,7 +105,7 @@ impl Deserializable for Package {
         }
 
         // Read package name
-        let name = String::read_from(source)?;
+        let name = read_string(source)?;
 
         // Read MAST artifact
         let mast = MastArtifact::read_from(source)?;
@@ -180,7 +182,7 @@ impl Deserializable for PackageManifest {
 
         // Read dependencies
         let deps_len = source.read_usize()?;
-        let mut dependencies = Vec::with_capacity(deps_len);
+        let mut dependencies = Vec::new();
         for _ in 0..deps_len {
             dependencies.push(Dependency::read_from(source)?);
         }
@@ -0,0 +1,692 @@
+use alloc::boxed::Box;
+use core::{future::Future, pin::Pin};
+
+use vm_core::{
+    DecoratorIterator, Operation, Program, StackInputs, StackOutputs,
+    mast::{
+        BasicBlockNode, CallNode, DynNode, JoinNode, LoopNode, MastForest, MastNode, MastNodeId,
+        OpBatch, SplitNode,
+    },
+};
+
+use crate::{
+    AsyncHost, BasicBlockPosition, ExecutionError, ExecutionOptions, ExecutionStatus,
+    ExecutionTrace, Interrupt, Process, ResumeFrame, host::SyncHostAdapter, load_advice_map,
+};
+
+/// A boxed future, needed to execute MAST nodes recursively.
+type BoxedFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+// EXECUTORS
+// ================================================================================================
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, using an [AsyncHost].
+///
+/// This is the asynchronous counterpart of [crate::execute]: execution is suspended whenever the
+/// host resolves a MAST forest, or handles an event, and the resulting trace is identical to the
+/// one produced by [crate::execute] with an equivalent synchronous host.
+#[tracing::instrument("execute_program_async", skip_all)]
+pub async fn execute_async(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: &mut impl AsyncHost,
+    options: ExecutionOptions,
+) -> Result<ExecutionTrace, ExecutionError> {
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, options);
+    let stack_outputs = process.execute_async(program, host).await?;
+    let trace = ExecutionTrace::new(process, stack_outputs);
+    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
+    Ok(trace)
+}
+
+// PROCESS
+// ================================================================================================
+
+/// The node executors below share all of their execution steps with the synchronous ones in the
+/// crate root, and only differ from them in awaiting the host to get MAST forests and to handle
+/// events.
+impl Process {
+    // PROGRAM EXECUTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the provided [`Program`] in this process, using an [AsyncHost].
+    pub async fn execute_async(
+        &mut self,
+        program: &Program,
+        host: &mut impl AsyncHost,
+    ) -> Result<StackOutputs, ExecutionError> {
+        if self.system.clk() != 0 {
+            return Err(ExecutionError::ProgramAlreadyExecuted);
+        }
+
+        load_advice_map(program.mast_forest(), host.advice_provider_mut())?;
+        if let Some(coverage) = &mut self.coverage {
+            coverage.add_forest(program.mast_forest());
+        }
+
+        self.execute_mast_node_async(program.entrypoint(), program.mast_forest(), host)
+            .await
+            .map_err(Interrupt::into_error)?;
+
+        self.stack.build_stack_outputs()
+    }
+
+    /// Executes the provided [`Program`] in this process using an [AsyncHost], pausing the
+    /// execution once `cycle_budget` cycles have been executed, or when the host requests it via
+    /// [AsyncHost::should_yield].
+    ///
+    /// This is the asynchronous counterpart of [Process::execute_pausable].
+    pub async fn execute_pausable_async(
+        &mut self,
+        program: &Program,
+        host: &mut impl AsyncHost,
+        cycle_budget: Option<u32>,
+    ) -> Result<ExecutionStatus, ExecutionError> {
+        if self.system.clk() != 0 {
+            return Err(ExecutionError::ProgramAlreadyExecuted);
+        }
+
+        load_advice_map(program.mast_forest(), host.advice_provider_mut())?;
+        if let Some(coverage) = &mut self.coverage {
+            coverage.add_forest(program.mast_forest());
+        }
+
+        self.run_pausable_async(program, host, cycle_budget).await
+    }
+
+    /// Resumes the paused execution of the provided [`Program`] in this process using an
+    /// [AsyncHost].
+    ///
+    /// This is the asynchronous counterpart of [Process::resume].
+    pub async fn resume_async(
+        &mut self,
+        program: &Program,
+        host: &mut impl AsyncHost,
+        cycle_budget: Option<u32>,
+    ) -> Result<ExecutionStatus, ExecutionError> {
+        self.check_paused_program(program)?;
+        self.run_pausable_async(program, host, cycle_budget).await
+    }
+
+    async fn run_pausable_async(
+        &mut self,
+        program: &Program,
+        host: &mut impl AsyncHost,
+        cycle_budget: Option<u32>,
+    ) -> Result<ExecutionStatus, ExecutionError> {
+        self.start_pausable_run(cycle_budget);
+        let result = self
+            .execute_mast_node_async(program.entrypoint(), program.mast_forest(), host)
+            .await;
+        self.end_pausable_run(program, result)
+    }
+
+    // NODE EXECUTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the specified node, see [Process::execute_mast_node] for details.
+    fn execute_mast_node_async<'a, H: AsyncHost>(
+        &'a mut self,
+        node_id: MastNodeId,
+        program: &'a MastForest,
+        host: &'a mut H,
+    ) -> BoxedFuture<'a, Result<(), Interrupt>> {
+        Box::pin(async move {
+            let node = program
+                .get_node_by_id(node_id)
+                .ok_or(ExecutionError::MastNodeNotFoundInForest { node_id })?;
+
+            let resume_frame = self.enter_mast_node(node, program, &mut SyncHostAdapter(host))?;
+
+            match node {
+                MastNode::Block(node) => {
+                    self.execute_basic_block_node_async(node, program, host, resume_frame).await?
+                },
+                MastNode::Join(node) => {
+                    self.execute_join_node_async(node, program, host, resume_frame).await?
+                },
+                MastNode::Split(node) => {
+                    self.execute_split_node_async(node, program, host, resume_frame).await?
+                },
+                MastNode::Loop(node) => {
+                    self.execute_loop_node_async(node, program, host, resume_frame).await?
+                },
+                MastNode::Call(node) => {
+                    self.execute_call_node_async(node, program, host, resume_frame).await?
+                },
+                MastNode::Dyn(node) => {
+                    self.execute_dyn_node_async(node, program, host, resume_frame).await?
+                },
+                MastNode::External(external_node) => {
+                    if !matches!(resume_frame, None | Some(ResumeFrame::External)) {
+                        return Err(ExecutionError::InvalidPausedState.into());
+                    }
+
+                    let mast_forest = host.get_mast_forest(&external_node.digest()).await;
+                    let (mast_forest, root_id) =
+                        self.resolve_external_node(external_node, mast_forest)?;
+                    self.execute_mast_node_async(root_id, &mast_forest, host)
+                        .await
+                        .map_err(|err| self.record_frame(err, ResumeFrame::External))?;
+                },
+            }
+
+            Ok(self.exit_mast_node(node, program, &mut SyncHostAdapter(host))?)
+        })
+    }
+
+    /// Executes the specified [JoinNode].
+    async fn execute_join_node_async(
+        &mut self,
+        node: &JoinNode,
+        program: &MastForest,
+        host: &mut impl AsyncHost,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
+        let first_child_executed =
+            self.enter_join_node(node, program, &mut SyncHostAdapter(host), resume_frame)?;
+
+        // execute first and then second child of the join block
+        if !first_child_executed {
+            self.execute_mast_node_async(node.first(), program, host).await.map_err(|err| {
+                self.record_frame(err, ResumeFrame::Join { first_child_executed: false })
+            })?;
+        }
+        self.execute_mast_node_async(node.second(), program, host)
+            .await
+            .map_err(|err| {
+                self.record_frame(err, ResumeFrame::Join { first_child_executed: true })
+            })?;
+
+        Ok(self.end_join_node(node, &mut SyncHostAdapter(host))?)
+    }
+
+    /// Executes the specified [SplitNode].
+    async fn execute_split_node_async(
+        &mut self,
+        node: &SplitNode,
+        program: &MastForest,
+        host: &mut impl AsyncHost,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
+        // execute either the true or the false branch of the split block based on the condition
+        if self.enter_split_node(node, program, &mut SyncHostAdapter(host), resume_frame)? {
+            self.execute_mast_node_async(node.on_true(), program, host)
+                .await
+                .map_err(|err| self.record_frame(err, ResumeFrame::Split { on_true: true }))?;
+        } else {
+            self.execute_mast_node_async(node.on_false(), program, host)
+                .await
+                .map_err(|err| self.record_frame(err, ResumeFrame::Split { on_true: false }))?;
+        }
+
+        Ok(self.end_split_node(node, &mut SyncHostAdapter(host))?)
+    }
+
+    /// Executes the specified [LoopNode].
+    async fn execute_loop_node_async(
+        &mut self,
+        node: &LoopNode,
+        program: &MastForest,
+        host: &mut impl AsyncHost,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
+        if !self.enter_loop_node(node, program, &mut SyncHostAdapter(host), resume_frame)? {
+            return Ok(());
+        }
+
+        // execute the loop body at least once (or finish the iteration which was paused), and
+        // keep executing it until the condition on the top of the stack is no longer ONE
+        loop {
+            self.execute_mast_node_async(node.body(), program, host)
+                .await
+                .map_err(|err| self.record_frame(err, ResumeFrame::Loop))?;
+            if !self.repeat_loop_body(&mut SyncHostAdapter(host))? {
+                break;
+            }
+        }
+
+        Ok(self.exit_loop_node(node, &mut SyncHostAdapter(host))?)
+    }
+
+    /// Executes the specified [CallNode].
+    async fn execute_call_node_async(
+        &mut self,
+        call_node: &CallNode,
+        program: &MastForest,
+        host: &mut impl AsyncHost,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
+        self.enter_call_node(call_node, program, &mut SyncHostAdapter(host), resume_frame)?;
+        self.execute_mast_node_async(call_node.callee(), program, host)
+            .await
+            .map_err(|err| self.record_frame(err, ResumeFrame::Call))?;
+        Ok(self.end_call_node(call_node, &mut SyncHostAdapter(host))?)
+    }
+
+    /// Executes the specified [DynNode].
+    async fn execute_dyn_node_async(
+        &mut self,
+        node: &DynNode,
+        program: &MastForest,
+        host: &mut impl AsyncHost,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
+        let callee_hash = self.enter_dyn_node(node, &mut SyncHostAdapter(host), resume_frame)?;
+
+        // if the callee is not in the program's MAST forest, try to find a MAST forest for it in
+        // the host (corresponding to an external library loaded in the host); if none are
+        // found, return an error.
+        let result = match program.find_procedure_root(callee_hash.into()) {
+            Some(callee_id) => self.execute_mast_node_async(callee_id, program, host).await,
+            None => {
+                let mast_forest = host.get_mast_forest(&callee_hash.into()).await;
+                let (mast_forest, root_id) = self.resolve_dyn_callee(callee_hash, mast_forest)?;
+                self.execute_mast_node_async(root_id, &mast_forest, host).await
+            },
+        };
+        result.map_err(|err| self.record_frame(err, ResumeFrame::Dyn { callee_hash }))?;
+
+        Ok(self.exit_dyn_node(node, &mut SyncHostAdapter(host))?)
+    }
+
+    /// Executes the specified [BasicBlockNode].
+    async fn execute_basic_block_node_async(
+        &mut self,
+        basic_block: &BasicBlockNode,
+        program: &MastForest,
+        host: &mut impl AsyncHost,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
+        let (position, mut op_offset, mut decorator_ids) =
+            self.enter_basic_block_node(basic_block, &mut SyncHostAdapter(host), resume_frame)?;
+
+        // execute the operation batches, starting from the one in which the execution was paused
+        for (batch_idx, op_batch) in
+            basic_block.op_batches().iter().enumerate().skip(position.batch_idx)
+        {
+            let batch_position =
+                self.enter_op_batch(op_batch, batch_idx, position, &mut SyncHostAdapter(host))?;
+            self.execute_op_batch_async(
+                op_batch,
+                batch_position,
+                &mut decorator_ids,
+                op_offset,
+                program,
+                host,
+            )
+            .await?;
+            op_offset += op_batch.ops().len();
+        }
+
+        Ok(self.exit_basic_block_node(
+            basic_block,
+            decorator_ids,
+            program,
+            &mut SyncHostAdapter(host),
+        )?)
+    }
+
+    /// Executes the operations in an [OpBatch], see [Process::execute_op_batch] for details.
+    ///
+    /// Events which are not system events are handled by awaiting [AsyncHost::on_event].
+    async fn execute_op_batch_async(
+        &mut self,
+        batch: &OpBatch,
+        mut position: BasicBlockPosition,
+        decorators: &mut DecoratorIterator<'_>,
+        op_offset: usize,
+        program: &MastForest,
+        host: &mut impl AsyncHost,
+    ) -> Result<(), Interrupt> {
+        // execute operations in the batch one by one
+        while let Some(op) = self.decode_next_op(
+            batch,
+            &position,
+            decorators,
+            op_offset,
+            program,
+            &mut SyncHostAdapter(host),
+        )? {
+            match op {
+                Operation::Emit(event_id) => self.execute_emit_async(event_id, host).await?,
+                _ => self.execute_op(op, &mut SyncHostAdapter(host))?,
+            }
+            self.advance_op_batch(batch, op, &mut position, &mut SyncHostAdapter(host))?;
+        }
+
+        Ok(self.end_op_batch(batch, &position, &mut SyncHostAdapter(host))?)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, sync::Arc, vec::Vec};
+    use core::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use assembly::{
+        Assembler, Library,
+        ast::{Module, ModuleKind},
+    };
+    use vm_core::{
+        Felt, Program, StackInputs,
+        crypto::hash::RpoDigest,
+        debuginfo::{DefaultSourceManager, SourceManager},
+        mast::MastForest,
+    };
+    use winter_prover::Trace;
+
+    use super::*;
+    use crate::{
+        AdviceProvider, AdviceSource, CoverageData, DefaultHost, MemAdviceProvider, MemAsyncHost,
+        ProcessState,
+    };
+
+    const EVENT_PUSH_ADVICE: u32 = 7;
+
+    const LIBRARY_SOURCE: &str = "
+    export.triple
+        push.3 mul
+    end";
+
+    const PROGRAM_SOURCE: &str = "
+    use.lib::math
+
+    proc.callee
+        push.7 mem_store.100
+        mem_load.100 add
+    end
+
+    begin
+        emit.7 adv_push.1 swap drop
+        exec.math::triple
+        dup push.30 eq
+        if.true add.1 else mul.2 end
+        push.3 dup neq.0
+        while.true
+            sub.1 dup neq.0
+        end
+        drop
+        call.callee
+        procref.callee mem_storew.40 dropw push.40 dynexec
+        procref.math::triple mem_storew.44 dropw push.44 dynexec
+    end";
+
+    #[test]
+    fn execute_async_matches_execute() {
+        let (program, library) = build_program();
+
+        let mut sync_host = DefaultHost::default();
+        sync_host.load_mast_forest(library.clone()).unwrap();
+        sync_host.register_handler(EVENT_PUSH_ADVICE, push_advice).unwrap();
+        let expected = crate::execute(
+            &program,
+            StackInputs::default(),
+            &mut sync_host,
+            ExecutionOptions::default(),
+        )
+        .unwrap();
+
+        let mut host = YieldingHost::new(library);
+        let future =
+            execute_async(&program, StackInputs::default(), &mut host, ExecutionOptions::default());
+        assert_send(&future);
+        let trace = block_on(future).unwrap();
+
+        assert_eq!(trace.stack_outputs(), expected.stack_outputs());
+        assert_eq!(trace.stack_outputs().get_stack_item(0), Some(Felt::new(135)));
+        assert_traces_eq(&trace, &expected);
+        assert_eq!(host.events, [EVENT_PUSH_ADVICE]);
+    }
+
+    #[test]
+    fn execute_async_records_same_coverage() {
+        let (program, library) = build_program();
+
+        let mut process = Process::new(
+            program.kernel().clone(),
+            StackInputs::default(),
+            ExecutionOptions::default(),
+        );
+        process.enable_coverage();
+        let mut sync_host = DefaultHost::default();
+        sync_host.load_mast_forest(library.clone()).unwrap();
+        sync_host.register_handler(EVENT_PUSH_ADVICE, push_advice).unwrap();
+        process.execute(&program, &mut sync_host).unwrap();
+        let expected = process.take_coverage().unwrap();
+
+        let mut process = Process::new(
+            program.kernel().clone(),
+            StackInputs::default(),
+            ExecutionOptions::default(),
+        );
+        process.enable_coverage();
+        let mut host = YieldingHost::new(library);
+        block_on(process.execute_async(&program, &mut host)).unwrap();
+        let coverage: CoverageData = process.take_coverage().unwrap();
+
+        assert_eq!(coverage.branches().count(), 2);
+        assert_eq!(coverage, expected);
+    }
+
+    #[test]
+    fn execute_async_fails_like_execute() {
+        let sources = [
+            "begin push.2 if.true push.1 else push.0 end end",
+            "begin push.2 while.true push.1 end end",
+            "begin push.1 while.true push.2 end end",
+            "begin push.1 push.2 assert end",
+            "begin push.1.2.3.4 dynexec end",
+        ];
+        for source in sources {
+            let program = Assembler::default().assemble_program(source).unwrap();
+            let expected = crate::execute(
+                &program,
+                StackInputs::default(),
+                &mut DefaultHost::default(),
+                ExecutionOptions::default(),
+            )
+            .err()
+            .expect("execution should fail");
+
+            let mut host = YieldingHost::default();
+            let err = block_on(execute_async(
+                &program,
+                StackInputs::default(),
+                &mut host,
+                ExecutionOptions::default(),
+            ))
+            .err()
+            .expect("async execution should fail");
+            assert_eq!(format!("{err:?}"), format!("{expected:?}"), "{source}");
+        }
+    }
+
+    #[test]
+    fn execute_async_fails_on_missing_mast_forest() {
+        let (program, library) = build_program();
+        let mut host = YieldingHost::default();
+        host.host.register_handler(EVENT_PUSH_ADVICE, push_advice).unwrap();
+
+        let result = block_on(execute_async(
+            &program,
+            StackInputs::default(),
+            &mut host,
+            ExecutionOptions::default(),
+        ));
+        let library_root = library.procedure_digests().next().unwrap();
+        assert!(matches!(
+            result,
+            Err(ExecutionError::NoMastForestWithProcedure { root_digest }) if root_digest == library_root
+        ));
+    }
+
+    #[test]
+    fn resumed_async_execution_produces_identical_trace() {
+        let (program, library) = build_program();
+
+        let mut sync_host = DefaultHost::default();
+        sync_host.load_mast_forest(library.clone()).unwrap();
+        sync_host.register_handler(EVENT_PUSH_ADVICE, push_advice).unwrap();
+        let expected = crate::execute(
+            &program,
+            StackInputs::default(),
+            &mut sync_host,
+            ExecutionOptions::default(),
+        )
+        .unwrap();
+
+        let mut process = Process::new(
+            program.kernel().clone(),
+            StackInputs::default(),
+            ExecutionOptions::default(),
+        );
+        let mut host = YieldingHost::new(library);
+        let mut status =
+            block_on(process.execute_pausable_async(&program, &mut host, Some(7))).unwrap();
+        let mut num_pauses = 0;
+        while status == ExecutionStatus::Paused {
+            num_pauses += 1;
+            status = block_on(process.resume_async(&program, &mut host, Some(7))).unwrap();
+        }
+        assert!(num_pauses > 10);
+
+        let ExecutionStatus::Completed(stack_outputs) = status else {
+            panic!("execution was not completed")
+        };
+        let trace = ExecutionTrace::new(process, stack_outputs);
+        assert_eq!(trace.stack_outputs(), expected.stack_outputs());
+        assert_traces_eq(&trace, &expected);
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a program which uses every kind of MAST node, assembled in debug mode against a
+    /// library which is not vendored into the program, along with the MAST forest of that
+    /// library.
+    fn build_program() -> (Program, Arc<MastForest>) {
+        let source_manager = Arc::new(DefaultSourceManager::default());
+        let module = Module::parser(ModuleKind::Library)
+            .parse_str("lib::math".parse().unwrap(), LIBRARY_SOURCE, source_manager.as_ref())
+            .unwrap();
+        let library: Library = Assembler::new(source_manager.clone())
+            .with_debug_mode(true)
+            .assemble_library([module])
+            .unwrap();
+
+        let source = source_manager.load("test.masm", PROGRAM_SOURCE.to_string());
+        let program = Assembler::new(source_manager)
+            .with_debug_mode(true)
+            .with_library(&library)
+            .unwrap()
+            .assemble_program(source)
+            .unwrap();
+
+        (program, library.mast_forest().clone())
+    }
+
+    fn push_advice(
+        _process: ProcessState,
+        advice_provider: &mut MemAdviceProvider,
+    ) -> Result<(), ExecutionError> {
+        advice_provider.push_stack(AdviceSource::Value(Felt::new(10)))
+    }
+
+    fn assert_traces_eq(trace: &ExecutionTrace, expected: &ExecutionTrace) {
+        let (main_trace, expected_main_trace) = (trace.main_segment(), expected.main_segment());
+        assert_eq!(main_trace.num_rows(), expected_main_trace.num_rows());
+        for i in 0..expected_main_trace.num_cols() {
+            assert_eq!(main_trace.get_column(i), expected_main_trace.get_column(i), "column {i}");
+        }
+    }
+
+    /// An [AsyncHost] which suspends execution once before resolving each MAST forest and event.
+    #[derive(Default)]
+    struct YieldingHost {
+        host: MemAsyncHost,
+        events: Vec<u32>,
+    }
+
+    impl YieldingHost {
+        /// Returns a host which provides the specified library, and pushes a value onto the advice
+        /// stack on [EVENT_PUSH_ADVICE].
+        fn new(library: Arc<MastForest>) -> Self {
+            let mut host = Self::default();
+            host.host.load_mast_forest(library).unwrap();
+            host.host.register_handler(EVENT_PUSH_ADVICE, push_advice).unwrap();
+            host
+        }
+    }
+
+    impl AsyncHost for YieldingHost {
+        type AdviceProvider = MemAdviceProvider;
+
+        fn advice_provider(&self) -> &Self::AdviceProvider {
+            self.host.advice_provider()
+        }
+
+        fn advice_provider_mut(&mut self) -> &mut Self::AdviceProvider {
+            self.host.advice_provider_mut()
+        }
+
+        fn get_mast_forest(
+            &self,
+            node_digest: &RpoDigest,
+        ) -> impl Future<Output = Option<Arc<MastForest>>> + Send {
+            let mast_forest = self.host.get_mast_forest(node_digest);
+            async move {
+                YieldOnce(false).await;
+                mast_forest.await
+            }
+        }
+
+        async fn on_event(
+            &mut self,
+            process: ProcessState<'_>,
+            event_id: u32,
+        ) -> Result<(), ExecutionError> {
+            YieldOnce(false).await;
+            self.events.push(event_id);
+            self.host.on_event(process, event_id).await
+        }
+    }
+
+    /// A future which is pending the first time it is polled.
+    struct YieldOnce(bool);
+
+    impl Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    /// Polls `future` to completion on the current thread.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    fn assert_send<T: Send>(_: &T) {}
+}
@@ -1,9 +1,10 @@
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 
 use miden_air::trace::main_trace::MainTrace;
 use vm_core::Kernel;
 
 use super::{super::trace::AuxColumnBuilder, Felt, FieldElement};
+use crate::debug::BusDebugger;
 
 mod bus;
 pub use bus::BusColumnBuilder;
@@ -44,4 +45,26 @@ impl AuxTraceBuilder {
         debug_assert_eq!(*b_chip.last().unwrap(), E::ONE);
         vec![t_chip, b_chip]
     }
+
+    /// Builds the chiplets auxiliary trace columns like [Self::build_aux_columns()], but without
+    /// checking that the virtual table and the bus are empty at the end of the execution. Each
+    /// column is returned together with the debugger into which the messages sent on it were
+    /// recorded.
+    pub(crate) fn build_aux_columns_with_debuggers<E: FieldElement<BaseField = Felt>>(
+        &self,
+        main_trace: &MainTrace,
+        rand_elements: &[E],
+    ) -> Vec<(Vec<E>, BusDebugger<E>)> {
+        let mut t_chip_debugger = BusDebugger::new("chiplets virtual table".to_string());
+        let t_chip = ChipletsVTableColBuilder::new(self.kernel.clone())
+            .build_aux_column_with_debugger(main_trace, rand_elements, &mut t_chip_debugger);
+        let mut b_chip_debugger = BusDebugger::new("chiplets bus".to_string());
+        let b_chip = BusColumnBuilder::default().build_aux_column_with_debugger(
+            main_trace,
+            rand_elements,
+            &mut b_chip_debugger,
+        );
+
+        vec![(t_chip, t_chip_debugger), (b_chip, b_chip_debugger)]
+    }
 }
@@ -4,6 +4,7 @@ use miden_air::trace::chiplets::bitwise::{
     A_COL_IDX, A_COL_RANGE, B_COL_IDX, B_COL_RANGE, BITWISE_AND, BITWISE_XOR, OUTPUT_COL_IDX,
     PREV_OUTPUT_COL_IDX, TRACE_WIDTH,
 };
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 use super::{ExecutionError, Felt, TraceFragment, ZERO, utils::get_trace_len};
 
@@ -198,6 +199,18 @@ impl Default for Bitwise {
     }
 }
 
+impl Serializable for Bitwise {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.trace.write_into(target);
+    }
+}
+
+impl Deserializable for Bitwise {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self { trace: source.read()? })
+    }
+}
+
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
 
@@ -1,3 +1,5 @@
+#![allow(clippy::needless_range_loop)]
+
 use alloc::vec::Vec;
 
 use miden_air::trace::chiplets::bitwise::{
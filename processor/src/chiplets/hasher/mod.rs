@@ -4,6 +4,7 @@ use miden_air::trace::chiplets::hasher::{
     DIGEST_LEN, DIGEST_RANGE, Digest, LINEAR_HASH, MP_VERIFY, MR_UPDATE_NEW, MR_UPDATE_OLD,
     RATE_LEN, RETURN_HASH, RETURN_STATE, STATE_WIDTH, Selectors, TRACE_WIDTH,
 };
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 use super::{
     Felt, HasherState, MerklePath, MerkleRootUpdate, ONE, OpBatch, TraceFragment, Word, ZERO,
@@ -364,6 +365,22 @@ impl Hasher {
     }
 }
 
+impl Serializable for Hasher {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.trace.write_into(target);
+        self.memoized_trace_map.write_into(target);
+    }
+}
+
+impl Deserializable for Hasher {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            trace: source.read()?,
+            memoized_trace_map: source.read()?,
+        })
+    }
+}
+
 // MERKLE PATH CONTEXT
 // ================================================================================================
 
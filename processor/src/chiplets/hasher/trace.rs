@@ -2,7 +2,10 @@ use alloc::vec::Vec;
 use core::ops::Range;
 
 use miden_air::trace::chiplets::hasher::NUM_ROUNDS;
-use vm_core::chiplets::hasher::apply_round;
+use vm_core::{
+    chiplets::hasher::apply_round,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 
 use super::{Felt, HasherState, STATE_WIDTH, Selectors, TRACE_WIDTH, TraceFragment, ZERO};
 
@@ -147,3 +150,21 @@ impl HasherTrace {
         }
     }
 }
+
+impl Serializable for HasherTrace {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.selectors.write_into(target);
+        self.hasher_state.write_into(target);
+        self.node_index.write_into(target);
+    }
+}
+
+impl Deserializable for HasherTrace {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            selectors: source.read()?,
+            hasher_state: source.read()?,
+            node_index: source.read()?,
+        })
+    }
+}
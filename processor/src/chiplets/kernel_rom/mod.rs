@@ -1,6 +1,7 @@
 use alloc::collections::BTreeMap;
 
 use miden_air::{RowIndex, trace::chiplets::kernel_rom::TRACE_WIDTH};
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 use super::{Digest, ExecutionError, Felt, Kernel, ONE, TraceFragment, Word, ZERO};
 
@@ -125,6 +126,24 @@ impl KernelRom {
     }
 }
 
+impl Serializable for KernelRom {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.access_map.write_into(target);
+        self.kernel.write_into(target);
+        self.trace_len.write_into(target);
+    }
+}
+
+impl Deserializable for KernelRom {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            access_map: source.read()?,
+            kernel: source.read()?,
+            trace_len: source.read()?,
+        })
+    }
+}
+
 // PROCEDURE ACCESS INFO
 // ================================================================================================
 
@@ -155,3 +174,19 @@ impl ProcAccessInfo {
         trace.set(row, 5, self.proc_hash[3]);
     }
 }
+
+impl Serializable for ProcAccessInfo {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.proc_hash.write_into(target);
+        self.num_accesses.write_into(target);
+    }
+}
+
+impl Deserializable for ProcAccessInfo {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            proc_hash: source.read()?,
+            num_accesses: source.read()?,
+        })
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::needless_range_loop)]
+
 use alloc::vec::Vec;
 
 use super::{Felt, Kernel, KernelRom, ONE, TRACE_WIDTH, TraceFragment, Word, ZERO};
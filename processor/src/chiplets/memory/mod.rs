@@ -9,7 +9,10 @@ use miden_air::{
         MEMORY_WRITE, V_COL_RANGE, WORD_COL_IDX,
     },
 };
-use vm_core::{WORD_SIZE, ZERO};
+use vm_core::{
+    WORD_SIZE, ZERO,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 
 use super::{
     EMPTY_WORD, Felt, FieldElement, ONE, RangeChecker, TraceFragment, Word,
@@ -409,3 +412,19 @@ impl Memory {
         self.trace.iter().fold(0, |acc, (_, s)| acc + s.num_accessed_words())
     }
 }
+
+impl Serializable for Memory {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.trace.write_into(target);
+        self.num_trace_rows.write_into(target);
+    }
+}
+
+impl Deserializable for Memory {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            trace: source.read()?,
+            num_trace_rows: source.read()?,
+        })
+    }
+}
@@ -4,7 +4,10 @@ use alloc::{
 };
 
 use miden_air::RowIndex;
-use vm_core::WORD_SIZE;
+use vm_core::{
+    WORD_SIZE,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 
 use super::{Felt, INIT_MEM_VALUE, Word};
 use crate::{ContextId, ExecutionError};
@@ -336,6 +339,18 @@ impl MemorySegmentTrace {
     }
 }
 
+impl Serializable for MemorySegmentTrace {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+}
+
+impl Deserializable for MemorySegmentTrace {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        source.read().map(Self)
+    }
+}
+
 // MEMORY ACCESS
 // ================================================================================================
 
@@ -390,6 +405,44 @@ impl MemorySegmentAccess {
     }
 }
 
+impl Serializable for MemorySegmentAccess {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.clk.write_into(target);
+        target.write_bool(self.operation == MemoryOperation::Write);
+        match self.access_type {
+            MemoryAccessType::Element { addr_idx_in_word } => {
+                target.write_u8(addr_idx_in_word);
+            },
+            MemoryAccessType::Word => target.write_u8(u8::MAX),
+        }
+        self.word.write_into(target);
+    }
+}
+
+impl Deserializable for MemorySegmentAccess {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let clk = source.read()?;
+        let operation = if source.read_bool()? {
+            MemoryOperation::Write
+        } else {
+            MemoryOperation::Read
+        };
+        let access_type = match source.read_u8()? {
+            u8::MAX => MemoryAccessType::Word,
+            addr_idx_in_word if (addr_idx_in_word as usize) < WORD_SIZE => {
+                MemoryAccessType::Element { addr_idx_in_word }
+            },
+            idx => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid memory address index in word {idx}"
+                )));
+            },
+        };
+        let word = source.read()?;
+        Ok(Self::new(clk, operation, access_type, word))
+    }
+}
+
 // HELPERS
 // ================================================================================================
 
@@ -4,7 +4,11 @@ use miden_air::{
     RowIndex,
     trace::chiplets::hasher::{Digest, HasherState},
 };
-use vm_core::{Kernel, mast::OpBatch};
+use vm_core::{
+    Kernel,
+    mast::OpBatch,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 
 use super::{
     CHIPLETS_WIDTH, ChipletsTrace, EMPTY_WORD, ExecutionError, Felt, FieldElement, ONE,
@@ -274,12 +278,42 @@ impl Chiplets {
             }
         }
 
-        // fill the fragments with the execution trace from each chiplet
-        // TODO: this can be parallelized to fill the traces in multiple threads
-        hasher.fill_trace(&mut hasher_fragment);
-        bitwise.fill_trace(&mut bitwise_fragment);
-        memory.fill_trace(&mut memory_fragment);
-        kernel_rom.fill_trace(&mut kernel_rom_fragment);
+        // fill the fragments with the execution trace from each chiplet; the fragments cover
+        // disjoint parts of the trace, so they can be filled in parallel
+        utils::join(
+            || {
+                utils::join(
+                    || hasher.fill_trace(&mut hasher_fragment),
+                    || bitwise.fill_trace(&mut bitwise_fragment),
+                )
+            },
+            || {
+                utils::join(
+                    || memory.fill_trace(&mut memory_fragment),
+                    || kernel_rom.fill_trace(&mut kernel_rom_fragment),
+                )
+            },
+        );
+    }
+}
+
+impl Serializable for Chiplets {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.hasher.write_into(target);
+        self.bitwise.write_into(target);
+        self.memory.write_into(target);
+        self.kernel_rom.write_into(target);
+    }
+}
+
+impl Deserializable for Chiplets {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            hasher: source.read()?,
+            bitwise: source.read()?,
+            memory: source.read()?,
+            kernel_rom: source.read()?,
+        })
     }
 }
 
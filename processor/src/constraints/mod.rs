@@ -0,0 +1,363 @@
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use miden_air::{
+    AuxRandElements, ProcessorAir, ProvingOptions, PublicInputs, RowIndex,
+    trace::{AUX_TRACE_WIDTH, TRACE_WIDTH, main_trace_column_names, range::B_RANGE_COL_IDX},
+};
+use vm_core::{
+    AssemblyOp, Operation,
+    debuginfo::{FileLineCol, Location, SourceManager},
+    utils::Deserializable,
+};
+use winter_prover::{Air, Assertion, EvaluationFrame, Trace};
+
+use crate::{ExecutionTrace, Felt, FieldElement, QuadFelt, ZERO, debug::BusDebugger};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTRAINT CHECKER
+// ================================================================================================
+
+/// Checks that the provided execution trace satisfies all constraints of [ProcessorAir], and
+/// returns the first constraint which it violates.
+///
+/// The boundary and transition constraints of the main and auxiliary segments of the trace are
+/// evaluated row by row, so that the violation at the lowest row is reported. Then, the buses and
+/// virtual tables described by the auxiliary columns are checked to be empty at the end of the
+/// execution; the messages which were not matched are only reported in tests, or when the
+/// `bus-debugger` feature is enabled.
+///
+/// The operation executed at the row of a violation is always reported for operations without
+/// immediate values. The operations with immediate values and the assembly instructions are only
+/// available when the program was executed in debug mode.
+///
+/// This is a debugging aid: it is much slower than proving the execution, but points to the cause
+/// of a proof which fails to verify.
+pub fn check_constraints(trace: &ExecutionTrace) -> Result<(), ConstraintViolation> {
+    let pub_inputs = PublicInputs::new(
+        trace.program_info().clone(),
+        trace.init_stack_state(),
+        trace.stack_outputs().clone(),
+    );
+    let air = ProcessorAir::new(trace.info().clone(), pub_inputs, ProvingOptions::default().into());
+
+    let rand_elements = trace.draw_aux_rand_elements();
+    let aux_columns = build_aux_columns(trace, &rand_elements);
+    let aux_rand_elements = AuxRandElements::new(rand_elements);
+
+    // group the boundary constraints by the row they apply to
+    let mut assertions = BTreeMap::<usize, Vec<(ConstraintKind, Assertion<QuadFelt>)>>::new();
+    for assertion in air.get_assertions() {
+        let assertion = Assertion::single(
+            assertion.column(),
+            assertion.first_step(),
+            assertion.values()[0].into(),
+        );
+        assertions
+            .entry(assertion.first_step())
+            .or_default()
+            .push((ConstraintKind::Boundary, assertion));
+    }
+    for assertion in air.get_aux_assertions(&aux_rand_elements) {
+        assertions
+            .entry(assertion.first_step())
+            .or_default()
+            .push((ConstraintKind::AuxBoundary, assertion));
+    }
+
+    let column_names = main_trace_column_names();
+    let transition_names = ProcessorAir::transition_constraint_names();
+    let aux_transition_names = ProcessorAir::aux_transition_constraint_names();
+    let periodic_columns = air.get_periodic_column_values();
+
+    let num_rows = trace.length();
+    let num_transition_rows = num_rows - air.context().num_transition_exemptions();
+    let mut main_frame = EvaluationFrame::<Felt>::new(TRACE_WIDTH);
+    let mut aux_frame = EvaluationFrame::<QuadFelt>::new(AUX_TRACE_WIDTH);
+    let mut periodic_values = vec![ZERO; periodic_columns.len()];
+    let mut result = vec![ZERO; air.context().num_main_transition_constraints()];
+    let mut aux_result = vec![QuadFelt::ZERO; air.context().num_aux_transition_constraints()];
+
+    for row in 0..num_rows {
+        // --- boundary constraints ---------------------------------------------------------------
+        for (kind, assertion) in assertions.get(&row).into_iter().flatten() {
+            let column = assertion.column();
+            let (name, value) = match kind {
+                ConstraintKind::Boundary => {
+                    (column_names[column].clone(), trace.main_segment().get(column, row).into())
+                },
+                _ => (aux_columns[column].name.clone(), aux_columns[column].column[row]),
+            };
+            if value != assertion.values()[0] {
+                return Err(ConstraintViolation::new(trace, *kind, name, row));
+            }
+        }
+
+        if row >= num_transition_rows {
+            continue;
+        }
+
+        // --- transition constraints -------------------------------------------------------------
+        trace.read_main_frame(row, &mut main_frame);
+        for (value, column) in periodic_values.iter_mut().zip(periodic_columns.iter()) {
+            *value = column[row % column.len()];
+        }
+        result.fill(ZERO);
+        air.evaluate_transition(&main_frame, &periodic_values, &mut result);
+        if let Some(index) = result.iter().position(|&value| value != ZERO) {
+            let name = transition_names[index].clone();
+            return Err(ConstraintViolation::new(trace, ConstraintKind::Transition, name, row));
+        }
+
+        for (index, aux_column) in aux_columns.iter().enumerate() {
+            aux_frame.current_mut()[index] = aux_column.column[row];
+            aux_frame.next_mut()[index] = aux_column.column[row + 1];
+        }
+        aux_result.fill(QuadFelt::ZERO);
+        air.evaluate_aux_transition(
+            &main_frame,
+            &aux_frame,
+            &periodic_values,
+            &aux_rand_elements,
+            &mut aux_result,
+        );
+        if let Some(index) = aux_result.iter().position(|&value| value != QuadFelt::ZERO) {
+            let name = aux_transition_names[index].clone();
+            return Err(ConstraintViolation::new(trace, ConstraintKind::AuxTransition, name, row));
+        }
+    }
+
+    // --- buses ----------------------------------------------------------------------------------
+    for aux_column in aux_columns {
+        let Some(debugger) = aux_column.debugger else {
+            continue;
+        };
+        if *aux_column.column.last().expect("trace is empty") != QuadFelt::ONE {
+            return Err(ConstraintViolation {
+                kind: ConstraintKind::Bus,
+                name: aux_column.name,
+                row: RowIndex::from(num_rows - 1),
+                op: None,
+                asmop: None,
+                details: (!debugger.is_empty()).then(|| debugger.to_string()),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+// CONSTRAINT VIOLATION
+// ================================================================================================
+
+/// The kind of a constraint of [ProcessorAir].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// A boundary constraint on a column of the main trace.
+    Boundary,
+    /// A transition constraint on the main trace.
+    Transition,
+    /// A boundary constraint on a column of the auxiliary trace.
+    AuxBoundary,
+    /// A transition constraint on the auxiliary trace.
+    AuxTransition,
+    /// A bus or virtual table, which must be empty at the end of the execution.
+    Bus,
+}
+
+impl fmt::Display for ConstraintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boundary => write!(f, "boundary constraint on"),
+            Self::Transition => write!(f, "transition constraint"),
+            Self::AuxBoundary => write!(f, "auxiliary boundary constraint on"),
+            Self::AuxTransition => write!(f, "auxiliary transition constraint"),
+            Self::Bus => write!(f, "bus"),
+        }
+    }
+}
+
+/// A constraint of [ProcessorAir] which is violated by an execution trace, as reported by
+/// [check_constraints()].
+#[derive(Debug, Clone)]
+pub struct ConstraintViolation {
+    kind: ConstraintKind,
+    name: String,
+    row: RowIndex,
+    op: Option<Operation>,
+    asmop: Option<Box<AssemblyOp>>,
+    details: Option<String>,
+}
+
+impl ConstraintViolation {
+    fn new(trace: &ExecutionTrace, kind: ConstraintKind, name: String, row: usize) -> Self {
+        let (op, asmop) = if row <= trace.main_trace().last_program_row().as_usize() {
+            (get_op_at(trace, row), get_asmop_at(trace, row))
+        } else {
+            (None, None)
+        };
+
+        Self {
+            kind,
+            name,
+            row: RowIndex::from(row),
+            op,
+            asmop,
+            details: None,
+        }
+    }
+
+    /// Returns the kind of the violated constraint.
+    pub fn kind(&self) -> ConstraintKind {
+        self.kind
+    }
+
+    /// Returns the name of the violated constraint.
+    ///
+    /// Transition constraints are named after the module of [ProcessorAir] which defines them and
+    /// the check they enforce (e.g. `stack::field_ops::mul`), boundary constraints after the
+    /// column they apply to, and buses after the table they describe.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the row of the trace at which the constraint is violated.
+    ///
+    /// For a transition constraint, this is the first of the two rows the constraint applies to.
+    /// For a bus, this is the last row of the trace.
+    pub fn row(&self) -> RowIndex {
+        self.row
+    }
+
+    /// Returns the operation executed by the VM at the row of the violation, if the row is part of
+    /// the execution of the program and the operation is known.
+    pub fn op(&self) -> Option<&Operation> {
+        self.op.as_ref()
+    }
+
+    /// Returns the assembly instruction executed at the row of the violation, if the program was
+    /// executed in debug mode.
+    pub fn asmop(&self) -> Option<&AssemblyOp> {
+        self.asmop.as_deref()
+    }
+
+    /// Returns the source location of the assembly instruction executed at the row of the
+    /// violation, if known.
+    pub fn location(&self) -> Option<&Location> {
+        self.asmop.as_ref().and_then(|asmop| asmop.location())
+    }
+
+    /// Resolves the source location of the violation to a file, line and column using the
+    /// provided source manager.
+    pub fn file_line_col(&self, source_manager: &dyn SourceManager) -> Option<FileLineCol> {
+        let span = source_manager.location_to_span(self.location()?.clone())?;
+        source_manager.file_line_col(span).ok()
+    }
+
+    /// Returns further details about the violation, e.g. the messages which were not matched on
+    /// a bus.
+    pub fn details(&self) -> Option<&str> {
+        self.details.as_deref()
+    }
+}
+
+impl fmt::Display for ConstraintViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ConstraintKind::Bus => {
+                write!(f, "{} {} is not empty at the end of the trace", self.kind, self.name)?
+            },
+            _ => write!(f, "{} {} is not satisfied at row {}", self.kind, self.name, self.row)?,
+        }
+        if let Some(op) = &self.op {
+            write!(f, " while executing {op}")?;
+        }
+        if let Some(asmop) = &self.asmop {
+            write!(f, " (`{}` in {})", asmop.op(), asmop.context_name())?;
+        }
+        if let Some(details) = &self.details {
+            write!(f, "\n{details}")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for ConstraintViolation {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// A column of the auxiliary trace, together with the debugger which recorded the messages sent on
+/// it if it describes a bus or a virtual table.
+struct AuxColumn<E: FieldElement<BaseField = Felt>> {
+    name: String,
+    column: Vec<E>,
+    debugger: Option<BusDebugger<E>>,
+}
+
+impl<E: FieldElement<BaseField = Felt>> AuxColumn<E> {
+    fn with_debugger((column, debugger): (Vec<E>, BusDebugger<E>)) -> Self {
+        Self {
+            name: debugger.bus_name.clone(),
+            column,
+            debugger: Some(debugger),
+        }
+    }
+}
+
+/// Builds the columns of the auxiliary trace, in the order of the auxiliary trace layout, without
+/// checking that the buses are empty at the end of the execution.
+fn build_aux_columns<E: FieldElement<BaseField = Felt>>(
+    trace: &ExecutionTrace,
+    rand_elements: &[E],
+) -> Vec<AuxColumn<E>> {
+    let main_trace = trace.main_trace();
+    let builders = trace.aux_trace_builders();
+
+    let decoder = builders.decoder.build_aux_columns_with_debuggers(main_trace, rand_elements);
+    let stack = builders.stack.build_aux_columns_with_debuggers(main_trace, rand_elements);
+    let range = builders.range.build_aux_columns(main_trace, rand_elements);
+    let chiplets = builders.chiplets.build_aux_columns_with_debuggers(main_trace, rand_elements);
+
+    let mut columns = Vec::with_capacity(AUX_TRACE_WIDTH);
+    columns.extend(decoder.into_iter().map(AuxColumn::with_debugger));
+    columns.extend(stack.into_iter().map(AuxColumn::with_debugger));
+    columns.extend(range.into_iter().map(|column| AuxColumn {
+        name: "range::b_range".to_string(),
+        column,
+        debugger: None,
+    }));
+    columns.extend(chiplets.into_iter().map(AuxColumn::with_debugger));
+    debug_assert_eq!(columns.len(), AUX_TRACE_WIDTH);
+    debug_assert!(columns[B_RANGE_COL_IDX].debugger.is_none());
+
+    columns
+}
+
+/// Returns the operation executed at the specified row of the trace.
+///
+/// Without debug mode, the operation is decoded from the operation bits of the decoder, which is
+/// only possible for operations without immediate values.
+fn get_op_at(trace: &ExecutionTrace, row: usize) -> Option<Operation> {
+    if let Some(op) = trace.debug_info().operations().get(row) {
+        return Some(*op);
+    }
+    let op_code = trace.main_trace().get_op_code(RowIndex::from(row)).as_int();
+    Operation::read_from_bytes(&[op_code as u8]).ok()
+}
+
+/// Returns the assembly instruction executed at the specified row of the trace, if the program
+/// was executed in debug mode.
+fn get_asmop_at(trace: &ExecutionTrace, row: usize) -> Option<Box<AssemblyOp>> {
+    let assembly_ops = trace.debug_info().assembly_ops();
+    let index = assembly_ops.partition_point(|(clk, _)| *clk <= row).checked_sub(1)?;
+    let (clk, asmop) = &assembly_ops[index];
+    (row < clk + asmop.num_cycles() as usize).then(|| Box::new(asmop.clone()))
+}
@@ -0,0 +1,104 @@
+use alloc::{format, string::ToString, sync::Arc};
+
+use assembly::Assembler;
+use miden_air::trace::{CLK_COL_IDX, STACK_TRACE_OFFSET};
+use vm_core::{Operation, debuginfo::DefaultSourceManager};
+
+use super::{ConstraintKind, check_constraints};
+use crate::{DefaultHost, ExecutionOptions, ExecutionTrace, Felt, StackInputs, execute};
+
+const SOURCE: &str = "\
+proc.add_mul
+    add
+    mul
+end
+
+begin
+    push.3 push.4 push.5
+    exec.add_mul
+    u32split drop drop
+end
+";
+
+#[test]
+fn valid_trace() {
+    let trace = build_trace(ExecutionOptions::default().with_debugging());
+    assert!(check_constraints(&trace).is_ok());
+
+    let trace = build_trace(ExecutionOptions::default());
+    assert!(check_constraints(&trace).is_ok());
+}
+
+#[test]
+fn transition_violation() {
+    let mut trace = build_trace(ExecutionOptions::default().with_debugging());
+    let row = trace
+        .debug_info()
+        .operations()
+        .iter()
+        .position(|op| matches!(op, Operation::Add))
+        .unwrap();
+
+    // corrupt the result of the `add` instruction
+    let result = trace.main_trace().get(STACK_TRACE_OFFSET, row + 1);
+    trace.main_trace_mut().set(STACK_TRACE_OFFSET, row + 1, result + Felt::new(1));
+
+    let violation = check_constraints(&trace).unwrap_err();
+    assert_eq!(violation.kind(), ConstraintKind::Transition);
+    assert_eq!(violation.name(), "stack::field_ops::add");
+    assert_eq!(violation.row().as_usize(), row);
+    assert_eq!(violation.op(), Some(&Operation::Add));
+
+    let asmop = violation.asmop().unwrap();
+    assert_eq!(asmop.op(), "add");
+    assert!(asmop.context_name().ends_with("add_mul"));
+    assert!(violation.to_string().starts_with(&format!(
+        "transition constraint {} is not satisfied at row {row} while executing add (`add` in ",
+        violation.name()
+    )));
+}
+
+#[test]
+fn transition_violation_without_debugging() {
+    let mut trace = build_trace(ExecutionOptions::default());
+    let row = (0..trace.main_trace().num_rows())
+        .find(|&row| {
+            trace.main_trace().get_op_code(row.into()) == Felt::from(Operation::Mul.op_code())
+        })
+        .unwrap();
+
+    // corrupt the result of the `mul` instruction
+    let result = trace.main_trace().get(STACK_TRACE_OFFSET, row + 1);
+    trace.main_trace_mut().set(STACK_TRACE_OFFSET, row + 1, result + Felt::new(1));
+
+    // the operation is still decoded from the trace, but the assembly instruction is unknown
+    let violation = check_constraints(&trace).unwrap_err();
+    assert_eq!(violation.kind(), ConstraintKind::Transition);
+    assert_eq!(violation.row().as_usize(), row);
+    assert_eq!(violation.op(), Some(&Operation::Mul));
+    assert!(violation.asmop().is_none());
+}
+
+#[test]
+fn boundary_violation() {
+    let mut trace = build_trace(ExecutionOptions::default());
+    trace.main_trace_mut().set(CLK_COL_IDX, 0, Felt::new(1));
+
+    let violation = check_constraints(&trace).unwrap_err();
+    assert_eq!(violation.kind(), ConstraintKind::Boundary);
+    assert_eq!(violation.name(), "clk");
+    assert_eq!(violation.row().as_usize(), 0);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_trace(options: ExecutionOptions) -> ExecutionTrace {
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let program = Assembler::new(source_manager)
+        .with_debug_mode(true)
+        .assemble_program(SOURCE)
+        .unwrap();
+    let mut host = DefaultHost::default();
+    execute(&program, StackInputs::default(), &mut host, options).unwrap()
+}
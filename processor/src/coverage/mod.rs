@@ -0,0 +1,292 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use vm_core::{
+    Decorator,
+    debuginfo::Location,
+    mast::{DecoratorId, LoopNode, MastForest, MastNode, MastNodeId, SplitNode},
+};
+
+mod report;
+pub use report::{CoverageReport, FileCoverage};
+
+#[cfg(test)]
+mod tests;
+
+// COVERAGE DATA
+// ================================================================================================
+
+/// Key of a block with branches: the location of its first instruction, and its nesting depth.
+type BlockKey = (Location, u32);
+
+/// Coverage of Miden assembly source code by one or more executions of programs.
+///
+/// Coverage is recorded for instructions, identified by the source locations of the `AsmOp`
+/// decorators which the assembler emits in debug mode, and for branches of `if.true` and `while`
+/// blocks. A block is identified by the location of its first instruction, and by the number of
+/// blocks starting at the same instruction nested in it. The coverage of an `if.true` block records
+/// how many times each of its branches was taken, and the coverage of a `while` block records how
+/// many times its body was entered and skipped.
+///
+/// Programs (and libraries) assembled without debug information contain no source locations, and
+/// hence are not covered.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageData {
+    instructions: BTreeMap<Location, u64>,
+    branches: BTreeMap<BlockKey, BranchCoverage>,
+}
+
+impl CoverageData {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns empty coverage data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if no instructions or branches are covered by this data.
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty() && self.branches.is_empty()
+    }
+
+    /// Returns the locations of the instructions covered by this data, together with the number of
+    /// times they were executed.
+    pub fn instructions(&self) -> impl Iterator<Item = (&Location, u64)> {
+        self.instructions.iter().map(|(location, &hits)| (location, hits))
+    }
+
+    /// Returns the locations of the blocks with branches covered by this data, together with the
+    /// number of times each branch was taken.
+    pub fn branches(&self) -> impl Iterator<Item = (&Location, &BranchCoverage)> {
+        self.branches.iter().map(|((location, _), coverage)| (location, coverage))
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds all instructions and branches of the specified MAST forest to this data, so that the
+    /// ones which are never executed are reported as not covered.
+    pub fn add_forest(&mut self, forest: &MastForest) {
+        for node in forest.nodes() {
+            // only decorators attached to nodes are executed; the forest may also contain
+            // decorators of instructions which were removed during assembly (e.g., `exec`)
+            let block_decorators = match node {
+                MastNode::Block(block) => block.decorators().as_slice(),
+                _ => &[],
+            };
+            let decorators = node
+                .before_enter()
+                .iter()
+                .chain(node.after_exit())
+                .chain(block_decorators.iter().map(|(_, id)| id));
+            for &decorator_id in decorators {
+                if let Some(location) = asm_op_location(forest, decorator_id) {
+                    self.instructions.entry(location).or_insert(0);
+                }
+            }
+
+            let block = match node {
+                MastNode::Split(node) => split_location(forest, node),
+                MastNode::Loop(node) => loop_location(forest, node),
+                _ => None,
+            };
+            if let Some((key, coverage)) = block {
+                self.branches.entry(key).or_insert(coverage);
+            }
+        }
+    }
+
+    /// Merges the coverage recorded by another execution into this data.
+    pub fn merge(&mut self, other: &CoverageData) {
+        for (location, hits) in other.instructions() {
+            *self.instructions.entry(location.clone()).or_insert(0) += hits;
+        }
+        for (location, branch) in other.branches() {
+            self.branches
+                .entry((location.clone(), branch.depth))
+                .and_modify(|coverage| {
+                    coverage.on_true += branch.on_true;
+                    coverage.on_false += branch.on_false;
+                })
+                .or_insert(*branch);
+        }
+    }
+
+    /// Records an execution of the instruction at the specified location.
+    pub(crate) fn record_instruction(&mut self, location: &Location) {
+        match self.instructions.get_mut(location) {
+            Some(hits) => *hits += 1,
+            None => {
+                self.instructions.insert(location.clone(), 1);
+            },
+        }
+    }
+
+    /// Records that the specified branch of the block with the specified key was taken.
+    fn record_branch(&mut self, key: &BlockKey, block: BranchCoverage, on_true: bool) {
+        let coverage = match self.branches.get_mut(key) {
+            Some(coverage) => coverage,
+            None => self.branches.entry(key.clone()).or_insert(block),
+        };
+        if on_true {
+            coverage.on_true += 1;
+        } else {
+            coverage.on_false += 1;
+        }
+    }
+}
+
+// BRANCH COVERAGE
+// ================================================================================================
+
+/// Kind of a block with branches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BranchKind {
+    /// An `if.true` block.
+    If,
+    /// A `while` block.
+    While,
+}
+
+/// Coverage of the branches of a single `if.true` or `while` block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BranchCoverage {
+    /// Kind of the block.
+    pub kind: BranchKind,
+    /// Number of blocks with branches which start at the same instruction as this block, and are
+    /// nested in it.
+    pub depth: u32,
+    /// Number of times the `true` branch of an `if.true` block was taken, or the body of a `while`
+    /// block was entered.
+    pub on_true: u64,
+    /// Number of times the `false` branch of an `if.true` block was taken, or the body of a
+    /// `while` block was skipped.
+    pub on_false: u64,
+}
+
+impl BranchCoverage {
+    fn new(kind: BranchKind, depth: u32) -> Self {
+        Self { kind, depth, on_true: 0, on_false: 0 }
+    }
+}
+
+// COVERAGE RECORDER
+// ================================================================================================
+
+/// Records the coverage of a single execution.
+///
+/// The locations of blocks with branches are computed once per node, as finding them requires
+/// traversing the MAST. Nodes and forests are identified by their addresses, which do not change
+/// during an execution.
+#[derive(Debug, Default)]
+pub(crate) struct CoverageRecorder {
+    data: CoverageData,
+    /// Addresses of the MAST forests which were already added to the coverage data.
+    forests: BTreeSet<usize>,
+    /// Keys and empty coverage of the blocks with branches which were executed, by the addresses of
+    /// their nodes.
+    branch_locations: BTreeMap<usize, Option<(BlockKey, BranchCoverage)>>,
+}
+
+impl CoverageRecorder {
+    /// Adds all instructions and branches of the specified MAST forest to the coverage data, unless
+    /// the forest was already added.
+    pub fn add_forest(&mut self, forest: &MastForest) {
+        if self.forests.insert(forest_address(forest)) {
+            self.data.add_forest(forest);
+        }
+    }
+
+    /// Records an execution of the instruction at the specified location.
+    pub fn record_instruction(&mut self, location: &Location) {
+        self.data.record_instruction(location);
+    }
+
+    /// Records that the specified branch of the `if.true` block at the specified node was taken.
+    pub fn record_split(&mut self, forest: &MastForest, node: &SplitNode, on_true: bool) {
+        let block = self
+            .branch_locations
+            .entry(node as *const SplitNode as usize)
+            .or_insert_with(|| split_location(forest, node));
+        if let Some((key, block)) = block {
+            self.data.record_branch(key, *block, on_true);
+        }
+    }
+
+    /// Records whether the body of the `while` block at the specified node was entered.
+    pub fn record_loop(&mut self, forest: &MastForest, node: &LoopNode, entered: bool) {
+        let block = self
+            .branch_locations
+            .entry(node as *const LoopNode as usize)
+            .or_insert_with(|| loop_location(forest, node));
+        if let Some((key, block)) = block {
+            self.data.record_branch(key, *block, entered);
+        }
+    }
+
+    /// Returns the recorded coverage data.
+    pub fn into_data(self) -> CoverageData {
+        self.data
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn forest_address(forest: &MastForest) -> usize {
+    forest as *const MastForest as usize
+}
+
+/// Returns the key of the specified `if.true` block together with its empty coverage, if the
+/// location of the block is known.
+fn split_location(forest: &MastForest, node: &SplitNode) -> Option<(BlockKey, BranchCoverage)> {
+    let (location, depth) = first_location(forest, node.on_true())
+        .or_else(|| first_location(forest, node.on_false()))?;
+    Some(((location, depth), BranchCoverage::new(BranchKind::If, depth)))
+}
+
+/// Returns the key of the specified `while` block together with its empty coverage, if the
+/// location of the block is known.
+fn loop_location(forest: &MastForest, node: &LoopNode) -> Option<(BlockKey, BranchCoverage)> {
+    let (location, depth) = first_location(forest, node.body())?;
+    Some(((location, depth), BranchCoverage::new(BranchKind::While, depth)))
+}
+
+/// Returns the location of the first instruction executed when entering the specified node,
+/// without following calls, together with the number of blocks with branches entered before
+/// reaching it.
+fn first_location(forest: &MastForest, node_id: MastNodeId) -> Option<(Location, u32)> {
+    let node = forest.get_node_by_id(node_id)?;
+    let location = node.before_enter().iter().find_map(|&id| asm_op_location(forest, id));
+    if let Some(location) = location {
+        return Some((location, 0));
+    }
+
+    match node {
+        MastNode::Block(block) => block
+            .decorators()
+            .iter()
+            .find_map(|&(_, id)| asm_op_location(forest, id))
+            .map(|location| (location, 0)),
+        MastNode::Join(join) => {
+            first_location(forest, join.first()).or_else(|| first_location(forest, join.second()))
+        },
+        MastNode::Split(split) => first_location(forest, split.on_true())
+            .or_else(|| first_location(forest, split.on_false()))
+            .map(|(location, depth)| (location, depth + 1)),
+        MastNode::Loop(node) => {
+            first_location(forest, node.body()).map(|(location, depth)| (location, depth + 1))
+        },
+        MastNode::Call(_) | MastNode::Dyn(_) | MastNode::External(_) => None,
+    }
+}
+
+fn asm_op_location(forest: &MastForest, decorator_id: DecoratorId) -> Option<Location> {
+    match forest.get_decorator_by_id(decorator_id)? {
+        Decorator::AsmOp(assembly_op) => assembly_op.location().cloned(),
+        _ => None,
+    }
+}
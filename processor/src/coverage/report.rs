@@ -0,0 +1,332 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use vm_core::debuginfo::{Location, SourceFile, SourceManager};
+
+use super::{BranchKind, CoverageData};
+
+// COVERAGE REPORT
+// ================================================================================================
+
+/// Line and branch coverage of Miden assembly source files, which can be rendered as an lcov
+/// tracefile or as an HTML page.
+///
+/// A report is built from [CoverageData] by mapping the source locations of instructions and
+/// branches to lines through a [SourceManager]. Instructions and branches in source files which
+/// are unknown to the source manager (and, with the `std` feature, cannot be loaded from disk) are
+/// not included in the report.
+#[derive(Debug, Clone, Default)]
+pub struct CoverageReport {
+    files: BTreeMap<Arc<str>, FileCoverage>,
+}
+
+/// Line and branch coverage of a single source file.
+#[derive(Debug, Clone, Default)]
+pub struct FileCoverage {
+    source: Option<Arc<SourceFile>>,
+    /// Number of times each line with instructions was executed, by (1-based) line number.
+    lines: BTreeMap<u32, u64>,
+    /// Number of times each branch of a block was taken, by line number and nesting depth.
+    branches: BTreeMap<(u32, u32), (BranchKind, [u64; 2])>,
+}
+
+impl CoverageReport {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds a report from the provided coverage data, resolving source files using the provided
+    /// source manager.
+    ///
+    /// The number of executions of a line is the largest number of executions of an instruction on
+    /// this line.
+    pub fn new(data: &CoverageData, source_manager: &dyn SourceManager) -> Self {
+        let mut report = Self::default();
+
+        for (location, hits) in data.instructions() {
+            if let Some((file, line)) = report.resolve(location, source_manager) {
+                let line_hits = file.lines.entry(line).or_insert(0);
+                *line_hits = (*line_hits).max(hits);
+            }
+        }
+        for (location, branch) in data.branches() {
+            if let Some((file, line)) = report.resolve(location, source_manager) {
+                file.branches
+                    .insert((line, branch.depth), (branch.kind, [branch.on_true, branch.on_false]));
+            }
+        }
+
+        report
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the covered source files, by path.
+    pub fn files(&self) -> impl Iterator<Item = (&str, &FileCoverage)> {
+        self.files.iter().map(|(path, file)| (path.as_ref(), file))
+    }
+
+    /// Returns the number of lines with instructions, and the number of those lines which were
+    /// executed, across all files.
+    pub fn line_totals(&self) -> (usize, usize) {
+        self.files.values().fold((0, 0), |(found, hit), file| {
+            let (file_found, file_hit) = file.line_totals();
+            (found + file_found, hit + file_hit)
+        })
+    }
+
+    /// Returns the number of branches, and the number of those branches which were taken, across
+    /// all files.
+    pub fn branch_totals(&self) -> (usize, usize) {
+        self.files.values().fold((0, 0), |(found, hit), file| {
+            let (file_found, file_hit) = file.branch_totals();
+            (found + file_found, hit + file_hit)
+        })
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Merges another report into this report, summing the number of executions of lines and
+    /// branches of the same files.
+    pub fn merge(&mut self, other: CoverageReport) {
+        for (path, other) in other.files {
+            let file = self.files.entry(path).or_default();
+            if file.source.is_none() {
+                file.source = other.source;
+            }
+            for (line, hits) in other.lines {
+                *file.lines.entry(line).or_insert(0) += hits;
+            }
+            for (key, (kind, hits)) in other.branches {
+                let branch = file.branches.entry(key).or_insert((kind, [0, 0]));
+                branch.1[0] += hits[0];
+                branch.1[1] += hits[1];
+            }
+        }
+    }
+
+    // RENDERING
+    // --------------------------------------------------------------------------------------------
+
+    /// Renders this report as an lcov tracefile.
+    ///
+    /// The two branches of a block are reported as branches 0 (the `true` branch of an `if.true`
+    /// block, or entering the body of a `while` block) and 1, and blocks which start on the same
+    /// line are distinguished by their nesting depth.
+    pub fn to_lcov(&self) -> String {
+        let mut lcov = String::new();
+        for (path, file) in self.files.iter() {
+            let _ = writeln!(lcov, "TN:");
+            let _ = writeln!(lcov, "SF:{path}");
+            for (&(line, depth), (_, hits)) in file.branches.iter() {
+                let executed = hits[0] + hits[1] > 0;
+                for (branch, taken) in hits.iter().enumerate() {
+                    if executed {
+                        let _ = writeln!(lcov, "BRDA:{line},{depth},{branch},{taken}");
+                    } else {
+                        let _ = writeln!(lcov, "BRDA:{line},{depth},{branch},-");
+                    }
+                }
+            }
+            let (branches_found, branches_hit) = file.branch_totals();
+            let _ = writeln!(lcov, "BRF:{branches_found}");
+            let _ = writeln!(lcov, "BRH:{branches_hit}");
+            for (line, hits) in file.lines.iter() {
+                let _ = writeln!(lcov, "DA:{line},{hits}");
+            }
+            let (lines_found, lines_hit) = file.line_totals();
+            let _ = writeln!(lcov, "LF:{lines_found}");
+            let _ = writeln!(lcov, "LH:{lines_hit}");
+            let _ = writeln!(lcov, "end_of_record");
+        }
+        lcov
+    }
+
+    /// Renders this report as a standalone HTML page, listing the source of every covered file with
+    /// executed lines highlighted in green and lines which were not executed in red.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        html.push_str(HTML_HEADER);
+
+        let (lines_found, lines_hit) = self.line_totals();
+        let (branches_found, branches_hit) = self.branch_totals();
+        let _ = writeln!(html, "<h1>MASM coverage report</h1>");
+        let _ = writeln!(html, "<table><tr><th>File</th><th>Lines</th><th>Branches</th></tr>");
+        for (index, (path, file)) in self.files.iter().enumerate() {
+            let (file_lines_found, file_lines_hit) = file.line_totals();
+            let (file_branches_found, file_branches_hit) = file.branch_totals();
+            let _ = writeln!(
+                html,
+                "<tr><td><a href=\"#file{index}\">{}</a></td><td>{}</td><td>{}</td></tr>",
+                escape_html(path),
+                ratio(file_lines_hit, file_lines_found),
+                ratio(file_branches_hit, file_branches_found),
+            );
+        }
+        let _ = writeln!(
+            html,
+            "<tr><th>Total</th><th>{}</th><th>{}</th></tr></table>",
+            ratio(lines_hit, lines_found),
+            ratio(branches_hit, branches_found),
+        );
+
+        for (index, (path, file)) in self.files.iter().enumerate() {
+            let _ = writeln!(html, "<h2 id=\"file{index}\">{}</h2>", escape_html(path));
+            let Some(source) = &file.source else {
+                continue;
+            };
+            let _ = writeln!(html, "<pre>");
+            for (line_index, line) in source.as_str().lines().enumerate() {
+                let line_number = line_index as u32 + 1;
+                let class = match file.lines.get(&line_number) {
+                    Some(0) => "miss",
+                    Some(_) => "hit",
+                    None => "none",
+                };
+                let hits = file.lines.get(&line_number).map(|hits| hits.to_string());
+                let branches = file.line_branches(line_number);
+                let _ = writeln!(
+                    html,
+                    "<span class=\"{class}\"><span class=\"num\">{line_number:>5}</span>\
+                     <span class=\"hits\">{:>8}</span><span class=\"br\">{:>6}</span> {}</span>",
+                    hits.unwrap_or_default(),
+                    branches.map(|(found, hit)| ratio(hit, found)).unwrap_or_default(),
+                    escape_html(line),
+                );
+            }
+            let _ = writeln!(html, "</pre>");
+        }
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the coverage of the file of the specified location together with the line of the
+    /// location, if the file can be resolved.
+    fn resolve(
+        &mut self,
+        location: &Location,
+        source_manager: &dyn SourceManager,
+    ) -> Option<(&mut FileCoverage, u32)> {
+        let source = match self.files.get(&location.path).and_then(|file| file.source.clone()) {
+            Some(source) => source,
+            None => {
+                let source = find_source(&location.path, source_manager)?;
+                self.files.entry(location.path.clone()).or_default().source = Some(source.clone());
+                source
+            },
+        };
+        let line = source.content().line_index(location.start).number().get();
+        let file = self.files.get_mut(&location.path).expect("file was inserted above");
+        Some((file, line))
+    }
+}
+
+impl FileCoverage {
+    /// Returns the source of this file, if known.
+    pub fn source(&self) -> Option<&Arc<SourceFile>> {
+        self.source.as_ref()
+    }
+
+    /// Returns the lines with instructions in this file, together with the number of times they
+    /// were executed.
+    pub fn lines(&self) -> impl Iterator<Item = (u32, u64)> + '_ {
+        self.lines.iter().map(|(&line, &hits)| (line, hits))
+    }
+
+    /// Returns the number of lines with instructions, and the number of those lines which were
+    /// executed.
+    pub fn line_totals(&self) -> (usize, usize) {
+        (self.lines.len(), self.lines.values().filter(|&&hits| hits > 0).count())
+    }
+
+    /// Returns the number of branches, and the number of those branches which were taken.
+    pub fn branch_totals(&self) -> (usize, usize) {
+        let hit = self.branches.values().flat_map(|(_, hits)| hits).filter(|&&h| h > 0).count();
+        (self.branches.len() * 2, hit)
+    }
+
+    /// Returns the number of branches of the blocks starting on the specified line, and the number
+    /// of those branches which were taken, if any blocks start on this line.
+    fn line_branches(&self, line: u32) -> Option<(usize, usize)> {
+        let branches: Vec<_> = self
+            .branches
+            .range((line, 0)..=(line, u32::MAX))
+            .map(|(_, (_, hits))| hits)
+            .collect();
+        if branches.is_empty() {
+            return None;
+        }
+        let hit = branches.iter().flat_map(|hits| hits.iter()).filter(|&&h| h > 0).count();
+        Some((branches.len() * 2, hit))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const HTML_HEADER: &str = "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>MASM coverage report</title>
+<style>
+body { font-family: sans-serif; }
+table { border-collapse: collapse; }
+td, th { border: 1px solid #ccc; padding: 2px 8px; text-align: left; }
+pre { font-family: monospace; }
+.hit { background-color: #dfd; }
+.miss { background-color: #fdd; }
+.num, .hits, .br { color: #888; padding-right: 8px; }
+</style>
+</head>
+<body>
+";
+
+/// Returns the source file at the specified path, loading it from disk if it is not known to the
+/// source manager.
+fn find_source(path: &str, source_manager: &dyn SourceManager) -> Option<Arc<SourceFile>> {
+    if let Some(source) = source_manager.get_by_path(path) {
+        return Some(source);
+    }
+
+    #[cfg(feature = "std")]
+    {
+        use vm_core::debuginfo::SourceManagerExt;
+        source_manager.load_file(std::path::Path::new(path)).ok()
+    }
+
+    #[cfg(not(feature = "std"))]
+    None
+}
+
+fn ratio(hit: usize, found: usize) -> String {
+    if found == 0 {
+        String::from("-")
+    } else {
+        alloc::format!("{hit}/{found}")
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
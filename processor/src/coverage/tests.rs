@@ -0,0 +1,130 @@
+use alloc::{string::ToString, sync::Arc, vec::Vec};
+
+use assembly::Assembler;
+use vm_core::debuginfo::{DefaultSourceManager, SourceManager};
+
+use super::{BranchKind, CoverageData, CoverageReport};
+use crate::{DefaultHost, ExecutionOptions, StackInputs, execute_with_coverage};
+
+const SOURCE: &str = "\
+begin
+    if.true
+        push.1
+    else
+        push.2
+    end
+    drop
+    while.true
+        push.0
+    end
+end
+";
+
+#[test]
+fn branch_coverage() {
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let coverage = run(&source_manager, &[[0, 1], [1, 0], [0, 1]]);
+
+    let mut branches: Vec<_> = coverage.branches().map(|(_, branch)| *branch).collect();
+    branches.sort_by_key(|branch| branch.kind as u8);
+    assert_eq!(branches.len(), 2);
+
+    // the `if.true` block took its `true` branch twice and its `false` branch once
+    assert_eq!(branches[0].kind, BranchKind::If);
+    assert_eq!((branches[0].on_true, branches[0].on_false), (2, 1));
+
+    // the body of the `while` block was entered once and skipped twice
+    assert_eq!(branches[1].kind, BranchKind::While);
+    assert_eq!((branches[1].on_true, branches[1].on_false), (1, 2));
+}
+
+#[test]
+fn line_coverage_report() {
+    let source_manager = Arc::new(DefaultSourceManager::default());
+
+    // only the `true` branch of the `if.true` block is taken, and the loop is never entered
+    let coverage = run(&source_manager, &[[0, 1]]);
+    let report = CoverageReport::new(&coverage, source_manager.as_ref());
+
+    let (path, file) = report.files().next().unwrap();
+    assert_eq!(path, "test.masm");
+    let lines: Vec<_> = file.lines().collect();
+    assert!(lines.contains(&(3, 1)));
+    assert!(lines.contains(&(5, 0)));
+    assert!(lines.contains(&(7, 1)));
+    assert!(lines.contains(&(9, 0)));
+    assert_eq!(file.branch_totals(), (4, 2));
+
+    let lcov = report.to_lcov();
+    assert!(lcov.starts_with("TN:\nSF:test.masm\n"));
+    assert!(lcov.contains("DA:3,1\n"));
+    assert!(lcov.contains("DA:5,0\n"));
+    assert!(lcov.contains("BRF:4\nBRH:2\n"));
+    assert!(lcov.ends_with("end_of_record\n"));
+
+    let html = report.to_html();
+    assert!(html.contains("test.masm"));
+    assert!(html.contains("class=\"miss\""));
+
+    // merging a report of an execution which takes the other branches covers all lines
+    let mut merged = report.clone();
+    merged.merge(CoverageReport::new(&run(&source_manager, &[[1, 0]]), source_manager.as_ref()));
+    assert_eq!(merged.line_totals().0, merged.line_totals().1);
+    assert_eq!(merged.branch_totals(), (4, 4));
+}
+
+#[test]
+fn merge_coverage_data() {
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let mut first = run(&source_manager, &[[0, 1]]);
+    let second = run(&source_manager, &[[1, 0], [1, 0]]);
+    first.merge(&second);
+
+    assert_eq!(first, run(&source_manager, &[[0, 1], [1, 0], [1, 0]]));
+}
+
+#[test]
+fn no_coverage_without_debug_info() {
+    let program = Assembler::default().assemble_program(SOURCE).unwrap();
+    let mut coverage = CoverageData::new();
+    let stack_inputs = StackInputs::try_from_ints([1, 0]).unwrap();
+    let mut host = DefaultHost::default();
+    execute_with_coverage(
+        &program,
+        stack_inputs,
+        &mut host,
+        ExecutionOptions::default(),
+        &mut coverage,
+    )
+    .unwrap();
+
+    assert!(coverage.is_empty());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes the test program once for each of the provided inputs, and returns the coverage of
+/// all executions.
+fn run(source_manager: &Arc<DefaultSourceManager>, inputs: &[[u64; 2]]) -> CoverageData {
+    let source = source_manager.load("test.masm", SOURCE.to_string());
+    let program = Assembler::new(source_manager.clone())
+        .with_debug_mode(true)
+        .assemble_program(source)
+        .unwrap();
+
+    let mut coverage = CoverageData::new();
+    for inputs in inputs {
+        let stack_inputs = StackInputs::try_from_ints(inputs.iter().copied()).unwrap();
+        let mut host = DefaultHost::default();
+        execute_with_coverage(
+            &program,
+            stack_inputs,
+            &mut host,
+            ExecutionOptions::default(),
+            &mut coverage,
+        )
+        .unwrap();
+    }
+    coverage
+}
@@ -1,10 +1,10 @@
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 
 use miden_air::trace::main_trace::MainTrace;
 use vm_core::FieldElement;
 
 use super::{Felt, ONE, ZERO};
-use crate::trace::AuxColumnBuilder;
+use crate::{debug::BusDebugger, trace::AuxColumnBuilder};
 
 mod block_hash_table;
 use block_hash_table::BlockHashTableColumnBuilder;
@@ -59,4 +59,35 @@ impl AuxTraceBuilder {
 
         vec![p1, p2, p3]
     }
+
+    /// Builds the decoder auxiliary trace columns like [Self::build_aux_columns()], but without
+    /// checking that the tables are empty at the end of the execution. Each column is returned
+    /// together with the debugger into which the rows added to and removed from its table were
+    /// recorded.
+    pub(crate) fn build_aux_columns_with_debuggers<E: FieldElement<BaseField = Felt>>(
+        &self,
+        main_trace: &MainTrace,
+        rand_elements: &[E],
+    ) -> Vec<(Vec<E>, BusDebugger<E>)> {
+        let mut p1_debugger = BusDebugger::new("block stack table".to_string());
+        let p1 = BlockStackColumnBuilder::default().build_aux_column_with_debugger(
+            main_trace,
+            rand_elements,
+            &mut p1_debugger,
+        );
+        let mut p2_debugger = BusDebugger::new("block hash table".to_string());
+        let p2 = BlockHashTableColumnBuilder::default().build_aux_column_with_debugger(
+            main_trace,
+            rand_elements,
+            &mut p2_debugger,
+        );
+        let mut p3_debugger = BusDebugger::new("op group table".to_string());
+        let p3 = OpGroupTableColumnBuilder::default().build_aux_column_with_debugger(
+            main_trace,
+            rand_elements,
+            &mut p3_debugger,
+        );
+
+        vec![(p1, p1_debugger), (p2, p2_debugger), (p3, p3_debugger)]
+    }
 }
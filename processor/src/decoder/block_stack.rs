@@ -1,5 +1,7 @@
 use alloc::vec::Vec;
 
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
 use super::{Felt, ONE, Word, ZERO};
 use crate::system::ContextId;
 
@@ -197,3 +199,101 @@ pub enum BlockType {
     SysCall,
     Span,
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BlockStack {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.blocks.write_into(target);
+    }
+}
+
+impl Deserializable for BlockStack {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self { blocks: source.read()? })
+    }
+}
+
+impl Serializable for BlockInfo {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.addr.write_into(target);
+        self.block_type.write_into(target);
+        self.parent_addr.write_into(target);
+        self.ctx_info.write_into(target);
+        target.write_bool(self.is_loop_body);
+        target.write_bool(self.is_first_child);
+    }
+}
+
+impl Deserializable for BlockInfo {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            addr: source.read()?,
+            block_type: source.read()?,
+            parent_addr: source.read()?,
+            ctx_info: source.read()?,
+            is_loop_body: source.read_bool()?,
+            is_first_child: source.read_bool()?,
+        })
+    }
+}
+
+impl Serializable for ExecutionContextInfo {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.parent_ctx.write_into(target);
+        self.parent_fn_hash.write_into(target);
+        self.parent_fmp.write_into(target);
+        target.write_u32(self.parent_stack_depth);
+        self.parent_next_overflow_addr.write_into(target);
+    }
+}
+
+impl Deserializable for ExecutionContextInfo {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            parent_ctx: source.read()?,
+            parent_fn_hash: source.read()?,
+            parent_fmp: source.read()?,
+            parent_stack_depth: source.read_u32()?,
+            parent_next_overflow_addr: source.read()?,
+        })
+    }
+}
+
+impl Serializable for BlockType {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Join(first_child_executed) => {
+                target.write_u8(0);
+                target.write_bool(*first_child_executed);
+            },
+            Self::Split => target.write_u8(1),
+            Self::Loop(loop_entered) => {
+                target.write_u8(2);
+                target.write_bool(*loop_entered);
+            },
+            Self::Call => target.write_u8(3),
+            Self::Dyn => target.write_u8(4),
+            Self::Dyncall => target.write_u8(5),
+            Self::SysCall => target.write_u8(6),
+            Self::Span => target.write_u8(7),
+        }
+    }
+}
+
+impl Deserializable for BlockType {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Join(source.read_bool()?)),
+            1 => Ok(Self::Split),
+            2 => Ok(Self::Loop(source.read_bool()?)),
+            3 => Ok(Self::Call),
+            4 => Ok(Self::Dyn),
+            5 => Ok(Self::Dyncall),
+            6 => Ok(Self::SysCall),
+            7 => Ok(Self::Span),
+            tag => Err(DeserializationError::InvalidValue(format!("invalid block type tag {tag}"))),
+        }
+    }
+}
@@ -16,6 +16,7 @@ use vm_core::{
         BasicBlockNode, CallNode, DynNode, JoinNode, LoopNode, MastForest, OP_BATCH_SIZE, SplitNode,
     },
     stack::MIN_STACK_DEPTH,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
 use super::{
@@ -590,6 +591,12 @@ impl Decoder {
         &self.debug_info
     }
 
+    /// Takes the debug info of this decoder, leaving an empty one in its place.
+    pub fn take_debug_info(&mut self) -> DebugInfo {
+        let in_debug_mode = self.in_debug_mode();
+        core::mem::replace(&mut self.debug_info, DebugInfo::new(in_debug_mode))
+    }
+
     /// Returns whether this decoder instance is instantiated in debug mode.
     pub fn in_debug_mode(&self) -> bool {
         self.debug_info.in_debug_mode()
@@ -900,6 +907,26 @@ impl Default for Decoder {
     }
 }
 
+impl Serializable for Decoder {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.block_stack.write_into(target);
+        self.span_context.write_into(target);
+        self.trace.write_into(target);
+        self.debug_info.write_into(target);
+    }
+}
+
+impl Deserializable for Decoder {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            block_stack: source.read()?,
+            span_context: source.read()?,
+            trace: source.read()?,
+            debug_info: source.read()?,
+        })
+    }
+}
+
 // SPAN CONTEXT
 // ================================================================================================
 
@@ -914,6 +941,22 @@ struct SpanContext {
     num_groups_left: Felt,
 }
 
+impl Serializable for SpanContext {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.group_ops_left.write_into(target);
+        self.num_groups_left.write_into(target);
+    }
+}
+
+impl Deserializable for SpanContext {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            group_ops_left: source.read()?,
+            num_groups_left: source.read()?,
+        })
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -999,3 +1042,21 @@ impl DebugInfo {
         self.assembly_ops.push((clk.into(), asmop));
     }
 }
+
+impl Serializable for DebugInfo {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bool(self.in_debug_mode);
+        self.operations.write_into(target);
+        self.assembly_ops.write_into(target);
+    }
+}
+
+impl Deserializable for DebugInfo {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            in_debug_mode: source.read_bool()?,
+            operations: source.read()?,
+            assembly_ops: source.read()?,
+        })
+    }
+}
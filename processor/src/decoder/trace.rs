@@ -3,7 +3,9 @@ use core::ops::Range;
 
 #[cfg(test)]
 use miden_air::trace::decoder::NUM_USER_OP_HELPERS;
-use vm_core::utils::new_array_vec;
+use vm_core::utils::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, new_array_vec,
+};
 
 use super::{
     super::utils::get_trace_len, DIGEST_LEN, Felt, MIN_TRACE_LEN, NUM_HASHER_COLUMNS,
@@ -553,3 +555,31 @@ fn get_op_batch_flags(num_groups_left: Felt) -> [Felt; NUM_OP_BATCH_FLAGS] {
         ),
     }
 }
+
+impl Serializable for DecoderTrace {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.addr_trace.write_into(target);
+        self.op_bits_trace.write_into(target);
+        self.hasher_trace.write_into(target);
+        self.in_span_trace.write_into(target);
+        self.group_count_trace.write_into(target);
+        self.op_idx_trace.write_into(target);
+        self.op_batch_flag_trace.write_into(target);
+        self.op_bit_extra_trace.write_into(target);
+    }
+}
+
+impl Deserializable for DecoderTrace {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            addr_trace: source.read()?,
+            op_bits_trace: source.read()?,
+            hasher_trace: source.read()?,
+            in_span_trace: source.read()?,
+            group_count_trace: source.read()?,
+            op_idx_trace: source.read()?,
+            op_batch_flag_trace: source.read()?,
+            op_bit_extra_trace: source.read()?,
+        })
+    }
+}
@@ -5,6 +5,7 @@ use miden_air::RowIndex;
 use vm_core::{
     mast::{DecoratorId, MastNodeId},
     stack::MIN_STACK_DEPTH,
+    sys_events::SystemEvent,
     utils::to_hex,
 };
 use winter_prover::{ProverError, math::FieldElement};
@@ -82,6 +83,8 @@ pub enum ExecutionError {
     InvalidMerkleTreeDepth { depth: Felt },
     #[error("provided node index {value} is out of bounds for a merkle tree node at depth {depth}")]
     InvalidMerkleTreeNodeIndex { depth: Felt, value: Felt },
+    #[error("cannot resume execution: the state of the paused process does not match the program")]
+    InvalidPausedState,
     #[error("attempted to calculate integer logarithm with zero argument at clock cycle {0}")]
     LogArgumentZero(RowIndex),
     #[error("malformed signature key: {0}")]
@@ -126,6 +129,12 @@ pub enum ExecutionError {
     NotU32Value(Felt, Felt),
     #[error("stack should have at most {MIN_STACK_DEPTH} elements at the end of program execution, but had {} elements", MIN_STACK_DEPTH + .0)]
     OutputStackOverflow(usize),
+    #[error(
+        "cannot resume execution of program {actual}: the process was paused while executing program {expected}"
+    )]
+    PausedProgramMismatch { expected: Digest, actual: Digest },
+    #[error("cannot resume execution: the process is not paused")]
+    ProcessNotPaused,
     #[error("a program has already been executed in this process")]
     ProgramAlreadyExecuted,
     #[error("proof generation failed")]
@@ -141,6 +150,10 @@ pub enum ExecutionError {
       hex = to_hex(.0.as_bytes())
     )]
     SyscallTargetNotInKernel(Digest),
+    #[error(
+        "failed to replay the {request} request: it does not match interaction {index} of the recorded host transcript"
+    )]
+    TranscriptMismatch { index: usize, request: &'static str },
 }
 
 impl From<Ext2InttError> for ExecutionError {
@@ -174,6 +187,17 @@ pub enum Ext2InttError {
     UninitializedMemoryAddress(u32),
 }
 
+// EVENT HANDLER ERROR
+// ================================================================================================
+
+#[derive(Debug, thiserror::Error)]
+pub enum EventHandlerError {
+    #[error("a handler for the event with id {0} is already registered")]
+    DuplicateEventId(u32),
+    #[error("event id {event_id} is reserved for the system event {system_event}")]
+    ReservedEventId { event_id: u32, system_event: SystemEvent },
+}
+
 #[cfg(test)]
 mod error_assertions {
     use super::*;
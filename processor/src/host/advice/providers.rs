@@ -1,6 +1,9 @@
 use alloc::{collections::BTreeMap, vec::Vec};
 
-use vm_core::crypto::merkle::{MerkleStore, NodeIndex, StoreNode};
+use vm_core::{
+    crypto::merkle::{MerkleStore, NodeIndex, StoreNode},
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 
 use super::{
     AdviceInputs, AdviceProvider, AdviceSource, ExecutionError, Felt, MerklePath, RpoDigest, Word,
@@ -289,6 +292,26 @@ impl MemAdviceProvider {
     }
 }
 
+impl Serializable for MemAdviceProvider {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let BaseAdviceProvider { stack, map, store } = &self.provider;
+        stack.write_into(target);
+        map.write_into(target);
+        store.write_into(target);
+    }
+}
+
+impl Deserializable for MemAdviceProvider {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let provider = BaseAdviceProvider {
+            stack: source.read()?,
+            map: source.read()?,
+            store: source.read()?,
+        };
+        Ok(Self { provider })
+    }
+}
+
 // RECORDING ADVICE PROVIDER
 // ================================================================================================
 
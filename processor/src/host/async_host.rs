@@ -0,0 +1,306 @@
+use alloc::sync::Arc;
+use core::future::{Future, ready};
+
+use vm_core::{DebugOptions, crypto::hash::RpoDigest, mast::MastForest};
+
+use super::{AdviceProvider, DefaultHost, EventHandler, Host, ProcessState};
+use crate::{EventHandlerError, ExecutionError, MemAdviceProvider};
+
+// ASYNC HOST TRAIT
+// ================================================================================================
+
+/// Defines an asynchronous interface by which the VM can interact with the host.
+///
+/// This is the asynchronous counterpart of [Host], used by [crate::execute_async]. Getting a
+/// library's MAST forest and handling events are asynchronous, which allows hosts to fetch them
+/// from e.g. a database without blocking the executing thread. Accessing the advice provider, and
+/// handling debug and trace events remain synchronous.
+///
+/// The futures returned by the host must be [Send], so that [crate::execute_async] can be run on
+/// multi-threaded executors.
+pub trait AsyncHost: Send {
+    type AdviceProvider: AdviceProvider + Send;
+
+    // REQUIRED METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the advice provider.
+    fn advice_provider(&self) -> &Self::AdviceProvider;
+
+    /// Returns a mutable reference to the advice provider.
+    fn advice_provider_mut(&mut self) -> &mut Self::AdviceProvider;
+
+    /// Returns MAST forest corresponding to the specified digest, or None if the MAST forest for
+    /// this digest could not be found in this [AsyncHost].
+    fn get_mast_forest(
+        &self,
+        node_digest: &RpoDigest,
+    ) -> impl Future<Output = Option<Arc<MastForest>>> + Send;
+
+    // PROVIDED METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Handles the event emitted from the VM.
+    fn on_event(
+        &mut self,
+        _process: ProcessState,
+        _event_id: u32,
+    ) -> impl Future<Output = Result<(), ExecutionError>> + Send {
+        #[cfg(feature = "std")]
+        std::println!(
+            "Event with id {} emitted at step {} in context {}",
+            _event_id,
+            _process.clk(),
+            _process.ctx()
+        );
+        ready(Ok(()))
+    }
+
+    /// Handles the debug request from the VM.
+    fn on_debug(
+        &mut self,
+        _process: ProcessState,
+        _options: &DebugOptions,
+    ) -> Result<(), ExecutionError> {
+        #[cfg(feature = "std")]
+        super::debug::print_debug_info(_process, _options);
+        Ok(())
+    }
+
+    /// Handles the trace emitted from the VM.
+    fn on_trace(&mut self, _process: ProcessState, _trace_id: u32) -> Result<(), ExecutionError> {
+        #[cfg(feature = "std")]
+        std::println!(
+            "Trace with id {} emitted at step {} in context {}",
+            _trace_id,
+            _process.clk(),
+            _process.ctx()
+        );
+        Ok(())
+    }
+
+    /// Handles the failure of the assertion instruction.
+    fn on_assert_failed(&mut self, process: ProcessState, err_code: u32) -> ExecutionError {
+        ExecutionError::FailedAssertion {
+            clk: process.clk(),
+            err_code,
+            err_msg: None,
+        }
+    }
+
+    /// Returns true if the host requests the execution of the program to be paused.
+    ///
+    /// This is only checked by executions started with [crate::Process::execute_pausable_async],
+    /// see [Host::should_yield] for details.
+    fn should_yield(&mut self) -> bool {
+        false
+    }
+}
+
+impl<H> AsyncHost for &mut H
+where
+    H: AsyncHost,
+{
+    type AdviceProvider = H::AdviceProvider;
+
+    fn advice_provider(&self) -> &Self::AdviceProvider {
+        H::advice_provider(self)
+    }
+
+    fn advice_provider_mut(&mut self) -> &mut Self::AdviceProvider {
+        H::advice_provider_mut(self)
+    }
+
+    fn get_mast_forest(
+        &self,
+        node_digest: &RpoDigest,
+    ) -> impl Future<Output = Option<Arc<MastForest>>> + Send {
+        H::get_mast_forest(self, node_digest)
+    }
+
+    fn on_event(
+        &mut self,
+        process: ProcessState,
+        event_id: u32,
+    ) -> impl Future<Output = Result<(), ExecutionError>> + Send {
+        H::on_event(self, process, event_id)
+    }
+
+    fn on_debug(
+        &mut self,
+        process: ProcessState,
+        options: &DebugOptions,
+    ) -> Result<(), ExecutionError> {
+        H::on_debug(self, process, options)
+    }
+
+    fn on_trace(&mut self, process: ProcessState, trace_id: u32) -> Result<(), ExecutionError> {
+        H::on_trace(self, process, trace_id)
+    }
+
+    fn on_assert_failed(&mut self, process: ProcessState, err_code: u32) -> ExecutionError {
+        H::on_assert_failed(self, process, err_code)
+    }
+
+    fn should_yield(&mut self) -> bool {
+        H::should_yield(self)
+    }
+}
+
+// SYNC HOST ADAPTER
+// ================================================================================================
+
+/// Exposes an [AsyncHost] as a [Host], for use by the parts of the VM which only need the
+/// synchronous functionality of the host.
+///
+/// MAST forests and events are never requested through this adapter, as the async executor
+/// resolves them using the [AsyncHost] directly.
+pub(crate) struct SyncHostAdapter<'a, H>(pub &'a mut H);
+
+impl<H: AsyncHost> Host for SyncHostAdapter<'_, H> {
+    type AdviceProvider = H::AdviceProvider;
+
+    fn advice_provider(&self) -> &Self::AdviceProvider {
+        self.0.advice_provider()
+    }
+
+    fn advice_provider_mut(&mut self) -> &mut Self::AdviceProvider {
+        self.0.advice_provider_mut()
+    }
+
+    fn get_mast_forest(&self, _node_digest: &RpoDigest) -> Option<Arc<MastForest>> {
+        unreachable!("MAST forests are resolved by the async executor")
+    }
+
+    fn on_event(&mut self, _process: ProcessState, _event_id: u32) -> Result<(), ExecutionError> {
+        unreachable!("events are handled by the async executor")
+    }
+
+    fn on_debug(
+        &mut self,
+        process: ProcessState,
+        options: &DebugOptions,
+    ) -> Result<(), ExecutionError> {
+        self.0.on_debug(process, options)
+    }
+
+    fn on_trace(&mut self, process: ProcessState, trace_id: u32) -> Result<(), ExecutionError> {
+        self.0.on_trace(process, trace_id)
+    }
+
+    fn on_assert_failed(&mut self, process: ProcessState, err_code: u32) -> ExecutionError {
+        self.0.on_assert_failed(process, err_code)
+    }
+
+    fn should_yield(&mut self) -> bool {
+        self.0.should_yield()
+    }
+}
+
+// IN-MEMORY ASYNC HOST
+// ================================================================================================
+
+/// An [AsyncHost] which keeps all MAST forests in memory, and handles events using the handlers
+/// registered with it.
+///
+/// It behaves exactly like [DefaultHost], with all of its futures resolving immediately, and is
+/// mainly intended as a stand-in for hosts backed by external storage in tests.
+pub struct MemAsyncHost<A = MemAdviceProvider> {
+    host: DefaultHost<A>,
+}
+
+impl Default for MemAsyncHost<MemAdviceProvider> {
+    fn default() -> Self {
+        Self { host: DefaultHost::default() }
+    }
+}
+
+impl<A: AdviceProvider> MemAsyncHost<A> {
+    pub fn new(adv_provider: A) -> Self {
+        Self { host: DefaultHost::new(adv_provider) }
+    }
+
+    pub fn load_mast_forest(&mut self, mast_forest: Arc<MastForest>) -> Result<(), ExecutionError> {
+        self.host.load_mast_forest(mast_forest)
+    }
+
+    /// Registers `handler` as the handler of the event with the specified ID.
+    ///
+    /// See [DefaultHost::register_handler] for details.
+    pub fn register_handler(
+        &mut self,
+        event_id: u32,
+        handler: impl EventHandler<A> + 'static,
+    ) -> Result<(), EventHandlerError> {
+        self.host.register_handler(event_id, handler)
+    }
+
+    /// Registers `handler` as the handler of the event whose ID is derived from `name`, and returns
+    /// that ID.
+    ///
+    /// See [DefaultHost::register_named_handler] for details.
+    pub fn register_named_handler(
+        &mut self,
+        name: &str,
+        handler: impl EventHandler<A> + 'static,
+    ) -> Result<u32, EventHandlerError> {
+        self.host.register_named_handler(name, handler)
+    }
+
+    pub fn into_inner(self) -> A {
+        self.host.into_inner()
+    }
+}
+
+impl<A> From<DefaultHost<A>> for MemAsyncHost<A> {
+    fn from(host: DefaultHost<A>) -> Self {
+        Self { host }
+    }
+}
+
+impl<A: AdviceProvider + Send> AsyncHost for MemAsyncHost<A> {
+    type AdviceProvider = A;
+
+    fn advice_provider(&self) -> &Self::AdviceProvider {
+        self.host.advice_provider()
+    }
+
+    fn advice_provider_mut(&mut self) -> &mut Self::AdviceProvider {
+        self.host.advice_provider_mut()
+    }
+
+    fn get_mast_forest(
+        &self,
+        node_digest: &RpoDigest,
+    ) -> impl Future<Output = Option<Arc<MastForest>>> + Send {
+        ready(self.host.get_mast_forest(node_digest))
+    }
+
+    fn on_event(
+        &mut self,
+        process: ProcessState,
+        event_id: u32,
+    ) -> impl Future<Output = Result<(), ExecutionError>> + Send {
+        ready(self.host.on_event(process, event_id))
+    }
+
+    fn on_debug(
+        &mut self,
+        process: ProcessState,
+        options: &DebugOptions,
+    ) -> Result<(), ExecutionError> {
+        self.host.on_debug(process, options)
+    }
+
+    fn on_trace(&mut self, process: ProcessState, trace_id: u32) -> Result<(), ExecutionError> {
+        self.host.on_trace(process, trace_id)
+    }
+
+    fn on_assert_failed(&mut self, process: ProcessState, err_code: u32) -> ExecutionError {
+        self.host.on_assert_failed(process, err_code)
+    }
+
+    fn should_yield(&mut self) -> bool {
+        self.host.should_yield()
+    }
+}
@@ -0,0 +1,223 @@
+use alloc::{collections::BTreeMap, sync::Arc};
+use core::fmt;
+
+use vm_core::{
+    crypto::hash::{Blake3_256, Digest},
+    sys_events::SystemEvent,
+};
+
+use crate::{EventHandlerError, ExecutionError, ProcessState};
+
+// EVENT HANDLER
+// ================================================================================================
+
+/// Handles an event emitted by a program via the `emit` instruction.
+///
+/// A handler is given access to the state of the process at the time the event was emitted, and
+/// to the advice provider of the host, which it may use to supply non-deterministic inputs to the
+/// program.
+///
+/// This trait is implemented for all closures with the signature of [EventHandler::on_event].
+pub trait EventHandler<A>: Send + Sync {
+    /// Handles the event emitted by the process in the state `process`.
+    fn on_event(
+        &self,
+        process: ProcessState,
+        advice_provider: &mut A,
+    ) -> Result<(), ExecutionError>;
+}
+
+impl<A, F> EventHandler<A> for F
+where
+    F: Fn(ProcessState, &mut A) -> Result<(), ExecutionError> + Send + Sync,
+{
+    fn on_event(
+        &self,
+        process: ProcessState,
+        advice_provider: &mut A,
+    ) -> Result<(), ExecutionError> {
+        self(process, advice_provider)
+    }
+}
+
+// EVENT HANDLER REGISTRY
+// ================================================================================================
+
+/// A registry of [EventHandler]s, keyed by the ID of the event they handle.
+///
+/// Event IDs can either be chosen explicitly, or derived from a name using [event_id_from_name].
+/// Each ID can only be handled by a single handler, and the IDs of [SystemEvent]s are reserved,
+/// as system events are handled by the VM itself.
+pub struct EventHandlerRegistry<A> {
+    handlers: BTreeMap<u32, Arc<dyn EventHandler<A>>>,
+}
+
+impl<A> EventHandlerRegistry<A> {
+    /// Returns a new, empty [EventHandlerRegistry].
+    pub fn new() -> Self {
+        Self { handlers: BTreeMap::new() }
+    }
+
+    /// Registers `handler` as the handler of the event with the specified ID.
+    ///
+    /// # Errors
+    /// Returns an error if a handler for `event_id` is already registered, or if `event_id` is the
+    /// ID of a [SystemEvent].
+    pub fn register(
+        &mut self,
+        event_id: u32,
+        handler: impl EventHandler<A> + 'static,
+    ) -> Result<(), EventHandlerError> {
+        if let Some(system_event) = SystemEvent::from_event_id(event_id) {
+            return Err(EventHandlerError::ReservedEventId { event_id, system_event });
+        }
+        if self.handlers.contains_key(&event_id) {
+            return Err(EventHandlerError::DuplicateEventId(event_id));
+        }
+
+        self.handlers.insert(event_id, Arc::new(handler));
+        Ok(())
+    }
+
+    /// Registers `handler` as the handler of the event whose ID is derived from `name`, and returns
+    /// that ID.
+    ///
+    /// See [event_id_from_name] for details on how the ID is derived.
+    ///
+    /// # Errors
+    /// Returns an error if the derived ID is already registered, or collides with the ID of a
+    /// [SystemEvent].
+    pub fn register_named(
+        &mut self,
+        name: &str,
+        handler: impl EventHandler<A> + 'static,
+    ) -> Result<u32, EventHandlerError> {
+        let event_id = event_id_from_name(name);
+        self.register(event_id, handler)?;
+        Ok(event_id)
+    }
+
+    /// Removes the handler of the event with the specified ID, returning true if one was
+    /// registered.
+    pub fn unregister(&mut self, event_id: u32) -> bool {
+        self.handlers.remove(&event_id).is_some()
+    }
+
+    /// Returns true if a handler is registered for the event with the specified ID.
+    pub fn contains(&self, event_id: u32) -> bool {
+        self.handlers.contains_key(&event_id)
+    }
+
+    /// Returns the handler of the event with the specified ID, if any.
+    pub fn get(&self, event_id: u32) -> Option<&dyn EventHandler<A>> {
+        self.handlers.get(&event_id).map(|handler| handler.as_ref())
+    }
+
+    /// Returns an iterator over the IDs of the events handled by this registry.
+    pub fn event_ids(&self) -> impl Iterator<Item = u32> + '_ {
+        self.handlers.keys().copied()
+    }
+
+    /// Returns the number of registered handlers.
+    pub fn len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    /// Returns true if no handlers are registered.
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    /// Handles the event with the specified ID, if a handler is registered for it.
+    ///
+    /// Returns `None` if there is no such handler.
+    pub fn handle_event(
+        &self,
+        event_id: u32,
+        process: ProcessState,
+        advice_provider: &mut A,
+    ) -> Option<Result<(), ExecutionError>> {
+        self.get(event_id).map(|handler| handler.on_event(process, advice_provider))
+    }
+}
+
+impl<A> Default for EventHandlerRegistry<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> Clone for EventHandlerRegistry<A> {
+    fn clone(&self) -> Self {
+        Self { handlers: self.handlers.clone() }
+    }
+}
+
+impl<A> fmt::Debug for EventHandlerRegistry<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.handlers.keys()).finish()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Derives an event ID from `name`.
+///
+/// The ID is computed as the first 4 bytes of the BLAKE3 hash of `name`, interpreted as a
+/// little-endian integer. Names should be namespaced to avoid collisions, e.g.
+/// `miden::my_lib::my_event`.
+pub fn event_id_from_name(name: &str) -> u32 {
+    let digest = Blake3_256::hash(name.as_bytes());
+    let bytes = Digest::as_bytes(&digest);
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use vm_core::sys_events::EVENT_U64_DIV;
+
+    use super::*;
+    use crate::MemAdviceProvider;
+
+    fn noop(_process: ProcessState, _advice: &mut MemAdviceProvider) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    #[test]
+    fn registering_duplicate_event_ids_fails() {
+        let mut registry = EventHandlerRegistry::<MemAdviceProvider>::new();
+        registry.register(1, noop).unwrap();
+        assert!(matches!(
+            registry.register(1, noop),
+            Err(EventHandlerError::DuplicateEventId(1))
+        ));
+
+        let event_id = registry.register_named("test::event", noop).unwrap();
+        assert_eq!(event_id, event_id_from_name("test::event"));
+        assert!(matches!(
+            registry.register_named("test::event", noop),
+            Err(EventHandlerError::DuplicateEventId(id)) if id == event_id
+        ));
+
+        assert!(registry.unregister(1));
+        registry.register(1, noop).unwrap();
+        assert_eq!(registry.len(), 2);
+    }
+
+    #[test]
+    fn registering_system_event_ids_fails() {
+        let mut registry = EventHandlerRegistry::<MemAdviceProvider>::new();
+        assert!(matches!(
+            registry.register(EVENT_U64_DIV, noop),
+            Err(EventHandlerError::ReservedEventId {
+                event_id: EVENT_U64_DIV,
+                system_event: SystemEvent::U64Div
+            })
+        ));
+        assert!(registry.is_empty());
+    }
+}
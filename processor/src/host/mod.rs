@@ -3,17 +3,30 @@ use alloc::sync::Arc;
 use vm_core::{DebugOptions, crypto::hash::RpoDigest, mast::MastForest};
 
 use super::{ExecutionError, ProcessState};
-use crate::{KvMap, MemAdviceProvider};
+use crate::{EventHandlerError, KvMap, MemAdviceProvider};
 
 pub(super) mod advice;
-use advice::AdviceProvider;
+use advice::{AdviceProvider, AdviceSource};
+
+#[cfg(feature = "async")]
+mod async_host;
+#[cfg(feature = "async")]
+pub(crate) use async_host::SyncHostAdapter;
+#[cfg(feature = "async")]
+pub use async_host::{AsyncHost, MemAsyncHost};
 
 #[cfg(feature = "std")]
 mod debug;
 
+mod events;
+pub use events::{EventHandler, EventHandlerRegistry, event_id_from_name};
+
 mod mast_forest_store;
 pub use mast_forest_store::{MastForestStore, MemMastForestStore};
 
+mod transcript;
+pub use transcript::{HostInteraction, HostTranscript, RecordingHost, ReplayHost};
+
 // HOST TRAIT
 // ================================================================================================
 
@@ -86,6 +99,15 @@ pub trait Host {
             err_msg: None,
         }
     }
+
+    /// Returns true if the host requests the execution of the program to be paused.
+    ///
+    /// This is only checked by executions started with [crate::Process::execute_pausable], before
+    /// each operation executed in a basic block. Hosts can use it to suspend the execution e.g.
+    /// from an event handler, and should stop requesting a pause once the execution was paused.
+    fn should_yield(&mut self) -> bool {
+        false
+    }
 }
 
 impl<H> Host for &mut H
@@ -125,15 +147,23 @@ where
     fn on_assert_failed(&mut self, process: ProcessState, err_code: u32) -> ExecutionError {
         H::on_assert_failed(self, process, err_code)
     }
+
+    fn should_yield(&mut self) -> bool {
+        H::should_yield(self)
+    }
 }
 
 // DEFAULT HOST IMPLEMENTATION
 // ================================================================================================
 
 /// A default [Host] implementation that provides the essential functionality required by the VM.
+///
+/// Events emitted by the program are dispatched to the handlers registered with
+/// [DefaultHost::register_handler].
 pub struct DefaultHost<A> {
     adv_provider: A,
     store: MemMastForestStore,
+    event_handlers: EventHandlerRegistry<A>,
 }
 
 impl<A: Clone> Clone for DefaultHost<A> {
@@ -141,6 +171,7 @@ impl<A: Clone> Clone for DefaultHost<A> {
         Self {
             adv_provider: self.adv_provider.clone(),
             store: self.store.clone(),
+            event_handlers: self.event_handlers.clone(),
         }
     }
 }
@@ -150,6 +181,7 @@ impl Default for DefaultHost<MemAdviceProvider> {
         Self {
             adv_provider: MemAdviceProvider::default(),
             store: MemMastForestStore::default(),
+            event_handlers: EventHandlerRegistry::default(),
         }
     }
 }
@@ -159,9 +191,45 @@ impl<A: AdviceProvider> DefaultHost<A> {
         Self {
             adv_provider,
             store: MemMastForestStore::default(),
+            event_handlers: EventHandlerRegistry::default(),
         }
     }
 
+    /// Registers `handler` as the handler of the event with the specified ID.
+    ///
+    /// # Errors
+    /// Returns an error if a handler for `event_id` is already registered, or if `event_id` is the
+    /// ID of a system event.
+    pub fn register_handler(
+        &mut self,
+        event_id: u32,
+        handler: impl EventHandler<A> + 'static,
+    ) -> Result<(), EventHandlerError> {
+        self.event_handlers.register(event_id, handler)
+    }
+
+    /// Registers `handler` as the handler of the event whose ID is derived from `name`, and returns
+    /// that ID.
+    ///
+    /// See [event_id_from_name] for details on how the ID is derived.
+    pub fn register_named_handler(
+        &mut self,
+        name: &str,
+        handler: impl EventHandler<A> + 'static,
+    ) -> Result<u32, EventHandlerError> {
+        self.event_handlers.register_named(name, handler)
+    }
+
+    /// Returns the registry of the event handlers of this host.
+    pub fn event_handlers(&self) -> &EventHandlerRegistry<A> {
+        &self.event_handlers
+    }
+
+    /// Returns a mutable reference to the registry of the event handlers of this host.
+    pub fn event_handlers_mut(&mut self) -> &mut EventHandlerRegistry<A> {
+        &mut self.event_handlers
+    }
+
     pub fn load_mast_forest(&mut self, mast_forest: Arc<MastForest>) -> Result<(), ExecutionError> {
         // Load the MAST's advice data into the advice provider.
 
@@ -209,13 +277,19 @@ impl<A: AdviceProvider> Host for DefaultHost<A> {
         self.store.get(node_digest)
     }
 
-    fn on_event(&mut self, _process: ProcessState, _event_id: u32) -> Result<(), ExecutionError> {
+    fn on_event(&mut self, process: ProcessState, event_id: u32) -> Result<(), ExecutionError> {
+        if let Some(result) =
+            self.event_handlers.handle_event(event_id, process, &mut self.adv_provider)
+        {
+            return result;
+        }
+
         #[cfg(feature = "std")]
         std::println!(
             "Event with id {} emitted at step {} in context {}",
-            _event_id,
-            _process.clk(),
-            _process.ctx()
+            event_id,
+            process.clk(),
+            process.ctx()
         );
         Ok(())
     }
@@ -0,0 +1,700 @@
+use alloc::{sync::Arc, vec::Vec};
+use core::cell::{Cell, RefCell};
+
+use vm_core::{
+    DebugOptions, Felt, Word,
+    crypto::{hash::RpoDigest, merkle::MerklePath},
+    mast::MastForest,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+use super::{AdviceProvider, AdviceSource, Host, ProcessState};
+use crate::ExecutionError;
+
+// HOST INTERACTION
+// ================================================================================================
+
+/// An interaction of the VM with its host, together with the response of the host.
+///
+/// Only interactions whose response can affect the execution of a program are recorded. In
+/// particular, the side effects of events on the advice provider are captured through the
+/// responses to all subsequent advice requests of the VM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostInteraction {
+    /// An element was popped from the advice stack.
+    PopStack(Felt),
+    /// A word was popped from the advice stack.
+    PopStackWord(Word),
+    /// A double word was popped from the advice stack.
+    PopStackDword([Word; 2]),
+    /// The values associated with `key` were looked up in the advice map.
+    GetMappedValues {
+        key: RpoDigest,
+        values: Option<Vec<Felt>>,
+    },
+    /// A node of a Merkle tree was looked up in the Merkle store.
+    GetTreeNode {
+        root: Word,
+        depth: Felt,
+        index: Felt,
+        node: Word,
+    },
+    /// A Merkle path was looked up in the Merkle store.
+    GetMerklePath {
+        root: Word,
+        depth: Felt,
+        index: Felt,
+        path: MerklePath,
+    },
+    /// The depth of a leaf was looked up in the Merkle store.
+    GetLeafDepth {
+        root: Word,
+        tree_depth: Felt,
+        index: Felt,
+        leaf_depth: u8,
+    },
+    /// A node of a Merkle tree was updated in the Merkle store.
+    UpdateMerkleNode {
+        root: Word,
+        depth: Felt,
+        index: Felt,
+        value: Word,
+        path: MerklePath,
+        new_root: Word,
+    },
+    /// Two Merkle trees were merged in the Merkle store.
+    MergeRoots { lhs: Word, rhs: Word, root: Word },
+    /// An event was handled by the host.
+    Event(u32),
+}
+
+impl HostInteraction {
+    /// Returns the name of the host method this interaction corresponds to.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::PopStack(_) => "pop_stack",
+            Self::PopStackWord(_) => "pop_stack_word",
+            Self::PopStackDword(_) => "pop_stack_dword",
+            Self::GetMappedValues { .. } => "get_mapped_values",
+            Self::GetTreeNode { .. } => "get_tree_node",
+            Self::GetMerklePath { .. } => "get_merkle_path",
+            Self::GetLeafDepth { .. } => "get_leaf_depth",
+            Self::UpdateMerkleNode { .. } => "update_merkle_node",
+            Self::MergeRoots { .. } => "merge_roots",
+            Self::Event(_) => "on_event",
+        }
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            Self::PopStack(_) => 0,
+            Self::PopStackWord(_) => 1,
+            Self::PopStackDword(_) => 2,
+            Self::GetMappedValues { .. } => 3,
+            Self::GetTreeNode { .. } => 4,
+            Self::GetMerklePath { .. } => 5,
+            Self::GetLeafDepth { .. } => 6,
+            Self::UpdateMerkleNode { .. } => 7,
+            Self::MergeRoots { .. } => 8,
+            Self::Event(_) => 9,
+        }
+    }
+}
+
+impl Serializable for HostInteraction {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.tag());
+        match self {
+            Self::PopStack(value) => value.write_into(target),
+            Self::PopStackWord(word) => word.write_into(target),
+            Self::PopStackDword(words) => words.write_into(target),
+            Self::GetMappedValues { key, values } => {
+                key.write_into(target);
+                values.write_into(target);
+            },
+            Self::GetTreeNode { root, depth, index, node } => {
+                (root, depth, index).write_into(target);
+                node.write_into(target);
+            },
+            Self::GetMerklePath { root, depth, index, path } => {
+                (root, depth, index).write_into(target);
+                path.write_into(target);
+            },
+            Self::GetLeafDepth { root, tree_depth, index, leaf_depth } => {
+                (root, tree_depth, index).write_into(target);
+                target.write_u8(*leaf_depth);
+            },
+            Self::UpdateMerkleNode {
+                root,
+                depth,
+                index,
+                value,
+                path,
+                new_root,
+            } => {
+                (root, depth, index).write_into(target);
+                value.write_into(target);
+                path.write_into(target);
+                new_root.write_into(target);
+            },
+            Self::MergeRoots { lhs, rhs, root } => {
+                (lhs, rhs, root).write_into(target);
+            },
+            Self::Event(event_id) => target.write_u32(*event_id),
+        }
+    }
+}
+
+impl Deserializable for HostInteraction {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let interaction = match source.read_u8()? {
+            0 => Self::PopStack(source.read()?),
+            1 => Self::PopStackWord(source.read()?),
+            2 => Self::PopStackDword(source.read()?),
+            3 => Self::GetMappedValues {
+                key: source.read()?,
+                values: source.read()?,
+            },
+            4 => Self::GetTreeNode {
+                root: source.read()?,
+                depth: source.read()?,
+                index: source.read()?,
+                node: source.read()?,
+            },
+            5 => Self::GetMerklePath {
+                root: source.read()?,
+                depth: source.read()?,
+                index: source.read()?,
+                path: source.read()?,
+            },
+            6 => Self::GetLeafDepth {
+                root: source.read()?,
+                tree_depth: source.read()?,
+                index: source.read()?,
+                leaf_depth: source.read_u8()?,
+            },
+            7 => Self::UpdateMerkleNode {
+                root: source.read()?,
+                depth: source.read()?,
+                index: source.read()?,
+                value: source.read()?,
+                path: source.read()?,
+                new_root: source.read()?,
+            },
+            8 => Self::MergeRoots {
+                lhs: source.read()?,
+                rhs: source.read()?,
+                root: source.read()?,
+            },
+            9 => Self::Event(source.read_u32()?),
+            tag => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid host interaction tag: {tag}"
+                )));
+            },
+        };
+        Ok(interaction)
+    }
+}
+
+// HOST TRANSCRIPT
+// ================================================================================================
+
+/// A record of all interactions of the VM with its host during the execution of a program, as
+/// captured by a [RecordingHost].
+///
+/// A transcript contains everything needed to re-execute, or prove, the execution of the program
+/// using a [ReplayHost], without access to the original host.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HostTranscript {
+    interactions: Vec<HostInteraction>,
+    mast_forests: Vec<Arc<MastForest>>,
+}
+
+impl HostTranscript {
+    /// Returns the interactions of the VM with the host, in the order they happened in.
+    pub fn interactions(&self) -> &[HostInteraction] {
+        &self.interactions
+    }
+
+    /// Returns the MAST forests served by the host.
+    pub fn mast_forests(&self) -> &[Arc<MastForest>] {
+        &self.mast_forests
+    }
+}
+
+impl Serializable for HostTranscript {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.interactions.write_into(target);
+        target.write_usize(self.mast_forests.len());
+        for mast_forest in self.mast_forests.iter() {
+            mast_forest.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for HostTranscript {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let interactions = source.read()?;
+        let num_mast_forests = source.read_usize()?;
+        let mast_forests = source
+            .read_many::<MastForest>(num_mast_forests)?
+            .into_iter()
+            .map(Arc::new)
+            .collect();
+        Ok(Self { interactions, mast_forests })
+    }
+}
+
+// RECORDING HOST
+// ================================================================================================
+
+/// A [Host] which wraps another host, and records all interactions of the VM with it into a
+/// [HostTranscript].
+///
+/// The recording host acts as its own advice provider, so that it can record the requests made by
+/// the VM, while forwarding them to the advice provider of the wrapped host. Requests made by the
+/// wrapped host itself, e.g. while handling an event, are not recorded.
+pub struct RecordingHost<H> {
+    host: H,
+    interactions: RefCell<Vec<HostInteraction>>,
+    mast_forests: RefCell<Vec<Arc<MastForest>>>,
+}
+
+impl<H: Host> RecordingHost<H> {
+    /// Returns a new [RecordingHost] wrapping `host`.
+    pub fn new(host: H) -> Self {
+        Self {
+            host,
+            interactions: RefCell::default(),
+            mast_forests: RefCell::default(),
+        }
+    }
+
+    /// Returns a reference to the wrapped host.
+    pub fn inner(&self) -> &H {
+        &self.host
+    }
+
+    /// Returns the transcript of the interactions recorded so far.
+    pub fn transcript(&self) -> HostTranscript {
+        HostTranscript {
+            interactions: self.interactions.borrow().clone(),
+            mast_forests: self.mast_forests.borrow().clone(),
+        }
+    }
+
+    /// Consumes the recording host, and returns the wrapped host together with the transcript of
+    /// the recorded interactions.
+    pub fn into_parts(self) -> (H, HostTranscript) {
+        let transcript = HostTranscript {
+            interactions: self.interactions.into_inner(),
+            mast_forests: self.mast_forests.into_inner(),
+        };
+        (self.host, transcript)
+    }
+
+    /// Records `interaction`, if the response of the host was successful.
+    fn record<T>(
+        &self,
+        response: Result<T, ExecutionError>,
+        interaction: impl FnOnce(&T) -> HostInteraction,
+    ) -> Result<T, ExecutionError> {
+        if let Ok(value) = response.as_ref() {
+            self.interactions.borrow_mut().push(interaction(value));
+        }
+        response
+    }
+}
+
+impl<H: Host> Host for RecordingHost<H> {
+    type AdviceProvider = Self;
+
+    fn advice_provider(&self) -> &Self::AdviceProvider {
+        self
+    }
+
+    fn advice_provider_mut(&mut self) -> &mut Self::AdviceProvider {
+        self
+    }
+
+    fn get_mast_forest(&self, node_digest: &RpoDigest) -> Option<Arc<MastForest>> {
+        let mast_forest = self.host.get_mast_forest(node_digest)?;
+        let mut mast_forests = self.mast_forests.borrow_mut();
+        if !mast_forests.iter().any(|recorded| Arc::ptr_eq(recorded, &mast_forest)) {
+            mast_forests.push(mast_forest.clone());
+        }
+        Some(mast_forest)
+    }
+
+    fn on_event(&mut self, process: ProcessState, event_id: u32) -> Result<(), ExecutionError> {
+        self.host.on_event(process, event_id)?;
+        self.interactions.get_mut().push(HostInteraction::Event(event_id));
+        Ok(())
+    }
+
+    fn on_debug(
+        &mut self,
+        process: ProcessState,
+        options: &DebugOptions,
+    ) -> Result<(), ExecutionError> {
+        self.host.on_debug(process, options)
+    }
+
+    fn on_trace(&mut self, process: ProcessState, trace_id: u32) -> Result<(), ExecutionError> {
+        self.host.on_trace(process, trace_id)
+    }
+
+    fn on_assert_failed(&mut self, process: ProcessState, err_code: u32) -> ExecutionError {
+        self.host.on_assert_failed(process, err_code)
+    }
+
+    fn should_yield(&mut self) -> bool {
+        self.host.should_yield()
+    }
+}
+
+impl<H: Host> AdviceProvider for RecordingHost<H> {
+    fn pop_stack(&mut self, process: ProcessState) -> Result<Felt, ExecutionError> {
+        let response = self.host.advice_provider_mut().pop_stack(process);
+        self.record(response, |value| HostInteraction::PopStack(*value))
+    }
+
+    fn pop_stack_word(&mut self, process: ProcessState) -> Result<Word, ExecutionError> {
+        let response = self.host.advice_provider_mut().pop_stack_word(process);
+        self.record(response, |word| HostInteraction::PopStackWord(*word))
+    }
+
+    fn pop_stack_dword(&mut self, process: ProcessState) -> Result<[Word; 2], ExecutionError> {
+        let response = self.host.advice_provider_mut().pop_stack_dword(process);
+        self.record(response, |words| HostInteraction::PopStackDword(*words))
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        self.host.advice_provider_mut().push_stack(source)
+    }
+
+    fn get_mapped_values(&self, key: &RpoDigest) -> Option<&[Felt]> {
+        let values = self.host.advice_provider().get_mapped_values(key);
+        self.interactions.borrow_mut().push(HostInteraction::GetMappedValues {
+            key: *key,
+            values: values.map(|values| values.to_vec()),
+        });
+        values
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) {
+        self.host.advice_provider_mut().insert_into_map(key, values)
+    }
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        let response = self.host.advice_provider().get_tree_node(root, depth, index);
+        self.record(response, |node| HostInteraction::GetTreeNode {
+            root,
+            depth: *depth,
+            index: *index,
+            node: *node,
+        })
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        let response = self.host.advice_provider().get_merkle_path(root, depth, index);
+        self.record(response, |path| HostInteraction::GetMerklePath {
+            root,
+            depth: *depth,
+            index: *index,
+            path: path.clone(),
+        })
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        let response = self.host.advice_provider().get_leaf_depth(root, tree_depth, index);
+        self.record(response, |leaf_depth| HostInteraction::GetLeafDepth {
+            root,
+            tree_depth: *tree_depth,
+            index: *index,
+            leaf_depth: *leaf_depth,
+        })
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+        value: Word,
+    ) -> Result<(MerklePath, Word), ExecutionError> {
+        let response =
+            self.host.advice_provider_mut().update_merkle_node(root, depth, index, value);
+        self.record(response, |(path, new_root)| HostInteraction::UpdateMerkleNode {
+            root,
+            depth: *depth,
+            index: *index,
+            value,
+            path: path.clone(),
+            new_root: *new_root,
+        })
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        let response = self.host.advice_provider_mut().merge_roots(lhs, rhs);
+        self.record(response, |root| HostInteraction::MergeRoots { lhs, rhs, root: *root })
+    }
+}
+
+// REPLAY HOST
+// ================================================================================================
+
+/// A [Host] which serves all requests of the VM from a [HostTranscript] recorded by a
+/// [RecordingHost].
+///
+/// Replaying a transcript reproduces the execution it was recorded from exactly, so the replay host
+/// can be used to re-execute, or prove, a program without access to the original host. Requests
+/// which do not match the transcript, e.g. because a different program is executed, result in an
+/// [ExecutionError::TranscriptMismatch] error.
+///
+/// Values pushed onto the advice stack, or inserted into the advice map or the Merkle store, are
+/// discarded, as all subsequent requests are served from the transcript.
+///
+/// Once a request does not match the transcript, all subsequent requests fail with the error of
+/// that first mismatch. Advice map lookups cannot fail, so a mismatching lookup is reported by the
+/// next fallible request, or by [ReplayHost::finish] if there is none.
+#[derive(Debug, Default)]
+pub struct ReplayHost {
+    transcript: HostTranscript,
+    next_interaction: Cell<usize>,
+    mismatch: Cell<Option<(usize, &'static str)>>,
+}
+
+impl ReplayHost {
+    /// Returns a new [ReplayHost] which serves requests from `transcript`.
+    pub fn new(transcript: HostTranscript) -> Self {
+        Self {
+            transcript,
+            next_interaction: Cell::new(0),
+            mismatch: Cell::new(None),
+        }
+    }
+
+    /// Returns the number of interactions of the transcript which have not been replayed yet.
+    pub fn num_remaining_interactions(&self) -> usize {
+        self.transcript.interactions.len() - self.next_interaction.get()
+    }
+
+    /// Finishes replaying the transcript.
+    ///
+    /// # Errors
+    /// Returns an error if a request did not match the transcript, including advice map lookups
+    /// whose mismatch could not be reported to the VM.
+    pub fn finish(&self) -> Result<(), ExecutionError> {
+        match self.mismatch.get() {
+            Some((index, request)) => Err(ExecutionError::TranscriptMismatch { index, request }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the next interaction of the transcript, if it satisfies `is_match`.
+    ///
+    /// # Errors
+    /// Returns an error if the transcript has no more interactions, if the next interaction does
+    /// not satisfy `is_match`, or if a previous request did not match the transcript.
+    fn replay<'a, T>(
+        &'a self,
+        request: &'static str,
+        is_match: impl FnOnce(&'a HostInteraction) -> Option<T>,
+    ) -> Result<T, ExecutionError> {
+        self.finish()?;
+
+        let index = self.next_interaction.get();
+        let response = self.transcript.interactions.get(index).and_then(is_match);
+        match response {
+            Some(response) => {
+                self.next_interaction.set(index + 1);
+                Ok(response)
+            },
+            None => {
+                self.mismatch.set(Some((index, request)));
+                Err(ExecutionError::TranscriptMismatch { index, request })
+            },
+        }
+    }
+}
+
+impl Host for ReplayHost {
+    type AdviceProvider = Self;
+
+    fn advice_provider(&self) -> &Self::AdviceProvider {
+        self
+    }
+
+    fn advice_provider_mut(&mut self) -> &mut Self::AdviceProvider {
+        self
+    }
+
+    fn get_mast_forest(&self, node_digest: &RpoDigest) -> Option<Arc<MastForest>> {
+        self.transcript
+            .mast_forests
+            .iter()
+            .find(|mast_forest| mast_forest.find_procedure_root(*node_digest).is_some())
+            .cloned()
+    }
+
+    fn on_event(&mut self, _process: ProcessState, event_id: u32) -> Result<(), ExecutionError> {
+        self.replay("on_event", |interaction| match interaction {
+            HostInteraction::Event(id) if *id == event_id => Some(()),
+            _ => None,
+        })
+    }
+}
+
+impl AdviceProvider for ReplayHost {
+    fn pop_stack(&mut self, _process: ProcessState) -> Result<Felt, ExecutionError> {
+        self.replay("pop_stack", |interaction| match interaction {
+            HostInteraction::PopStack(value) => Some(*value),
+            _ => None,
+        })
+    }
+
+    fn pop_stack_word(&mut self, _process: ProcessState) -> Result<Word, ExecutionError> {
+        self.replay("pop_stack_word", |interaction| match interaction {
+            HostInteraction::PopStackWord(word) => Some(*word),
+            _ => None,
+        })
+    }
+
+    fn pop_stack_dword(&mut self, _process: ProcessState) -> Result<[Word; 2], ExecutionError> {
+        self.replay("pop_stack_dword", |interaction| match interaction {
+            HostInteraction::PopStackDword(words) => Some(*words),
+            _ => None,
+        })
+    }
+
+    fn push_stack(&mut self, _source: AdviceSource) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    fn get_mapped_values(&self, key: &RpoDigest) -> Option<&[Felt]> {
+        // a mismatch is recorded by `replay`, and reported by the next fallible request
+        self.replay("get_mapped_values", |interaction| match interaction {
+            HostInteraction::GetMappedValues { key: recorded_key, values }
+                if recorded_key == key =>
+            {
+                Some(values.as_deref())
+            },
+            _ => None,
+        })
+        .ok()
+        .flatten()
+    }
+
+    fn insert_into_map(&mut self, _key: Word, _values: Vec<Felt>) {}
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        self.replay("get_tree_node", |interaction| match interaction {
+            HostInteraction::GetTreeNode {
+                root: recorded_root,
+                depth: recorded_depth,
+                index: recorded_index,
+                node,
+            } if (recorded_root, recorded_depth, recorded_index) == (&root, depth, index) => {
+                Some(*node)
+            },
+            _ => None,
+        })
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        self.replay("get_merkle_path", |interaction| match interaction {
+            HostInteraction::GetMerklePath {
+                root: recorded_root,
+                depth: recorded_depth,
+                index: recorded_index,
+                path,
+            } if (recorded_root, recorded_depth, recorded_index) == (&root, depth, index) => {
+                Some(path.clone())
+            },
+            _ => None,
+        })
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        self.replay("get_leaf_depth", |interaction| match interaction {
+            HostInteraction::GetLeafDepth {
+                root: recorded_root,
+                tree_depth: recorded_tree_depth,
+                index: recorded_index,
+                leaf_depth,
+            } if (recorded_root, recorded_tree_depth, recorded_index)
+                == (&root, tree_depth, index) =>
+            {
+                Some(*leaf_depth)
+            },
+            _ => None,
+        })
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+        value: Word,
+    ) -> Result<(MerklePath, Word), ExecutionError> {
+        self.replay("update_merkle_node", |interaction| match interaction {
+            HostInteraction::UpdateMerkleNode {
+                root: recorded_root,
+                depth: recorded_depth,
+                index: recorded_index,
+                value: recorded_value,
+                path,
+                new_root,
+            } if (recorded_root, recorded_depth, recorded_index, recorded_value)
+                == (&root, depth, index, &value) =>
+            {
+                Some((path.clone(), *new_root))
+            },
+            _ => None,
+        })
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        self.replay("merge_roots", |interaction| match interaction {
+            HostInteraction::MergeRoots {
+                lhs: recorded_lhs,
+                rhs: recorded_rhs,
+                root,
+            } if (recorded_lhs, recorded_rhs) == (&lhs, &rhs) => Some(*root),
+            _ => None,
+        })
+    }
+}
@@ -6,7 +6,7 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
-use alloc::vec::Vec;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use miden_air::trace::{
     CHIPLETS_WIDTH, DECODER_TRACE_WIDTH, MIN_TRACE_LEN, RANGE_CHECK_TRACE_WIDTH, STACK_TRACE_WIDTH,
@@ -26,7 +26,8 @@ pub use vm_core::{
 use vm_core::{
     Decorator, DecoratorIterator, FieldElement,
     mast::{
-        BasicBlockNode, CallNode, DynNode, JoinNode, LoopNode, OP_GROUP_SIZE, OpBatch, SplitNode,
+        BasicBlockNode, CallNode, DynNode, ExternalNode, JoinNode, LoopNode, OP_GROUP_SIZE,
+        OpBatch, SplitNode,
     },
 };
 pub use winter_prover::matrix::ColMatrix;
@@ -47,9 +48,13 @@ mod range;
 use range::RangeChecker;
 
 mod host;
+#[cfg(feature = "async")]
+pub use host::{AsyncHost, MemAsyncHost};
 pub use host::{
-    DefaultHost, Host, MastForestStore, MemMastForestStore,
+    DefaultHost, EventHandler, EventHandlerRegistry, Host, HostInteraction, HostTranscript,
+    MastForestStore, MemMastForestStore, RecordingHost, ReplayHost,
     advice::{AdviceInputs, AdviceProvider, AdviceSource, MemAdviceProvider, RecAdviceProvider},
+    event_id_from_name,
 };
 
 mod chiplets;
@@ -58,9 +63,27 @@ use chiplets::Chiplets;
 mod trace;
 use trace::TraceFragment;
 pub use trace::{ChipletsLengths, ExecutionTrace, NUM_RAND_ROWS, TraceLenSummary};
+#[cfg(feature = "std")]
+pub use trace::{TRACE_EXPORT_MAGIC, TRACE_EXPORT_VERSION, TraceExportFormat, TraceExportOptions};
+
+#[cfg(feature = "async")]
+mod async_executor;
+#[cfg(feature = "async")]
+pub use async_executor::execute_async;
 
 mod errors;
-pub use errors::{ExecutionError, Ext2InttError};
+pub use errors::{EventHandlerError, ExecutionError, Ext2InttError};
+
+mod snapshot;
+use snapshot::{BasicBlockPosition, Interrupt, PauseState, ResumeFrame};
+pub use snapshot::{ExecutionStatus, ProcessSnapshot};
+
+mod constraints;
+pub use constraints::{ConstraintKind, ConstraintViolation, check_constraints};
+
+mod coverage;
+use coverage::CoverageRecorder;
+pub use coverage::{BranchCoverage, BranchKind, CoverageData, CoverageReport, FileCoverage};
 
 pub mod utils;
 
@@ -78,7 +101,8 @@ pub mod math {
 pub mod crypto {
     pub use vm_core::crypto::{
         hash::{
-            Blake3_192, Blake3_256, ElementHasher, Hasher, Rpo256, RpoDigest, Rpx256, RpxDigest,
+            Blake3_192, Blake3_256, ElementHasher, Hasher, Poseidon2, Poseidon2Digest, Rpo256,
+            RpoDigest, Rpx256, RpxDigest,
         },
         merkle::{
             MerkleError, MerklePath, MerkleStore, MerkleTree, NodeIndex, PartialMerkleTree,
@@ -155,6 +179,29 @@ pub fn execute_iter(
     VmStateIterator::new(process, result)
 }
 
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, and merges the coverage of the execution into the provided coverage data.
+///
+/// The coverage is merged even if the execution fails. Only programs and libraries assembled in
+/// debug mode contain the source locations needed to record coverage.
+pub fn execute_with_coverage(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: &mut impl Host,
+    options: ExecutionOptions,
+    coverage: &mut CoverageData,
+) -> Result<ExecutionTrace, ExecutionError> {
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, options);
+    process.enable_coverage();
+    let result = process.execute(program, host);
+    if let Some(data) = process.take_coverage() {
+        coverage.merge(&data);
+    }
+    let trace = ExecutionTrace::new(process, result?);
+    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
+    Ok(trace)
+}
+
 // PROCESS
 // ================================================================================================
 
@@ -176,6 +223,8 @@ pub struct Process {
     chiplets: Chiplets,
     max_cycles: u32,
     enable_tracing: bool,
+    pause: PauseState,
+    coverage: Option<Box<CoverageRecorder>>,
 }
 
 #[cfg(any(test, feature = "testing"))]
@@ -187,6 +236,8 @@ pub struct Process {
     pub chiplets: Chiplets,
     pub max_cycles: u32,
     pub enable_tracing: bool,
+    pause: PauseState,
+    coverage: Option<Box<CoverageRecorder>>,
 }
 
 impl Process {
@@ -220,9 +271,30 @@ impl Process {
             chiplets: Chiplets::new(kernel),
             max_cycles: execution_options.max_cycles(),
             enable_tracing: execution_options.enable_tracing(),
+            pause: PauseState::default(),
+            coverage: None,
         }
     }
 
+    // COVERAGE
+    // --------------------------------------------------------------------------------------------
+
+    /// Enables recording the coverage of the programs executed in this process.
+    ///
+    /// See [CoverageData] for details on what is recorded.
+    pub fn enable_coverage(&mut self) {
+        if self.coverage.is_none() {
+            self.coverage = Some(Box::default());
+        }
+    }
+
+    /// Returns the coverage recorded since it was enabled, and disables recording it.
+    ///
+    /// Returns `None` if recording the coverage was not enabled.
+    pub fn take_coverage(&mut self) -> Option<CoverageData> {
+        self.coverage.take().map(|recorder| recorder.into_data())
+    }
+
     // PROGRAM EXECUTOR
     // --------------------------------------------------------------------------------------------
 
@@ -236,18 +308,13 @@ impl Process {
             return Err(ExecutionError::ProgramAlreadyExecuted);
         }
 
-        // Load the program's advice data into the advice provider
-        for (digest, values) in program.mast_forest().advice_map().iter() {
-            if let Some(stored_values) = host.advice_provider().get_mapped_values(digest) {
-                if stored_values != values {
-                    return Err(ExecutionError::AdviceMapKeyAlreadyPresent(digest.into()));
-                }
-            } else {
-                host.advice_provider_mut().insert_into_map(digest.into(), values.clone());
-            }
+        load_advice_map(program.mast_forest(), host.advice_provider_mut())?;
+        if let Some(coverage) = &mut self.coverage {
+            coverage.add_forest(program.mast_forest());
         }
 
-        self.execute_mast_node(program.entrypoint(), &program.mast_forest().clone(), host)?;
+        self.execute_mast_node(program.entrypoint(), &program.mast_forest().clone(), host)
+            .map_err(Interrupt::into_error)?;
 
         self.stack.build_stack_outputs()
     }
@@ -255,54 +322,45 @@ impl Process {
     // NODE EXECUTORS
     // --------------------------------------------------------------------------------------------
 
+    /// Executes the specified node.
+    ///
+    /// When resuming a paused execution, the node has already been entered, and its execution
+    /// continues from the point described by the next frame of the paused execution.
     fn execute_mast_node(
         &mut self,
         node_id: MastNodeId,
         program: &MastForest,
         host: &mut impl Host,
-    ) -> Result<(), ExecutionError> {
+    ) -> Result<(), Interrupt> {
         let node = program
             .get_node_by_id(node_id)
             .ok_or(ExecutionError::MastNodeNotFoundInForest { node_id })?;
 
-        for &decorator_id in node.before_enter() {
-            self.execute_decorator(&program[decorator_id], host)?;
-        }
+        let resume_frame = self.enter_mast_node(node, program, host)?;
 
         match node {
-            MastNode::Block(node) => self.execute_basic_block_node(node, program, host)?,
-            MastNode::Join(node) => self.execute_join_node(node, program, host)?,
-            MastNode::Split(node) => self.execute_split_node(node, program, host)?,
-            MastNode::Loop(node) => self.execute_loop_node(node, program, host)?,
-            MastNode::Call(node) => self.execute_call_node(node, program, host)?,
-            MastNode::Dyn(node) => self.execute_dyn_node(node, program, host)?,
+            MastNode::Block(node) => {
+                self.execute_basic_block_node(node, program, host, resume_frame)?
+            },
+            MastNode::Join(node) => self.execute_join_node(node, program, host, resume_frame)?,
+            MastNode::Split(node) => self.execute_split_node(node, program, host, resume_frame)?,
+            MastNode::Loop(node) => self.execute_loop_node(node, program, host, resume_frame)?,
+            MastNode::Call(node) => self.execute_call_node(node, program, host, resume_frame)?,
+            MastNode::Dyn(node) => self.execute_dyn_node(node, program, host, resume_frame)?,
             MastNode::External(external_node) => {
-                let node_digest = external_node.digest();
-                let mast_forest = host.get_mast_forest(&node_digest).ok_or(
-                    ExecutionError::NoMastForestWithProcedure { root_digest: node_digest },
-                )?;
-
-                // We limit the parts of the program that can be called externally to procedure
-                // roots, even though MAST doesn't have that restriction.
-                let root_id = mast_forest.find_procedure_root(node_digest).ok_or(
-                    ExecutionError::MalformedMastForestInHost { root_digest: node_digest },
-                )?;
-
-                // if the node that we got by looking up an external reference is also an External
-                // node, we are about to enter into an infinite loop - so, return an error
-                if mast_forest[root_id].is_external() {
-                    return Err(ExecutionError::CircularExternalNode(node_digest));
+                if !matches!(resume_frame, None | Some(ResumeFrame::External)) {
+                    return Err(ExecutionError::InvalidPausedState.into());
                 }
 
-                self.execute_mast_node(root_id, &mast_forest, host)?;
+                let mast_forest = host.get_mast_forest(&external_node.digest());
+                let (mast_forest, root_id) =
+                    self.resolve_external_node(external_node, mast_forest)?;
+                self.execute_mast_node(root_id, &mast_forest, host)
+                    .map_err(|err| self.record_frame(err, ResumeFrame::External))?;
             },
         }
 
-        for &decorator_id in node.after_exit() {
-            self.execute_decorator(&program[decorator_id], host)?;
-        }
-
-        Ok(())
+        Ok(self.exit_mast_node(node, program, host)?)
     }
 
     /// Executes the specified [JoinNode].
@@ -312,14 +370,21 @@ impl Process {
         node: &JoinNode,
         program: &MastForest,
         host: &mut impl Host,
-    ) -> Result<(), ExecutionError> {
-        self.start_join_node(node, program, host)?;
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
+        let first_child_executed = self.enter_join_node(node, program, host, resume_frame)?;
 
         // execute first and then second child of the join block
-        self.execute_mast_node(node.first(), program, host)?;
-        self.execute_mast_node(node.second(), program, host)?;
+        if !first_child_executed {
+            self.execute_mast_node(node.first(), program, host).map_err(|err| {
+                self.record_frame(err, ResumeFrame::Join { first_child_executed: false })
+            })?;
+        }
+        self.execute_mast_node(node.second(), program, host).map_err(|err| {
+            self.record_frame(err, ResumeFrame::Join { first_child_executed: true })
+        })?;
 
-        self.end_join_node(node, host)
+        Ok(self.end_join_node(node, host)?)
     }
 
     /// Executes the specified [SplitNode].
@@ -329,20 +394,18 @@ impl Process {
         node: &SplitNode,
         program: &MastForest,
         host: &mut impl Host,
-    ) -> Result<(), ExecutionError> {
-        // start the SPLIT block; this also pops the stack and returns the popped element
-        let condition = self.start_split_node(node, program, host)?;
-
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
         // execute either the true or the false branch of the split block based on the condition
-        if condition == ONE {
-            self.execute_mast_node(node.on_true(), program, host)?;
-        } else if condition == ZERO {
-            self.execute_mast_node(node.on_false(), program, host)?;
+        if self.enter_split_node(node, program, host, resume_frame)? {
+            self.execute_mast_node(node.on_true(), program, host)
+                .map_err(|err| self.record_frame(err, ResumeFrame::Split { on_true: true }))?;
         } else {
-            return Err(ExecutionError::NotBinaryValue(condition));
+            self.execute_mast_node(node.on_false(), program, host)
+                .map_err(|err| self.record_frame(err, ResumeFrame::Split { on_true: false }))?;
         }
 
-        self.end_split_node(node, host)
+        Ok(self.end_split_node(node, host)?)
     }
 
     /// Executes the specified [LoopNode].
@@ -352,37 +415,23 @@ impl Process {
         node: &LoopNode,
         program: &MastForest,
         host: &mut impl Host,
-    ) -> Result<(), ExecutionError> {
-        // start the LOOP block; this also pops the stack and returns the popped element
-        let condition = self.start_loop_node(node, program, host)?;
-
-        // if the top of the stack is ONE, execute the loop body; otherwise skip the loop body
-        if condition == ONE {
-            // execute the loop body at least once
-            self.execute_mast_node(node.body(), program, host)?;
-
-            // keep executing the loop body until the condition on the top of the stack is no
-            // longer ONE; each iteration of the loop is preceded by executing REPEAT operation
-            // which drops the condition from the stack
-            while self.stack.peek() == ONE {
-                self.decoder.repeat();
-                self.execute_op(Operation::Drop, host)?;
-                self.execute_mast_node(node.body(), program, host)?;
-            }
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
+        if !self.enter_loop_node(node, program, host, resume_frame)? {
+            return Ok(());
+        }
 
-            if self.stack.peek() != ZERO {
-                return Err(ExecutionError::NotBinaryValue(self.stack.peek()));
+        // execute the loop body at least once (or finish the iteration which was paused), and
+        // keep executing it until the condition on the top of the stack is no longer ONE
+        loop {
+            self.execute_mast_node(node.body(), program, host)
+                .map_err(|err| self.record_frame(err, ResumeFrame::Loop))?;
+            if !self.repeat_loop_body(host)? {
+                break;
             }
-
-            // end the LOOP block and drop the condition from the stack
-            self.end_loop_node(node, true, host)
-        } else if condition == ZERO {
-            // end the LOOP block, but don't drop the condition from the stack because it was
-            // already dropped when we started the LOOP block
-            self.end_loop_node(node, false, host)
-        } else {
-            Err(ExecutionError::NotBinaryValue(condition))
         }
+
+        Ok(self.exit_loop_node(node, host)?)
     }
 
     /// Executes the specified [CallNode].
@@ -392,24 +441,12 @@ impl Process {
         call_node: &CallNode,
         program: &MastForest,
         host: &mut impl Host,
-    ) -> Result<(), ExecutionError> {
-        // call or syscall are not allowed inside a syscall
-        if self.system.in_syscall() {
-            let instruction = if call_node.is_syscall() { "syscall" } else { "call" };
-            return Err(ExecutionError::CallInSyscall(instruction));
-        }
-
-        // if this is a syscall, make sure the call target exists in the kernel
-        if call_node.is_syscall() {
-            let callee = program.get_node_by_id(call_node.callee()).ok_or_else(|| {
-                ExecutionError::MastNodeNotFoundInForest { node_id: call_node.callee() }
-            })?;
-            self.chiplets.kernel_rom.access_proc(callee.digest())?;
-        }
-
-        self.start_call_node(call_node, program, host)?;
-        self.execute_mast_node(call_node.callee(), program, host)?;
-        self.end_call_node(call_node, host)
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
+        self.enter_call_node(call_node, program, host, resume_frame)?;
+        self.execute_mast_node(call_node.callee(), program, host)
+            .map_err(|err| self.record_frame(err, ResumeFrame::Call))?;
+        Ok(self.end_call_node(call_node, host)?)
     }
 
     /// Executes the specified [vm_core::mast::DynNode].
@@ -422,38 +459,363 @@ impl Process {
         node: &DynNode,
         program: &MastForest,
         host: &mut impl Host,
-    ) -> Result<(), ExecutionError> {
-        // dyn calls are not allowed inside a syscall
-        if node.is_dyncall() && self.system.in_syscall() {
-            return Err(ExecutionError::CallInSyscall("dyncall"));
-        }
-
-        let callee_hash = if node.is_dyncall() {
-            self.start_dyncall_node(node)?
-        } else {
-            self.start_dyn_node(node, host)?
-        };
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
+        let callee_hash = self.enter_dyn_node(node, host, resume_frame)?;
 
         // if the callee is not in the program's MAST forest, try to find a MAST forest for it in
         // the host (corresponding to an external library loaded in the host); if none are
         // found, return an error.
-        match program.find_procedure_root(callee_hash.into()) {
-            Some(callee_id) => self.execute_mast_node(callee_id, program, host)?,
+        let result = match program.find_procedure_root(callee_hash.into()) {
+            Some(callee_id) => self.execute_mast_node(callee_id, program, host),
+            None => {
+                let mast_forest = host.get_mast_forest(&callee_hash.into());
+                let (mast_forest, root_id) = self.resolve_dyn_callee(callee_hash, mast_forest)?;
+                self.execute_mast_node(root_id, &mast_forest, host)
+            },
+        };
+        result.map_err(|err| self.record_frame(err, ResumeFrame::Dyn { callee_hash }))?;
+
+        Ok(self.exit_dyn_node(node, host)?)
+    }
+
+    /// Executes the specified [BasicBlockNode].
+    #[inline(always)]
+    fn execute_basic_block_node(
+        &mut self,
+        basic_block: &BasicBlockNode,
+        program: &MastForest,
+        host: &mut impl Host,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), Interrupt> {
+        let (position, mut op_offset, mut decorator_ids) =
+            self.enter_basic_block_node(basic_block, host, resume_frame)?;
+
+        // execute the operation batches, starting from the one in which the execution was paused
+        for (batch_idx, op_batch) in
+            basic_block.op_batches().iter().enumerate().skip(position.batch_idx)
+        {
+            let batch_position = self.enter_op_batch(op_batch, batch_idx, position, host)?;
+            self.execute_op_batch(
+                op_batch,
+                batch_position,
+                &mut decorator_ids,
+                op_offset,
+                program,
+                host,
+            )?;
+            op_offset += op_batch.ops().len();
+        }
+
+        Ok(self.exit_basic_block_node(basic_block, decorator_ids, program, host)?)
+    }
+
+    /// Executes the operations in an [OpBatch], starting from the specified position. This also
+    /// ensures that all alignment rules are satisfied by executing NOOPs as needed. Specifically:
+    /// - If an operation group ends with an operation carrying an immediate value, a NOOP is
+    ///   executed after it.
+    /// - If the number of groups in a batch is not a power of 2, NOOPs are executed (one per group)
+    ///   to bring it up to the next power of two (e.g., 3 -> 4, 5 -> 8).
+    ///
+    /// If the execution is pausable, it is paused before executing an operation once the cycle
+    /// budget is exhausted, or if the host requests it.
+    #[inline(always)]
+    fn execute_op_batch(
+        &mut self,
+        batch: &OpBatch,
+        mut position: BasicBlockPosition,
+        decorators: &mut DecoratorIterator,
+        op_offset: usize,
+        program: &MastForest,
+        host: &mut impl Host,
+    ) -> Result<(), Interrupt> {
+        // execute operations in the batch one by one
+        while let Some(op) =
+            self.decode_next_op(batch, &position, decorators, op_offset, program, host)?
+        {
+            self.execute_op(op, host)?;
+            self.advance_op_batch(batch, op, &mut position, host)?;
+        }
+
+        Ok(self.end_op_batch(batch, &position, host)?)
+    }
+
+    // NODE EXECUTION STEPS
+    // --------------------------------------------------------------------------------------------
+    // The steps below are shared by the synchronous node executors above and the asynchronous ones,
+    // which only differ in how they invoke the host to get MAST forests and to handle events.
+
+    /// Starts executing the specified node, and returns the frame from which its execution
+    /// continues when resuming a paused execution.
+    ///
+    /// The decorators preceding the node are only executed when the node is entered for the first
+    /// time.
+    #[inline(always)]
+    fn enter_mast_node(
+        &mut self,
+        node: &MastNode,
+        program: &MastForest,
+        host: &mut impl Host,
+    ) -> Result<Option<ResumeFrame>, ExecutionError> {
+        let resume_frame = self.pause.frames.pop();
+        if resume_frame.is_none() {
+            for &decorator_id in node.before_enter() {
+                self.execute_decorator(&program[decorator_id], host)?;
+            }
+        }
+
+        Ok(resume_frame)
+    }
+
+    /// Executes the decorators following the specified node, once its execution is complete.
+    #[inline(always)]
+    fn exit_mast_node(
+        &mut self,
+        node: &MastNode,
+        program: &MastForest,
+        host: &mut impl Host,
+    ) -> Result<(), ExecutionError> {
+        for &decorator_id in node.after_exit() {
+            self.execute_decorator(&program[decorator_id], host)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the MAST forest the host provided for the specified [ExternalNode], along with the
+    /// ID of the procedure root the node refers to.
+    fn resolve_external_node(
+        &mut self,
+        external_node: &ExternalNode,
+        mast_forest: Option<Arc<MastForest>>,
+    ) -> Result<(Arc<MastForest>, MastNodeId), ExecutionError> {
+        let node_digest = external_node.digest();
+        let mast_forest = mast_forest
+            .ok_or(ExecutionError::NoMastForestWithProcedure { root_digest: node_digest })?;
+
+        // We limit the parts of the program that can be called externally to procedure
+        // roots, even though MAST doesn't have that restriction.
+        let root_id = mast_forest
+            .find_procedure_root(node_digest)
+            .ok_or(ExecutionError::MalformedMastForestInHost { root_digest: node_digest })?;
+
+        // if the node that we got by looking up an external reference is also an External
+        // node, we are about to enter into an infinite loop - so, return an error
+        if mast_forest[root_id].is_external() {
+            return Err(ExecutionError::CircularExternalNode(node_digest));
+        }
+
+        if let Some(coverage) = &mut self.coverage {
+            coverage.add_forest(&mast_forest);
+        }
+
+        Ok((mast_forest, root_id))
+    }
+
+    /// Starts executing the specified [JoinNode], and returns true if its first child was already
+    /// executed.
+    #[inline(always)]
+    fn enter_join_node(
+        &mut self,
+        node: &JoinNode,
+        program: &MastForest,
+        host: &mut impl Host,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<bool, ExecutionError> {
+        match resume_frame {
+            None => {
+                self.start_join_node(node, program, host)?;
+                Ok(false)
+            },
+            Some(ResumeFrame::Join { first_child_executed }) => Ok(first_child_executed),
+            Some(_) => Err(ExecutionError::InvalidPausedState),
+        }
+    }
+
+    /// Starts executing the specified [SplitNode], and returns true if its true branch is to be
+    /// executed.
+    #[inline(always)]
+    fn enter_split_node(
+        &mut self,
+        node: &SplitNode,
+        program: &MastForest,
+        host: &mut impl Host,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<bool, ExecutionError> {
+        match resume_frame {
+            None => {
+                // start the SPLIT block; this also pops the stack and returns the popped element
+                let condition = self.start_split_node(node, program, host)?;
+                if condition != ONE && condition != ZERO {
+                    return Err(ExecutionError::NotBinaryValue(condition));
+                }
+
+                if let Some(coverage) = &mut self.coverage {
+                    coverage.record_split(program, node, condition == ONE);
+                }
+                Ok(condition == ONE)
+            },
+            Some(ResumeFrame::Split { on_true }) => Ok(on_true),
+            Some(_) => Err(ExecutionError::InvalidPausedState),
+        }
+    }
+
+    /// Starts executing the specified [LoopNode], and returns true if its body is to be executed.
+    ///
+    /// If the body is skipped, the LOOP block is ended as well.
+    #[inline(always)]
+    fn enter_loop_node(
+        &mut self,
+        node: &LoopNode,
+        program: &MastForest,
+        host: &mut impl Host,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<bool, ExecutionError> {
+        match resume_frame {
+            None => {
+                // start the LOOP block; this also pops the stack and returns the popped element
+                let condition = self.start_loop_node(node, program, host)?;
+                if condition != ONE && condition != ZERO {
+                    return Err(ExecutionError::NotBinaryValue(condition));
+                }
+
+                if let Some(coverage) = &mut self.coverage {
+                    coverage.record_loop(program, node, condition == ONE);
+                }
+
+                // if the top of the stack is ZERO, skip the loop body; in this case we end the
+                // LOOP block, but don't drop the condition from the stack because it was already
+                // dropped when we started the LOOP block
+                if condition == ZERO {
+                    self.end_loop_node(node, false, host)?;
+                }
+                Ok(condition == ONE)
+            },
+            // the execution was paused inside the loop body
+            Some(ResumeFrame::Loop) => Ok(true),
+            Some(_) => Err(ExecutionError::InvalidPausedState),
+        }
+    }
+
+    /// Returns true if the loop body is to be executed again, i.e., if the condition on the top of
+    /// the stack is ONE.
+    ///
+    /// Each iteration of the loop is preceded by executing REPEAT operation which drops the
+    /// condition from the stack.
+    #[inline(always)]
+    fn repeat_loop_body(&mut self, host: &mut impl Host) -> Result<bool, ExecutionError> {
+        if self.stack.peek() != ONE {
+            return Ok(false);
+        }
+
+        self.decoder.repeat();
+        self.execute_op(Operation::Drop, host)?;
+        Ok(true)
+    }
+
+    /// Ends the LOOP block of the specified [LoopNode] once its body is no longer repeated, and
+    /// drops the condition from the stack.
+    #[inline(always)]
+    fn exit_loop_node(
+        &mut self,
+        node: &LoopNode,
+        host: &mut impl Host,
+    ) -> Result<(), ExecutionError> {
+        if self.stack.peek() != ZERO {
+            return Err(ExecutionError::NotBinaryValue(self.stack.peek()));
+        }
+
+        self.end_loop_node(node, true, host)
+    }
+
+    /// Starts executing the specified [CallNode].
+    #[inline(always)]
+    fn enter_call_node(
+        &mut self,
+        call_node: &CallNode,
+        program: &MastForest,
+        host: &mut impl Host,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(), ExecutionError> {
+        match resume_frame {
             None => {
-                let mast_forest = host
-                    .get_mast_forest(&callee_hash.into())
-                    .ok_or_else(|| ExecutionError::DynamicNodeNotFound(callee_hash.into()))?;
+                // call or syscall are not allowed inside a syscall
+                if self.system.in_syscall() {
+                    let instruction = if call_node.is_syscall() { "syscall" } else { "call" };
+                    return Err(ExecutionError::CallInSyscall(instruction));
+                }
 
-                // We limit the parts of the program that can be called externally to procedure
-                // roots, even though MAST doesn't have that restriction.
-                let root_id = mast_forest.find_procedure_root(callee_hash.into()).ok_or(
-                    ExecutionError::MalformedMastForestInHost { root_digest: callee_hash.into() },
-                )?;
+                // if this is a syscall, make sure the call target exists in the kernel
+                if call_node.is_syscall() {
+                    let callee = program.get_node_by_id(call_node.callee()).ok_or_else(|| {
+                        ExecutionError::MastNodeNotFoundInForest { node_id: call_node.callee() }
+                    })?;
+                    self.chiplets.kernel_rom.access_proc(callee.digest())?;
+                }
 
-                self.execute_mast_node(root_id, &mast_forest, host)?
+                self.start_call_node(call_node, program, host)
             },
+            Some(ResumeFrame::Call) => Ok(()),
+            Some(_) => Err(ExecutionError::InvalidPausedState),
         }
+    }
+
+    /// Starts executing the specified [DynNode], and returns the MAST root of its callee.
+    #[inline(always)]
+    fn enter_dyn_node(
+        &mut self,
+        node: &DynNode,
+        host: &mut impl Host,
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<Word, ExecutionError> {
+        match resume_frame {
+            None => {
+                // dyn calls are not allowed inside a syscall
+                if node.is_dyncall() && self.system.in_syscall() {
+                    return Err(ExecutionError::CallInSyscall("dyncall"));
+                }
 
+                if node.is_dyncall() {
+                    self.start_dyncall_node(node)
+                } else {
+                    self.start_dyn_node(node, host)
+                }
+            },
+            Some(ResumeFrame::Dyn { callee_hash }) => Ok(callee_hash),
+            Some(_) => Err(ExecutionError::InvalidPausedState),
+        }
+    }
+
+    /// Returns the MAST forest the host provided for the callee of a [DynNode] which is not in
+    /// the program's MAST forest, along with the ID of the callee in that forest.
+    fn resolve_dyn_callee(
+        &mut self,
+        callee_hash: Word,
+        mast_forest: Option<Arc<MastForest>>,
+    ) -> Result<(Arc<MastForest>, MastNodeId), ExecutionError> {
+        let mast_forest =
+            mast_forest.ok_or_else(|| ExecutionError::DynamicNodeNotFound(callee_hash.into()))?;
+
+        // We limit the parts of the program that can be called externally to procedure
+        // roots, even though MAST doesn't have that restriction.
+        let root_id = mast_forest
+            .find_procedure_root(callee_hash.into())
+            .ok_or(ExecutionError::MalformedMastForestInHost { root_digest: callee_hash.into() })?;
+
+        if let Some(coverage) = &mut self.coverage {
+            coverage.add_forest(&mast_forest);
+        }
+
+        Ok((mast_forest, root_id))
+    }
+
+    /// Ends the DYN or DYNCALL block of the specified [DynNode].
+    #[inline(always)]
+    fn exit_dyn_node(
+        &mut self,
+        node: &DynNode,
+        host: &mut impl Host,
+    ) -> Result<(), ExecutionError> {
         if node.is_dyncall() {
             self.end_dyncall_node(node, host)
         } else {
@@ -461,133 +823,167 @@ impl Process {
         }
     }
 
-    /// Executes the specified [BasicBlockNode].
+    /// Starts executing the specified [BasicBlockNode], and returns the position from which its
+    /// operations are executed, the number of operations in the batches preceding that position,
+    /// and the decorators of the block which were not executed yet.
     #[inline(always)]
-    fn execute_basic_block_node(
+    fn enter_basic_block_node<'a>(
         &mut self,
-        basic_block: &BasicBlockNode,
-        program: &MastForest,
+        basic_block: &'a BasicBlockNode,
         host: &mut impl Host,
-    ) -> Result<(), ExecutionError> {
-        self.start_basic_block_node(basic_block, host)?;
+        resume_frame: Option<ResumeFrame>,
+    ) -> Result<(BasicBlockPosition, usize, DecoratorIterator<'a>), ExecutionError> {
+        let op_batches = basic_block.op_batches();
+        let position = match resume_frame {
+            None => {
+                self.start_basic_block_node(basic_block, host)?;
+                BasicBlockPosition::batch_start(0)
+            },
+            // a basic block is always the innermost node being executed
+            Some(ResumeFrame::BasicBlock(position))
+                if position.batch_idx < op_batches.len() && self.pause.frames.is_empty() =>
+            {
+                position
+            },
+            Some(_) => return Err(ExecutionError::InvalidPausedState),
+        };
 
-        let mut op_offset = 0;
+        let op_offset: usize =
+            op_batches[..position.batch_idx].iter().map(|batch| batch.ops().len()).sum();
         let mut decorator_ids = basic_block.decorator_iter();
 
-        // execute the first operation batch
-        self.execute_op_batch(
-            &basic_block.op_batches()[0],
-            &mut decorator_ids,
-            op_offset,
-            program,
-            host,
-        )?;
-        op_offset += basic_block.op_batches()[0].ops().len();
-
-        // if the span contains more operation batches, execute them. each additional batch is
-        // preceded by a RESPAN operation; executing RESPAN operation does not change the state
-        // of the stack
-        for op_batch in basic_block.op_batches().iter().skip(1) {
-            self.respan(op_batch);
-            self.execute_op(Operation::Noop, host)?;
-            self.execute_op_batch(op_batch, &mut decorator_ids, op_offset, program, host)?;
-            op_offset += op_batch.ops().len();
+        // when resuming a paused execution, skip the decorators which were already executed
+        for op_pos in 0..(op_offset + position.op_pos) {
+            while decorator_ids.next_filtered(op_pos).is_some() {}
         }
 
-        self.end_basic_block_node(basic_block, host)?;
+        Ok((position, op_offset, decorator_ids))
+    }
 
-        // execute any decorators which have not been executed during span ops execution; this
-        // can happen for decorators appearing after all operations in a block. these decorators
-        // are executed after SPAN block is closed to make sure the VM clock cycle advances beyond
-        // the last clock cycle of the SPAN block ops.
-        for &decorator_id in decorator_ids {
-            let decorator = program
-                .get_decorator_by_id(decorator_id)
-                .ok_or(ExecutionError::DecoratorNotFoundInForest { decorator_id })?;
-            self.execute_decorator(decorator, host)?;
+    /// Starts executing the operation batch at index `batch_idx` of a basic block, and returns the
+    /// position of its first operation to be executed.
+    ///
+    /// The batch in which the execution of the block starts (or was paused) is executed from
+    /// `start_position`. Each additional batch is preceded by a RESPAN operation; executing RESPAN
+    /// operation does not change the state of the stack.
+    #[inline(always)]
+    fn enter_op_batch(
+        &mut self,
+        batch: &OpBatch,
+        batch_idx: usize,
+        start_position: BasicBlockPosition,
+        host: &mut impl Host,
+    ) -> Result<BasicBlockPosition, ExecutionError> {
+        if batch_idx == start_position.batch_idx {
+            return Ok(start_position);
         }
 
-        Ok(())
+        self.respan(batch);
+        self.execute_op(Operation::Noop, host)?;
+        Ok(BasicBlockPosition::batch_start(batch_idx))
     }
 
-    /// Executes all operations in an [OpBatch]. This also ensures that all alignment rules are
-    /// satisfied by executing NOOPs as needed. Specifically:
-    /// - If an operation group ends with an operation carrying an immediate value, a NOOP is
-    ///   executed after it.
-    /// - If the number of groups in a batch is not a power of 2, NOOPs are executed (one per group)
-    ///   to bring it up to the next power of two (e.g., 3 -> 4, 5 -> 8).
+    /// Executes the decorators preceding the operation at `position` in the specified batch, and
+    /// decodes the operation. Returns `None` once all operations of the batch were executed.
+    ///
+    /// If the execution is to be paused, `position` is recorded as the point from which it will
+    /// be resumed.
     #[inline(always)]
-    fn execute_op_batch(
+    fn decode_next_op(
         &mut self,
         batch: &OpBatch,
+        position: &BasicBlockPosition,
         decorators: &mut DecoratorIterator,
         op_offset: usize,
         program: &MastForest,
         host: &mut impl Host,
-    ) -> Result<(), ExecutionError> {
-        let op_counts = batch.op_counts();
-        let mut op_idx = 0;
-        let mut group_idx = 0;
-        let mut next_group_idx = 1;
+    ) -> Result<Option<Operation>, Interrupt> {
+        let Some(&op) = batch.ops().get(position.op_pos) else {
+            return Ok(None);
+        };
 
-        // round up the number of groups to be processed to the next power of two; we do this
-        // because the processor requires the number of groups to be either 1, 2, 4, or 8; if
-        // the actual number of groups is smaller, we'll pad the batch with NOOPs at the end
-        let num_batch_groups = batch.num_groups().next_power_of_two();
+        if self.should_pause(host) {
+            self.pause.frames.push(ResumeFrame::BasicBlock(*position));
+            return Err(Interrupt::Pause);
+        }
 
-        // execute operations in the batch one by one
-        for (i, &op) in batch.ops().iter().enumerate() {
-            while let Some(&decorator_id) = decorators.next_filtered(i + op_offset) {
-                let decorator = program
-                    .get_decorator_by_id(decorator_id)
-                    .ok_or(ExecutionError::DecoratorNotFoundInForest { decorator_id })?;
-                self.execute_decorator(decorator, host)?;
-            }
+        while let Some(&decorator_id) = decorators.next_filtered(position.op_pos + op_offset) {
+            let decorator = program
+                .get_decorator_by_id(decorator_id)
+                .ok_or(ExecutionError::DecoratorNotFoundInForest { decorator_id })?;
+            self.execute_decorator(decorator, host)?;
+        }
 
-            // decode and execute the operation
-            self.decoder.execute_user_op(op, op_idx);
-            self.execute_op(op, host)?;
+        self.decoder.execute_user_op(op, position.op_idx);
+        Ok(Some(op))
+    }
+
+    /// Advances `position` past the specified operation, which was just executed.
+    #[inline(always)]
+    fn advance_op_batch(
+        &mut self,
+        batch: &OpBatch,
+        op: Operation,
+        position: &mut BasicBlockPosition,
+        host: &mut impl Host,
+    ) -> Result<(), ExecutionError> {
+        position.op_pos += 1;
+
+        // if the operation carries an immediate value, the value is stored at the next group
+        // pointer; so, we advance the pointer to the following group
+        let has_imm = op.imm_value().is_some();
+        if has_imm {
+            position.next_group_idx += 1;
+        }
 
-            // if the operation carries an immediate value, the value is stored at the next group
-            // pointer; so, we advance the pointer to the following group
-            let has_imm = op.imm_value().is_some();
+        // determine if we've executed all non-decorator operations in a group
+        if position.op_idx == batch.op_counts()[position.group_idx] - 1 {
+            // if we are at the end of the group, first check if the operation carries an
+            // immediate value
             if has_imm {
-                next_group_idx += 1;
+                // an operation with an immediate value cannot be the last operation in a group
+                // so, we need execute a NOOP after it. the assert also makes sure that there
+                // is enough room in the group to execute a NOOP (if there isn't, there is a
+                // bug somewhere in the assembler)
+                debug_assert!(position.op_idx < OP_GROUP_SIZE - 1, "invalid op index");
+                self.decoder.execute_user_op(Operation::Noop, position.op_idx + 1);
+                self.execute_op(Operation::Noop, host)?;
             }
 
-            // determine if we've executed all non-decorator operations in a group
-            if op_idx == op_counts[group_idx] - 1 {
-                // if we are at the end of the group, first check if the operation carries an
-                // immediate value
-                if has_imm {
-                    // an operation with an immediate value cannot be the last operation in a group
-                    // so, we need execute a NOOP after it. the assert also makes sure that there
-                    // is enough room in the group to execute a NOOP (if there isn't, there is a
-                    // bug somewhere in the assembler)
-                    debug_assert!(op_idx < OP_GROUP_SIZE - 1, "invalid op index");
-                    self.decoder.execute_user_op(Operation::Noop, op_idx + 1);
-                    self.execute_op(Operation::Noop, host)?;
-                }
-
-                // then, move to the next group and reset operation index
-                group_idx = next_group_idx;
-                next_group_idx += 1;
-                op_idx = 0;
+            // then, move to the next group and reset operation index
+            position.group_idx = position.next_group_idx;
+            position.next_group_idx += 1;
+            position.op_idx = 0;
 
-                // if we haven't reached the end of the batch yet, set up the decoder for
-                // decoding the next operation group
-                if group_idx < num_batch_groups {
-                    self.decoder.start_op_group(batch.groups()[group_idx]);
-                }
-            } else {
-                // if we are not at the end of the group, just increment the operation index
-                op_idx += 1;
+            // if we haven't reached the end of the batch yet, set up the decoder for
+            // decoding the next operation group
+            if position.group_idx < batch.num_groups().next_power_of_two() {
+                self.decoder.start_op_group(batch.groups()[position.group_idx]);
             }
+        } else {
+            // if we are not at the end of the group, just increment the operation index
+            position.op_idx += 1;
         }
 
+        Ok(())
+    }
+
+    /// Ends the execution of an [OpBatch] whose operations were all executed.
+    #[inline(always)]
+    fn end_op_batch(
+        &mut self,
+        batch: &OpBatch,
+        position: &BasicBlockPosition,
+        host: &mut impl Host,
+    ) -> Result<(), ExecutionError> {
+        // round up the number of groups to be processed to the next power of two; we do this
+        // because the processor requires the number of groups to be either 1, 2, 4, or 8; if
+        // the actual number of groups is smaller, we'll pad the batch with NOOPs at the end
+        let num_batch_groups = batch.num_groups().next_power_of_two();
+
         // make sure we execute the required number of operation groups; this would happen when
         // the actual number of operation groups was not a power of two
-        for group_idx in group_idx..num_batch_groups {
+        for group_idx in position.group_idx..num_batch_groups {
             self.decoder.execute_user_op(Operation::Noop, 0);
             self.execute_op(Operation::Noop, host)?;
 
@@ -602,6 +998,31 @@ impl Process {
         Ok(())
     }
 
+    /// Ends the specified [BasicBlockNode], and executes its remaining decorators.
+    #[inline(always)]
+    fn exit_basic_block_node(
+        &mut self,
+        basic_block: &BasicBlockNode,
+        decorator_ids: DecoratorIterator,
+        program: &MastForest,
+        host: &mut impl Host,
+    ) -> Result<(), ExecutionError> {
+        self.end_basic_block_node(basic_block, host)?;
+
+        // execute any decorators which have not been executed during span ops execution; this
+        // can happen for decorators appearing after all operations in a block. these decorators
+        // are executed after SPAN block is closed to make sure the VM clock cycle advances beyond
+        // the last clock cycle of the SPAN block ops.
+        for &decorator_id in decorator_ids {
+            let decorator = program
+                .get_decorator_by_id(decorator_id)
+                .ok_or(ExecutionError::DecoratorNotFoundInForest { decorator_id })?;
+            self.execute_decorator(decorator, host)?;
+        }
+
+        Ok(())
+    }
+
     /// Executes the specified decorator
     fn execute_decorator(
         &mut self,
@@ -615,6 +1036,11 @@ impl Process {
                 }
             },
             Decorator::AsmOp(assembly_op) => {
+                if let (Some(coverage), Some(location)) =
+                    (&mut self.coverage, assembly_op.location())
+                {
+                    coverage.record_instruction(location);
+                }
                 if self.decoder.in_debug_mode() {
                     self.decoder.append_asmop(self.system.clk(), assembly_op.clone());
                 }
@@ -640,6 +1066,30 @@ impl Process {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Loads the advice data of the specified MAST forest into the advice provider.
+///
+/// # Errors
+/// Returns an error if a key of the advice map is already present in the advice provider, with
+/// different values.
+fn load_advice_map(
+    mast_forest: &MastForest,
+    advice_provider: &mut impl AdviceProvider,
+) -> Result<(), ExecutionError> {
+    for (digest, values) in mast_forest.advice_map().iter() {
+        if let Some(stored_values) = advice_provider.get_mapped_values(digest) {
+            if stored_values != values {
+                return Err(ExecutionError::AdviceMapKeyAlreadyPresent(digest.into()));
+            }
+        } else {
+            advice_provider.insert_into_map(digest.into(), values.clone());
+        }
+    }
+    Ok(())
+}
+
 // PROCESS STATE
 // ================================================================================================
 
@@ -1,6 +1,8 @@
 use vm_core::stack::MIN_STACK_DEPTH;
 
 use super::{ExecutionError, Felt, FieldElement, Host, Operation, Process};
+#[cfg(feature = "async")]
+use crate::AsyncHost;
 
 mod crypto_ops;
 mod ext2_ops;
@@ -162,6 +164,21 @@ impl Process {
         Ok(())
     }
 
+    /// Executes the `emit` operation with the specified event ID, forwarding events which are not
+    /// system events to an [AsyncHost].
+    ///
+    /// This is the asynchronous counterpart of executing [Operation::Emit] via [Self::execute_op].
+    #[cfg(feature = "async")]
+    pub(crate) async fn execute_emit_async(
+        &mut self,
+        event_id: u32,
+        host: &mut impl AsyncHost,
+    ) -> Result<(), ExecutionError> {
+        self.ensure_trace_capacity();
+        self.op_emit_async(event_id, host).await?;
+        self.advance_clock()
+    }
+
     /// Increments the clock cycle for all components of the process.
     pub(super) fn advance_clock(&mut self) -> Result<(), ExecutionError> {
         self.system.advance_clock(self.max_cycles)?;
@@ -8,6 +8,8 @@ use super::{
     ExecutionError, Process,
 };
 use crate::Host;
+#[cfg(feature = "async")]
+use crate::{AsyncHost, host::SyncHostAdapter};
 mod sys_event_handlers;
 
 // SYSTEM OPERATIONS
@@ -131,6 +133,27 @@ impl Process {
             host.on_event(self.into(), event_id)
         }
     }
+
+    /// Asynchronous counterpart of [Self::op_emit], which forwards events which are not system
+    /// events to an [AsyncHost].
+    #[cfg(feature = "async")]
+    pub(super) async fn op_emit_async<H>(
+        &mut self,
+        event_id: u32,
+        host: &mut H,
+    ) -> Result<(), ExecutionError>
+    where
+        H: AsyncHost,
+    {
+        self.stack.copy_state(0);
+        self.decoder.set_user_op_helpers(Operation::Emit(event_id), &[event_id.into()]);
+
+        if let Some(system_event) = SystemEvent::from_event_id(event_id) {
+            self.handle_system_event(system_event, &mut SyncHostAdapter(host))
+        } else {
+            host.on_event(self.into(), event_id).await
+        }
+    }
 }
 
 // TESTS
@@ -1,6 +1,7 @@
 use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_air::RowIndex;
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 use super::{Felt, FieldElement, RangeCheckTrace, ZERO, trace::NUM_RAND_ROWS};
 use crate::utils::uninit_vector;
@@ -206,6 +207,22 @@ impl Default for RangeChecker {
     }
 }
 
+impl Serializable for RangeChecker {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.lookups.write_into(target);
+        self.cycle_lookups.write_into(target);
+    }
+}
+
+impl Deserializable for RangeChecker {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            lookups: source.read()?,
+            cycle_lookups: source.read()?,
+        })
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -0,0 +1,568 @@
+use alloc::vec::Vec;
+
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use super::{
+    Digest, ExecutionError, Host, MemAdviceProvider, Process, Program, StackOutputs, Word,
+    load_advice_map,
+};
+
+// EXECUTION STATUS
+// ================================================================================================
+
+/// The outcome of running a pausable execution, see [Process::execute_pausable].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutionStatus {
+    /// The program was executed to completion, and left the specified outputs on the stack.
+    Completed(StackOutputs),
+    /// The execution was paused, and can be continued using [Process::resume].
+    Paused,
+}
+
+// PAUSABLE EXECUTION
+// ================================================================================================
+
+impl Process {
+    /// Executes the provided [`Program`] in this process, pausing the execution once `cycle_budget`
+    /// cycles have been executed, or when the host requests it via [Host::should_yield].
+    ///
+    /// The execution can only be paused in between the operations of a basic block, so it is
+    /// paused at the first operation executed once the cycle budget is exhausted. A paused process
+    /// can be turned into a [ProcessSnapshot], and its execution continued using
+    /// [Process::resume]. The trace of an execution which was paused and resumed any number of
+    /// times is identical to the trace of an uninterrupted execution of the same program.
+    pub fn execute_pausable(
+        &mut self,
+        program: &Program,
+        host: &mut impl Host,
+        cycle_budget: Option<u32>,
+    ) -> Result<ExecutionStatus, ExecutionError> {
+        if self.system.clk() != 0 {
+            return Err(ExecutionError::ProgramAlreadyExecuted);
+        }
+
+        load_advice_map(program.mast_forest(), host.advice_provider_mut())?;
+
+        self.run_pausable(program, host, cycle_budget)
+    }
+
+    /// Resumes the paused execution of the provided [`Program`] in this process, pausing it again
+    /// once `cycle_budget` more cycles have been executed, or when the host requests it.
+    ///
+    /// The host is expected to be in the same state as when the execution was paused; in
+    /// particular, its advice provider must be the one the execution was paused with.
+    ///
+    /// # Errors
+    /// Returns an error if this process is not paused, or if it was paused while executing a
+    /// different program.
+    pub fn resume(
+        &mut self,
+        program: &Program,
+        host: &mut impl Host,
+        cycle_budget: Option<u32>,
+    ) -> Result<ExecutionStatus, ExecutionError> {
+        self.check_paused_program(program)?;
+        self.run_pausable(program, host, cycle_budget)
+    }
+
+    /// Returns true if the execution of a program in this process is paused.
+    pub fn is_paused(&self) -> bool {
+        self.pause.paused_program.is_some()
+    }
+
+    fn run_pausable(
+        &mut self,
+        program: &Program,
+        host: &mut impl Host,
+        cycle_budget: Option<u32>,
+    ) -> Result<ExecutionStatus, ExecutionError> {
+        self.start_pausable_run(cycle_budget);
+        let result =
+            self.execute_mast_node(program.entrypoint(), &program.mast_forest().clone(), host);
+        self.end_pausable_run(program, result)
+    }
+
+    /// Returns an error if this process is not paused while executing the provided [`Program`].
+    pub(super) fn check_paused_program(&self, program: &Program) -> Result<(), ExecutionError> {
+        let paused_program = self.pause.paused_program.ok_or(ExecutionError::ProcessNotPaused)?;
+        if paused_program != program.hash() {
+            return Err(ExecutionError::PausedProgramMismatch {
+                expected: paused_program,
+                actual: program.hash(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Starts running a pausable execution, which is paused once `cycle_budget` more cycles have
+    /// been executed.
+    pub(super) fn start_pausable_run(&mut self, cycle_budget: Option<u32>) {
+        let clk = self.system.clk().as_u32();
+        self.pause.clk_limit =
+            Some(cycle_budget.map_or(u32::MAX, |budget| clk.saturating_add(budget)));
+        self.pause.paused_program = None;
+    }
+
+    /// Ends running a pausable execution of the provided [`Program`], which was interrupted or
+    /// completed as described by `result`.
+    pub(super) fn end_pausable_run(
+        &mut self,
+        program: &Program,
+        result: Result<(), Interrupt>,
+    ) -> Result<ExecutionStatus, ExecutionError> {
+        self.pause.clk_limit = None;
+
+        match result {
+            Ok(()) => Ok(ExecutionStatus::Completed(self.stack.build_stack_outputs()?)),
+            Err(Interrupt::Pause) => {
+                self.pause.paused_program = Some(program.hash());
+                Ok(ExecutionStatus::Paused)
+            },
+            Err(Interrupt::Error(err)) => Err(err),
+        }
+    }
+
+    /// Returns true if the execution should be paused before executing the next operation.
+    #[inline(always)]
+    pub(super) fn should_pause(&mut self, host: &mut impl Host) -> bool {
+        match self.pause.clk_limit {
+            Some(clk_limit) => self.system.clk().as_u32() >= clk_limit || host.should_yield(),
+            None => false,
+        }
+    }
+
+    /// Records `frame` as the state of a node whose execution was interrupted by `interrupt`, if
+    /// the execution was paused.
+    pub(super) fn record_frame(&mut self, interrupt: Interrupt, frame: ResumeFrame) -> Interrupt {
+        if let Interrupt::Pause = interrupt {
+            self.pause.frames.push(frame);
+        }
+        interrupt
+    }
+}
+
+// PROCESS SNAPSHOT
+// ================================================================================================
+
+/// A serializable snapshot of the state of a [Process], together with the state of the advice
+/// provider of its host.
+///
+/// Snapshots are typically taken of paused processes, so that their execution can be resumed
+/// later, possibly on another machine. To resume the execution, a host must be instantiated with
+/// the advice provider of the snapshot and the libraries the program depends on, and passed to
+/// [Process::resume] along with the program.
+pub struct ProcessSnapshot<A = MemAdviceProvider> {
+    process: Process,
+    advice_provider: A,
+}
+
+impl<A> ProcessSnapshot<A> {
+    /// Returns a new [ProcessSnapshot] of the specified process and advice provider.
+    pub fn new(process: Process, advice_provider: A) -> Self {
+        Self { process, advice_provider }
+    }
+
+    /// Returns the process of this snapshot.
+    pub fn process(&self) -> &Process {
+        &self.process
+    }
+
+    /// Returns the advice provider of this snapshot.
+    pub fn advice_provider(&self) -> &A {
+        &self.advice_provider
+    }
+
+    /// Consumes this snapshot, returning its process and advice provider.
+    pub fn into_parts(self) -> (Process, A) {
+        (self.process, self.advice_provider)
+    }
+}
+
+impl<A: Serializable> Serializable for ProcessSnapshot<A> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.process.write_into(target);
+        self.advice_provider.write_into(target);
+    }
+}
+
+impl<A: Deserializable> Deserializable for ProcessSnapshot<A> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            process: source.read()?,
+            advice_provider: source.read()?,
+        })
+    }
+}
+
+impl Serializable for Process {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.system.write_into(target);
+        self.decoder.write_into(target);
+        self.stack.write_into(target);
+        self.range.write_into(target);
+        self.chiplets.write_into(target);
+        target.write_u32(self.max_cycles);
+        target.write_bool(self.enable_tracing);
+        self.pause.write_into(target);
+    }
+}
+
+impl Deserializable for Process {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            system: source.read()?,
+            decoder: source.read()?,
+            stack: source.read()?,
+            range: source.read()?,
+            chiplets: source.read()?,
+            max_cycles: source.read_u32()?,
+            enable_tracing: source.read_bool()?,
+            pause: source.read()?,
+            coverage: None,
+        })
+    }
+}
+
+// PAUSE STATE
+// ================================================================================================
+
+/// Keeps track of the state needed to pause the execution of a program, and to resume it.
+#[derive(Debug, Default)]
+pub(crate) struct PauseState {
+    /// The clock cycle from which the execution is paused, set only while running a pausable
+    /// execution.
+    pub(super) clk_limit: Option<u32>,
+    /// The hash of the program whose execution is paused, if any.
+    pub(super) paused_program: Option<Digest>,
+    /// The state of the nodes being executed when the execution was paused, with the innermost
+    /// node first.
+    pub(super) frames: Vec<ResumeFrame>,
+}
+
+impl Serializable for PauseState {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.paused_program.write_into(target);
+        self.frames.write_into(target);
+    }
+}
+
+impl Deserializable for PauseState {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            clk_limit: None,
+            paused_program: source.read()?,
+            frames: source.read()?,
+        })
+    }
+}
+
+/// The point at which the execution of a node was paused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResumeFrame {
+    BasicBlock(BasicBlockPosition),
+    Join { first_child_executed: bool },
+    Split { on_true: bool },
+    Loop,
+    Call,
+    Dyn { callee_hash: Word },
+    External,
+}
+
+impl Serializable for ResumeFrame {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::BasicBlock(position) => {
+                target.write_u8(0);
+                position.write_into(target);
+            },
+            Self::Join { first_child_executed } => {
+                target.write_u8(1);
+                target.write_bool(*first_child_executed);
+            },
+            Self::Split { on_true } => {
+                target.write_u8(2);
+                target.write_bool(*on_true);
+            },
+            Self::Loop => target.write_u8(3),
+            Self::Call => target.write_u8(4),
+            Self::Dyn { callee_hash } => {
+                target.write_u8(5);
+                callee_hash.write_into(target);
+            },
+            Self::External => target.write_u8(6),
+        }
+    }
+}
+
+impl Deserializable for ResumeFrame {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::BasicBlock(source.read()?)),
+            1 => Ok(Self::Join {
+                first_child_executed: source.read_bool()?,
+            }),
+            2 => Ok(Self::Split { on_true: source.read_bool()? }),
+            3 => Ok(Self::Loop),
+            4 => Ok(Self::Call),
+            5 => Ok(Self::Dyn { callee_hash: source.read()? }),
+            6 => Ok(Self::External),
+            tag => {
+                Err(DeserializationError::InvalidValue(format!("invalid resume frame tag {tag}")))
+            },
+        }
+    }
+}
+
+/// The position of the next operation to be executed in a basic block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BasicBlockPosition {
+    /// The index of the operation batch.
+    pub(super) batch_idx: usize,
+    /// The index of the operation in the batch.
+    pub(super) op_pos: usize,
+    /// The index of the operation in its operation group.
+    pub(super) op_idx: usize,
+    /// The index of the operation group.
+    pub(super) group_idx: usize,
+    /// The index of the next operation group, which is greater than `group_idx + 1` when the
+    /// group contains operations with immediate values.
+    pub(super) next_group_idx: usize,
+}
+
+impl BasicBlockPosition {
+    /// Returns the position of the first operation of the specified batch.
+    pub(super) fn batch_start(batch_idx: usize) -> Self {
+        Self {
+            batch_idx,
+            op_pos: 0,
+            op_idx: 0,
+            group_idx: 0,
+            next_group_idx: 1,
+        }
+    }
+}
+
+impl Serializable for BasicBlockPosition {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.batch_idx.write_into(target);
+        self.op_pos.write_into(target);
+        self.op_idx.write_into(target);
+        self.group_idx.write_into(target);
+        self.next_group_idx.write_into(target);
+    }
+}
+
+impl Deserializable for BasicBlockPosition {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            batch_idx: source.read()?,
+            op_pos: source.read()?,
+            op_idx: source.read()?,
+            group_idx: source.read()?,
+            next_group_idx: source.read()?,
+        })
+    }
+}
+
+// INTERRUPT
+// ================================================================================================
+
+/// The reason the execution of a node was interrupted.
+#[derive(Debug)]
+pub(crate) enum Interrupt {
+    /// The execution was paused.
+    Pause,
+    /// The execution failed.
+    Error(ExecutionError),
+}
+
+impl Interrupt {
+    /// Returns the error which interrupted the execution.
+    ///
+    /// # Panics
+    /// Panics if the execution was paused, which can only happen during pausable executions.
+    pub(super) fn into_error(self) -> ExecutionError {
+        match self {
+            Self::Error(err) => err,
+            Self::Pause => unreachable!("only pausable executions can be paused"),
+        }
+    }
+}
+
+impl From<ExecutionError> for Interrupt {
+    fn from(err: ExecutionError) -> Self {
+        Self::Error(err)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+    use core::mem;
+
+    use assembly::Assembler;
+    use vm_core::{
+        Felt, StackInputs,
+        crypto::hash::RpoDigest,
+        mast::MastForest,
+        utils::{Deserializable, Serializable},
+    };
+    use winter_prover::Trace;
+
+    use super::*;
+    use crate::{
+        AdviceInputs, DefaultHost, ExecutionOptions, ExecutionTrace, ProcessState, execute,
+    };
+
+    const SOURCE: &str = "
+    proc.store_locals.4
+        loc_storew.0 dropw
+        loc_loadw.0
+    end
+
+    proc.callee
+        push.7 mem_store.100
+        mem_load.100 add
+    end
+
+    begin
+        push.1.2.3.4.5.6.7.8.9.10.11.12
+        push.13.14.15.16.17.18.19.20
+        push.10 dup neq.0
+        while.true
+            sub.1 dup push.3 u32and mem_store.8
+            dup neq.0
+        end
+        drop
+        call.callee
+        exec.store_locals
+        adv_push.2 add hperm
+        push.5 push.1 u32and
+        if.true add else mul end
+        procref.callee mem_storew.40 dropw push.40 dynexec
+        sdepth push.16 gt
+        while.true
+            drop sdepth push.16 gt
+        end
+    end";
+
+    #[test]
+    fn resumed_execution_produces_identical_trace() {
+        let program = Assembler::default().assemble_program(SOURCE).unwrap();
+        let stack_inputs = StackInputs::try_from_ints([1, 2, 3]).unwrap();
+        let advice_inputs = AdviceInputs::default().with_stack_values([1, 2]).unwrap();
+
+        let mut host = DefaultHost::new(MemAdviceProvider::from(advice_inputs.clone()));
+        let expected =
+            execute(&program, stack_inputs.clone(), &mut host, ExecutionOptions::default())
+                .unwrap();
+
+        // pause the execution every few cycles, and resume it from a deserialized snapshot
+        let mut process =
+            Process::new(program.kernel().clone(), stack_inputs, ExecutionOptions::default());
+        let mut host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+        let mut status = process.execute_pausable(&program, &mut host, Some(13)).unwrap();
+        let mut num_pauses = 0;
+        while status == ExecutionStatus::Paused {
+            num_pauses += 1;
+            let bytes = ProcessSnapshot::new(process, host.into_inner()).to_bytes();
+            let (paused_process, advice_provider) =
+                ProcessSnapshot::<MemAdviceProvider>::read_from_bytes(&bytes)
+                    .unwrap()
+                    .into_parts();
+
+            process = paused_process;
+            host = DefaultHost::new(advice_provider);
+            status = process.resume(&program, &mut host, Some(13)).unwrap();
+        }
+        assert!(num_pauses > 10);
+
+        let ExecutionStatus::Completed(stack_outputs) = status else {
+            panic!("execution was not completed")
+        };
+        let trace = ExecutionTrace::new(process, stack_outputs);
+        assert_eq!(trace.stack_outputs(), expected.stack_outputs());
+
+        let (main_trace, expected_main_trace) = (trace.main_segment(), expected.main_segment());
+        assert_eq!(main_trace.num_rows(), expected_main_trace.num_rows());
+        for i in 0..expected_main_trace.num_cols() {
+            assert_eq!(main_trace.get_column(i), expected_main_trace.get_column(i), "column {i}");
+        }
+    }
+
+    #[test]
+    fn host_can_pause_execution() {
+        let program =
+            Assembler::default().assemble_program("begin push.1 emit.3 push.2 add swap drop end");
+        let program = program.unwrap();
+        let mut process =
+            Process::new(program.kernel().clone(), StackInputs::default(), Default::default());
+        let mut host = YieldOnEventHost::default();
+
+        // the execution is paused right after the event
+        let status = process.execute_pausable(&program, &mut host, None).unwrap();
+        assert_eq!(status, ExecutionStatus::Paused);
+        assert!(process.is_paused());
+        assert_eq!(ProcessState::from(&process).get_stack_item(0), Felt::new(1));
+
+        // the execution can't be resumed with another program
+        let other_program = Assembler::default().assemble_program("begin push.3 end").unwrap();
+        assert!(matches!(
+            process.resume(&other_program, &mut host, None),
+            Err(ExecutionError::PausedProgramMismatch { .. })
+        ));
+
+        let status = process.resume(&program, &mut host, None).unwrap();
+        let ExecutionStatus::Completed(stack_outputs) = status else {
+            panic!("execution was not completed")
+        };
+        assert_eq!(stack_outputs.get_stack_item(0), Some(Felt::new(3)));
+        assert!(matches!(
+            process.resume(&program, &mut host, None),
+            Err(ExecutionError::ProcessNotPaused)
+        ));
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// A host which requests the execution to be paused after each event.
+    #[derive(Default)]
+    struct YieldOnEventHost {
+        host: DefaultHost<MemAdviceProvider>,
+        yield_requested: bool,
+    }
+
+    impl Host for YieldOnEventHost {
+        type AdviceProvider = MemAdviceProvider;
+
+        fn advice_provider(&self) -> &Self::AdviceProvider {
+            self.host.advice_provider()
+        }
+
+        fn advice_provider_mut(&mut self) -> &mut Self::AdviceProvider {
+            self.host.advice_provider_mut()
+        }
+
+        fn get_mast_forest(&self, node_digest: &RpoDigest) -> Option<Arc<MastForest>> {
+            self.host.get_mast_forest(node_digest)
+        }
+
+        fn on_event(
+            &mut self,
+            _process: ProcessState,
+            _event_id: u32,
+        ) -> Result<(), ExecutionError> {
+            self.yield_requested = true;
+            Ok(())
+        }
+
+        fn should_yield(&mut self) -> bool {
+            mem::take(&mut self.yield_requested)
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 
 use miden_air::{RowIndex, trace::main_trace::MainTrace};
 use vm_core::OPCODE_DYNCALL;
@@ -26,6 +26,21 @@ impl AuxTraceBuilder {
         debug_assert_eq!(*p1.last().unwrap(), E::ONE);
         vec![p1]
     }
+
+    /// Builds the stack auxiliary trace columns like [Self::build_aux_columns()], but without
+    /// checking that the overflow table is empty at the end of the execution. Each column is
+    /// returned together with the debugger into which the rows added to and removed from its table
+    /// were recorded.
+    pub(crate) fn build_aux_columns_with_debuggers<E: FieldElement<BaseField = Felt>>(
+        &self,
+        main_trace: &MainTrace,
+        rand_elements: &[E],
+    ) -> Vec<(Vec<E>, BusDebugger<E>)> {
+        let mut p1_debugger = BusDebugger::new("stack overflow table".to_string());
+        let p1 = self.build_aux_column_with_debugger(main_trace, rand_elements, &mut p1_debugger);
+
+        vec![(p1, p1_debugger)]
+    }
 }
 
 impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for AuxTraceBuilder {
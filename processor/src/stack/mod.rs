@@ -1,7 +1,11 @@
 use alloc::vec::Vec;
 
 use miden_air::RowIndex;
-use vm_core::{WORD_SIZE, Word, stack::MIN_STACK_DEPTH};
+use vm_core::{
+    WORD_SIZE, Word,
+    stack::MIN_STACK_DEPTH,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 
 use super::{
     ExecutionError, Felt, FieldElement, ONE, STACK_TRACE_WIDTH, StackInputs, StackOutputs, ZERO,
@@ -370,3 +374,25 @@ impl Stack {
         self.trace.get_helpers_state_at(self.clk)
     }
 }
+
+impl Serializable for Stack {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.clk.write_into(target);
+        self.trace.write_into(target);
+        self.overflow.write_into(target);
+        self.active_depth.write_into(target);
+        self.full_depth.write_into(target);
+    }
+}
+
+impl Deserializable for Stack {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            clk: source.read()?,
+            trace: source.read()?,
+            overflow: source.read()?,
+            active_depth: source.read()?,
+            full_depth: source.read()?,
+        })
+    }
+}
@@ -1,5 +1,7 @@
 use alloc::{collections::BTreeMap, vec::Vec};
 
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
 use super::{Felt, FieldElement, ZERO};
 
 // OVERFLOW TABLE
@@ -178,6 +180,28 @@ impl OverflowTable {
     }
 }
 
+impl Serializable for OverflowTable {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.all_rows.write_into(target);
+        self.active_rows.write_into(target);
+        self.trace.write_into(target);
+        target.write_bool(self.trace_enabled);
+        self.last_row_addr.write_into(target);
+    }
+}
+
+impl Deserializable for OverflowTable {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            all_rows: source.read()?,
+            active_rows: source.read()?,
+            trace: source.read()?,
+            trace_enabled: source.read_bool()?,
+            last_row_addr: source.read()?,
+        })
+    }
+}
+
 // OVERFLOW TABLE ROW
 // ================================================================================================
 
@@ -209,3 +233,21 @@ impl OverflowTableRow {
             + alphas[3].mul_base(self.prev)
     }
 }
+
+impl Serializable for OverflowTableRow {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.val.write_into(target);
+        self.clk.write_into(target);
+        self.prev.write_into(target);
+    }
+}
+
+impl Deserializable for OverflowTableRow {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            val: source.read()?,
+            clk: source.read()?,
+            prev: source.read()?,
+        })
+    }
+}
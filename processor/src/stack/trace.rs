@@ -4,7 +4,11 @@ use miden_air::{
     RowIndex,
     trace::stack::{H0_COL_IDX, NUM_STACK_HELPER_COLS},
 };
-use vm_core::{FieldElement, stack::MIN_STACK_DEPTH};
+use vm_core::{
+    FieldElement,
+    stack::MIN_STACK_DEPTH,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 
 use super::{super::utils::get_trace_len, Felt, MAX_TOP_IDX, ONE, STACK_TRACE_WIDTH, ZERO};
 use crate::utils::math::batch_inversion;
@@ -228,6 +232,22 @@ impl StackTrace {
     }
 }
 
+impl Serializable for StackTrace {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.stack.write_into(target);
+        self.helpers.write_into(target);
+    }
+}
+
+impl Deserializable for StackTrace {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            stack: source.read()?,
+            helpers: source.read()?,
+        })
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -2,6 +2,7 @@ use alloc::vec::Vec;
 use core::fmt::{self, Display};
 
 use miden_air::RowIndex;
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 use super::{EMPTY_WORD, ExecutionError, Felt, FieldElement, ONE, SysTrace, Word, ZERO};
 
@@ -299,6 +300,38 @@ impl System {
     }
 }
 
+impl Serializable for System {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.clk.write_into(target);
+        self.ctx.write_into(target);
+        self.fmp.write_into(target);
+        target.write_bool(self.in_syscall);
+        self.fn_hash.write_into(target);
+        self.ctx_trace.write_into(target);
+        self.clk_trace.write_into(target);
+        self.fmp_trace.write_into(target);
+        self.in_syscall_trace.write_into(target);
+        self.fn_hash_trace.write_into(target);
+    }
+}
+
+impl Deserializable for System {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            clk: source.read()?,
+            ctx: source.read()?,
+            fmp: source.read()?,
+            in_syscall: source.read_bool()?,
+            fn_hash: source.read()?,
+            ctx_trace: source.read()?,
+            clk_trace: source.read()?,
+            fmp_trace: source.read()?,
+            in_syscall_trace: source.read()?,
+            fn_hash_trace: source.read()?,
+        })
+    }
+}
+
 // EXECUTION CONTEXT
 // ================================================================================================
 
@@ -353,3 +386,15 @@ impl Display for ContextId {
         write!(f, "{}", self.0)
     }
 }
+
+impl Serializable for ContextId {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.0);
+    }
+}
+
+impl Deserializable for ContextId {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        source.read_u32().map(Self)
+    }
+}
@@ -0,0 +1,200 @@
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+use std::io::{self, Write};
+
+use miden_air::trace::{aux_trace_column_names, main_trace_column_names};
+use winter_prover::Trace;
+
+use super::ExecutionTrace;
+use crate::Felt;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The magic bytes at the start of a trace exported in the binary format.
+pub const TRACE_EXPORT_MAGIC: &[u8; 4] = b"MTRC";
+
+/// The version of the binary format of exported traces.
+pub const TRACE_EXPORT_VERSION: u8 = 1;
+
+// TRACE EXPORT OPTIONS
+// ================================================================================================
+
+/// The format into which an execution trace is exported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TraceExportFormat {
+    /// Comma-separated values, with a header row of column names and a leading `row` column.
+    #[default]
+    Csv,
+    /// A compact columnar format, laid out as follows (all integers are little-endian):
+    ///
+    /// - the magic bytes `MTRC` and a version byte;
+    /// - the number of columns (`u32`), the index of the first exported row (`u64`), and the
+    ///   number of exported rows (`u64`);
+    /// - for each column, the length of its name (`u16`) followed by the UTF-8 encoded name;
+    /// - for each column, the canonical values of its rows (`u64`).
+    Binary,
+}
+
+/// Options for exporting an execution trace with [ExecutionTrace::export()].
+///
+/// By default, all rows of the main trace are exported.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceExportOptions {
+    rows: Option<Range<usize>>,
+    include_aux_trace: bool,
+}
+
+impl TraceExportOptions {
+    /// Exports only the specified range of rows.
+    pub fn with_rows(mut self, rows: Range<usize>) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    /// Exports the columns of the auxiliary trace after the columns of the main trace.
+    ///
+    /// Each auxiliary column holds elements of the quadratic extension field, and is exported as
+    /// two columns holding their coefficients, suffixed with `.0` and `.1`. The auxiliary trace is
+    /// built with random elements drawn from the program hash, so the exported values differ from
+    /// the ones committed to by the prover.
+    pub fn with_aux_trace(mut self) -> Self {
+        self.include_aux_trace = true;
+        self
+    }
+
+    /// Returns the range of rows to export, if only a part of the trace is exported.
+    pub fn rows(&self) -> Option<&Range<usize>> {
+        self.rows.as_ref()
+    }
+
+    /// Returns true if the columns of the auxiliary trace are exported.
+    pub fn include_aux_trace(&self) -> bool {
+        self.include_aux_trace
+    }
+}
+
+// TRACE EXPORT
+// ================================================================================================
+
+impl ExecutionTrace {
+    /// Writes this trace to the provided writer in the specified format.
+    ///
+    /// Columns are named after the layout of the trace, see
+    /// [main_trace_column_names()](miden_air::trace::main_trace_column_names) and
+    /// [aux_trace_column_names()](miden_air::trace::aux_trace_column_names).
+    ///
+    /// # Errors
+    /// Returns an error if the range of rows in the options is empty or exceeds the length of the
+    /// trace, or if writing fails.
+    pub fn export<W: Write>(
+        &self,
+        writer: &mut W,
+        format: TraceExportFormat,
+        options: &TraceExportOptions,
+    ) -> io::Result<()> {
+        let rows = options.rows.clone().unwrap_or(0..self.length());
+        if rows.is_empty() || rows.end > self.length() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid row range {rows:?} for a trace of {} rows", self.length()),
+            ));
+        }
+
+        let mut names = main_trace_column_names();
+        let (aux_names, aux_columns) = if options.include_aux_trace {
+            self.aux_export_columns()
+        } else {
+            (Vec::new(), Vec::new())
+        };
+        names.extend(aux_names);
+
+        let columns = (0..self.main_trace.num_cols())
+            .map(|column| &self.main_trace.get_column(column)[rows.clone()])
+            .chain(aux_columns.iter().map(|column| &column[rows.clone()]))
+            .collect::<Vec<_>>();
+        match format {
+            TraceExportFormat::Csv => write_csv(writer, &names, &columns, rows.start),
+            TraceExportFormat::Binary => write_binary(writer, &names, &columns, rows.start),
+        }
+    }
+
+    /// Builds the auxiliary trace, and returns the names and values of the columns holding the
+    /// coefficients of its elements.
+    fn aux_export_columns(&self) -> (Vec<String>, Vec<Vec<Felt>>) {
+        let aux_trace = self
+            .build_aux_trace(&self.draw_aux_rand_elements())
+            .expect("failed to build the auxiliary trace");
+
+        let mut names = Vec::with_capacity(2 * aux_trace.num_cols());
+        let mut columns = Vec::with_capacity(2 * aux_trace.num_cols());
+        for (index, name) in aux_trace_column_names().into_iter().enumerate() {
+            let (c0, c1) = aux_trace
+                .get_column(index)
+                .iter()
+                .map(|value| {
+                    let [c0, c1] = value.to_base_elements();
+                    (c0, c1)
+                })
+                .unzip();
+            names.push(format!("{name}.0"));
+            names.push(format!("{name}.1"));
+            columns.push(c0);
+            columns.push(c1);
+        }
+
+        (names, columns)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn write_csv<W: Write>(
+    writer: &mut W,
+    names: &[String],
+    columns: &[&[Felt]],
+    first_row: usize,
+) -> io::Result<()> {
+    write!(writer, "row")?;
+    for name in names {
+        write!(writer, ",{name}")?;
+    }
+    writeln!(writer)?;
+
+    for row in 0..columns[0].len() {
+        write!(writer, "{}", first_row + row)?;
+        for column in columns {
+            write!(writer, ",{}", column[row].as_int())?;
+        }
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+fn write_binary<W: Write>(
+    writer: &mut W,
+    names: &[String],
+    columns: &[&[Felt]],
+    first_row: usize,
+) -> io::Result<()> {
+    writer.write_all(TRACE_EXPORT_MAGIC)?;
+    writer.write_all(&[TRACE_EXPORT_VERSION])?;
+    writer.write_all(&(columns.len() as u32).to_le_bytes())?;
+    writer.write_all(&(first_row as u64).to_le_bytes())?;
+    writer.write_all(&(columns[0].len() as u64).to_le_bytes())?;
+
+    for name in names {
+        writer.write_all(&(name.len() as u16).to_le_bytes())?;
+        writer.write_all(name.as_bytes())?;
+    }
+
+    for column in columns {
+        for value in column.iter() {
+            writer.write_all(&value.as_int().to_le_bytes())?;
+        }
+    }
+
+    Ok(())
+}
@@ -10,13 +10,20 @@ use vm_core::{ProgramInfo, StackInputs, StackOutputs, ZERO, stack::MIN_STACK_DEP
 use winter_prover::{EvaluationFrame, Trace, TraceInfo, crypto::RandomCoin};
 
 use super::{
-    ColMatrix, Digest, Felt, FieldElement, Process,
-    chiplets::AuxTraceBuilder as ChipletsAuxTraceBuilder, crypto::RpoRandomCoin,
-    decoder::AuxTraceBuilder as DecoderAuxTraceBuilder,
+    ColMatrix, Digest, Felt, FieldElement, Process, QuadFelt,
+    chiplets::AuxTraceBuilder as ChipletsAuxTraceBuilder,
+    crypto::RpoRandomCoin,
+    decoder::{AuxTraceBuilder as DecoderAuxTraceBuilder, DebugInfo},
     range::AuxTraceBuilder as RangeCheckerAuxTraceBuilder,
     stack::AuxTraceBuilder as StackAuxTraceBuilder,
+    utils::join,
 };
 
+#[cfg(feature = "std")]
+mod export;
+#[cfg(feature = "std")]
+pub use export::{TRACE_EXPORT_MAGIC, TRACE_EXPORT_VERSION, TraceExportFormat, TraceExportOptions};
+
 mod utils;
 pub use utils::{AuxColumnBuilder, ChipletsLengths, TraceFragment, TraceLenSummary};
 
@@ -56,6 +63,7 @@ pub struct ExecutionTrace {
     program_info: ProgramInfo,
     stack_outputs: StackOutputs,
     trace_len_summary: TraceLenSummary,
+    debug_info: DebugInfo,
 }
 
 impl ExecutionTrace {
@@ -68,7 +76,7 @@ impl ExecutionTrace {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Builds an execution trace for the provided process.
-    pub fn new(process: Process, stack_outputs: StackOutputs) -> Self {
+    pub fn new(mut process: Process, stack_outputs: StackOutputs) -> Self {
         // use program hash to initialize random element generator; this generator will be used
         // to inject random values at the end of the trace; using program hash here is OK because
         // we are using random values only to stabilize constraint degrees, and not to achieve
@@ -79,6 +87,7 @@ impl ExecutionTrace {
         // create a new program info instance with the underlying kernel
         let kernel = process.kernel().clone();
         let program_info = ProgramInfo::new(program_hash.into(), kernel);
+        let debug_info = process.decoder.take_debug_info();
         let (main_trace, aux_trace_builders, trace_len_summary) = finalize_trace(process, rng);
         let trace_info = TraceInfo::new_multi_segment(
             TRACE_WIDTH,
@@ -96,6 +105,7 @@ impl ExecutionTrace {
             program_info,
             stack_outputs,
             trace_len_summary,
+            debug_info,
         }
     }
 
@@ -161,6 +171,31 @@ impl ExecutionTrace {
         &self.meta
     }
 
+    /// Returns the main segment of this trace.
+    pub(crate) fn main_trace(&self) -> &MainTrace {
+        &self.main_trace
+    }
+
+    /// Returns the builders of the auxiliary segment of this trace.
+    pub(crate) fn aux_trace_builders(&self) -> &AuxTraceBuilders {
+        &self.aux_trace_builders
+    }
+
+    /// Returns the operations and assembly instructions executed at each cycle, which are only
+    /// recorded when the program is executed in debug mode.
+    pub(crate) fn debug_info(&self) -> &DebugInfo {
+        &self.debug_info
+    }
+
+    /// Returns random elements for building the auxiliary segment of this trace outside of the
+    /// prover, drawn deterministically from the program hash.
+    pub(crate) fn draw_aux_rand_elements(&self) -> Vec<QuadFelt> {
+        let mut rng = RpoRandomCoin::new(self.program_hash().into());
+        (0..AUX_TRACE_RAND_ELEMENTS)
+            .map(|_| rng.draw().expect("failed to draw a random value"))
+            .collect()
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -181,6 +216,11 @@ impl ExecutionTrace {
         }
     }
 
+    #[cfg(test)]
+    pub(crate) fn main_trace_mut(&mut self) -> &mut ColMatrix<Felt> {
+        &mut self.main_trace
+    }
+
     #[cfg(test)]
     pub fn test_finalize_trace(process: Process) -> (MainTrace, AuxTraceBuilders, TraceLenSummary) {
         let rng = RpoRandomCoin::new(EMPTY_WORD);
@@ -191,25 +231,25 @@ impl ExecutionTrace {
     where
         E: FieldElement<BaseField = Felt>,
     {
-        // add decoder's running product columns
-        let decoder_aux_columns = self
-            .aux_trace_builders
-            .decoder
-            .build_aux_columns(&self.main_trace, rand_elements);
-
-        // add stack's running product columns
-        let stack_aux_columns =
-            self.aux_trace_builders.stack.build_aux_columns(&self.main_trace, rand_elements);
-
-        // add the range checker's running product columns
-        let range_aux_columns =
-            self.aux_trace_builders.range.build_aux_columns(&self.main_trace, rand_elements);
-
-        // add the running product columns for the chiplets
-        let chiplets = self
-            .aux_trace_builders
-            .chiplets
-            .build_aux_columns(&self.main_trace, rand_elements);
+        let builders = &self.aux_trace_builders;
+        let main_trace = &self.main_trace;
+
+        // build the running product columns of the decoder, stack, range checker and chiplets,
+        // which are independent of each other
+        let ((decoder_aux_columns, stack_aux_columns), (range_aux_columns, chiplets)) = join(
+            || {
+                join(
+                    || builders.decoder.build_aux_columns(main_trace, rand_elements),
+                    || builders.stack.build_aux_columns(main_trace, rand_elements),
+                )
+            },
+            || {
+                join(
+                    || builders.range.build_aux_columns(main_trace, rand_elements),
+                    || builders.chiplets.build_aux_columns(main_trace, rand_elements),
+                )
+            },
+        );
 
         // combine all auxiliary columns into a single vector
         let mut aux_columns = decoder_aux_columns
@@ -301,15 +341,29 @@ fn finalize_trace(
     let trace_len_summary =
         TraceLenSummary::new(clk.into(), range_table_len, ChipletsLengths::new(&chiplets));
 
-    // Combine all trace segments into the main trace
-    let system_trace = system.into_trace(trace_len, NUM_RAND_ROWS);
-    let decoder_trace = decoder.into_trace(trace_len, NUM_RAND_ROWS);
-    let stack_trace = stack.into_trace(trace_len, NUM_RAND_ROWS);
-    let chiplets_trace = chiplets.into_trace(trace_len, NUM_RAND_ROWS);
-
-    // Combine the range trace segment using the support lookup table
-    let range_check_trace = range.into_trace_with_table(range_table_len, trace_len, NUM_RAND_ROWS);
+    // Build the trace segments of all components, which are independent of each other; the range
+    // trace segment is combined using the support lookup table
+    let ((system_trace, decoder_trace), (stack_trace, (chiplets_trace, range_check_trace))) = join(
+        || {
+            join(
+                || system.into_trace(trace_len, NUM_RAND_ROWS),
+                || decoder.into_trace(trace_len, NUM_RAND_ROWS),
+            )
+        },
+        || {
+            join(
+                || stack.into_trace(trace_len, NUM_RAND_ROWS),
+                || {
+                    join(
+                        || chiplets.into_trace(trace_len, NUM_RAND_ROWS),
+                        || range.into_trace_with_table(range_table_len, trace_len, NUM_RAND_ROWS),
+                    )
+                },
+            )
+        },
+    );
 
+    // Combine all trace segments into the main trace
     let mut trace = system_trace
         .into_iter()
         .chain(decoder_trace.trace)
@@ -0,0 +1,51 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use assembly::Assembler;
+use miden_air::trace::{AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, TRACE_WIDTH};
+use test_utils::rand::rand_array;
+use vm_core::debuginfo::DefaultSourceManager;
+
+use super::{ExecutionTrace, Felt, Trace, build_trace_from_program};
+
+/// Exercises the hasher, bitwise and memory chiplets and the range checker.
+const SOURCE: &str = "\
+begin
+    repeat.32
+        hperm
+        dup u32split u32and mem_store.100
+        padw mem_loadw.100 dropw
+    end
+end
+";
+
+/// Checks that the traces built in parallel are identical to the traces built sequentially, i.e.,
+/// on a thread pool with a single thread.
+#[test]
+fn parallel_trace_matches_sequential_trace() {
+    let program = Assembler::new(Arc::new(DefaultSourceManager::default()))
+        .assemble_program(SOURCE)
+        .unwrap();
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+
+    let build = |num_threads: usize| {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap();
+        pool.install(|| {
+            let trace = build_trace_from_program(&program, &[1, 2, 3, 4]);
+            let aux_trace = trace.build_aux_trace(&rand_elements).unwrap();
+            let aux_columns = (0..AUX_TRACE_WIDTH)
+                .map(|i| aux_trace.get_column(i).to_vec())
+                .collect::<Vec<_>>();
+            (trace, aux_columns)
+        })
+    };
+    let (sequential_trace, sequential_aux_columns) = build(1);
+    let (parallel_trace, parallel_aux_columns) = build(4);
+
+    assert_eq!(sequential_trace.length(), parallel_trace.length());
+    assert_eq!(main_columns(&sequential_trace), main_columns(&parallel_trace));
+    assert_eq!(sequential_aux_columns, parallel_aux_columns);
+}
+
+fn main_columns(trace: &ExecutionTrace) -> Vec<Vec<Felt>> {
+    (0..TRACE_WIDTH).map(|i| trace.main_segment().get_column(i).to_vec()).collect()
+}
@@ -0,0 +1,98 @@
+use alloc::{string::String, vec::Vec};
+
+use miden_air::trace::{AUX_TRACE_WIDTH, STACK_TRACE_OFFSET, TRACE_WIDTH};
+use vm_core::Operation;
+
+use super::{Trace, build_trace_from_ops};
+use crate::{TRACE_EXPORT_MAGIC, TRACE_EXPORT_VERSION, TraceExportFormat, TraceExportOptions};
+
+#[test]
+fn export_csv() {
+    let trace = build_trace_from_ops(vec![Operation::Add], &[2, 3]);
+    let mut csv = Vec::new();
+    trace
+        .export(&mut csv, TraceExportFormat::Csv, &TraceExportOptions::default())
+        .unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), trace.length() + 1);
+    let header = lines[0].split(',').collect::<Vec<_>>();
+    assert_eq!(header.len(), TRACE_WIDTH + 1);
+    assert_eq!(&header[..3], &["row", "clk", "fmp"]);
+    assert_eq!(header[STACK_TRACE_OFFSET + 1], "stack[0]");
+    assert_eq!(header[TRACE_WIDTH], "chiplets[17]");
+
+    // the result of the addition is at the top of the stack after the `add` operation
+    let row = lines[3].split(',').collect::<Vec<_>>();
+    assert_eq!(row[0], "2");
+    assert_eq!(row[1], "2");
+    assert_eq!(row[STACK_TRACE_OFFSET + 1], "5");
+}
+
+#[test]
+fn export_rows_and_aux_trace() {
+    let trace = build_trace_from_ops(vec![Operation::Add], &[2, 3]);
+    let options = TraceExportOptions::default().with_rows(1..4).with_aux_trace();
+    let mut csv = Vec::new();
+    trace.export(&mut csv, TraceExportFormat::Csv, &options).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    let header = lines[0].split(',').collect::<Vec<_>>();
+    assert_eq!(header.len(), TRACE_WIDTH + 2 * AUX_TRACE_WIDTH + 1);
+    assert_eq!(&header[TRACE_WIDTH + 1..TRACE_WIDTH + 3], &["decoder::p1.0", "decoder::p1.1"]);
+    assert!(lines[1].starts_with("1,1,"));
+    assert!(lines[3].starts_with("3,3,"));
+
+    // empty and out of bounds row ranges are rejected
+    for rows in [2..2, 0..trace.length() + 1] {
+        let options = TraceExportOptions::default().with_rows(rows);
+        assert!(trace.export(&mut Vec::new(), TraceExportFormat::Csv, &options).is_err());
+    }
+}
+
+#[test]
+fn export_binary() {
+    let trace = build_trace_from_ops(vec![Operation::Add], &[2, 3]);
+    let options = TraceExportOptions::default().with_rows(2..6);
+    let mut bytes = Vec::new();
+    trace.export(&mut bytes, TraceExportFormat::Binary, &options).unwrap();
+
+    assert_eq!(&bytes[..4], TRACE_EXPORT_MAGIC);
+    assert_eq!(bytes[4], TRACE_EXPORT_VERSION);
+    assert_eq!(read_u32(&bytes, 5) as usize, TRACE_WIDTH);
+    assert_eq!(read_u64(&bytes, 9), 2);
+    assert_eq!(read_u64(&bytes, 17), 4);
+
+    // read the column names
+    let mut offset = 25;
+    let mut names = Vec::new();
+    for _ in 0..TRACE_WIDTH {
+        let len = u16::from_le_bytes([bytes[offset], bytes[offset + 1]]) as usize;
+        names.push(String::from_utf8(bytes[offset + 2..offset + 2 + len].to_vec()).unwrap());
+        offset += 2 + len;
+    }
+    assert_eq!(names[0], "clk");
+    assert_eq!(bytes.len(), offset + TRACE_WIDTH * 4 * 8);
+
+    // the clock column holds the indices of the exported rows
+    let clk = (0..4).map(|row| read_u64(&bytes, offset + row * 8)).collect::<Vec<_>>();
+    assert_eq!(clk, [2, 3, 4, 5]);
+
+    // the top of the stack holds the result of the addition after the `add` operation
+    let stack_top = offset + STACK_TRACE_OFFSET * 4 * 8;
+    assert_eq!(read_u64(&bytes, stack_top), 5);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
@@ -10,7 +10,10 @@ use super::{
 use crate::{AdviceInputs, DefaultHost, ExecutionOptions, MemAdviceProvider, StackInputs};
 
 mod chiplets;
+#[cfg(feature = "concurrent")]
+mod concurrent;
 mod decoder;
+mod export;
 mod hasher;
 mod range;
 mod stack;
@@ -248,20 +248,38 @@ pub trait AuxColumnBuilder<E: FieldElement<BaseField = Felt>> {
 
     /// Builds the chiplets bus auxiliary trace column.
     fn build_aux_column(&self, main_trace: &MainTrace, alphas: &[E]) -> Vec<E> {
+        let mut bus_debugger = BusDebugger::new("chiplets bus".to_string());
+        let result_aux_column =
+            self.build_aux_column_with_debugger(main_trace, alphas, &mut bus_debugger);
+
+        #[cfg(any(test, feature = "bus-debugger"))]
+        assert!(bus_debugger.is_empty(), "{bus_debugger}");
+
+        result_aux_column
+    }
+
+    /// Builds the auxiliary trace column, recording the messages sent on the bus into the
+    /// provided debugger instead of checking that all of them are matched.
+    ///
+    /// Messages are only recorded in tests, or when the `bus-debugger` feature is enabled.
+    fn build_aux_column_with_debugger(
+        &self,
+        main_trace: &MainTrace,
+        alphas: &[E],
+        bus_debugger: &mut BusDebugger<E>,
+    ) -> Vec<E> {
         let mut responses_prod: Vec<E> = unsafe { uninit_vector(main_trace.num_rows()) };
         let mut requests: Vec<E> = unsafe { uninit_vector(main_trace.num_rows()) };
-        let mut bus_debugger = BusDebugger::new("chiplets bus".to_string());
 
-        responses_prod[0] = self.init_responses(main_trace, alphas, &mut bus_debugger);
-        requests[0] = self.init_requests(main_trace, alphas, &mut bus_debugger);
+        responses_prod[0] = self.init_responses(main_trace, alphas, bus_debugger);
+        requests[0] = self.init_requests(main_trace, alphas, bus_debugger);
 
         let mut requests_running_prod = requests[0];
         for row_idx in 0..main_trace.num_rows() - 1 {
             let row = row_idx.into();
             responses_prod[row_idx + 1] = responses_prod[row_idx]
-                * self.get_responses_at(main_trace, alphas, row, &mut bus_debugger);
-            requests[row_idx + 1] =
-                self.get_requests_at(main_trace, alphas, row, &mut bus_debugger);
+                * self.get_responses_at(main_trace, alphas, row, bus_debugger);
+            requests[row_idx + 1] = self.get_requests_at(main_trace, alphas, row, bus_debugger);
             requests_running_prod *= requests[row_idx + 1];
         }
 
@@ -272,9 +290,6 @@ pub trait AuxColumnBuilder<E: FieldElement<BaseField = Felt>> {
             requests_running_divisor *= requests[i];
         }
 
-        #[cfg(any(test, feature = "bus-debugger"))]
-        assert!(bus_debugger.is_empty(), "{bus_debugger}");
-
         result_aux_column
     }
 }
@@ -9,6 +9,27 @@ use super::Felt;
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Runs the two provided closures and returns their results.
+///
+/// When the `concurrent` feature is enabled, the closures may run in parallel on the rayon thread
+/// pool. Otherwise, they run one after the other.
+pub(crate) fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    #[cfg(feature = "concurrent")]
+    {
+        rayon::join(a, b)
+    }
+    #[cfg(not(feature = "concurrent"))]
+    {
+        (a(), b())
+    }
+}
+
 /// Returns the number of rows in the provided execution trace assumed to be in column-major form
 /// and contain at least one column.
 pub(crate) fn get_trace_len(trace: &[Vec<Felt>]) -> usize {
@@ -14,8 +14,8 @@ use miden_gpu::HashFn;
 use processor::{
     ExecutionTrace, Program,
     crypto::{
-        Blake3_192, Blake3_256, ElementHasher, RandomCoin, Rpo256, RpoRandomCoin, Rpx256,
-        RpxRandomCoin, WinterRandomCoin,
+        Blake3_192, Blake3_256, ElementHasher, Poseidon2, RandomCoin, Rpo256, RpoRandomCoin,
+        Rpx256, RpxRandomCoin, WinterRandomCoin,
     },
     math::{Felt, FieldElement},
 };
@@ -34,7 +34,10 @@ mod gpu;
 // EXPORTS
 // ================================================================================================
 
-pub use air::{DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProvingOptions};
+pub use air::{
+    DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProofBundle, ProofMetadata,
+    ProvingOptions,
+};
 pub use processor::{
     AdviceInputs, Digest, ExecutionError, Host, InputError, MemAdviceProvider, StackInputs,
     StackOutputs, Word, crypto, math, utils,
@@ -118,6 +121,14 @@ pub fn prove(
             let prover = gpu::metal::MetalExecutionProver::new(prover, HashFn::Rpx256);
             maybe_await!(prover.prove(trace))
         },
+        HashFunction::Poseidon2 => {
+            let prover = ExecutionProver::<Poseidon2, WinterRandomCoin<_>>::new(
+                options,
+                stack_inputs,
+                stack_outputs.clone(),
+            );
+            maybe_await!(prover.prove(trace))
+        },
     }
     .map_err(ExecutionError::ProverError)?;
     let proof = ExecutionProof::new(proof, hash_fn);
@@ -6,6 +6,7 @@ mod ecdsa_secp256k1;
 mod elgamal;
 mod fri;
 mod keccak256;
+mod poseidon2;
 mod rpo;
 mod sha256;
 mod stark;
@@ -0,0 +1,128 @@
+use processor::crypto::{Poseidon2, Poseidon2Digest};
+use test_utils::{
+    Felt,
+    rand::{rand_array, rand_vector},
+};
+
+/// The result of applying the permutation to the state [0, 1, ..., 11], as published with the
+/// reference implementation of Poseidon2 for the Goldilocks field with a state width of 12.
+const PERMUTATION_OUTPUT: [u64; 12] = [
+    0x01eaef96bdf1c0c1,
+    0x1f0d2cc525b2540c,
+    0x6282c1dfe1e0358d,
+    0xe780d721f698e1e6,
+    0x280c0b6f753d833b,
+    0x1b942dd5023156ab,
+    0x43f0df3fcccb8398,
+    0xe8e8190585489025,
+    0x56bdbf72f77ada22,
+    0x7911c32bf9dcd705,
+    0xec467926508fbe67,
+    0x6a50450ddf85a6ed,
+];
+
+#[test]
+fn permute() {
+    let state = rand_array::<Felt, 12>();
+    let mut expected = state;
+    Poseidon2::apply_permutation(&mut expected);
+    build_test!(&permute_source(&state), &[]).expect_stack(&to_stack(&expected));
+}
+
+#[test]
+fn permute_test_vector() {
+    let state: [Felt; 12] = core::array::from_fn(|i| Felt::new(i as u64));
+    let expected = PERMUTATION_OUTPUT.map(Felt::new);
+    build_test!(&permute_source(&state), &[]).expect_stack(&to_stack(&expected));
+}
+
+#[test]
+fn merge() {
+    let digests = [Poseidon2Digest::new(rand_array()), Poseidon2Digest::new(rand_array())];
+    let source = format!(
+        "
+    use.std::crypto::hashes::poseidon2
+
+    begin
+        {} {}
+        exec.poseidon2::merge
+
+        # truncate stack
+        repeat.4 movup.4 drop end
+    end",
+        push_elements(digests[0].as_elements()),
+        push_elements(digests[1].as_elements())
+    );
+
+    let expected = Poseidon2::merge(&digests);
+    build_test!(&source, &[]).expect_stack(&to_stack(expected.as_elements()));
+}
+
+#[test]
+fn hash_memory_words() {
+    let input = rand_vector::<Felt>(16);
+    for num_words in [0, 1, 2, 3, 4] {
+        let elements = &input[..num_words * 4];
+        let store_elements = elements
+            .iter()
+            .enumerate()
+            .map(|(i, element)| format!("push.{}.{} mem_store", element.as_int(), 1000 + i))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let source = format!(
+            "
+        use.std::crypto::hashes::poseidon2
+
+        begin
+            {store_elements}
+
+            push.{} # end address
+            push.1000 # start address
+
+            exec.poseidon2::hash_memory_words
+
+            # truncate stack
+            swapw dropw
+        end",
+            1000 + elements.len()
+        );
+
+        let expected = Poseidon2::hash_elements(elements);
+        build_test!(&source, &[]).expect_stack(&to_stack(expected.as_elements()));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a program which applies the permutation to the provided state.
+fn permute_source(state: &[Felt; 12]) -> String {
+    format!(
+        "
+    use.std::crypto::hashes::poseidon2
+
+    begin
+        {}
+        exec.poseidon2::permute
+
+        # truncate stack
+        repeat.12 movup.12 drop end
+    end",
+        push_elements(state)
+    )
+}
+
+/// Returns the MASM code which pushes the provided elements onto the stack, such that the last
+/// element is on top of the stack.
+fn push_elements(elements: &[Felt]) -> String {
+    elements
+        .iter()
+        .map(|element| format!("push.{}", element.as_int()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the provided elements in stack order, i.e., with the last element on top of the stack.
+fn to_stack(elements: &[Felt]) -> Vec<u64> {
+    elements.iter().rev().map(|element| element.as_int()).collect()
+}
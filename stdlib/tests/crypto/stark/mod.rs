@@ -1,11 +1,10 @@
-mod verifier_recursive;
 use assembly::Assembler;
 use miden_air::{FieldExtension, HashFunction, PublicInputs};
 use processor::{DefaultHost, Program, ProgramInfo};
 use test_utils::{
     AdviceInputs, MemAdviceProvider, ProvingOptions, StackInputs, VerifierError, prove,
 };
-use verifier_recursive::{VerifierData, generate_advice_inputs};
+use verifier::recursive::{VerifierData, generate_advice_inputs};
 
 // Note: Changes to Miden VM may cause this test to fail when some of the assumptions documented
 // in `stdlib/asm/crypto/stark/verifier.masm` are violated.
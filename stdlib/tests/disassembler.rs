@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use assembly::{
+    Assembler, DefaultSourceManager, Disassembler,
+    ast::{Module, ModuleKind},
+};
+
+/// Tests that the disassembled stdlib assembles back into procedures with the same MAST roots.
+///
+/// This test is added here since the stdlib is not available in miden-assembly where the
+/// disassembler is implemented, and it exercises most of the instruction set.
+#[test]
+fn disassemble_stdlib() {
+    let std_lib = miden_stdlib::StdLibrary::default();
+    let modules = Disassembler::new().disassemble_library(std_lib.as_ref());
+
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let mut lossy_modules = Vec::new();
+    let parsed = modules
+        .iter()
+        .map(|module| {
+            if !module.is_lossless() {
+                lossy_modules.push(module.path().clone());
+            }
+            Module::parser(ModuleKind::Library)
+                .parse_str(module.path().clone(), module.source(), source_manager.as_ref())
+                .unwrap_or_else(|err| panic!("failed to parse {}: {err:?}", module.path()))
+        })
+        .collect::<Vec<_>>();
+    assert!(lossy_modules.is_empty(), "lossy disassembly of {lossy_modules:?}");
+
+    let reassembled = Assembler::new(source_manager).assemble_library(parsed).unwrap();
+    for proc_name in std_lib.as_ref().exports() {
+        let expected =
+            std_lib.mast_forest()[std_lib.as_ref().get_export_node_id(proc_name)].digest();
+        let actual = reassembled.mast_forest()[reassembled.get_export_node_id(proc_name)].digest();
+        assert_eq!(expected, actual, "MAST root mismatch for {proc_name}");
+    }
+}
@@ -18,8 +18,8 @@ pub use assembly::{LibraryPath, SourceFile, SourceManager, diagnostics::Report};
 pub use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
 use processor::Program;
 pub use processor::{
-    AdviceInputs, AdviceProvider, ContextId, ExecutionError, ExecutionOptions, ExecutionTrace,
-    Process, ProcessState, VmStateIterator,
+    AdviceInputs, AdviceProvider, ContextId, CoverageData, CoverageReport, ExecutionError,
+    ExecutionOptions, ExecutionTrace, Process, ProcessState, VmStateIterator,
 };
 #[cfg(not(target_family = "wasm"))]
 use proptest::prelude::{Arbitrary, Strategy};
@@ -345,6 +345,34 @@ impl Test {
         )
     }
 
+    /// Compiles the test's source to a Program and executes it with the tests inputs, merging the
+    /// coverage of the execution into `coverage`. Returns a resulting execution trace or error.
+    ///
+    /// Coverage is only recorded for code assembled in debug mode, so the test should be built
+    /// with `build_debug_test!`. A report can be built from the coverage data using the test's
+    /// source manager, e.g. `CoverageReport::new(&coverage, test.source_manager.as_ref())`.
+    #[track_caller]
+    pub fn execute_with_coverage(
+        &self,
+        coverage: &mut CoverageData,
+    ) -> Result<ExecutionTrace, ExecutionError> {
+        let (program, kernel) = self.compile().expect("Failed to compile test source.");
+        let mut host = TestHost::new(MemAdviceProvider::from(self.advice_inputs.clone()));
+        if let Some(kernel) = kernel {
+            host.load_mast_forest(kernel.mast_forest().clone()).unwrap();
+        }
+        for library in &self.libraries {
+            host.load_mast_forest(library.mast_forest().clone()).unwrap();
+        }
+        processor::execute_with_coverage(
+            &program,
+            self.stack_inputs.clone(),
+            &mut host,
+            ExecutionOptions::default(),
+            coverage,
+        )
+    }
+
     /// Compiles the test's source to a Program and executes it with the tests inputs. Returns the
     /// process once execution is finished.
     pub fn execute_process(&self) -> Result<(Process, TestHost), ExecutionError> {
@@ -5,11 +5,9 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
-use alloc::vec;
-
-use air::{HashFunction, ProcessorAir, ProvingOptions, PublicInputs};
+use air::{ProcessorAir, PublicInputs};
 use vm_core::crypto::{
-    hash::{Blake3_192, Blake3_256, Rpo256, Rpx256},
+    hash::{Blake3_192, Blake3_256, Poseidon2, Rpo256, Rpx256},
     random::{RpoRandomCoin, RpxRandomCoin, WinterRandomCoin},
 };
 // EXPORTS
@@ -20,7 +18,12 @@ use winter_verifier::{crypto::MerkleTree, verify as verify_proof};
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
 }
-pub use air::ExecutionProof;
+pub use air::{ExecutionProof, HashFunction, ProofBundle, ProofMetadata, ProvingOptions};
+
+mod policy;
+pub use policy::VerifierPolicy;
+
+pub mod recursive;
 
 // VERIFIER
 // ================================================================================================
@@ -47,53 +50,74 @@ pub use air::ExecutionProof;
 /// - 128-bit security level, non-recursive context (RPO hash function).
 /// - 128-bit security level, recursive context (RPO hash function).
 ///
+/// To accept proofs generated using other parameters, use [verify_with_policy].
+///
 /// # Errors
 /// Returns an error if:
 /// - The provided proof does not prove a correct execution of the program.
 /// - The protocol parameters used to generate the proof are not in the set of acceptable
 ///   parameters.
-#[tracing::instrument("verify_program", skip_all)]
 pub fn verify(
     program_info: ProgramInfo,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
     proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    verify_with_policy(program_info, stack_inputs, stack_outputs, proof, &VerifierPolicy::default())
+}
+
+/// Returns the security level of the proof if the specified program was executed correctly against
+/// the specified inputs and outputs, and the proof is acceptable according to the provided
+/// [VerifierPolicy].
+///
+/// See [verify] for the expected order of the stack inputs and outputs.
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided proof does not prove a correct execution of the program.
+/// - The proof was generated using a hash function not allowed by the policy.
+/// - The protocol parameters used to generate the proof are not acceptable according to the
+///   policy.
+#[tracing::instrument("verify_program", skip_all)]
+pub fn verify_with_policy(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+    policy: &VerifierPolicy,
 ) -> Result<u32, VerificationError> {
     // get security level of the proof
     let security_level = proof.security_level();
     let program_hash = *program_info.program_hash();
 
+    let hash_fn = proof.hash_fn();
+    if !policy.is_hash_fn_allowed(hash_fn) {
+        return Err(VerificationError::HashFunctionNotAllowed(hash_fn));
+    }
+    let opts = policy.acceptable_options(hash_fn);
+
     // build public inputs and try to verify the proof
     let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
     let (hash_fn, proof) = proof.into_parts();
     match hash_fn {
         HashFunction::Blake3_192 => {
-            let opts = AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_96_BITS]);
             verify_proof::<ProcessorAir, Blake3_192, WinterRandomCoin<_>, MerkleTree<_>>(
                 proof, pub_inputs, &opts,
             )
         },
         HashFunction::Blake3_256 => {
-            let opts = AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_128_BITS]);
             verify_proof::<ProcessorAir, Blake3_256, WinterRandomCoin<_>, MerkleTree<_>>(
                 proof, pub_inputs, &opts,
             )
         },
-        HashFunction::Rpo256 => {
-            let opts = AcceptableOptions::OptionSet(vec![
-                ProvingOptions::RECURSIVE_96_BITS,
-                ProvingOptions::RECURSIVE_128_BITS,
-            ]);
-            verify_proof::<ProcessorAir, Rpo256, RpoRandomCoin, MerkleTree<_>>(
-                proof, pub_inputs, &opts,
-            )
-        },
-        HashFunction::Rpx256 => {
-            let opts = AcceptableOptions::OptionSet(vec![
-                ProvingOptions::RECURSIVE_96_BITS,
-                ProvingOptions::RECURSIVE_128_BITS,
-            ]);
-            verify_proof::<ProcessorAir, Rpx256, RpxRandomCoin, MerkleTree<_>>(
+        HashFunction::Rpo256 => verify_proof::<ProcessorAir, Rpo256, RpoRandomCoin, MerkleTree<_>>(
+            proof, pub_inputs, &opts,
+        ),
+        HashFunction::Rpx256 => verify_proof::<ProcessorAir, Rpx256, RpxRandomCoin, MerkleTree<_>>(
+            proof, pub_inputs, &opts,
+        ),
+        HashFunction::Poseidon2 => {
+            verify_proof::<ProcessorAir, Poseidon2, WinterRandomCoin<_>, MerkleTree<_>>(
                 proof, pub_inputs, &opts,
             )
         },
@@ -115,4 +139,8 @@ pub enum VerificationError {
     InputNotFieldElement(u64),
     #[error("the output {0} is not a valid field element")]
     OutputNotFieldElement(u64),
+    #[error(
+        "proofs generated using the {0:?} hash function are not accepted by the verifier policy"
+    )]
+    HashFunctionNotAllowed(HashFunction),
 }
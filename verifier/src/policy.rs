@@ -0,0 +1,132 @@
+use alloc::{vec, vec::Vec};
+
+use air::{HashFunction, ProvingOptions};
+use winter_verifier::AcceptableOptions;
+
+// VERIFIER POLICY
+// ================================================================================================
+
+/// All hash functions which can be used to generate execution proofs.
+const ALL_HASH_FUNCTIONS: [HashFunction; 5] = [
+    HashFunction::Blake3_192,
+    HashFunction::Blake3_256,
+    HashFunction::Rpo256,
+    HashFunction::Rpx256,
+    HashFunction::Poseidon2,
+];
+
+/// Specifies which execution proofs are acceptable to the verifier, see
+/// [verify_with_policy](crate::verify_with_policy).
+///
+/// A policy consists of a security requirement on the parameters of a proof, and of a list of hash
+/// functions with which the proof may have been generated. The default policy accepts proofs
+/// generated with any hash function using one of the standard parameter sets defined in
+/// [ProvingOptions] (see [verify](crate::verify)).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifierPolicy {
+    requirement: SecurityRequirement,
+    hash_fns: Vec<HashFunction>,
+}
+
+impl VerifierPolicy {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a policy which accepts proofs with a conjectured security level of at least the
+    /// specified number of bits.
+    pub fn min_conjectured_security(bits: u32) -> Self {
+        Self::with_requirement(SecurityRequirement::MinConjecturedSecurity(bits))
+    }
+
+    /// Returns a policy which accepts proofs with a proven security level of at least the specified
+    /// number of bits.
+    pub fn min_proven_security(bits: u32) -> Self {
+        Self::with_requirement(SecurityRequirement::MinProvenSecurity(bits))
+    }
+
+    /// Returns a policy which accepts only proofs generated using one of the specified options.
+    ///
+    /// A proof is accepted if its parameters are equal to the parameters of one of the specified
+    /// options which use the same hash function as the proof.
+    pub fn option_set(options: Vec<ProvingOptions>) -> Self {
+        Self::with_requirement(SecurityRequirement::OptionSet(options))
+    }
+
+    /// Restricts this policy to accept only proofs generated using one of the specified hash
+    /// functions.
+    pub fn with_hash_functions(mut self, hash_fns: impl IntoIterator<Item = HashFunction>) -> Self {
+        self.hash_fns = hash_fns.into_iter().collect();
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if this policy accepts proofs generated using the specified hash function.
+    pub fn is_hash_fn_allowed(&self, hash_fn: HashFunction) -> bool {
+        self.hash_fns.contains(&hash_fn)
+    }
+
+    /// Returns the proof options acceptable by this policy for proofs generated using the specified
+    /// hash function.
+    pub(crate) fn acceptable_options(&self, hash_fn: HashFunction) -> AcceptableOptions {
+        match &self.requirement {
+            SecurityRequirement::Standard => match hash_fn {
+                HashFunction::Blake3_192 => {
+                    AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_96_BITS])
+                },
+                HashFunction::Blake3_256 => {
+                    AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_128_BITS])
+                },
+                HashFunction::Rpo256 | HashFunction::Rpx256 | HashFunction::Poseidon2 => {
+                    AcceptableOptions::OptionSet(vec![
+                        ProvingOptions::RECURSIVE_96_BITS,
+                        ProvingOptions::RECURSIVE_128_BITS,
+                    ])
+                },
+            },
+            SecurityRequirement::MinConjecturedSecurity(bits) => {
+                AcceptableOptions::MinConjecturedSecurity(*bits)
+            },
+            SecurityRequirement::MinProvenSecurity(bits) => {
+                AcceptableOptions::MinProvenSecurity(*bits)
+            },
+            SecurityRequirement::OptionSet(options) => AcceptableOptions::OptionSet(
+                options
+                    .iter()
+                    .filter(|options| options.hash_fn() == hash_fn)
+                    .map(|options| options.clone().into())
+                    .collect(),
+            ),
+        }
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn with_requirement(requirement: SecurityRequirement) -> Self {
+        Self {
+            requirement,
+            hash_fns: ALL_HASH_FUNCTIONS.to_vec(),
+        }
+    }
+}
+
+impl Default for VerifierPolicy {
+    fn default() -> Self {
+        Self::with_requirement(SecurityRequirement::Standard)
+    }
+}
+
+// SECURITY REQUIREMENT
+// ================================================================================================
+
+/// A requirement on the parameters of the proofs accepted by a [VerifierPolicy].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SecurityRequirement {
+    /// One of the standard parameter sets for the hash function of the proof.
+    Standard,
+    MinConjecturedSecurity(u32),
+    MinProvenSecurity(u32),
+    OptionSet(Vec<ProvingOptions>),
+}
@@ -1,19 +1,25 @@
-use alloc::vec::Vec;
-
-use miden_air::ProcessorAir;
-use test_utils::{
-    Felt, MerkleTreeVC, VerifierError,
-    crypto::{BatchMerkleProof, PartialMerkleTree, Rpo256, RpoDigest},
-    group_slice_elements,
-    math::{FieldElement, QuadExtension, StarkField},
+use alloc::{borrow::ToOwned, format, string::ToString, vec, vec::Vec};
+
+use air::ProcessorAir;
+use vm_core::{
+    Felt, FieldElement, StarkField,
+    crypto::{
+        hash::{Rpo256, RpoDigest},
+        merkle::PartialMerkleTree,
+    },
+    utils::group_slice_elements,
 };
 use winter_air::{
     Air,
     proof::{Proof, Queries, Table, TraceOodFrame},
 };
 use winter_fri::{VerifierChannel as FriVerifierChannel, folding::fold_positions};
+use winter_verifier::{
+    VerifierError,
+    crypto::{BatchMerkleProof, MerkleTree},
+};
 
-pub type QuadExt = QuadExtension<Felt>;
+use super::QuadExt;
 
 type AdvMap = Vec<(RpoDigest, Vec<Felt>)>;
 
@@ -85,7 +91,7 @@ impl VerifierChannel {
             .parse_remainder()
             .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
         let (fri_layer_queries, fri_layer_proofs) = fri_proof
-            .parse_layers::<QuadExt, Rpo256, MerkleTreeVC<Rpo256>>(
+            .parse_layers::<QuadExt, Rpo256, MerkleTree<Rpo256>>(
                 lde_domain_size,
                 fri_options.folding_factor(),
             )
@@ -260,7 +266,7 @@ impl VerifierChannel {
 
 impl FriVerifierChannel<QuadExt> for VerifierChannel {
     type Hasher = Rpo256;
-    type VectorCommitment = MerkleTreeVC<Self::Hasher>;
+    type VectorCommitment = MerkleTree<Self::Hasher>;
 
     fn read_fri_num_partitions(&self) -> usize {
         self.fri_num_partitions
@@ -310,7 +316,7 @@ impl TraceQueries {
         let main_segment_width = air.trace_info().main_trace_width();
         let main_segment_queries = queries.remove(0);
         let (main_segment_query_proofs, main_segment_states) = main_segment_queries
-            .parse::<Felt, Rpo256, MerkleTreeVC<Rpo256>>(
+            .parse::<Felt, Rpo256, MerkleTree<Rpo256>>(
                 air.lde_domain_size(),
                 num_queries,
                 main_segment_width,
@@ -332,7 +338,7 @@ impl TraceQueries {
             let aux_segment_queries = queries.remove(0);
 
             let (segment_query_proof, segment_trace_states) = aux_segment_queries
-                .parse::<QuadExt, Rpo256, MerkleTreeVC<Rpo256>>(
+                .parse::<QuadExt, Rpo256, MerkleTree<Rpo256>>(
                     air.lde_domain_size(),
                     num_queries,
                     segment_width,
@@ -378,7 +384,7 @@ impl ConstraintQueries {
         num_queries: usize,
     ) -> Result<Self, VerifierError> {
         let (query_proofs, evaluations) = queries
-            .parse::<QuadExt, Rpo256, MerkleTreeVC<Rpo256>>(
+            .parse::<QuadExt, Rpo256, MerkleTree<Rpo256>>(
                 air.lde_domain_size(),
                 num_queries,
                 air.ce_blowup_factor(),
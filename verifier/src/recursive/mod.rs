@@ -1,32 +1,51 @@
-use alloc::vec::Vec;
-
-use miden_air::ProcessorAir;
-use processor::crypto::RpoRandomCoin;
-use test_utils::{
-    VerifierError,
-    crypto::{MerkleStore, RandomCoin, Rpo256, RpoDigest},
-    math::{FieldElement, QuadExtension, ToElements},
+//! Helpers for verifying Miden VM proofs inside Miden VM.
+//!
+//! The recursive STARK verifier of the standard library (`std::crypto::stark::verifier`) reads the
+//! proof to be verified from the advice provider. This module converts a proof into the data which
+//! the verifier expects on the operand stack and in the advice provider.
+
+use alloc::{vec, vec::Vec};
+
+use air::{ProcessorAir, PublicInputs};
+use vm_core::{
+    Felt, FieldElement, QuadExtension, ToElements,
+    crypto::{
+        hash::{Rpo256, RpoDigest},
+        merkle::MerkleStore,
+        random::RpoRandomCoin,
+    },
 };
-use vm_core::Felt;
 use winter_air::{Air, proof::Proof};
 use winter_fri::VerifierChannel as FriVerifierChannel;
+use winter_verifier::{VerifierError, crypto::RandomCoin};
 
 mod channel;
 use channel::VerifierChannel;
 
-pub type QuadExt = QuadExtension<Felt>;
+type QuadExt = QuadExtension<Felt>;
 
+// VERIFIER DATA
+// ================================================================================================
+
+/// The data required by `std::crypto::stark::verifier::verify` to verify a single proof.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct VerifierData {
+    /// The proof parameters expected on the operand stack, with the top of the stack last.
     pub initial_stack: Vec<u64>,
+    /// The proof commitments, out-of-domain evaluations and FRI remainder, in the order in which
+    /// the verifier reads them from the advice stack.
     pub advice_stack: Vec<u64>,
+    /// The Merkle paths of the queried trace, constraint and FRI layer positions.
     pub store: MerkleStore,
+    /// The queried trace, constraint and FRI layer values, keyed by their leaf digests.
     pub advice_map: Vec<(RpoDigest, Vec<Felt>)>,
 }
 
+/// Builds the data which the recursive STARK verifier reads from the operand stack and the
+/// advice provider in order to verify the provided proof against the provided public inputs.
 pub fn generate_advice_inputs(
     proof: Proof,
-    pub_inputs: <ProcessorAir as Air>::PublicInputs,
+    pub_inputs: PublicInputs,
 ) -> Result<VerifierData, VerifierError> {
     // we need to provide the following instance specific data through the operand stack
     let initial_stack = vec![
@@ -49,7 +68,7 @@ pub fn generate_advice_inputs(
     advice_stack.extend_from_slice(&pub_inputs_int[..]);
 
     // create AIR instance for the computation specified in the proof
-    let air = ProcessorAir::new(proof.trace_info().to_owned(), pub_inputs, proof.options().clone());
+    let air = ProcessorAir::new(proof.trace_info().clone(), pub_inputs, proof.options().clone());
     let seed_digest = Rpo256::hash_elements(&public_coin_seed);
     let mut public_coin: RpoRandomCoin = RpoRandomCoin::new(seed_digest.into());
     let mut channel = VerifierChannel::new(&air, proof)?;
@@ -190,13 +209,13 @@ pub fn generate_advice_inputs(
 // HELPER FUNCTIONS
 // ================================================================================================
 
-pub fn digest_to_int_vec(digest: &[RpoDigest]) -> Vec<u64> {
+fn digest_to_int_vec(digest: &[RpoDigest]) -> Vec<u64> {
     digest
         .iter()
         .flat_map(|digest| digest.as_elements().iter().map(|e| e.as_int()))
         .collect()
 }
 
-pub fn to_int_vec(ext_felts: &[QuadExt]) -> Vec<u64> {
+fn to_int_vec(ext_felts: &[QuadExt]) -> Vec<u64> {
     QuadExt::slice_as_base_elements(ext_felts).iter().map(|e| e.as_int()).collect()
 }